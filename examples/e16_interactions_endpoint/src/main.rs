@@ -1,5 +1,5 @@
 use serenity::builder::*;
-use serenity::interactions_endpoint::Verifier;
+use serenity::interactions_endpoint::{parse_interaction, Verifier};
 use serenity::model::application::*;
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
@@ -36,7 +36,7 @@ fn handle_request(
     }
 
     // Build Discord response
-    let response = match serde_json::from_slice::<Interaction>(body)? {
+    let response = match parse_interaction(body)? {
         // Discord rejects the interaction endpoints URL if pings are not acknowledged
         Interaction::Ping(_) => CreateInteractionResponse::Pong,
         Interaction::Command(interaction) => handle_command(interaction),
@@ -93,6 +93,9 @@ pub fn print_ranking() {
         ("GuildWelcomeChannel", std::mem::size_of::<GuildWelcomeChannel>()),
         ("GuildWelcomeScreen", std::mem::size_of::<GuildWelcomeScreen>()),
         ("GuildWidget", std::mem::size_of::<GuildWidget>()),
+        ("GuildWidgetChannel", std::mem::size_of::<GuildWidgetChannel>()),
+        ("GuildWidgetMember", std::mem::size_of::<GuildWidgetMember>()),
+        ("GuildWidgetSettings", std::mem::size_of::<GuildWidgetSettings>()),
         ("Incident", std::mem::size_of::<Incident>()),
         ("IncidentUpdate", std::mem::size_of::<IncidentUpdate>()),
         ("InstallParams", std::mem::size_of::<InstallParams>()),
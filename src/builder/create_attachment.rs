@@ -1,14 +1,23 @@
 use std::borrow::Cow;
+use std::fmt;
 use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+#[cfg(feature = "http")]
+use std::time::Duration;
 
+#[cfg(feature = "http")]
+use reqwest::header::CONTENT_TYPE;
+#[cfg(feature = "http")]
+use reqwest::redirect::Policy;
 use serde::ser::{Serialize, SerializeSeq, Serializer};
 use tokio::fs::File;
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncRead, AsyncReadExt};
 
 #[allow(unused)] // Error is used in docs
 use crate::error::{Error, Result};
 #[cfg(feature = "http")]
-use crate::http::Http;
+use crate::http::{AttachmentDownloadError, Http, HttpError};
 use crate::model::channel::Message;
 use crate::model::id::AttachmentId;
 
@@ -22,6 +31,32 @@ pub struct CreateAttachment<'a> {
     pub filename: Cow<'static, str>,
     pub description: Option<Cow<'a, str>>,
     pub data: Cow<'static, [u8]>,
+    pub(crate) reader: Option<AttachmentReader>,
+}
+
+pub(crate) type BoxedReader = Pin<Box<dyn AsyncRead + Send>>;
+
+/// A reader which is streamed into the request body instead of [`CreateAttachment::data`], see
+/// [`CreateAttachment::from_reader`].
+///
+/// The reader is shared between clones, as requests are cloned before being sent, and can only
+/// be taken once.
+#[derive(Clone)]
+pub(crate) struct AttachmentReader {
+    reader: Arc<parking_lot::Mutex<Option<BoxedReader>>>,
+    pub(crate) len: u64,
+}
+
+impl AttachmentReader {
+    pub(crate) fn take(&self) -> Option<BoxedReader> {
+        self.reader.lock().take()
+    }
+}
+
+impl fmt::Debug for AttachmentReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AttachmentReader").field("len", &self.len).finish_non_exhaustive()
+    }
 }
 
 impl<'a> CreateAttachment<'a> {
@@ -34,6 +69,50 @@ impl<'a> CreateAttachment<'a> {
             data: data.into(),
             filename: filename.into(),
             description: None,
+            reader: None,
+        }
+    }
+
+    /// Builds an [`CreateAttachment`] which streams its data from the given reader when the
+    /// request is sent, instead of holding it all in memory. `len` must be the exact number of
+    /// bytes the reader will produce.
+    ///
+    /// The reader can only be read once. If the request has to be retried, for example because it
+    /// was ratelimited, sending it fails with [`HttpError::AttachmentReaderConsumed`]. Streamed
+    /// attachments also cannot be converted with [`Self::to_base64`], so they can only be used for
+    /// message attachments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::ChannelId;
+    /// # async fn run(http: &Http, channel_id: ChannelId) -> serenity::Result<()> {
+    /// use serenity::builder::{CreateAttachment, CreateMessage};
+    ///
+    /// let file = tokio::fs::File::open("video.mp4").await?;
+    /// let len = file.metadata().await?.len();
+    ///
+    /// let attachment = CreateAttachment::from_reader(file, "video.mp4", len);
+    /// channel_id.send_message(http, CreateMessage::new().add_file(attachment)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`HttpError::AttachmentReaderConsumed`]: crate::http::HttpError::AttachmentReaderConsumed
+    pub fn from_reader(
+        reader: impl AsyncRead + Send + 'static,
+        filename: impl Into<Cow<'static, str>>,
+        len: u64,
+    ) -> Self {
+        CreateAttachment {
+            data: Cow::Borrowed(&[]),
+            filename: filename.into(),
+            description: None,
+            reader: Some(AttachmentReader {
+                reader: Arc::new(parking_lot::Mutex::new(Some(Box::pin(reader)))),
+                len,
+            }),
         }
     }
 
@@ -75,6 +154,9 @@ impl<'a> CreateAttachment<'a> {
 
     /// Builds an [`CreateAttachment`] by downloading attachment data from a URL.
     ///
+    /// The file is downloaded without any limits. When downloading URLs provided by users, use
+    /// [`Self::url_with_options`] instead.
+    ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if downloading the data fails.
@@ -84,8 +166,61 @@ impl<'a> CreateAttachment<'a> {
         url: impl reqwest::IntoUrl,
         filename: impl Into<Cow<'static, str>>,
     ) -> Result<Self> {
-        let response = http.client.get(url).send().await?;
-        let data = response.bytes().await?.to_vec();
+        Self::url_with_options(http, url, filename, &AttachmentDownloadOptions::default()).await
+    }
+
+    /// Builds an [`CreateAttachment`] by downloading attachment data from a URL, rejecting the
+    /// download if it violates the given options.
+    ///
+    /// The download is aborted as soon as it exceeds the maximum size, instead of buffering
+    /// whatever the remote sends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// use serenity::builder::{AttachmentDownloadOptions, CreateAttachment};
+    /// # use serenity::http::Http;
+    ///
+    /// # async fn run(http: &Http) -> serenity::Result<()> {
+    /// let options = AttachmentDownloadOptions::new()
+    ///     .max_size(8 * 1024 * 1024)
+    ///     .timeout(Duration::from_secs(10))
+    ///     .allowed_content_types(["image/*"])
+    ///     .max_redirects(2);
+    /// let attachment = CreateAttachment::url_with_options(
+    ///     http,
+    ///     "https://example.com/image.png",
+    ///     "image.png",
+    ///     &options,
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::AttachmentDownload`] if the download violates the options or the
+    /// remote responds with an unsuccessful status code, and [`Error::Http`] if downloading the
+    /// data fails otherwise.
+    ///
+    /// [`HttpError::AttachmentDownload`]: crate::http::HttpError::AttachmentDownload
+    #[cfg(feature = "http")]
+    pub async fn url_with_options(
+        http: &Http,
+        url: impl reqwest::IntoUrl,
+        filename: impl Into<Cow<'static, str>>,
+        options: &AttachmentDownloadOptions,
+    ) -> Result<Self> {
+        let download = options.download(http, url);
+        let data = match options.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, download)
+                .await
+                .map_err(|_| HttpError::AttachmentDownload(AttachmentDownloadError::TimedOut))??,
+            None => download.await?,
+        };
 
         Ok(CreateAttachment::bytes(data, filename))
     }
@@ -111,6 +246,128 @@ impl<'a> CreateAttachment<'a> {
     }
 }
 
+/// Limits for downloading an attachment via [`CreateAttachment::url_with_options`].
+///
+/// By default, there are no limits besides the redirect policy of the [`Http`] client.
+#[cfg(feature = "http")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AttachmentDownloadOptions {
+    max_size: Option<u64>,
+    timeout: Option<Duration>,
+    allowed_content_types: Vec<Cow<'static, str>>,
+    max_redirects: Option<usize>,
+}
+
+#[cfg(feature = "http")]
+impl AttachmentDownloadOptions {
+    /// Creates options without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of the file in bytes.
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets how long the whole download, including connecting and following redirects, may take.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the allowed content types, such as `image/png`. A type ending in `/*`, such as
+    /// `image/*`, allows all subtypes. Responses without a content type are rejected.
+    pub fn allowed_content_types(
+        mut self,
+        content_types: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    ) -> Self {
+        self.allowed_content_types = content_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets how many redirects are followed. Set to 0 to reject all redirects.
+    pub fn max_redirects(mut self, max_redirects: usize) -> Self {
+        self.max_redirects = Some(max_redirects);
+        self
+    }
+
+    fn allows_content_type(&self, content_type: &str) -> bool {
+        let essence = content_type.split(';').next().unwrap_or_default().trim();
+        self.allowed_content_types.iter().any(|allowed| match allowed.strip_suffix('*') {
+            Some(prefix) => {
+                essence.len() >= prefix.len()
+                    && essence[..prefix.len()].eq_ignore_ascii_case(prefix)
+            },
+            None => essence.eq_ignore_ascii_case(allowed),
+        })
+    }
+
+    async fn download(&self, http: &Http, url: impl reqwest::IntoUrl) -> Result<Vec<u8>> {
+        let fail = |why| Error::Http(HttpError::AttachmentDownload(why));
+
+        // The redirect policy can only be set per client.
+        let client = match self.max_redirects {
+            Some(0) => reqwest::Client::builder().redirect(Policy::none()).build()?,
+            Some(max) => reqwest::Client::builder().redirect(Policy::limited(max)).build()?,
+            None => http.client.clone(),
+        };
+
+        let mut response = client.get(url).send().await.map_err(|why| {
+            if why.is_redirect() {
+                fail(AttachmentDownloadError::TooManyRedirects)
+            } else {
+                why.into()
+            }
+        })?;
+
+        let status = response.status();
+        if status.is_redirection() {
+            return Err(fail(AttachmentDownloadError::TooManyRedirects));
+        }
+        if !status.is_success() {
+            return Err(fail(AttachmentDownloadError::UnsuccessfulStatus(status)));
+        }
+
+        if !self.allowed_content_types.is_empty() {
+            let content_type = response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string);
+            if !content_type.as_deref().is_some_and(|ty| self.allows_content_type(ty)) {
+                return Err(fail(AttachmentDownloadError::DisallowedContentType(content_type)));
+            }
+        }
+
+        let too_large = |max_size| {
+            fail(AttachmentDownloadError::TooLarge {
+                max_size,
+            })
+        };
+        if let Some(max_size) = self.max_size {
+            if response.content_length().is_some_and(|length| length > max_size) {
+                return Err(too_large(max_size));
+            }
+        }
+
+        let mut data = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if let Some(max_size) = self.max_size {
+                if (data.len() + chunk.len()) as u64 > max_size {
+                    return Err(too_large(max_size));
+                }
+            }
+
+            data.extend_from_slice(&chunk);
+        }
+
+        Ok(data)
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 struct ExistingAttachment {
     id: AttachmentId,
@@ -254,10 +511,11 @@ impl<'a> EditAttachments<'a> {
         let mut files = Vec::new();
         for attachment in &mut self.new_and_existing_attachments {
             if let NewOrExisting::New(attachment) = attachment {
-                let cloned_attachment = CreateAttachment::bytes(
+                let mut cloned_attachment = CreateAttachment::bytes(
                     std::mem::take(&mut attachment.data),
                     attachment.filename.clone(),
                 );
+                cloned_attachment.reader = attachment.reader.take();
 
                 files.push(cloned_attachment);
             }
@@ -162,7 +162,7 @@ impl<'a> CreateChannel<'a> {
     }
 
     /// A set of overwrites defining what a user or a user carrying a certain role can and cannot
-    /// do.
+    /// do. See [`PermissionOverwritePreset`] for commonly used combinations.
     ///
     /// # Example
     ///
@@ -1,7 +1,12 @@
 use std::borrow::Cow;
+#[cfg(feature = "http")]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "http")]
+use serde_json::json;
 
 #[cfg(feature = "http")]
-use crate::http::Http;
+use crate::http::{Http, HttpError};
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
@@ -376,6 +381,23 @@ impl<'a> CreateCommand<'a> {
         }
     }
 
+    /// Creates a new builder for a user context menu command, shown when right-clicking a user.
+    ///
+    /// Context menu commands don't have a description or options, and their name may contain
+    /// uppercase letters and spaces.
+    pub fn user_context(name: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(name).kind(CommandType::User)
+    }
+
+    /// Creates a new builder for a message context menu command, shown when right-clicking a
+    /// message.
+    ///
+    /// Context menu commands don't have a description or options, and their name may contain
+    /// uppercase letters and spaces.
+    pub fn message_context(name: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(name).kind(CommandType::Message)
+    }
+
     /// Specifies the name of the application command, replacing the current value as set in
     /// [`Self::new`].
     ///
@@ -387,6 +409,18 @@ impl<'a> CreateCommand<'a> {
         self
     }
 
+    /// The name of the command, used to route interactions to it.
+    #[cfg(feature = "framework")]
+    pub(crate) fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The type of the command, used to route interactions to it.
+    #[cfg(feature = "framework")]
+    pub(crate) fn get_kind(&self) -> CommandType {
+        self.kind.unwrap_or(CommandType::ChatInput)
+    }
+
     /// Specifies a localized name of the application command.
     ///
     /// ```rust
@@ -499,6 +533,40 @@ impl<'a> CreateCommand<'a> {
         self
     }
 
+    /// Checks the command against Discord's rules, such as the length of names and descriptions,
+    /// required options coming before optional ones, localizations using valid locales, and
+    /// subcommands being nested correctly.
+    ///
+    /// This runs automatically before the command is registered, so invalid commands are reported
+    /// with every violation instead of Discord rejecting them one at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::{CreateCommand, CreateCommandOption};
+    /// use serenity::model::application::CommandOptionType;
+    /// use serenity::model::ModelError;
+    ///
+    /// let command = CreateCommand::new("Ban").description("Bans a user").add_option(
+    ///     CreateCommandOption::new(CommandOptionType::User, "user", "The user to ban"),
+    /// );
+    ///
+    /// let Err(ModelError::InvalidCommands(invalid)) = command.validate() else { unreachable!() };
+    /// assert_eq!(invalid[0].errors[0].0, "name");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::InvalidCommands`] listing every violation found.
+    pub fn validate(&self) -> StdResult<(), ModelError> {
+        let invalid = validate_commands([(0, self)]);
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(ModelError::InvalidCommands(invalid))
+        }
+    }
+
     /// Create a [`Command`], overriding an existing one with the same name if it exists.
     ///
     /// Providing a [`GuildId`] will create a command in the corresponding [`Guild`]. Otherwise, a
@@ -508,7 +576,9 @@ impl<'a> CreateCommand<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if invalid data is given. See [Discord's docs] for more details.
+    /// Returns [`ModelError::InvalidCommands`] if the command breaks Discord's rules, see
+    /// [`Self::validate`]. Returns [`Error::Http`] if Discord rejects the command anyway. See
+    /// [Discord's docs] for more details.
     ///
     /// May also return [`Error::Json`] if there is an error in deserializing the API response.
     ///
@@ -520,6 +590,8 @@ impl<'a> CreateCommand<'a> {
         guild_id: Option<GuildId>,
         command_id: Option<CommandId>,
     ) -> Result<Command> {
+        self.validate()?;
+
         match (guild_id, command_id) {
             (Some(guild_id), Some(cmd_id)) => {
                 http.edit_guild_command(guild_id, cmd_id, &self).await
@@ -531,6 +603,479 @@ impl<'a> CreateCommand<'a> {
     }
 }
 
+/// Overwrites the commands of a guild, or the global commands if no guild is given. Commands
+/// rejected by Discord are reported as [`ModelError::InvalidCommands`].
+#[cfg(feature = "http")]
+pub(crate) async fn overwrite_commands(
+    http: &Http,
+    guild_id: Option<GuildId>,
+    commands: &[CreateCommand<'_>],
+) -> Result<Vec<Command>> {
+    let invalid = validate_commands(commands.iter().enumerate());
+    if !invalid.is_empty() {
+        return Err(Error::Model(ModelError::InvalidCommands(invalid)));
+    }
+
+    let result = match guild_id {
+        Some(guild_id) => http.create_guild_commands(guild_id, &commands).await,
+        None => http.create_global_commands(&commands).await,
+    };
+
+    result.map_err(|error| {
+        let invalid = invalid_commands(&error, commands.iter().enumerate());
+        if invalid.is_empty() {
+            error
+        } else {
+            Error::Model(ModelError::InvalidCommands(invalid))
+        }
+    })
+}
+
+/// Like [`overwrite_commands`], but leaves out rejected commands and retries with the remaining
+/// ones, until Discord accepts the request.
+#[cfg(feature = "http")]
+pub(crate) async fn overwrite_commands_partial(
+    http: &Http,
+    guild_id: Option<GuildId>,
+    commands: &[CreateCommand<'_>],
+) -> Result<CommandRegistration> {
+    let mut invalid = validate_commands(commands.iter().enumerate());
+    let mut remaining: Vec<_> = commands
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !invalid.iter().any(|command| command.index == *index))
+        .collect();
+
+    loop {
+        let batch: Vec<_> = remaining.iter().map(|(_, command)| command).collect();
+        let result = match guild_id {
+            Some(guild_id) => http.create_guild_commands(guild_id, &batch).await,
+            None => http.create_global_commands(&batch).await,
+        };
+
+        match result {
+            Ok(registered) => {
+                invalid.sort_unstable_by_key(|command| command.index);
+                return Ok(CommandRegistration {
+                    registered,
+                    invalid,
+                });
+            },
+            Err(error) => {
+                // Each retry drops at least one command, so this always terminates.
+                let rejected = invalid_commands(&error, remaining.iter().copied());
+                if rejected.is_empty() {
+                    return Err(error);
+                }
+
+                remaining.retain(|(index, _)| !rejected.iter().any(|cmd| cmd.index == *index));
+                invalid.extend(rejected);
+            },
+        }
+    }
+}
+
+/// Brings the commands of a guild, or the global commands if no guild is given, in line with the
+/// given ones, by only creating, editing and deleting the commands that differ. If `dry_run` is
+/// set, the changes are only planned and not applied.
+#[cfg(feature = "http")]
+pub(crate) async fn sync_commands(
+    http: &Http,
+    guild_id: Option<GuildId>,
+    commands: &[CreateCommand<'_>],
+    dry_run: bool,
+) -> Result<CommandSyncPlan> {
+    let invalid = validate_commands(commands.iter().enumerate());
+    if !invalid.is_empty() {
+        return Err(Error::Model(ModelError::InvalidCommands(invalid)));
+    }
+
+    let existing = match guild_id {
+        Some(guild_id) => http.get_guild_commands_with_localizations(guild_id).await?,
+        None => http.get_global_commands_with_localizations().await?,
+    };
+
+    let mut plan = CommandSyncPlan::default();
+    let mut changes = Vec::new();
+    let mut matched = vec![false; existing.len()];
+    for command in commands {
+        let kind = command.kind.unwrap_or(CommandType::ChatInput);
+        let position = existing.iter().position(|c| c.name == *command.name && c.kind == kind);
+        let Some(position) = position else {
+            plan.created.push(FixedString::from_str_trunc(&command.name));
+            changes.push((command, None));
+            continue;
+        };
+
+        matched[position] = true;
+        let current = &existing[position];
+        let entry = (current.id, current.name.clone());
+        if json_matches(&serde_json::to_value(command)?, &serde_json::to_value(current)?) {
+            plan.unchanged.push(entry);
+        } else {
+            plan.edited.push(entry);
+            changes.push((command, Some(current.id)));
+        }
+    }
+
+    let unmatched = existing.iter().zip(matched).filter(|(_, matched)| !matched);
+    plan.deleted = unmatched.map(|(command, _)| (command.id, command.name.clone())).collect();
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    // Delete first, to make room in case the command limit is reached.
+    for (command_id, _) in &plan.deleted {
+        match guild_id {
+            Some(guild_id) => http.delete_guild_command(guild_id, *command_id).await?,
+            None => http.delete_global_command(*command_id).await?,
+        }
+    }
+
+    for (command, command_id) in changes {
+        match (guild_id, command_id) {
+            (Some(guild_id), Some(id)) => http.edit_guild_command(guild_id, id, command).await?,
+            (Some(guild_id), None) => http.create_guild_command(guild_id, command).await?,
+            (None, Some(id)) => http.edit_global_command(id, command).await?,
+            (None, None) => http.create_global_command(command).await?,
+        };
+    }
+
+    Ok(plan)
+}
+
+/// Whether the registered command, serialized as `existing`, matches the `desired` one.
+///
+/// Both sides are normalized before comparing, see [`normalize_command`], so that fields left out
+/// or set to `null` are equal to the defaults Discord fills in.
+#[cfg(feature = "http")]
+fn json_matches(desired: &Value, existing: &Value) -> bool {
+    let desired = normalize_command(desired);
+    let mut existing = normalize_command(existing);
+
+    // These default to the application's settings, so they can only be compared if set.
+    for key in ["integration_types", "contexts"] {
+        if desired[key].is_null() {
+            existing[key] = Value::Null;
+        }
+    }
+
+    desired == existing
+}
+
+/// Picks out every field of a serialized command which Discord stores, replacing missing and
+/// `null` fields by their defaults. Fields only present in responses, such as the id, are dropped.
+#[cfg(feature = "http")]
+fn normalize_command(command: &Value) -> Value {
+    json!({
+        "type": field(command, "type").unwrap_or(json!(1)),
+        "name": field(command, "name"),
+        "name_localizations": field(command, "name_localizations").unwrap_or(json!({})),
+        "description": field(command, "description").unwrap_or(json!("")),
+        "description_localizations":
+            field(command, "description_localizations").unwrap_or(json!({})),
+        "options": normalize_all(command, "options", normalize_option),
+        "default_member_permissions": field(command, "default_member_permissions"),
+        "dm_permission": field(command, "dm_permission").unwrap_or(json!(true)),
+        "nsfw": field(command, "nsfw").unwrap_or(json!(false)),
+        "integration_types": field(command, "integration_types"),
+        "contexts": field(command, "contexts"),
+    })
+}
+
+/// Like [`normalize_command`], for a serialized option.
+#[cfg(feature = "http")]
+fn normalize_option(option: &Value) -> Value {
+    json!({
+        "type": field(option, "type"),
+        "name": field(option, "name"),
+        "name_localizations": field(option, "name_localizations").unwrap_or(json!({})),
+        "description": field(option, "description"),
+        "description_localizations":
+            field(option, "description_localizations").unwrap_or(json!({})),
+        "required": field(option, "required").unwrap_or(json!(false)),
+        "choices": normalize_all(option, "choices", normalize_choice),
+        "options": normalize_all(option, "options", normalize_option),
+        "channel_types": field(option, "channel_types").unwrap_or(json!([])),
+        "min_value": field(option, "min_value"),
+        "max_value": field(option, "max_value"),
+        "min_length": field(option, "min_length"),
+        "max_length": field(option, "max_length"),
+        "autocomplete": field(option, "autocomplete").unwrap_or(json!(false)),
+    })
+}
+
+/// Like [`normalize_command`], for a serialized option choice.
+#[cfg(feature = "http")]
+fn normalize_choice(choice: &Value) -> Value {
+    json!({
+        "name": field(choice, "name"),
+        "name_localizations": field(choice, "name_localizations").unwrap_or(json!({})),
+        "value": field(choice, "value"),
+    })
+}
+
+/// The field with the given key, unless it is missing or `null`.
+#[cfg(feature = "http")]
+fn field(value: &Value, key: &str) -> Option<Value> {
+    value.get(key).filter(|value| !value.is_null()).cloned()
+}
+
+/// Normalizes each item of the array with the given key, treating a missing array as empty.
+#[cfg(feature = "http")]
+fn normalize_all(value: &Value, key: &str, normalize: fn(&Value) -> Value) -> Value {
+    let items = value.get(key).and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+    Value::Array(items.iter().map(normalize).collect())
+}
+
+/// Checks the commands, given as pairs of their index and the command, against Discord's rules,
+/// see [`CreateCommand::validate`].
+fn validate_commands<'a, 'b: 'a>(
+    commands: impl IntoIterator<Item = (usize, &'a CreateCommand<'b>)>,
+) -> Vec<InvalidCommand> {
+    commands
+        .into_iter()
+        .filter_map(|(index, command)| {
+            let mut violations = Violations::default();
+            violations.check_command(command);
+
+            (!violations.0.is_empty()).then(|| InvalidCommand {
+                index,
+                name: FixedString::from_str_trunc(&command.name),
+                errors: violations.0,
+            })
+        })
+        .collect()
+}
+
+/// Where a list of options is nested, which decides the kinds of options it may contain.
+#[derive(Clone, Copy, PartialEq)]
+enum OptionParent {
+    Command,
+    SubCommandGroup,
+    SubCommand,
+}
+
+/// The rules a command breaks, as pairs of the path to the field, in the same format as Discord's
+/// errors, and a message.
+#[derive(Default)]
+struct Violations(Vec<(FixedString, FixedString)>);
+
+impl Violations {
+    fn add(&mut self, path: &str, message: &str) {
+        self.0.push((FixedString::from_str_trunc(path), FixedString::from_str_trunc(message)));
+    }
+
+    fn check_length(&mut self, path: &str, value: &str, max: usize) {
+        let len = value.chars().count();
+        if len == 0 || len > max {
+            self.add(path, &format!("Must be between 1 and {max} characters long"));
+        }
+    }
+
+    fn check_name(&mut self, path: &str, name: &str, chat_input: bool) {
+        /// Whether the character matches `[-_'\p{L}\p{N}\p{sc=Deva}\p{sc=Thai}]`, the characters
+        /// Discord allows in chat input names.
+        fn is_allowed(c: char) -> bool {
+            matches!(c, '-' | '_' | '\'')
+                || c.is_alphanumeric()
+                // Devanagari, including the extended blocks, and Thai.
+                || matches!(c, '\u{0900}'..='\u{097F}' | '\u{A8E0}'..='\u{A8FF}')
+                || matches!(c, '\u{11B00}'..='\u{11B5F}' | '\u{0E00}'..='\u{0E7F}')
+        }
+
+        self.check_length(path, name, 32);
+        if chat_input {
+            if !name.chars().all(is_allowed) {
+                self.add(path, "Must only contain letters, numbers, `-`, `_` and `'`");
+            }
+
+            if name.chars().any(char::is_uppercase) {
+                self.add(path, "Must be lowercase");
+            }
+        }
+    }
+
+    fn check_localizations<'a>(
+        &mut self,
+        path: &str,
+        localizations: impl IntoIterator<Item = (&'a Cow<'a, str>, &'a Cow<'a, str>)>,
+        mut check: impl FnMut(&mut Self, &str, &str),
+    ) {
+        let mut localizations: Vec<_> = localizations.into_iter().collect();
+        localizations.sort_unstable();
+
+        for (locale, value) in localizations {
+            let path = format!("{path}.{locale}");
+            if matches!(Locale::from(&**locale), Locale::Unknown(_)) {
+                self.add(&path, "Invalid locale");
+            }
+
+            check(self, &path, value);
+        }
+    }
+
+    fn check_command(&mut self, command: &CreateCommand<'_>) {
+        let chat_input = matches!(command.kind, None | Some(CommandType::ChatInput));
+
+        self.check_name("name", &command.name, chat_input);
+        self.check_localizations("name_localizations", &command.name_localizations, |v, p, n| {
+            v.check_name(p, n, chat_input);
+        });
+
+        match command.description.as_deref() {
+            Some(description) if chat_input => self.check_length("description", description, 100),
+            None if chat_input => self.add("description", "Required for chat input commands"),
+            Some(description) if !description.is_empty() => {
+                self.add("description", "Must be empty for context menu commands");
+            },
+            _ => {},
+        }
+
+        self.check_localizations(
+            "description_localizations",
+            &command.description_localizations,
+            |v, p, d| v.check_length(p, d, 100),
+        );
+
+        if !chat_input && !command.options.is_empty() {
+            self.add("options", "Context menu commands cannot have options");
+        }
+
+        self.check_options("options", &command.options, OptionParent::Command);
+    }
+
+    fn check_options(
+        &mut self,
+        path: &str,
+        options: &[CreateCommandOption<'_>],
+        parent: OptionParent,
+    ) {
+        if options.len() > 25 {
+            self.add(path, "Must contain at most 25 options");
+        }
+
+        let subcommands = options
+            .iter()
+            .filter(|option| {
+                matches!(
+                    option.kind,
+                    CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+                )
+            })
+            .count();
+        if subcommands != 0 && subcommands != options.len() {
+            self.add(path, "Cannot mix subcommands or groups with other options");
+        }
+
+        let mut optional = false;
+        for (i, option) in options.iter().enumerate() {
+            let path = format!("{path}.{i}");
+
+            self.check_name(&format!("{path}.name"), &option.name, true);
+            if let Some(localizations) = &option.name_localizations {
+                let path = format!("{path}.name_localizations");
+                self.check_localizations(&path, localizations, |v, p, n| v.check_name(p, n, true));
+            }
+
+            self.check_length(&format!("{path}.description"), &option.description, 100);
+            if let Some(localizations) = &option.description_localizations {
+                let path = format!("{path}.description_localizations");
+                self.check_localizations(&path, localizations, |v, p, d| {
+                    v.check_length(p, d, 100);
+                });
+            }
+
+            let options_path = format!("{path}.options");
+            match option.kind {
+                CommandOptionType::SubCommandGroup => {
+                    if parent != OptionParent::Command {
+                        self.add(&path, "Subcommand groups can only be nested in the command");
+                    }
+
+                    let is_subcommand = |option: &CreateCommandOption<'_>| {
+                        option.kind == CommandOptionType::SubCommand
+                    };
+                    if !option.options.iter().all(is_subcommand) {
+                        self.add(&options_path, "Subcommand groups can only contain subcommands");
+                    }
+
+                    let parent = OptionParent::SubCommandGroup;
+                    self.check_options(&options_path, &option.options, parent);
+                },
+                CommandOptionType::SubCommand => {
+                    if parent == OptionParent::SubCommand {
+                        self.add(&path, "Subcommands cannot be nested in other subcommands");
+                    }
+
+                    self.check_options(&options_path, &option.options, OptionParent::SubCommand);
+                },
+                _ => {
+                    if !option.options.is_empty() {
+                        self.add(&options_path, "Only subcommands and groups can contain options");
+                    }
+
+                    if option.required && optional {
+                        self.add(
+                            &format!("{path}.required"),
+                            "Required options must come before optional ones",
+                        );
+                    }
+
+                    optional |= !option.required;
+
+                    if option.choices.len() > 25 {
+                        self.add(&format!("{path}.choices"), "Must contain at most 25 choices");
+                    }
+
+                    for (j, choice) in option.choices.iter().enumerate() {
+                        let path = format!("{path}.choices.{j}");
+                        self.check_length(&format!("{path}.name"), &choice.name, 100);
+                        if let Value::String(value) = &choice.value {
+                            self.check_length(&format!("{path}.value"), value, 100);
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Maps the errors of a rejected bulk overwrite back to the commands they belong to, given as
+/// pairs of their index in the original list and the command. Discord reports the errors by
+/// the position of the command in the request body, such as `3.options.0.name`.
+#[cfg(feature = "http")]
+fn invalid_commands<'a, 'b: 'a>(
+    error: &Error,
+    commands: impl IntoIterator<Item = (usize, &'a CreateCommand<'b>)>,
+) -> Vec<InvalidCommand> {
+    let Some(json_error) = error.as_http().and_then(HttpError::json_error) else {
+        return Vec::new();
+    };
+
+    let mut errors: BTreeMap<usize, Vec<(FixedString, FixedString)>> = BTreeMap::new();
+    for error in &json_error.errors {
+        let (position, path) = error.path.split_once('.').unwrap_or((&error.path, ""));
+        if let Ok(position) = position.parse() {
+            let path = FixedString::from_str_trunc(path);
+            errors.entry(position).or_default().push((path, error.message.clone()));
+        }
+    }
+
+    commands
+        .into_iter()
+        .enumerate()
+        .filter_map(|(position, (index, command))| {
+            Some(InvalidCommand {
+                index,
+                name: FixedString::from_str_trunc(&command.name),
+                errors: errors.remove(&position)?,
+            })
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug, Serialize)]
 struct CreateCommandOptionChoice<'a> {
     pub name: Cow<'a, str>,
@@ -538,3 +1083,345 @@ struct CreateCommandOptionChoice<'a> {
     pub name_localizations: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
     pub value: Value,
 }
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    #[cfg(feature = "http_transport")]
+    use std::collections::VecDeque;
+    #[cfg(feature = "http_transport")]
+    use std::sync::Arc;
+
+    #[cfg(feature = "http_transport")]
+    use parking_lot::Mutex;
+    #[cfg(feature = "http_transport")]
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::{json_matches, CreateCommand, CreateCommandOption};
+    use crate::model::application::{Command, CommandOptionType, CommandType};
+    use crate::model::permissions::Permissions;
+    #[cfg(feature = "http_transport")]
+    use crate::http::{
+        HttpBuilder,
+        HttpError,
+        HttpTransport,
+        LightMethod,
+        MockTransport,
+        StatusCode,
+    };
+    #[cfg(feature = "http_transport")]
+    use crate::model::id::{ApplicationId, GuildId};
+    use crate::model::ModelError;
+    #[cfg(feature = "http_transport")]
+    use crate::{Error, Result};
+
+    #[test]
+    fn command_validation() {
+        let option = |kind, name| CreateCommandOption::new(kind, name, "Description");
+
+        let command = CreateCommand::new("config").description("Configures the bot").add_option(
+            option(CommandOptionType::SubCommandGroup, "greeting").add_sub_option(
+                option(CommandOptionType::SubCommand, "set")
+                    .add_sub_option(option(CommandOptionType::String, "text").required(true))
+                    .add_sub_option(option(CommandOptionType::Channel, "channel")),
+            ),
+        );
+        assert!(command.validate().is_ok());
+
+        let command = CreateCommand::new("Config")
+            .description("")
+            .name_localized("xx", "config")
+            .add_option(option(CommandOptionType::String, "text"))
+            .add_option(option(CommandOptionType::Channel, "channel").required(true))
+            .add_option(
+                option(CommandOptionType::SubCommand, "sub")
+                    .add_sub_option(option(CommandOptionType::SubCommand, "nested")),
+            );
+
+        let Err(ModelError::InvalidCommands(invalid)) = command.validate() else {
+            panic!("command should be invalid");
+        };
+        let paths: Vec<_> = invalid[0].errors.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, [
+            "name",
+            "name_localizations.xx",
+            "description",
+            "options",
+            "options.1.required",
+            "options.2.options.0",
+        ]);
+    }
+
+    #[test]
+    fn name_and_choice_validation() {
+        let paths = |command: CreateCommand<'_>| -> Vec<String> {
+            match command.validate() {
+                Ok(()) => Vec::new(),
+                Err(ModelError::InvalidCommands(invalid)) => {
+                    invalid[0].errors.iter().map(|(path, _)| path.to_string()).collect()
+                },
+                Err(error) => panic!("unexpected error: {error}"),
+            }
+        };
+        let command = |name| CreateCommand::new(name).description("Description");
+
+        let allowed = [
+            "rock-paper_scissors",
+            "don't",
+            "gr\u{f6}\u{df}e",
+            "\u{540d}\u{524d}",
+            // Devanagari and Thai, which rely on combining marks.
+            "\u{928}\u{92e}\u{938}\u{94d}\u{924}\u{947}",
+            "\u{e2a}\u{e27}\u{e31}\u{e2a}\u{e14}\u{e35}",
+            "v2",
+        ];
+        for name in allowed {
+            assert!(paths(command(name)).is_empty(), "{name} should be allowed");
+        }
+
+        for name in ["two words", "emoji\u{1f389}", "dot.name", "slash/name", "Upper", ""] {
+            assert_eq!(paths(command(name)), ["name"], "{name} should be rejected");
+        }
+
+        // Context menu commands may use any characters.
+        assert!(paths(command("Two Words").kind(CommandType::User).description("")).is_empty());
+
+        let option = CreateCommandOption::new(CommandOptionType::String, "size", "Description")
+            .add_string_choice("Small", "s")
+            .add_string_choice("Large", "l".repeat(101));
+        assert_eq!(paths(command("shirt").add_option(option)), ["options.0.choices.1.value"]);
+    }
+
+    #[test]
+    fn command_diffing() {
+        let existing = json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 1,
+            "name": "echo",
+            "description": "Echoes a message",
+            "name_localizations": null,
+            "dm_permission": true,
+            "nsfw": false,
+            "options": [{
+                "type": 3,
+                "name": "message",
+                "description": "The message to send",
+                "required": true,
+            }],
+        });
+
+        let option = CreateCommandOption::new(
+            CommandOptionType::String,
+            "message",
+            "The message to send",
+        );
+        let command = CreateCommand::new("echo")
+            .description("Echoes a message")
+            .add_option(option.clone().required(true));
+        assert!(json_matches(&serde_json::to_value(&command).unwrap(), &existing));
+
+        let command = CreateCommand::new("echo").description("Echoes a message").add_option(option);
+        assert!(!json_matches(&serde_json::to_value(&command).unwrap(), &existing));
+
+        let command = CreateCommand::new("echo").description("Repeats a message");
+        assert!(!json_matches(&serde_json::to_value(&command).unwrap(), &existing));
+    }
+
+    #[test]
+    fn command_diffing_registered() {
+        let registered: Command = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "version": "3",
+            "type": 1,
+            "name": "ban",
+            "name_localizations": {"de": "bannen"},
+            "description": "Bans a member",
+            "description_localizations": null,
+            "default_member_permissions": "4",
+            "nsfw": false,
+            "options": [{
+                "type": 6,
+                "name": "member",
+                "description": "The member to ban",
+                "required": true,
+            }, {
+                "type": 3,
+                "name": "reason",
+                "description": "Why the member is banned",
+                "choices": [{"name": "Spam", "value": "spam"}],
+            }],
+        }))
+        .unwrap();
+        let existing = serde_json::to_value(&registered).unwrap();
+
+        let command = || {
+            CreateCommand::new("ban")
+                .name_localized("de", "bannen")
+                .description("Bans a member")
+                .default_member_permissions(Permissions::BAN_MEMBERS)
+                .add_option(
+                    CreateCommandOption::new(
+                        CommandOptionType::User,
+                        "member",
+                        "The member to ban",
+                    )
+                    .required(true),
+                )
+                .add_option(
+                    CreateCommandOption::new(
+                        CommandOptionType::String,
+                        "reason",
+                        "Why the member is banned",
+                    )
+                    .add_string_choice("Spam", "spam"),
+                )
+        };
+        let matches = |command: CreateCommand<'_>| {
+            json_matches(&serde_json::to_value(&command).unwrap(), &existing)
+        };
+
+        assert!(matches(command()));
+        assert!(!matches(command().nsfw(true)));
+        assert!(!matches(command().default_member_permissions(Permissions::KICK_MEMBERS)));
+        assert!(!matches(command().name_localized("fr", "bannir")));
+        assert!(!matches(command().description_localized("de", "Bannt ein Mitglied")));
+    }
+
+    #[cfg(feature = "http_transport")]
+    #[derive(Debug, Default)]
+    struct SequenceTransport {
+        responses: Mutex<VecDeque<(StatusCode, Value)>>,
+        bodies: Mutex<Vec<Value>>,
+    }
+
+    #[cfg(feature = "http_transport")]
+    #[async_trait::async_trait]
+    impl HttpTransport for SequenceTransport {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let body = request.body().and_then(|body| body.as_bytes()).unwrap_or_default();
+            self.bodies.lock().push(serde_json::from_slice(body)?);
+
+            let (status, body) = self.responses.lock().pop_front().expect("unexpected request");
+            let response = http_crate::Response::builder()
+                .status(status)
+                .body(serde_json::to_vec(&body)?)
+                .expect("response should be valid");
+            Ok(response.into())
+        }
+    }
+
+    #[cfg(feature = "http_transport")]
+    fn invalid_form_body(errors: &Value) -> Value {
+        json!({"code": 50035, "message": "Invalid Form Body", "errors": errors})
+    }
+
+    #[cfg(feature = "http_transport")]
+    fn registered(name: &str) -> Value {
+        json!({
+            "id": "1",
+            "application_id": "2",
+            "version": "3",
+            "type": 1,
+            "name": name,
+            "description": "Description",
+        })
+    }
+
+    #[cfg(feature = "http_transport")]
+    #[tokio::test]
+    async fn overwrite_maps_errors_to_commands() {
+        let errors = json!({
+            "1": {"description": {"_errors": [{"code": "BASE_TYPE_BAD_LENGTH", "message": "a"}]}},
+            "3": {"options": {"0": {"name": {"_errors": [{"code": "INVALID", "message": "b"}]}}}},
+        });
+        let transport = MockTransport::new().respond(
+            LightMethod::Put,
+            "/applications/*/guilds/*/commands",
+            StatusCode::BAD_REQUEST,
+            invalid_form_body(&errors),
+        );
+        let http = HttpBuilder::new("token")
+            .application_id(ApplicationId::new(2))
+            .transport(transport)
+            .build();
+
+        let option = CreateCommandOption::new(CommandOptionType::String, "text", "Description");
+        let mut commands: Vec<_> = ["zero", "one", "two", "three"]
+            .into_iter()
+            .map(|name| CreateCommand::new(name).description("Description"))
+            .collect();
+        commands[3] = commands[3].clone().add_option(option);
+
+        let Err(Error::Model(ModelError::InvalidCommands(invalid))) =
+            GuildId::new(1).set_commands(&http, &commands).await
+        else {
+            panic!("commands should be rejected");
+        };
+
+        let invalid: Vec<_> = invalid
+            .iter()
+            .map(|command| (command.index, command.name.as_str(), command.errors[0].0.as_str()))
+            .collect();
+        assert_eq!(invalid, [(1, "one", "description"), (3, "three", "options.0.name")]);
+    }
+
+    #[cfg(feature = "http_transport")]
+    #[tokio::test]
+    async fn partial_overwrite_retries_without_rejected_commands() {
+        let transport = Arc::new(SequenceTransport::default());
+        transport.responses.lock().extend([
+            // Positions in the request body, which skips the command failing local validation.
+            (
+                StatusCode::BAD_REQUEST,
+                invalid_form_body(&json!({
+                    "1": {"name": {"_errors": [{"code": "DUPLICATE", "message": "Duplicate"}]}},
+                })),
+            ),
+            (StatusCode::OK, json!([registered("zero"), registered("three")])),
+        ]);
+        let http = HttpBuilder::new("token")
+            .application_id(ApplicationId::new(2))
+            .transport(Arc::clone(&transport))
+            .build();
+
+        let commands: Vec<_> = ["zero", "One", "two", "three"]
+            .into_iter()
+            .map(|name| CreateCommand::new(name).description("Description"))
+            .collect();
+        let registration = GuildId::new(1).set_commands_partial(&http, &commands).await.unwrap();
+
+        let names = |commands: &Value| -> Vec<String> {
+            let commands = commands.as_array().unwrap();
+            commands.iter().map(|command| command["name"].as_str().unwrap().into()).collect()
+        };
+        let bodies = transport.bodies.lock();
+        assert_eq!(names(&bodies[0]), ["zero", "two", "three"]);
+        assert_eq!(names(&bodies[1]), ["zero", "three"]);
+
+        let invalid: Vec<_> =
+            registration.invalid.iter().map(|command| (command.index, &*command.name)).collect();
+        assert_eq!(invalid, [(1, "One"), (2, "two")]);
+        assert_eq!(registration.registered.len(), 2);
+    }
+
+    #[cfg(feature = "http_transport")]
+    #[tokio::test]
+    async fn partial_overwrite_returns_unmapped_errors() {
+        let transport = Arc::new(SequenceTransport::default());
+        transport.responses.lock().push_back((
+            StatusCode::FORBIDDEN,
+            json!({"code": 50001, "message": "Missing Access"}),
+        ));
+        let http = HttpBuilder::new("token")
+            .application_id(ApplicationId::new(2))
+            .transport(Arc::clone(&transport))
+            .build();
+
+        let commands = [CreateCommand::new("zero").description("Description")];
+        let error = GuildId::new(1).set_commands_partial(&http, &commands).await.unwrap_err();
+        assert_eq!(error.as_http().and_then(HttpError::status_code), Some(StatusCode::FORBIDDEN));
+        assert_eq!(transport.bodies.lock().len(), 1);
+    }
+}
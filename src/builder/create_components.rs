@@ -3,8 +3,89 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::json;
 
+use super::InputValidation;
 use crate::model::prelude::*;
 
+/// A builder for creating a top-level component of a message.
+///
+/// All components other than [`CreateComponent::ActionRow`] are layout components, which require
+/// the [`MessageFlags::IS_COMPONENTS_V2`] flag to be set on the message.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::ChannelId;
+/// # async fn run(http: &Http, channel_id: ChannelId) -> serenity::Result<()> {
+/// use serenity::builder::*;
+/// use serenity::model::channel::MessageFlags;
+///
+/// let thumbnail = CreateThumbnail::new(CreateUnfurledMediaItem::new("attachment://logo.png"));
+/// let container = CreateContainer::new(vec![
+///     CreateSection::new(vec![CreateTextDisplay::new("# Release notes")], thumbnail).into(),
+///     CreateSeparator::new(true).into(),
+///     CreateTextDisplay::new("- Added layout components").into(),
+/// ])
+/// .accent_colour(0x5865F2);
+///
+/// let builder = CreateMessage::new()
+///     .flags(MessageFlags::IS_COMPONENTS_V2)
+///     .add_file(CreateAttachment::path("logo.png").await?)
+///     .components(vec![container.into()]);
+/// channel_id.send_message(http, builder).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#component-object).
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+#[must_use]
+pub enum CreateComponent<'a> {
+    ActionRow(CreateActionRow<'a>),
+    Section(CreateSection<'a>),
+    TextDisplay(CreateTextDisplay<'a>),
+    MediaGallery(CreateMediaGallery<'a>),
+    Separator(CreateSeparator),
+    Container(CreateContainer<'a>),
+}
+
+impl<'a> From<CreateActionRow<'a>> for CreateComponent<'a> {
+    fn from(component: CreateActionRow<'a>) -> Self {
+        Self::ActionRow(component)
+    }
+}
+
+impl<'a> From<CreateSection<'a>> for CreateComponent<'a> {
+    fn from(component: CreateSection<'a>) -> Self {
+        Self::Section(component)
+    }
+}
+
+impl<'a> From<CreateTextDisplay<'a>> for CreateComponent<'a> {
+    fn from(component: CreateTextDisplay<'a>) -> Self {
+        Self::TextDisplay(component)
+    }
+}
+
+impl<'a> From<CreateMediaGallery<'a>> for CreateComponent<'a> {
+    fn from(component: CreateMediaGallery<'a>) -> Self {
+        Self::MediaGallery(component)
+    }
+}
+
+impl From<CreateSeparator> for CreateComponent<'_> {
+    fn from(component: CreateSeparator) -> Self {
+        Self::Separator(component)
+    }
+}
+
+impl<'a> From<CreateContainer<'a>> for CreateComponent<'a> {
+    fn from(component: CreateContainer<'a>) -> Self {
+        Self::Container(component)
+    }
+}
+
 /// A builder for creating a components action row in a message.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/message-components#component-object).
@@ -405,16 +486,18 @@ impl<'a> CreateSelectMenuOption<'a> {
 pub struct CreateInputText<'a> {
     #[serde(rename = "type")]
     kind: ComponentType,
-    custom_id: Cow<'a, str>,
+    pub(super) custom_id: Cow<'a, str>,
     style: InputTextStyle,
-    label: Option<Cow<'a, str>>,
+    pub(super) label: Option<Cow<'a, str>>,
     min_length: Option<u16>,
     max_length: Option<u16>,
-    required: bool,
+    pub(super) required: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     value: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     placeholder: Option<Cow<'a, str>>,
+    #[serde(skip)]
+    pub(super) validation: Option<InputValidation>,
 }
 
 impl<'a> CreateInputText<'a> {
@@ -435,6 +518,7 @@ impl<'a> CreateInputText<'a> {
             max_length: None,
             value: None,
             required: true,
+            validation: None,
 
             kind: ComponentType::InputText,
         }
@@ -488,4 +572,314 @@ impl<'a> CreateInputText<'a> {
         self.required = required;
         self
     }
+
+    /// Sets the rules the submitted value is validated against by the [`ModalValidator`] of the
+    /// modal, as created by [`CreateModal::validator`]. These rules are not sent to Discord.
+    ///
+    /// [`ModalValidator`]: super::ModalValidator
+    /// [`CreateModal::validator`]: super::CreateModal::validator
+    pub fn validation(mut self, validation: InputValidation) -> Self {
+        self.validation = Some(validation);
+        self
+    }
+}
+
+/// A builder for creating a section layout component, which displays text alongside an
+/// accessory.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#section).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateSection<'a> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    components: Cow<'a, [CreateTextDisplay<'a>]>,
+    accessory: CreateSectionAccessory<'a>,
+}
+
+impl<'a> CreateSection<'a> {
+    /// Creates a section with the given text displays, between one and three, and accessory.
+    pub fn new(
+        components: impl Into<Cow<'a, [CreateTextDisplay<'a>]>>,
+        accessory: impl Into<CreateSectionAccessory<'a>>,
+    ) -> Self {
+        Self {
+            kind: ComponentType::Section,
+            components: components.into(),
+            accessory: accessory.into(),
+        }
+    }
+
+    /// Sets the text displays of this section. Replaces the current value as set in
+    /// [`Self::new`].
+    pub fn components(mut self, components: impl Into<Cow<'a, [CreateTextDisplay<'a>]>>) -> Self {
+        self.components = components.into();
+        self
+    }
+
+    /// Sets the accessory of this section. Replaces the current value as set in [`Self::new`].
+    pub fn accessory(mut self, accessory: impl Into<CreateSectionAccessory<'a>>) -> Self {
+        self.accessory = accessory.into();
+        self
+    }
+}
+
+/// A builder for the accessory of a [`CreateSection`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+#[must_use]
+pub enum CreateSectionAccessory<'a> {
+    Button(CreateButton<'a>),
+    Thumbnail(CreateThumbnail<'a>),
+}
+
+impl<'a> From<CreateButton<'a>> for CreateSectionAccessory<'a> {
+    fn from(accessory: CreateButton<'a>) -> Self {
+        Self::Button(accessory)
+    }
+}
+
+impl<'a> From<CreateThumbnail<'a>> for CreateSectionAccessory<'a> {
+    fn from(accessory: CreateThumbnail<'a>) -> Self {
+        Self::Thumbnail(accessory)
+    }
+}
+
+/// A builder for creating a text display layout component, which displays markdown text.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#text-display).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateTextDisplay<'a> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    content: Cow<'a, str>,
+}
+
+impl<'a> CreateTextDisplay<'a> {
+    /// Creates a text display with the given markdown content.
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            kind: ComponentType::TextDisplay,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the content of this text display. Replaces the current value as set in
+    /// [`Self::new`].
+    pub fn content(mut self, content: impl Into<Cow<'a, str>>) -> Self {
+        self.content = content.into();
+        self
+    }
+}
+
+/// A builder for creating a thumbnail layout component, which can only be used as the accessory
+/// of a [`CreateSection`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#thumbnail).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateThumbnail<'a> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    media: CreateUnfurledMediaItem<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Cow<'a, str>>,
+    spoiler: bool,
+}
+
+impl<'a> CreateThumbnail<'a> {
+    /// Creates a thumbnail displaying the given media.
+    pub fn new(media: CreateUnfurledMediaItem<'a>) -> Self {
+        Self {
+            kind: ComponentType::Thumbnail,
+            media,
+            description: None,
+            spoiler: false,
+        }
+    }
+
+    /// Sets the alt text of the thumbnail.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets whether the thumbnail is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+}
+
+/// A builder for creating a media gallery layout component, which displays up to 10 images or
+/// videos in a grid.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateMediaGallery<'a> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    items: Cow<'a, [CreateMediaGalleryItem<'a>]>,
+}
+
+impl<'a> CreateMediaGallery<'a> {
+    /// Creates a media gallery with the given items, between one and ten.
+    pub fn new(items: impl Into<Cow<'a, [CreateMediaGalleryItem<'a>]>>) -> Self {
+        Self {
+            kind: ComponentType::MediaGallery,
+            items: items.into(),
+        }
+    }
+
+    /// Sets the items of this media gallery. Replaces the current value as set in [`Self::new`].
+    pub fn items(mut self, items: impl Into<Cow<'a, [CreateMediaGalleryItem<'a>]>>) -> Self {
+        self.items = items.into();
+        self
+    }
+
+    /// Adds an item to this media gallery.
+    pub fn add_item(mut self, item: CreateMediaGalleryItem<'a>) -> Self {
+        self.items.to_mut().push(item);
+        self
+    }
+}
+
+/// A builder for creating an item of a [`CreateMediaGallery`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery-media-gallery-item-structure).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateMediaGalleryItem<'a> {
+    media: CreateUnfurledMediaItem<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Cow<'a, str>>,
+    spoiler: bool,
+}
+
+impl<'a> CreateMediaGalleryItem<'a> {
+    /// Creates a media gallery item displaying the given media.
+    pub fn new(media: CreateUnfurledMediaItem<'a>) -> Self {
+        Self {
+            media,
+            description: None,
+            spoiler: false,
+        }
+    }
+
+    /// Sets the alt text of the item.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets whether the item is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+}
+
+/// A builder for a media item referenced by a URL, used by [`CreateThumbnail`] and
+/// [`CreateMediaGalleryItem`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#unfurled-media-item).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateUnfurledMediaItem<'a> {
+    url: Cow<'a, str>,
+}
+
+impl<'a> CreateUnfurledMediaItem<'a> {
+    /// Creates a media item with the given URL. Attachments of the message can be referenced
+    /// with `attachment://<filename>`.
+    pub fn new(url: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            url: url.into(),
+        }
+    }
+}
+
+/// A builder for creating a separator layout component, which adds vertical padding and an
+/// optional divider between components.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#separator).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateSeparator {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    divider: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spacing: Option<SeparatorSpacing>,
+}
+
+impl CreateSeparator {
+    /// Creates a separator, with or without a visual divider.
+    pub fn new(divider: bool) -> Self {
+        Self {
+            kind: ComponentType::Separator,
+            divider,
+            spacing: None,
+        }
+    }
+
+    /// Sets the amount of padding of the separator.
+    pub fn spacing(mut self, spacing: SeparatorSpacing) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+}
+
+/// A builder for creating a container layout component, which visually groups components
+/// together, similar to an embed.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#container).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateContainer<'a> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    components: Cow<'a, [CreateComponent<'a>]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accent_color: Option<Colour>,
+    spoiler: bool,
+}
+
+impl<'a> CreateContainer<'a> {
+    /// Creates a container with the given components. Containers cannot be nested.
+    pub fn new(components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
+        Self {
+            kind: ComponentType::Container,
+            components: components.into(),
+            accent_color: None,
+            spoiler: false,
+        }
+    }
+
+    /// Sets the components of this container. Replaces the current value as set in
+    /// [`Self::new`].
+    pub fn components(mut self, components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
+        self.components = components.into();
+        self
+    }
+
+    /// Adds a component to this container.
+    pub fn add_component(mut self, component: impl Into<CreateComponent<'a>>) -> Self {
+        self.components.to_mut().push(component.into());
+        self
+    }
+
+    /// Sets the colour of the left-hand side of the container.
+    pub fn accent_colour(mut self, colour: impl Into<Colour>) -> Self {
+        self.accent_color = Some(colour.into());
+        self
+    }
+
+    /// Sets whether the container is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
 }
@@ -16,9 +16,11 @@
 
 use std::borrow::Cow;
 
-#[cfg(feature = "http")]
+use crate::constants::EMBED_MAX_LENGTH;
 use crate::internal::prelude::*;
+use crate::model::error::{EmbedPart, Maximum};
 use crate::model::prelude::*;
+use crate::model::ModelError;
 
 /// A builder to create an embed in a message
 ///
@@ -168,6 +170,11 @@ impl<'a> CreateEmbed<'a> {
         self
     }
 
+    /// Set the timestamp to the current time.
+    pub fn timestamp_now(self) -> Self {
+        self.timestamp(Timestamp::now())
+    }
+
     /// Set the title of the embed.
     pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
         self.title = Some(title.into());
@@ -192,7 +199,120 @@ impl<'a> CreateEmbed<'a> {
         self.image(filename)
     }
 
-    #[cfg(feature = "http")]
+    /// Checks the embed against the length limits imposed by Discord, which would otherwise only
+    /// be reported as an error response when sending it.
+    ///
+    /// This is done automatically before sending messages containing the embed. See
+    /// [`Self::truncate_to_limits`] for shortening the embed to fit instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::EmbedTooLarge`] naming the first part of the embed which is too long,
+    /// or [`ModelError::TooLarge`] if the embed's total length is over 6000 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::CreateEmbed;
+    /// use serenity::model::error::EmbedPart;
+    /// use serenity::model::ModelError;
+    ///
+    /// let embed = CreateEmbed::new().title("a".repeat(300));
+    /// assert_eq!(
+    ///     embed.validate(),
+    ///     Err(ModelError::EmbedTooLarge {
+    ///         field: EmbedPart::Title,
+    ///         len: 300,
+    ///         max: 256,
+    ///     })
+    /// );
+    /// ```
+    pub fn validate(&self) -> StdResult<(), ModelError> {
+        fn check(field: EmbedPart, len: usize) -> StdResult<(), ModelError> {
+            let max = field.max();
+            if len > max {
+                return Err(ModelError::EmbedTooLarge {
+                    field,
+                    len,
+                    max,
+                });
+            }
+
+            Ok(())
+        }
+
+        let char_count = |text: &Option<Cow<'_, str>>| text.as_ref().map_or(0, |t| t.chars().count());
+
+        check(EmbedPart::Title, char_count(&self.title))?;
+        check(EmbedPart::Description, char_count(&self.description))?;
+        check(EmbedPart::FieldCount, self.fields.len())?;
+        for field in &self.fields {
+            check(EmbedPart::FieldName, field.name.chars().count())?;
+            check(EmbedPart::FieldValue, field.value.chars().count())?;
+        }
+
+        if let Some(footer) = &self.footer {
+            check(EmbedPart::FooterText, footer.text.chars().count())?;
+        }
+
+        if let Some(author) = &self.author {
+            check(EmbedPart::AuthorName, author.name.chars().count())?;
+        }
+
+        Maximum::EmbedLength.check_overflow(self.get_length())
+    }
+
+    /// Shortens the embed to fit within the length limits imposed by Discord, see
+    /// [`Self::validate`].
+    ///
+    /// Each part is truncated to its own limit, and fields past the 25th are removed. If the
+    /// embed is still over the total limit, the description is shortened, and then fields are
+    /// removed from the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::CreateEmbed;
+    ///
+    /// let embed = CreateEmbed::new().title("a".repeat(300)).description("b".repeat(5000));
+    /// assert!(embed.validate().is_err());
+    /// assert!(embed.truncate_to_limits().validate().is_ok());
+    /// ```
+    pub fn truncate_to_limits(mut self) -> Self {
+        self.fields.truncate(EmbedPart::FieldCount.max());
+        for field in &mut self.fields {
+            truncate_chars(&mut field.name, EmbedPart::FieldName.max());
+            truncate_chars(&mut field.value, EmbedPart::FieldValue.max());
+        }
+
+        if let Some(title) = &mut self.title {
+            truncate_chars(title, EmbedPart::Title.max());
+        }
+        if let Some(description) = &mut self.description {
+            truncate_chars(description, EmbedPart::Description.max());
+        }
+        if let Some(footer) = &mut self.footer {
+            truncate_chars(&mut footer.text, EmbedPart::FooterText.max());
+        }
+        if let Some(author) = &mut self.author {
+            truncate_chars(&mut author.name, EmbedPart::AuthorName.max());
+        }
+
+        let mut excess = self.get_length().saturating_sub(EMBED_MAX_LENGTH);
+        if let Some(description) = &mut self.description {
+            let length = description.chars().count();
+            truncate_chars(description, length.saturating_sub(excess));
+            excess = excess.saturating_sub(length);
+        }
+
+        while excess > 0 {
+            let Some(field) = self.fields.pop() else { break };
+            excess = excess.saturating_sub(field.name.chars().count() + field.value.chars().count());
+        }
+
+        self
+    }
+
     pub(super) fn get_length(&self) -> usize {
         let mut length = 0;
         if let Some(author) = &self.author {
@@ -293,6 +413,19 @@ impl<'a> CreateEmbedAuthor<'a> {
         self.url = Some(url.into());
         self
     }
+
+    /// Creates an author object with the user's display name and avatar.
+    #[cfg(feature = "model")]
+    pub fn from_user(user: &User) -> Self {
+        Self::new(user.display_name().to_owned()).icon_url(user.face())
+    }
+
+    /// Creates an author object with the member's display name and avatar, preferring their guild
+    /// specific nickname and avatar.
+    #[cfg(feature = "model")]
+    pub fn from_member(member: &Member) -> Self {
+        Self::new(member.display_name().to_owned()).icon_url(member.face())
+    }
 }
 
 impl<'a> From<EmbedAuthor> for CreateEmbedAuthor<'a> {
@@ -341,6 +474,19 @@ impl<'a> CreateEmbedFooter<'a> {
         self.icon_url = Some(icon_url.into());
         self
     }
+
+    /// Creates a footer object with the user's display name and avatar.
+    #[cfg(feature = "model")]
+    pub fn from_user(user: &User) -> Self {
+        Self::new(user.display_name().to_owned()).icon_url(user.face())
+    }
+
+    /// Creates a footer object with the member's display name and avatar, preferring their guild
+    /// specific nickname and avatar.
+    #[cfg(feature = "model")]
+    pub fn from_member(member: &Member) -> Self {
+        Self::new(member.display_name().to_owned()).icon_url(member.face())
+    }
 }
 
 impl<'a> From<EmbedFooter> for CreateEmbedFooter<'a> {
@@ -352,6 +498,16 @@ impl<'a> From<EmbedFooter> for CreateEmbedFooter<'a> {
     }
 }
 
+/// Truncates the text to at most `max` characters.
+fn truncate_chars(text: &mut Cow<'_, str>, max: usize) {
+    if let Some((index, _)) = text.char_indices().nth(max) {
+        match text {
+            Cow::Borrowed(text) => *text = &text[..index],
+            Cow::Owned(text) => text.truncate(index),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 struct CreateEmbedField<'a> {
     name: Cow<'a, str>,
@@ -2,11 +2,14 @@ use std::borrow::Cow;
 
 use serde_json::json;
 
+use super::create_poll::Ready;
 use super::{
     CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
+    CreatePoll,
     EditAttachments,
 };
 #[cfg(feature = "http")]
@@ -94,10 +97,19 @@ impl CreateInteractionResponse<'_> {
         | CreateInteractionResponse::Defer(data)
         | CreateInteractionResponse::UpdateMessage(data) = self
         {
-            super::check_lengths(data.content.as_deref(), data.embeds.as_deref(), 0)
-        } else {
-            Ok(())
+            super::check_lengths(data.content.as_deref(), data.embeds.as_deref(), 0)?;
+
+            if let Some(poll) = &data.poll {
+                // Only a new message can carry a poll, deferring or updating a message cannot.
+                if !matches!(self, CreateInteractionResponse::Message(_)) {
+                    return Err(ModelError::PollNotAllowed);
+                }
+
+                poll.check_length()?;
+            }
         }
+
+        Ok(())
     }
 
     /// Creates a response to the interaction received.
@@ -107,7 +119,8 @@ impl CreateInteractionResponse<'_> {
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Model`] if the message content is too long. May also return an
+    /// Returns an [`Error::Model`] if the message content or poll is too long, or if a poll is set
+    /// on a response other than [`Self::Message`]. May also return an
     /// [`Error::Http`] if the API returns an error, or an [`Error::Json`] if there is an error in
     /// deserializing the API response.
     #[cfg(feature = "http")]
@@ -127,7 +140,7 @@ impl CreateInteractionResponse<'_> {
 
         if let Self::Message(msg) | Self::Defer(msg) | Self::UpdateMessage(msg) = &mut self {
             if msg.allowed_mentions.is_none() {
-                msg.allowed_mentions.clone_from(&http.default_allowed_mentions);
+                msg.allowed_mentions = http.default_allowed_mentions();
             }
         };
 
@@ -150,8 +163,10 @@ pub struct CreateInteractionResponseMessage<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<InteractionResponseFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Cow<'a, [CreateActionRow<'a>]>>,
+    components: Option<Cow<'a, [CreateComponent<'a>]>>,
     attachments: EditAttachments<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<CreatePoll<'a, Ready>>,
 }
 
 impl<'a> CreateInteractionResponseMessage<'a> {
@@ -261,11 +276,19 @@ impl<'a> CreateInteractionResponseMessage<'a> {
     }
 
     /// Sets the components of this message.
-    pub fn components(mut self, components: impl Into<Cow<'a, [CreateActionRow<'a>]>>) -> Self {
+    pub fn components(mut self, components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
         self.components = Some(components.into());
         self
     }
     super::button_and_select_menu_convenience_methods!(self.components);
+
+    /// Sets the [`Poll`] for this message.
+    ///
+    /// **Note**: Polls can only be sent with [`CreateInteractionResponse::Message`].
+    pub fn poll(mut self, poll: CreatePoll<'a, Ready>) -> Self {
+        self.poll = Some(poll);
+        self
+    }
 }
 
 // Same as CommandOptionChoice according to Discord, see
@@ -331,6 +354,49 @@ impl<'a> CreateAutocompleteResponse<'a> {
         self
     }
 
+    /// Sets the autocomplete suggestions from an iterator, such as a filtered list of matches,
+    /// keeping only as many choices as Discord accepts.
+    ///
+    /// Choices are taken from the iterator until 25 are collected, so the iterator may be
+    /// arbitrarily long. Choice names longer than 100 characters are truncated, while choices with
+    /// an empty name or a string value longer than 100 characters are skipped, since Discord would
+    /// reject the entire response otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::CreateAutocompleteResponse;
+    ///
+    /// let fruits = ["apple", "apricot", "banana", "blueberry"];
+    /// let input = "ap";
+    ///
+    /// let response = CreateAutocompleteResponse::new()
+    ///     .set_choices_from_iter(fruits.into_iter().filter(|fruit| fruit.starts_with(input)));
+    /// ```
+    pub fn set_choices_from_iter(
+        mut self,
+        choices: impl IntoIterator<Item = impl Into<AutocompleteChoice<'a>>>,
+    ) -> Self {
+        self.choices = choices
+            .into_iter()
+            .map(Into::into)
+            .filter_map(|mut choice: AutocompleteChoice<'a>| {
+                let value_len = choice.value.as_str().map_or(0, |value| value.chars().count());
+                if choice.name.is_empty() || value_len > 100 {
+                    return None;
+                }
+
+                if let Some((end, _)) = choice.name.char_indices().nth(100) {
+                    choice.name.to_mut().truncate(end);
+                }
+
+                Some(choice)
+            })
+            .take(25)
+            .collect();
+        self
+    }
+
     /// Add an int autocomplete choice.
     ///
     /// **Note**: There can be no more than 25 choices set. Name must be between 1 and 100
@@ -384,7 +450,7 @@ impl<'a> CreateAutocompleteResponse<'a> {
 #[derive(Clone, Debug, Default, Serialize)]
 #[must_use]
 pub struct CreateModal<'a> {
-    components: Cow<'a, [CreateActionRow<'a>]>,
+    pub(super) components: Cow<'a, [CreateActionRow<'a>]>,
     custom_id: Cow<'a, str>,
     title: Cow<'a, str>,
 }
@@ -407,3 +473,49 @@ impl<'a> CreateModal<'a> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AutocompleteChoice, CreateAutocompleteResponse};
+    #[cfg(feature = "http")]
+    use super::{CreateInteractionResponse, CreateInteractionResponseMessage};
+
+    #[test]
+    fn autocomplete_choices_from_iter() {
+        let long = "a".repeat(150);
+        let choices = ["", long.as_str(), "valid"]
+            .into_iter()
+            .map(|name| AutocompleteChoice::new(name, "value"))
+            .chain(std::iter::once(AutocompleteChoice::new("skipped", long.as_str())))
+            .chain((0..50).map(|i| AutocompleteChoice::new(i.to_string(), i)));
+
+        let response = CreateAutocompleteResponse::new().set_choices_from_iter(choices);
+        assert_eq!(response.choices.len(), 25);
+        assert_eq!(response.choices[0].name.chars().count(), 100);
+        assert_eq!(response.choices[1].name, "valid");
+        assert_eq!(response.choices[2].name, "0");
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn poll_only_allowed_in_new_messages() {
+        use std::time::Duration;
+
+        use crate::builder::{CreatePoll, CreatePollAnswer};
+        use crate::model::error::Error as ModelError;
+
+        let data = || {
+            let poll = CreatePoll::new()
+                .question("Cats or dogs?")
+                .answers(vec![CreatePollAnswer::new().text("Cats")])
+                .duration(Duration::from_secs(60 * 60));
+            CreateInteractionResponseMessage::new().poll(poll)
+        };
+
+        assert_eq!(CreateInteractionResponse::Message(data()).check_length(), Ok(()));
+        let defer = CreateInteractionResponse::Defer(data()).check_length();
+        assert_eq!(defer, Err(ModelError::PollNotAllowed));
+        let update = CreateInteractionResponse::UpdateMessage(data()).check_length();
+        assert_eq!(update, Err(ModelError::PollNotAllowed));
+    }
+}
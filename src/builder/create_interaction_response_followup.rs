@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 
+use super::create_poll::Ready;
 use super::{
-    CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
+    CreatePoll,
     EditAttachments,
 };
 #[cfg(feature = "http")]
@@ -27,10 +29,12 @@ pub struct CreateInteractionResponseFollowup<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<CreateAllowedMentions<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Cow<'a, [CreateActionRow<'a>]>>,
+    components: Option<Cow<'a, [CreateComponent<'a>]>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<MessageFlags>,
     attachments: EditAttachments<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<CreatePoll<'a, Ready>>,
 }
 
 impl<'a> CreateInteractionResponseFollowup<'a> {
@@ -41,7 +45,13 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
 
     #[cfg(feature = "http")]
     fn check_length(&self) -> Result<(), ModelError> {
-        super::check_lengths(self.content.as_deref(), self.embeds.as_deref(), 0)
+        super::check_lengths(self.content.as_deref(), self.embeds.as_deref(), 0)?;
+
+        if let Some(poll) = &self.poll {
+            poll.check_length()?;
+        }
+
+        Ok(())
     }
 
     /// Set the content of the message.
@@ -141,12 +151,20 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
     }
 
     /// Sets the components of this message.
-    pub fn components(mut self, components: impl Into<Cow<'a, [CreateActionRow<'a>]>>) -> Self {
+    pub fn components(mut self, components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
         self.components = Some(components.into());
         self
     }
     super::button_and_select_menu_convenience_methods!(self.components);
 
+    /// Sets the [`Poll`] for this message.
+    ///
+    /// **Note**: Polls cannot be added when editing an existing followup message.
+    pub fn poll(mut self, poll: CreatePoll<'a, Ready>) -> Self {
+        self.poll = Some(poll);
+        self
+    }
+
     /// Creates or edits a followup response to the response sent. If a [`MessageId`] is provided,
     /// then the corresponding message will be edited. Otherwise, a new message will be created.
     ///
@@ -155,27 +173,82 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Model`] if the content is too long. May also return [`Error::Http`] if the
+    /// Returns [`Error::Model`] if the content or poll is too long, or if a poll is set while
+    /// editing a message. May also return [`Error::Http`] if the
     /// API returns an error, or [`Error::Json`] if there is an error in deserializing the
     /// response.
     #[cfg(feature = "http")]
     pub async fn execute(
+        self,
+        http: &Http,
+        message_id: Option<MessageId>,
+        interaction_token: &str,
+    ) -> Result<Message> {
+        self.execute_for(http, None, message_id, interaction_token).await
+    }
+
+    /// Like [`Self::execute`], but for the given application instead of the one set on the
+    /// [`Http`], if any.
+    #[cfg(feature = "http")]
+    pub(crate) async fn execute_for(
         mut self,
         http: &Http,
+        application_id: Option<ApplicationId>,
         message_id: Option<MessageId>,
         interaction_token: &str,
     ) -> Result<Message> {
         self.check_length()?;
+        if message_id.is_some() && self.poll.is_some() {
+            return Err(Error::Model(ModelError::PollNotAllowed));
+        }
 
         let files = self.attachments.take_files();
 
         if self.allowed_mentions.is_none() {
-            self.allowed_mentions.clone_from(&http.default_allowed_mentions);
+            self.allowed_mentions = http.default_allowed_mentions();
         }
 
+        let application_id = application_id.map_or_else(|| http.try_application_id(), Ok)?;
         match message_id {
-            Some(id) => http.edit_followup_message(interaction_token, id, &self, files).await,
-            None => http.create_followup_message(interaction_token, &self, files).await,
+            Some(id) => {
+                http.edit_followup_message_for(application_id, interaction_token, id, &self, files)
+                    .await
+            },
+            None => {
+                http.create_followup_message_for(application_id, interaction_token, &self, files)
+                    .await
+            },
         }
     }
 }
+
+#[cfg(all(test, feature = "http_transport"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::CreateInteractionResponseFollowup;
+    use crate::builder::{CreatePoll, CreatePollAnswer};
+    use crate::http::{HttpBuilder, MockTransport};
+    use crate::model::error::Error as ModelError;
+    use crate::model::id::{ApplicationId, MessageId};
+    use crate::Error;
+
+    #[tokio::test]
+    async fn poll_not_allowed_when_editing() {
+        let transport = MockTransport::new();
+        let http = HttpBuilder::new("token")
+            .application_id(ApplicationId::new(1))
+            .transport(transport.clone())
+            .build();
+
+        let poll = CreatePoll::new()
+            .question("Cats or dogs?")
+            .answers(vec![CreatePollAnswer::new().text("Cats")])
+            .duration(Duration::from_secs(60 * 60));
+        let builder = CreateInteractionResponseFollowup::new().poll(poll);
+        let error = builder.execute(&http, Some(MessageId::new(2)), "token").await.unwrap_err();
+
+        assert!(matches!(error, Error::Model(ModelError::PollNotAllowed)));
+        assert!(transport.requests().is_empty());
+    }
+}
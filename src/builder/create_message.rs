@@ -2,9 +2,9 @@ use std::borrow::Cow;
 
 use super::create_poll::Ready;
 use super::{
-    CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
     CreatePoll,
     EditAttachments,
@@ -61,9 +61,9 @@ pub struct CreateMessage<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<CreateAllowedMentions<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    message_reference: Option<MessageReference>,
+    pub(crate) message_reference: Option<MessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Cow<'a, [CreateActionRow<'a>]>>,
+    components: Option<Cow<'a, [CreateComponent<'a>]>>,
     sticker_ids: Cow<'a, [StickerId]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<MessageFlags>,
@@ -75,6 +75,8 @@ pub struct CreateMessage<'a> {
     // The following fields are handled separately.
     #[serde(skip)]
     reactions: Cow<'a, [ReactionType]>,
+    #[serde(skip)]
+    idempotency_key: Option<Cow<'a, str>>,
 }
 
 impl<'a> CreateMessage<'a> {
@@ -84,7 +86,21 @@ impl<'a> CreateMessage<'a> {
 
     #[cfg(feature = "http")]
     fn check_length(&self) -> Result<(), ModelError> {
-        super::check_lengths(self.content.as_deref(), Some(&self.embeds), self.sticker_ids.len())
+        super::check_lengths(self.content.as_deref(), Some(&self.embeds), self.sticker_ids.len())?;
+
+        if let Some(poll) = &self.poll {
+            if self
+                .message_reference
+                .as_ref()
+                .is_some_and(|reference| reference.kind == MessageReferenceKind::Forward)
+            {
+                return Err(ModelError::PollNotAllowed);
+            }
+
+            poll.check_length()?;
+        }
+
+        Ok(())
     }
 
     /// Set the content of the message.
@@ -193,8 +209,58 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Set the reference message this message is a reply to, along with whether sending should
+    /// fail if the referenced message no longer exists.
+    ///
+    /// If `fail_if_not_exists` is `false`, the message is sent as a normal message instead of
+    /// erroring when the referenced message was deleted.
+    pub fn reference_message_with(
+        mut self,
+        reference: impl Into<MessageReference>,
+        fail_if_not_exists: bool,
+    ) -> Self {
+        let mut reference = reference.into();
+        reference.fail_if_not_exists = Some(fail_if_not_exists);
+
+        self.message_reference = Some(reference);
+        self
+    }
+
+    /// Forwards the given message, by setting the reference of this message to it with the
+    /// [`MessageReferenceKind::Forward`] type.
+    ///
+    /// **Note**: A forwarded message cannot have any content, embeds, attachments, stickers,
+    /// components or polls of its own.
+    pub fn forward(self, message: &Message) -> Self {
+        self.reference_message(MessageReference::forward(message))
+    }
+
+    /// Sets a key identifying this message, so that sending it again with the same key returns
+    /// the message sent the first time instead of posting a duplicate. This makes retrying after
+    /// an ambiguous failure, such as a timeout, safe.
+    ///
+    /// Keys are remembered by the [`IdempotencyStore`] set via
+    /// [`HttpBuilder::idempotency_store`]. Keys are not scoped by channel, so include the channel
+    /// in the key when sending the same message to several.
+    ///
+    /// Unless a [`Self::nonce`] is set, an enforced nonce is derived from the key as well, so
+    /// Discord deduplicates sends which happen within a few minutes of each other, even before
+    /// the first one completed or if there is no store.
+    ///
+    /// [`IdempotencyStore`]: crate::http::IdempotencyStore
+    /// [`HttpBuilder::idempotency_store`]: crate::http::HttpBuilder::idempotency_store
+    pub fn idempotency_key(mut self, key: impl Into<Cow<'a, str>>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
     /// Sets the components of this message.
-    pub fn components(mut self, components: impl Into<Cow<'a, [CreateActionRow<'a>]>>) -> Self {
+    ///
+    /// **Note**: Layout components, such as [`CreateContainer`], can only be sent with the
+    /// [`MessageFlags::IS_COMPONENTS_V2`] flag set, see [`Self::flags`].
+    ///
+    /// [`CreateContainer`]: super::CreateContainer
+    pub fn components(mut self, components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
         self.components = Some(components.into());
         self
     }
@@ -247,12 +313,13 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
-    /// Can be used to verify a message was sent (up to 25 characters). Value will appear in
-    /// [`Message::nonce`]
+    /// Can be used to verify a message was sent (up to 25 characters), either as a string or an
+    /// integer. Value will appear in [`Message::nonce`]
     ///
-    /// See [`Self::enforce_nonce`] if you would like discord to perform de-duplication.
-    pub fn nonce(mut self, nonce: Nonce) -> Self {
-        self.nonce = Some(nonce);
+    /// See [`Self::enforce_nonce`] if you would like discord to perform de-duplication, and
+    /// [`ChannelId::await_nonce`] to wait for the sent message to be received over the gateway.
+    pub fn nonce(mut self, nonce: impl Into<Nonce>) -> Self {
+        self.nonce = Some(nonce.into());
         self
     }
 
@@ -265,6 +332,8 @@ impl<'a> CreateMessage<'a> {
     }
 
     /// Sets the [`Poll`] for this message.
+    ///
+    /// **Note**: Polls cannot be sent in forwarded messages.
     pub fn poll(mut self, poll: CreatePoll<'a, Ready>) -> Self {
         self.poll = Some(poll);
         self
@@ -280,12 +349,15 @@ impl<'a> CreateMessage<'a> {
     ///
     /// # Errors
     ///
-    /// Returns a [`ModelError::TooLarge`] if the message contents are over the above limits.
+    /// Returns a [`ModelError::TooLarge`] if the message contents or poll are over the above
+    /// limits, or a [`ModelError::PollNotAllowed`] if a poll is set on a forwarded message.
     ///
-    /// Returns [`Error::Http`] if the current user lacks permission or if invalid data is given.
+    /// Returns [`Error::Http`] if the current user lacks permission or if invalid data is given,
+    /// or if a message was already sent with the [idempotency key] but can't be retrieved.
     ///
     /// [Send Messages]: Permissions::SEND_MESSAGES
     /// [Attach Files]: Permissions::ATTACH_FILES
+    /// [idempotency key]: Self::idempotency_key
     #[cfg(feature = "http")]
     pub async fn execute(
         mut self,
@@ -295,13 +367,34 @@ impl<'a> CreateMessage<'a> {
     ) -> Result<Message> {
         self.check_length()?;
 
+        let store = http.idempotency_store.as_ref().zip(self.idempotency_key.as_deref());
+        if let Some((store, key)) = store {
+            if let Some(message_id) = store.get(key).await {
+                let mut message = http.get_message(channel_id, message_id).await?;
+                if message.guild_id.is_none() {
+                    message.guild_id = guild_id;
+                }
+
+                return Ok(message);
+            }
+        }
+
+        if let (None, Some(key)) = (&self.nonce, &self.idempotency_key) {
+            self.nonce = Some(idempotency_nonce(key));
+            self.enforce_nonce = true;
+        }
+
         let files = self.attachments.take_files();
         if self.allowed_mentions.is_none() {
-            self.allowed_mentions.clone_from(&http.default_allowed_mentions);
+            self.allowed_mentions = http.default_allowed_mentions();
         }
 
         let mut message = http.send_message(channel_id, files, &self).await?;
 
+        if let Some((store, key)) = http.idempotency_store.as_ref().zip(self.idempotency_key) {
+            store.insert(&key, message.id).await;
+        }
+
         for reaction in self.reactions.iter() {
             http.create_reaction(channel_id, message.id, reaction).await?;
         }
@@ -315,3 +408,116 @@ impl<'a> CreateMessage<'a> {
         Ok(message)
     }
 }
+
+/// Derives the nonce for an idempotency key, using the 64-bit FNV-1a hash so it is stable across
+/// processes and fits in the 25 characters Discord allows.
+#[cfg(feature = "http")]
+fn idempotency_nonce(key: &str) -> Nonce {
+    let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+
+    Nonce::String(FixedString::from_string_trunc(format!("{hash:016x}")))
+}
+
+#[cfg(all(test, feature = "http_transport"))]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::builder::CreatePollAnswer;
+    use crate::http::{HttpBuilder, LightMethod, MemoryIdempotencyStore, MockTransport, StatusCode};
+
+    #[tokio::test]
+    async fn idempotency_key_deduplicates_sends() {
+        let message = json!({
+            "id": "2",
+            "channel_id": "1",
+            "author": {"id": "3", "username": "user", "discriminator": "0", "avatar": null},
+            "content": "hello",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        });
+        let transport = MockTransport::new()
+            .respond(LightMethod::Post, "/channels/*/messages", StatusCode::OK, message.clone())
+            .respond(LightMethod::Get, "/channels/*/messages/*", StatusCode::OK, message);
+        let http = HttpBuilder::new("token")
+            .transport(transport.clone())
+            .idempotency_store(MemoryIdempotencyStore::new(Duration::from_secs(60)))
+            .build();
+
+        let builder = CreateMessage::new().content("hello").idempotency_key("greeting");
+        let first = builder.clone().execute(&http, ChannelId::new(1), None).await.unwrap();
+        let second = builder.execute(&http, ChannelId::new(1), None).await.unwrap();
+        assert_eq!(first.id, second.id);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, reqwest::Method::GET);
+
+        // The first send lets Discord deduplicate concurrent sends using the key
+        let body = requests[0].json().unwrap();
+        assert_eq!(body["nonce"], json!("dbdc244fa0b52af6"));
+        assert_eq!(body["enforce_nonce"], json!(true));
+    }
+
+    fn message() -> Message {
+        serde_json::from_value(json!({
+            "id": "2",
+            "channel_id": "1",
+            "guild_id": "3",
+            "author": {"id": "3", "username": "user", "discriminator": "0", "avatar": null},
+            "content": "hello",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn forward_sets_reference_type() {
+        let message = message();
+        let body = serde_json::to_value(CreateMessage::new().forward(&message)).unwrap();
+        assert_eq!(body["message_reference"], json!({
+            "type": 1,
+            "message_id": "2",
+            "channel_id": "1",
+            "guild_id": "3",
+            "fail_if_not_exists": null,
+        }));
+    }
+
+    #[test]
+    fn poll_not_allowed_on_forward() {
+        let poll = || {
+            CreatePoll::new()
+                .question("Cats or dogs?")
+                .answers(vec![CreatePollAnswer::new().text("Cats")])
+                .duration(Duration::from_secs(60 * 60))
+        };
+        let message = message();
+
+        let forward = CreateMessage::new().forward(&message).poll(poll());
+        assert_eq!(forward.check_length(), Err(ModelError::PollNotAllowed));
+        let reply = CreateMessage::new().reference_message(&message).poll(poll());
+        assert_eq!(reply.check_length(), Ok(()));
+    }
+}
@@ -1,6 +1,8 @@
 use std::borrow::Cow;
 
 use crate::model::channel::{PollLayoutType, PollMediaEmoji};
+#[cfg(feature = "http")]
+use crate::model::error::{Error as ModelError, Maximum};
 
 #[derive(serde::Serialize, Clone, Debug)]
 pub struct NeedsQuestion;
@@ -135,6 +137,22 @@ impl<'a> CreatePoll<'a, NeedsDuration> {
     }
 }
 
+impl CreatePoll<'_, Ready> {
+    #[cfg(feature = "http")]
+    pub(crate) fn check_length(&self) -> Result<(), ModelError> {
+        Maximum::PollQuestionLength.check_overflow(self.question.text.chars().count())?;
+        Maximum::PollAnswerCount.check_overflow(self.answers.len())?;
+
+        for answer in self.answers.iter() {
+            if let Some(text) = &answer.poll_media.text {
+                Maximum::PollAnswerLength.check_overflow(text.chars().count())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<Stage: Sealed> CreatePoll<'_, Stage> {
     /// Sets the layout type for the Poll to take.
     ///
@@ -181,3 +199,40 @@ impl<'a> CreatePollAnswer<'a> {
         self
     }
 }
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::{CreatePoll, CreatePollAnswer, Ready};
+    use crate::model::error::{Error as ModelError, Maximum};
+
+    fn poll(question: String, count: usize, answer: String) -> CreatePoll<'static, Ready> {
+        CreatePoll::new()
+            .question(question)
+            .answers(vec![CreatePollAnswer::new().text(answer); count])
+            .duration(Duration::from_secs(60 * 60))
+    }
+
+    #[test]
+    fn check_length_limits() {
+        let text = |len| "a".repeat(len);
+        let too_large = |maximum, value| {
+            Err(ModelError::TooLarge {
+                maximum,
+                value,
+            })
+        };
+
+        assert_eq!(poll(text(300), 10, text(55)).check_length(), Ok(()));
+        // Lengths are counted in characters rather than bytes
+        assert_eq!(poll("\u{e9}".repeat(300), 10, "\u{e9}".repeat(55)).check_length(), Ok(()));
+
+        let question = poll(text(301), 10, text(55)).check_length();
+        assert_eq!(question, too_large(Maximum::PollQuestionLength, 301));
+        let answer_count = poll(text(300), 11, text(55)).check_length();
+        assert_eq!(answer_count, too_large(Maximum::PollAnswerCount, 11));
+        let answer = poll(text(300), 10, text(56)).check_length();
+        assert_eq!(answer, too_large(Maximum::PollAnswerLength, 56));
+    }
+}
@@ -17,6 +17,8 @@ pub struct CreateStageInstance<'a> {
     privacy_level: StageInstancePrivacyLevel,
     #[serde(skip_serializing_if = "Option::is_none")]
     send_start_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guild_scheduled_event_id: Option<ScheduledEventId>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -30,6 +32,7 @@ impl<'a> CreateStageInstance<'a> {
             topic: topic.into(),
             privacy_level: StageInstancePrivacyLevel::default(),
             send_start_notification: None,
+            guild_scheduled_event_id: None,
             audit_log_reason: None,
         }
     }
@@ -41,12 +44,25 @@ impl<'a> CreateStageInstance<'a> {
         self
     }
 
+    /// Sets the privacy level of the stage instance
+    pub fn privacy_level(mut self, privacy_level: StageInstancePrivacyLevel) -> Self {
+        self.privacy_level = privacy_level;
+        self
+    }
+
     /// Whether or not to notify @everyone that a stage instance has started.
     pub fn send_start_notification(mut self, send_start_notification: bool) -> Self {
         self.send_start_notification = Some(send_start_notification);
         self
     }
 
+    /// Sets the scheduled event the stage instance belongs to. The event should be a
+    /// [`ScheduledEventType::StageInstance`] event in the same channel.
+    pub fn guild_scheduled_event_id(mut self, event_id: ScheduledEventId) -> Self {
+        self.guild_scheduled_event_id = Some(event_id);
+        self
+    }
+
     /// Sets the request's audit log reason.
     pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
         self.audit_log_reason = Some(reason);
@@ -56,7 +56,13 @@ impl<'a> EditAutoModRule<'a> {
     /// Set the type of content which can trigger the rule.
     ///
     /// **None**: The trigger type can't be edited after creation. Only its values.
+    ///
+    /// Setting a [`Trigger::MemberProfile`] also sets the event type to
+    /// [`EventType::MemberUpdate`], as member profile rules don't support any other event type.
     pub fn trigger(mut self, trigger: Trigger) -> Self {
+        if trigger.kind() == TriggerType::MemberProfile {
+            self.event_type = EventType::MemberUpdate;
+        }
         self.trigger = Some(trigger);
         self
     }
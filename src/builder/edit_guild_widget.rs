@@ -4,7 +4,7 @@ use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
-/// A builder to specify the fields to edit in a [`GuildWidget`].
+/// A builder to specify the fields to edit in a [`GuildWidgetSettings`].
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-guild-widget)
 #[derive(Clone, Debug, Default, Serialize)]
@@ -53,7 +53,7 @@ impl<'a> EditGuildWidget<'a> {
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[cfg(feature = "http")]
-    pub async fn execute(self, http: &Http, guild_id: GuildId) -> Result<GuildWidget> {
+    pub async fn execute(self, http: &Http, guild_id: GuildId) -> Result<GuildWidgetSettings> {
         http.edit_guild_widget(guild_id, &self, self.audit_log_reason).await
     }
 }
@@ -1,9 +1,9 @@
 use std::borrow::Cow;
 
 use super::{
-    CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
     EditAttachments,
     EditWebhookMessage,
@@ -70,7 +70,7 @@ impl<'a> EditInteractionResponse<'a> {
     }
 
     /// Sets the components of this message.
-    pub fn components(self, components: impl Into<Cow<'a, [CreateActionRow<'a>]>>) -> Self {
+    pub fn components(self, components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
         Self(self.0.components(components))
     }
     super::button_and_select_menu_convenience_methods!(self.0.components);
@@ -111,11 +111,25 @@ impl<'a> EditInteractionResponse<'a> {
     /// [`Error::Http`] if the API returns an error, or an [`Error::Json`] if there is an error in
     /// deserializing the API response.
     #[cfg(feature = "http")]
-    pub async fn execute(mut self, http: &Http, interaction_token: &str) -> Result<Message> {
+    pub async fn execute(self, http: &Http, interaction_token: &str) -> Result<Message> {
+        self.execute_for(http, None, interaction_token).await
+    }
+
+    /// Like [`Self::execute`], but for the given application instead of the one set on the
+    /// [`Http`], if any.
+    #[cfg(feature = "http")]
+    pub(crate) async fn execute_for(
+        mut self,
+        http: &Http,
+        application_id: Option<ApplicationId>,
+        interaction_token: &str,
+    ) -> Result<Message> {
         self.0.check_length()?;
 
         let files = self.0.attachments.as_mut().map_or(Vec::new(), EditAttachments::take_files);
+        let application_id = application_id.map_or_else(|| http.try_application_id(), Ok)?;
 
-        http.edit_original_interaction_response(interaction_token, &self, files).await
+        http.edit_original_interaction_response_for(application_id, interaction_token, &self, files)
+            .await
     }
 }
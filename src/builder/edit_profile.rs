@@ -68,6 +68,23 @@ impl<'a> EditProfile<'a> {
     }
 
     /// Sets the banner of the current user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::builder::{EditProfile, CreateAttachment};
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// # use serenity::http::Http;
+    /// #
+    /// # #[cfg(feature = "http")]
+    /// # async fn _foo(http: &Http, current_user: &mut CurrentUser) -> Result<(), SerenityError> {
+    /// let banner = CreateAttachment::path("./my_banner.png").await.expect("Failed to read image.");
+    /// current_user.edit(http, EditProfile::new().banner(&banner)).await?;
+    /// println!("New banner: {:?}", current_user.banner_url());
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn banner(mut self, banner: &CreateAttachment<'_>) -> Self {
         self.banner = Some(Some(banner.to_base64()));
         self
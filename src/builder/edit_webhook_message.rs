@@ -1,9 +1,9 @@
 use std::borrow::Cow;
 
 use super::{
-    CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
     EditAttachments,
 };
@@ -26,7 +26,7 @@ pub struct EditWebhookMessage<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<CreateAllowedMentions<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) components: Option<Cow<'a, [CreateActionRow<'a>]>>,
+    pub(crate) components: Option<Cow<'a, [CreateComponent<'a>]>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) attachments: Option<EditAttachments<'a>>,
 
@@ -107,7 +107,7 @@ impl<'a> EditWebhookMessage<'a> {
     ///
     /// [`WebhookType::Application`]: crate::model::webhook::WebhookType
     /// [`WebhookType::Incoming`]: crate::model::webhook::WebhookType
-    pub fn components(mut self, components: impl Into<Cow<'a, [CreateActionRow<'a>]>>) -> Self {
+    pub fn components(mut self, components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
         self.components = Some(components.into());
         self
     }
@@ -167,7 +167,7 @@ impl<'a> EditWebhookMessage<'a> {
         let files = self.attachments.as_mut().map_or(Vec::new(), EditAttachments::take_files);
 
         if self.allowed_mentions.is_none() {
-            self.allowed_mentions.clone_from(&http.default_allowed_mentions);
+            self.allowed_mentions = http.default_allowed_mentions();
         }
 
         http.edit_webhook_message(
@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 
+use super::create_poll::Ready;
 use super::{
-    CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
+    CreatePoll,
     EditAttachments,
 };
 #[cfg(feature = "http")]
@@ -68,12 +70,14 @@ pub struct ExecuteWebhook<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<CreateAllowedMentions<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Cow<'a, [CreateActionRow<'a>]>>,
+    components: Option<Cow<'a, [CreateComponent<'a>]>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<MessageFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
     thread_name: Option<Cow<'a, str>>,
     attachments: EditAttachments<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<CreatePoll<'a, Ready>>,
 
     #[serde(skip)]
     thread_id: Option<ChannelId>,
@@ -87,7 +91,17 @@ impl<'a> ExecuteWebhook<'a> {
 
     #[cfg(feature = "http")]
     fn check_length(&self) -> Result<(), ModelError> {
-        super::check_lengths(self.content.as_deref(), Some(&self.embeds), 0)
+        super::check_lengths(self.content.as_deref(), Some(&self.embeds), 0)?;
+
+        if let Some(poll) = &self.poll {
+            poll.check_length()?;
+        }
+
+        if let Some(username) = &self.username {
+            super::check_webhook_username(username)?;
+        }
+
+        Ok(())
     }
 
     /// Override the default avatar of the webhook with an image URL.
@@ -211,7 +225,7 @@ impl<'a> ExecuteWebhook<'a> {
     ///
     /// [`WebhookType::Application`]: crate::model::webhook::WebhookType
     /// [`WebhookType::Incoming`]: crate::model::webhook::WebhookType
-    pub fn components(mut self, components: impl Into<Cow<'a, [CreateActionRow<'a>]>>) -> Self {
+    pub fn components(mut self, components: impl Into<Cow<'a, [CreateComponent<'a>]>>) -> Self {
         self.components = Some(components.into());
         self
     }
@@ -262,6 +276,12 @@ impl<'a> ExecuteWebhook<'a> {
 
     /// Override the default username of the webhook.
     ///
+    /// **Note**: The username must be between 1 and 80 characters long, and cannot contain
+    /// `"clyde"` or `"discord"`. When echoing user provided names, consider cleaning them up with
+    /// [`sanitize_webhook_username`] first.
+    ///
+    /// [`sanitize_webhook_username`]: crate::utils::sanitize_webhook_username
+    ///
     /// # Examples
     ///
     /// Overriding the username to `"hakase"`:
@@ -324,10 +344,20 @@ impl<'a> ExecuteWebhook<'a> {
         self.thread_name = Some(thread_name);
         self
     }
+
+    /// Sets the [`Poll`] for this message.
+    pub fn poll(mut self, poll: CreatePoll<'a, Ready>) -> Self {
+        self.poll = Some(poll);
+        self
+    }
+
     /// Executes the webhook with the given content.
     ///
     /// # Errors
     ///
+    /// Returns [`Error::Model`] if the content, embeds or poll are too long, or if the username
+    /// override is invalid.
+    ///
     /// Returns [`Error::Http`] if the content is malformed, if the token is invalid, or if
     /// execution is attempted in a thread not belonging to the webhook's [`Channel`].
     ///
@@ -345,7 +375,7 @@ impl<'a> ExecuteWebhook<'a> {
         let files = self.attachments.take_files();
 
         if self.allowed_mentions.is_none() {
-            self.allowed_mentions.clone_from(&http.default_allowed_mentions);
+            self.allowed_mentions = http.default_allowed_mentions();
         }
 
         http.execute_webhook(webhook_id, self.thread_id, webhook_token, wait, files, &self).await
@@ -28,13 +28,29 @@ pub(crate) fn check_lengths(
         Maximum::EmbedCount.check_overflow(embeds.len())?;
 
         for embed in embeds {
-            Maximum::EmbedLength.check_overflow(embed.get_length())?;
+            embed.validate()?;
         }
     }
 
     Maximum::StickerCount.check_overflow(stickers)
 }
 
+#[cfg(feature = "http")]
+pub(crate) fn check_webhook_username(username: &str) -> StdResult<(), ModelError> {
+    use crate::constants::WEBHOOK_USERNAME_FORBIDDEN;
+    use crate::model::error::{Maximum, Minimum};
+
+    let length = username.chars().count();
+    Minimum::WebhookUsername.check_underflow(length)?;
+    Maximum::WebhookUsername.check_overflow(length)?;
+
+    let username = username.to_ascii_lowercase();
+    match WEBHOOK_USERNAME_FORBIDDEN.iter().find(|forbidden| username.contains(**forbidden)) {
+        Some(forbidden) => Err(ModelError::ForbiddenWebhookUsername(forbidden)),
+        None => Ok(()),
+    }
+}
+
 mod add_member;
 mod bot_auth_parameters;
 mod create_allowed_mentions;
@@ -75,6 +91,7 @@ mod edit_webhook;
 mod edit_webhook_message;
 mod execute_webhook;
 mod get_messages;
+mod modal_validation;
 
 pub use add_member::*;
 pub use bot_auth_parameters::*;
@@ -116,6 +133,7 @@ pub use edit_webhook::*;
 pub use edit_webhook_message::*;
 pub use execute_webhook::*;
 pub use get_messages::*;
+pub use modal_validation::*;
 
 macro_rules! button_and_select_menu_convenience_methods {
     ($self:ident $(. $components_path:tt)+) => {
@@ -126,12 +144,16 @@ macro_rules! button_and_select_menu_convenience_methods {
         pub fn button(mut $self, button: super::CreateButton<'a>) -> Self {
             let rows = $self$(.$components_path)+.get_or_insert_with(Cow::default).to_mut();
             let row_with_space_left = rows.last_mut().and_then(|row| match row {
-                super::CreateActionRow::Buttons(buttons) if buttons.len() < 5 => Some(buttons),
+                super::CreateComponent::ActionRow(super::CreateActionRow::Buttons(buttons))
+                    if buttons.len() < 5 =>
+                {
+                    Some(buttons)
+                },
                 _ => None,
             });
             match row_with_space_left {
                 Some(row) => row.push(button),
-                None => rows.push(super::CreateActionRow::Buttons(vec![button])),
+                None => rows.push(super::CreateActionRow::Buttons(vec![button]).into()),
             }
             $self
         }
@@ -143,7 +165,7 @@ macro_rules! button_and_select_menu_convenience_methods {
             $self$(.$components_path)+
                 .get_or_insert_with(Cow::default)
                 .to_mut()
-                .push(super::CreateActionRow::SelectMenu(select_menu));
+                .push(super::CreateActionRow::SelectMenu(select_menu).into());
             $self
         }
     };
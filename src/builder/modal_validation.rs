@@ -0,0 +1,443 @@
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use super::{CreateActionRow, CreateModal};
+use crate::model::application::{ActionRowComponent, ModalInteractionData};
+
+/// Validation rules for the value of a text input, declared with
+/// [`CreateInputText::validation`].
+///
+/// Discord only enforces the length limits and whether an input is required, so values have to
+/// be validated by the bot when the modal is submitted, see [`CreateModal::validator`].
+///
+/// [`CreateInputText::validation`]: super::CreateInputText::validation
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct InputValidation {
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    number: bool,
+    range: Option<(Bound<f64>, Bound<f64>)>,
+    #[cfg(feature = "regex")]
+    pattern: Option<regex::Regex>,
+}
+
+impl InputValidation {
+    /// Creates validation rules accepting any value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the value to be at least the given number of characters long.
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Requires the value to be at most the given number of characters long.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Requires the value to be a number.
+    pub fn number(mut self) -> Self {
+        self.number = true;
+        self
+    }
+
+    /// Requires the value to be a number within the given range, such as `1.0..=10.0`.
+    pub fn number_range(mut self, range: impl RangeBounds<f64>) -> Self {
+        self.number = true;
+        self.range = Some((range.start_bound().cloned(), range.end_bound().cloned()));
+        self
+    }
+
+    /// Requires the entire value to match the given regex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regex.
+    #[cfg(feature = "regex")]
+    pub fn pattern(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.pattern = Some(regex::Regex::new(&format!("^(?:{pattern})$"))?);
+        Ok(self)
+    }
+
+    /// Validates a value against the rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule the value violates.
+    pub fn validate(&self, value: &str) -> Result<(), InputValidationError> {
+        let length = value.chars().count();
+        if let Some(min_length) = self.min_length.filter(|min_length| length < *min_length) {
+            return Err(InputValidationError::TooShort {
+                min_length,
+            });
+        }
+        if let Some(max_length) = self.max_length.filter(|max_length| length > *max_length) {
+            return Err(InputValidationError::TooLong {
+                max_length,
+            });
+        }
+
+        if self.number {
+            let number = value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .ok_or(InputValidationError::NotANumber)?;
+            if let Some(range) = self.range.filter(|range| !range.contains(&number)) {
+                return Err(InputValidationError::OutOfRange {
+                    min: range.0,
+                    max: range.1,
+                });
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        if self.pattern.as_ref().is_some_and(|pattern| !pattern.is_match(value)) {
+            return Err(InputValidationError::PatternMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A rule of an [`InputValidation`] violated by the value of a text input.
+///
+/// The [`Display`] implementation describes the error to the user who submitted the modal.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum InputValidationError {
+    /// A required input was not submitted.
+    Missing,
+    /// The value is shorter than [`InputValidation::min_length`].
+    TooShort { min_length: usize },
+    /// The value is longer than [`InputValidation::max_length`].
+    TooLong { max_length: usize },
+    /// The value is not a number.
+    NotANumber,
+    /// The value is outside of the [`InputValidation::number_range`].
+    OutOfRange { min: Bound<f64>, max: Bound<f64> },
+    /// The value doesn't match the [`InputValidation::pattern`].
+    #[cfg(feature = "regex")]
+    PatternMismatch,
+}
+
+impl fmt::Display for InputValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => f.write_str("This field is required"),
+            Self::TooShort {
+                min_length,
+            } => write!(f, "Must be at least {min_length} characters long"),
+            Self::TooLong {
+                max_length,
+            } => write!(f, "Must be at most {max_length} characters long"),
+            Self::NotANumber => f.write_str("Must be a number"),
+            Self::OutOfRange {
+                min,
+                max,
+            } => match (min, max) {
+                (Bound::Included(min), Bound::Included(max)) => {
+                    write!(f, "Must be between {min} and {max}")
+                },
+                (Bound::Included(min), _) => write!(f, "Must be at least {min}"),
+                (Bound::Excluded(min), _) => write!(f, "Must be greater than {min}"),
+                (Bound::Unbounded, Bound::Included(max)) => write!(f, "Must be at most {max}"),
+                (Bound::Unbounded, Bound::Excluded(max)) => write!(f, "Must be less than {max}"),
+                (Bound::Unbounded, Bound::Unbounded) => f.write_str("Must be a number"),
+            },
+            #[cfg(feature = "regex")]
+            Self::PatternMismatch => f.write_str("Has an invalid format"),
+        }
+    }
+}
+
+impl std::error::Error for InputValidationError {}
+
+/// A text input of a submitted modal which failed validation.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct InvalidInput {
+    /// The custom ID of the text input.
+    pub custom_id: String,
+    /// The label of the text input.
+    pub label: Option<String>,
+    /// The rule the value violated.
+    pub error: InputValidationError,
+}
+
+/// The text inputs of a submitted modal which failed validation, returned by
+/// [`ModalValidator::validate`].
+///
+/// The [`Display`] implementation lists the errors per field, and can be sent back to the user
+/// who submitted the modal.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ModalValidationErrors {
+    /// The invalid text inputs, in the order they appear in the modal.
+    pub inputs: Vec<InvalidInput>,
+}
+
+impl fmt::Display for ModalValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, input) in self.inputs.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            let name = input.label.as_deref().unwrap_or(&input.custom_id);
+            write!(f, "**{name}**: {}", input.error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ModalValidationErrors {}
+
+#[derive(Clone, Debug)]
+struct ValidatedInput {
+    custom_id: String,
+    label: Option<String>,
+    required: bool,
+    validation: InputValidation,
+}
+
+/// Validates submitted modals against the [`InputValidation`]s declared on the text inputs of a
+/// [`CreateModal`]. Created via [`CreateModal::validator`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::builder::*;
+/// # use serenity::model::prelude::*;
+/// # use serenity::prelude::*;
+/// # async fn run(ctx: &Context, command: &CommandInteraction, modal: &ModalInteraction) -> serenity::Result<()> {
+/// let age = CreateInputText::new(InputTextStyle::Short, "Age", "age")
+///     .validation(InputValidation::new().number_range(13.0..=150.0));
+/// let modal_builder =
+///     CreateModal::new("profile", "Profile").components(vec![CreateActionRow::InputText(age)]);
+/// let validator = modal_builder.validator();
+/// command.create_response(&ctx.http, CreateInteractionResponse::Modal(modal_builder)).await?;
+///
+/// // Once the modal is submitted:
+/// if let Err(errors) = validator.validate(&modal.data) {
+///     let message = CreateInteractionResponseMessage::new().content(errors.to_string());
+///     modal.create_response(&ctx.http, CreateInteractionResponse::Message(message)).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ModalValidator {
+    inputs: Vec<ValidatedInput>,
+}
+
+impl ModalValidator {
+    /// Validates the text inputs of a submitted modal. Inputs without validation rules, and empty
+    /// optional inputs, are always valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns every input which failed validation.
+    pub fn validate(&self, data: &ModalInteractionData) -> Result<(), ModalValidationErrors> {
+        let values =
+            data.components.iter().flat_map(|row| &row.components).filter_map(|component| {
+                match component {
+                    ActionRowComponent::InputText(input) => Some(input),
+                    _ => None,
+                }
+            });
+
+        let mut invalid = Vec::new();
+        for input in &self.inputs {
+            let value = values
+                .clone()
+                .find(|value| value.custom_id == *input.custom_id)
+                .and_then(|value| value.value.as_deref())
+                .unwrap_or_default();
+
+            let result = if value.is_empty() {
+                if input.required {
+                    Err(InputValidationError::Missing)
+                } else {
+                    Ok(())
+                }
+            } else {
+                input.validation.validate(value)
+            };
+
+            if let Err(error) = result {
+                invalid.push(InvalidInput {
+                    custom_id: input.custom_id.clone(),
+                    label: input.label.clone(),
+                    error,
+                });
+            }
+        }
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(ModalValidationErrors {
+                inputs: invalid,
+            })
+        }
+    }
+}
+
+impl CreateModal<'_> {
+    /// Creates a [`ModalValidator`] validating submissions of this modal against the
+    /// [`InputValidation`]s declared on its text inputs.
+    #[must_use]
+    pub fn validator(&self) -> ModalValidator {
+        let inputs = self
+            .components
+            .iter()
+            .filter_map(|row| match row {
+                CreateActionRow::InputText(input) => Some(input),
+                _ => None,
+            })
+            .filter_map(|input| {
+                Some(ValidatedInput {
+                    custom_id: input.custom_id.to_string(),
+                    label: input.label.as_deref().map(ToString::to_string),
+                    required: input.required,
+                    validation: input.validation.clone()?,
+                })
+            })
+            .collect();
+
+        ModalValidator {
+            inputs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use serde_json::json;
+
+    use super::{InputValidation, InputValidationError};
+    use crate::builder::{CreateActionRow, CreateInputText, CreateModal};
+    use crate::model::application::{InputTextStyle, ModalInteractionData};
+
+    #[test]
+    fn length_limits() {
+        let validation = InputValidation::new().min_length(2).max_length(4);
+        assert_eq!(validation.validate("a"), Err(InputValidationError::TooShort {
+            min_length: 2,
+        }));
+        assert_eq!(validation.validate("abcde"), Err(InputValidationError::TooLong {
+            max_length: 4,
+        }));
+        assert!(validation.validate("ab").is_ok());
+        // Lengths are counted in characters, not bytes.
+        assert!(validation.validate("\u{e9}\u{e9}\u{e9}\u{e9}").is_ok());
+    }
+
+    #[test]
+    fn numbers() {
+        let validation = InputValidation::new().number();
+        assert!(validation.validate(" 4.5 ").is_ok());
+        assert_eq!(validation.validate("four"), Err(InputValidationError::NotANumber));
+        assert_eq!(validation.validate("inf"), Err(InputValidationError::NotANumber));
+        assert_eq!(validation.validate("NaN"), Err(InputValidationError::NotANumber));
+    }
+
+    #[test]
+    fn number_ranges() {
+        let validation = InputValidation::new().number_range(1.0..10.0);
+        assert!(validation.validate("1").is_ok());
+        assert!(validation.validate("9.99").is_ok());
+        assert_eq!(validation.validate("10"), Err(InputValidationError::OutOfRange {
+            min: Bound::Included(1.0),
+            max: Bound::Excluded(10.0),
+        }));
+        assert_eq!(validation.validate("ten"), Err(InputValidationError::NotANumber));
+
+        let error = |range| match InputValidation::new().number_range(range).validate("0") {
+            Err(error) => error.to_string(),
+            Ok(()) => panic!("0 should be out of range"),
+        };
+        let bounds = (Bound::Included(1.0), Bound::Included(2.0));
+        assert_eq!(error(bounds), "Must be between 1 and 2");
+        assert_eq!(error((Bound::Included(1.0), Bound::Unbounded)), "Must be at least 1");
+        assert_eq!(error((Bound::Excluded(0.0), Bound::Unbounded)), "Must be greater than 0");
+        assert_eq!(error((Bound::Unbounded, Bound::Included(-1.0))), "Must be at most -1");
+        assert_eq!(error((Bound::Unbounded, Bound::Excluded(0.0))), "Must be less than 0");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn patterns() {
+        let validation = InputValidation::new().pattern("[a-z]+|[0-9]+").unwrap();
+        assert!(validation.validate("abc").is_ok());
+        assert!(validation.validate("123").is_ok());
+        // The pattern has to match the entire value.
+        assert_eq!(validation.validate("abc123"), Err(InputValidationError::PatternMismatch));
+        assert!(InputValidation::new().pattern("(").is_err());
+    }
+
+    #[test]
+    fn modal_validator() {
+        let input = |label, id| CreateInputText::new(InputTextStyle::Short, label, id);
+        let modal = CreateModal::new("profile", "Profile").components(vec![
+            CreateActionRow::InputText(
+                input("Age", "age").validation(InputValidation::new().number_range(13.0..)),
+            ),
+            CreateActionRow::InputText(
+                input("Nickname", "nick").validation(InputValidation::new().min_length(3)),
+            ),
+            CreateActionRow::InputText(
+                input("Website", "site")
+                    .required(false)
+                    .validation(InputValidation::new().min_length(8)),
+            ),
+            CreateActionRow::InputText(input("Bio", "bio")),
+        ]);
+        let validator = modal.validator();
+
+        let data = |age: &str, nick: &str, site: &str| -> ModalInteractionData {
+            let row = |custom_id, value| {
+                let input = json!({"type": 4, "custom_id": custom_id, "value": value});
+                json!({"type": 1, "components": [input]})
+            };
+            let rows = [row("age", age), row("nick", nick), row("site", site), row("bio", "")];
+            serde_json::from_value(json!({"custom_id": "profile", "components": rows}))
+            .unwrap()
+        };
+
+        // Empty optional inputs and inputs without validation rules are always valid.
+        assert!(validator.validate(&data("21", "ferris", "")).is_ok());
+
+        let errors = validator.validate(&data("12", "", "a.b")).unwrap_err();
+        let inputs: Vec<_> =
+            errors.inputs.iter().map(|input| (&*input.custom_id, input.error.clone())).collect();
+        assert_eq!(inputs, [
+            ("age", InputValidationError::OutOfRange {
+                min: Bound::Included(13.0),
+                max: Bound::Unbounded,
+            }),
+            ("nick", InputValidationError::Missing),
+            ("site", InputValidationError::TooShort {
+                min_length: 8,
+            }),
+        ]);
+        assert_eq!(
+            errors.to_string(),
+            "**Age**: Must be at least 13\n**Nickname**: This field is required\n**Website**: Must \
+             be at least 8 characters long"
+        );
+    }
+}
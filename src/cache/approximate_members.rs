@@ -0,0 +1,106 @@
+use super::{ApproximateMemberSettings, Cache};
+use crate::model::prelude::*;
+
+/// A member recorded by the approximate member cache, see [`Settings::approximate_members`].
+///
+/// [`Settings::approximate_members`]: super::Settings::approximate_members
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ApproximateMember {
+    /// The member, as last seen in a payload from Discord.
+    ///
+    /// Fields which the payload didn't include are left at their defaults. For example, members
+    /// seen in messages don't have a [`Member::avatar`] or
+    /// [`Member::communication_disabled_until`].
+    pub member: Member,
+    /// When the member was last seen. Any changes to the member since then are not reflected.
+    pub last_seen_at: Timestamp,
+}
+
+impl ApproximateMember {
+    fn is_expired(&self, settings: &ApproximateMemberSettings, now: Timestamp) -> bool {
+        let age = now.unix_timestamp() - self.last_seen_at.unix_timestamp();
+        u64::try_from(age).is_ok_and(|age| age > settings.time_to_live.as_secs())
+    }
+}
+
+impl Cache {
+    /// Records a member seen in a payload in the approximate member cache, if enabled via
+    /// [`Settings::approximate_members`].
+    ///
+    /// [`Settings::approximate_members`]: super::Settings::approximate_members
+    pub(crate) fn record_approximate_member(&self, guild_id: GuildId, member: &Member) {
+        let Some(settings) = self.settings().approximate_members.clone() else {
+            return;
+        };
+
+        let now = Timestamp::now();
+        let mut members = self.approximate_members.entry(guild_id).or_default();
+        members.retain(|_, seen| !seen.is_expired(&settings, now));
+
+        let user_id = member.user.id;
+        let mut member = member.clone();
+        member.guild_id = guild_id;
+        members.insert(user_id, ApproximateMember {
+            member,
+            last_seen_at: now,
+        });
+
+        if members.len() > settings.max_members_per_guild {
+            let oldest = members
+                .values()
+                .filter(|seen| seen.member.user.id != user_id)
+                .min_by_key(|seen| seen.last_seen_at)
+                .map(|seen| seen.member.user.id);
+            if let Some(oldest) = oldest {
+                members.remove(&oldest);
+            }
+        }
+    }
+
+    /// Removes a member from the approximate member cache, for example because they left the
+    /// guild.
+    pub(crate) fn forget_approximate_member(&self, guild_id: GuildId, user_id: UserId) {
+        if let Some(mut members) = self.approximate_members.get_mut(&guild_id) {
+            members.remove(&user_id);
+        }
+    }
+
+    /// Gets a member from the approximate member cache, if they were seen recently enough.
+    ///
+    /// Always returns [`None`] if [`Settings::approximate_members`] is not set.
+    ///
+    /// [`Settings::approximate_members`]: super::Settings::approximate_members
+    pub fn approximate_member(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Option<ApproximateMember> {
+        let settings = self.settings().approximate_members.clone()?;
+        let members = self.approximate_members.get(&guild_id)?;
+
+        members.get(&user_id).filter(|seen| !seen.is_expired(&settings, Timestamp::now())).cloned()
+    }
+
+    /// Gets all members of a guild from the approximate member cache which were seen recently
+    /// enough.
+    ///
+    /// This only contains members which have interacted with the bot or were otherwise included
+    /// in a payload, so it should not be treated as a complete member list.
+    ///
+    /// Always returns an empty list if [`Settings::approximate_members`] is not set.
+    ///
+    /// [`Settings::approximate_members`]: super::Settings::approximate_members
+    pub fn approximate_members(&self, guild_id: GuildId) -> Vec<ApproximateMember> {
+        let Some(settings) = self.settings().approximate_members.clone() else {
+            return Vec::new();
+        };
+        let Some(members) = self.approximate_members.get(&guild_id) else {
+            return Vec::new();
+        };
+
+        let now = Timestamp::now();
+        members.values().filter(|seen| !seen.is_expired(&settings, now)).cloned().collect()
+    }
+}
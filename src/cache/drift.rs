@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use super::Cache;
+use crate::model::prelude::*;
+
+/// The differences found between the cached state of a guild and its state fetched over HTTP.
+///
+/// Returned by [`Cache::reconcile_guild`], after the differences have already been applied to the
+/// cache.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GuildCacheDrift {
+    /// The Id of the guild that drifted.
+    pub guild_id: GuildId,
+    /// Roles that existed on Discord but were missing from the cache.
+    pub roles_created: Vec<Role>,
+    /// Roles whose cached data differed from Discord, with their new data.
+    pub roles_updated: Vec<Role>,
+    /// Roles that were cached but no longer exist on Discord.
+    pub roles_deleted: Vec<Role>,
+    /// Channels that existed on Discord but were missing from the cache.
+    pub channels_created: Vec<GuildChannel>,
+    /// Channels whose cached data differed from Discord, with their new data.
+    pub channels_updated: Vec<GuildChannel>,
+    /// Channels that were cached but no longer exist on Discord.
+    pub channels_deleted: Vec<GuildChannel>,
+}
+
+impl GuildCacheDrift {
+    /// Returns `true` if no differences were found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.roles_created.is_empty()
+            && self.roles_updated.is_empty()
+            && self.roles_deleted.is_empty()
+            && self.channels_created.is_empty()
+            && self.channels_updated.is_empty()
+            && self.channels_deleted.is_empty()
+    }
+}
+
+/// Compares the fields of a channel that are relevant for its structure within a guild, ignoring
+/// fields that change frequently or are not sent over HTTP, such as `last_message_id`.
+fn channel_changed(old: &GuildChannel, new: &GuildChannel) -> bool {
+    old.kind != new.kind
+        || old.name != new.name
+        || old.position != new.position
+        || old.parent_id != new.parent_id
+        || old.permission_overwrites != new.permission_overwrites
+        || old.topic != new.topic
+        || old.nsfw != new.nsfw
+        || old.rate_limit_per_user != new.rate_limit_per_user
+        || old.bitrate != new.bitrate
+        || old.user_limit != new.user_limit
+}
+
+impl Cache {
+    /// Marks a cached guild as potentially out of date, for example because gateway events may
+    /// have been missed.
+    ///
+    /// Stale guilds are refreshed over HTTP in the background if
+    /// [`Settings::refresh_stale_guilds_interval`] is set.
+    ///
+    /// [`Settings::refresh_stale_guilds_interval`]: super::Settings::refresh_stale_guilds_interval
+    pub fn mark_guild_stale(&self, guild_id: GuildId) {
+        if self.guilds.contains(&guild_id) {
+            self.stale_guilds.insert(guild_id, ());
+        }
+    }
+
+    /// Returns the Ids of all guilds which have been marked as stale and not yet refreshed.
+    pub fn stale_guilds(&self) -> Vec<GuildId> {
+        self.stale_guilds.iter().map(|entry| *entry.key()).collect()
+    }
+
+    /// Removes and returns the Ids of all guilds which have been marked as stale.
+    pub(crate) fn take_stale_guilds(&self) -> Vec<GuildId> {
+        // Remove the guilds while collecting them, so a guild marked again in the meantime is
+        // either returned or kept for the next call, instead of being dropped.
+        let mut guild_ids = Vec::new();
+        self.stale_guilds.retain(|guild_id, ()| {
+            guild_ids.push(*guild_id);
+            false
+        });
+
+        guild_ids
+    }
+
+    /// Compares the cached roles and channels of a guild with freshly fetched data, applies any
+    /// differences to the cache, and returns them.
+    ///
+    /// Returns [`None`] if the guild is not cached.
+    pub fn reconcile_guild(
+        &self,
+        guild_id: GuildId,
+        roles: impl IntoIterator<Item = Role>,
+        channels: impl IntoIterator<Item = GuildChannel>,
+    ) -> Option<GuildCacheDrift> {
+        let mut guild = self.guilds.get_mut(&guild_id)?;
+        let mut drift = GuildCacheDrift {
+            guild_id,
+            ..Default::default()
+        };
+
+        let mut seen_roles = HashSet::new();
+        for mut role in roles {
+            role.guild_id = guild_id;
+            seen_roles.insert(role.id);
+
+            match guild.roles.get(&role.id) {
+                Some(old) if *old == role => {},
+                Some(_) => drift.roles_updated.push(role.clone()),
+                None => drift.roles_created.push(role.clone()),
+            }
+
+            guild.roles.insert(role);
+        }
+
+        drift.roles_deleted =
+            guild.roles.iter().filter(|role| !seen_roles.contains(&role.id)).cloned().collect();
+        for role in &drift.roles_deleted {
+            guild.roles.remove(&role.id);
+        }
+
+        let mut seen_channels = HashSet::new();
+        for mut channel in channels {
+            channel.guild_id = guild_id;
+            seen_channels.insert(channel.id);
+
+            match guild.channels.get(&channel.id) {
+                Some(old) if !channel_changed(old, &channel) => continue,
+                Some(_) => drift.channels_updated.push(channel.clone()),
+                None => drift.channels_created.push(channel.clone()),
+            }
+
+            guild.channels.insert(channel);
+        }
+
+        drift.channels_deleted = guild
+            .channels
+            .iter()
+            .filter(|channel| !seen_channels.contains(&channel.id))
+            .cloned()
+            .collect();
+        for channel in &drift.channels_deleted {
+            guild.channels.remove(&channel.id);
+        }
+
+        Some(drift)
+    }
+}
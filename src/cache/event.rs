@@ -1,8 +1,9 @@
 use std::collections::{HashSet, VecDeque};
 use std::num::NonZeroU16;
 
-use super::{Cache, CacheUpdate};
+use super::{Cache, CacheUpdate, MaybeOwnedArc};
 use crate::internal::prelude::*;
+use crate::model::application::Interaction;
 use crate::model::channel::{GuildChannel, Message};
 use crate::model::event::{
     ChannelCreateEvent,
@@ -19,8 +20,14 @@ use crate::model::event::{
     GuildRoleCreateEvent,
     GuildRoleDeleteEvent,
     GuildRoleUpdateEvent,
+    GuildScheduledEventCreateEvent,
+    GuildScheduledEventDeleteEvent,
+    GuildScheduledEventUpdateEvent,
+    GuildScheduledEventUserAddEvent,
+    GuildScheduledEventUserRemoveEvent,
     GuildStickersUpdateEvent,
     GuildUpdateEvent,
+    InteractionCreateEvent,
     MessageCreateEvent,
     MessageUpdateEvent,
     PresenceUpdateEvent,
@@ -33,7 +40,14 @@ use crate::model::event::{
     VoiceStateUpdateEvent,
 };
 use crate::model::gateway::{Presence, ShardInfo};
-use crate::model::guild::{Guild, GuildMemberFlags, Member, MemberGeneratedFlags, Role};
+use crate::model::guild::{
+    Guild,
+    GuildMemberFlags,
+    Member,
+    MemberGeneratedFlags,
+    PartialMember,
+    Role,
+};
 use crate::model::id::ShardId;
 use crate::model::user::{CurrentUser, OnlineStatus};
 use crate::model::voice::VoiceState;
@@ -58,6 +72,8 @@ impl CacheUpdate for ChannelDeleteEvent {
         let (channel_id, guild_id) = (self.channel.id, self.channel.guild_id);
 
         cache.guilds.get_mut(&guild_id).map(|mut g| g.channels.remove(&channel_id));
+        #[cfg(feature = "temp_cache")]
+        cache.temp_channels.invalidate(&channel_id);
 
         // Remove the cached messages for the channel.
         cache.messages.remove(&channel_id).map(|(_, messages)| messages)
@@ -68,6 +84,9 @@ impl CacheUpdate for ChannelUpdateEvent {
     type Output = GuildChannel;
 
     fn update(&mut self, cache: &Cache) -> Option<GuildChannel> {
+        #[cfg(feature = "temp_cache")]
+        cache.temp_channels.invalidate(&self.channel.id);
+
         cache
             .guilds
             .get_mut(&self.channel.guild_id)
@@ -96,9 +115,15 @@ impl CacheUpdate for GuildCreateEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.remove(&self.guild.id);
-        let guild = self.guild.clone();
+        let mut guild = self.guild.clone();
+        guild.index_voice_states();
+
+        for event in &self.guild.scheduled_events {
+            cache.seed_scheduled_event_interest(event);
+        }
 
-        cache.guilds.insert(self.guild.id, guild);
+        cache.guilds.insert(self.guild.id, MaybeOwnedArc::new(guild));
+        cache.update_ready();
 
         None
     }
@@ -115,6 +140,9 @@ impl CacheUpdate for GuildDeleteEvent {
             return None;
         }
 
+        cache.approximate_members.remove(&self.guild.id);
+        cache.remove_guild_scheduled_event_interest(self.guild.id);
+
         match cache.guilds.remove(&self.guild.id) {
             Some(guild) => {
                 for channel in &guild.1.channels {
@@ -122,7 +150,7 @@ impl CacheUpdate for GuildDeleteEvent {
                     cache.messages.remove(&channel.id);
                 }
 
-                Some(guild.1)
+                Some(guild.1.into_owned())
             },
             None => None,
         }
@@ -158,6 +186,10 @@ impl CacheUpdate for GuildMemberRemoveEvent {
     type Output = Member;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.forget_approximate_member(self.guild_id, self.user.id);
+        #[cfg(feature = "temp_cache")]
+        cache.temp_members.invalidate(&(self.guild_id, self.user.id));
+
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             guild.member_count -= 1;
             return guild.members.remove(&self.user.id);
@@ -171,6 +203,9 @@ impl CacheUpdate for GuildMemberUpdateEvent {
     type Output = Member;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        #[cfg(feature = "temp_cache")]
+        cache.temp_members.invalidate(&(self.guild_id, self.user.id));
+
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             let item = if let Some(mut member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
@@ -265,6 +300,58 @@ impl CacheUpdate for GuildRoleUpdateEvent {
     }
 }
 
+impl CacheUpdate for GuildScheduledEventCreateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        // Newly created events have no interested users yet
+        let count = self.event.user_count.map_or(0, |count| count.get());
+        cache.set_scheduled_event_interest(&self.event, count);
+
+        None
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        cache.seed_scheduled_event_interest(&self.event);
+
+        None
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventDeleteEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        cache.remove_scheduled_event_interest(self.event.id);
+
+        None
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventUserAddEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        cache.update_scheduled_event_interest(self.scheduled_event_id, true);
+
+        None
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventUserRemoveEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        cache.update_scheduled_event_interest(self.scheduled_event_id, false);
+
+        None
+    }
+}
+
 impl CacheUpdate for GuildStickersUpdateEvent {
     type Output = ();
 
@@ -314,11 +401,38 @@ impl CacheUpdate for GuildUpdateEvent {
     }
 }
 
+impl CacheUpdate for InteractionCreateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let (guild_id, member) = match &self.interaction {
+            Interaction::Command(i) | Interaction::Autocomplete(i) => {
+                (i.guild_id, i.member.as_deref())
+            },
+            Interaction::Component(i) => (i.guild_id, i.member.as_ref()),
+            Interaction::Modal(i) => (i.guild_id, i.member.as_ref()),
+            Interaction::Ping(_) => (None, None),
+        };
+
+        if let (Some(guild_id), Some(member)) = (guild_id, member) {
+            cache.record_approximate_member(guild_id, member);
+        }
+
+        None
+    }
+}
+
 impl CacheUpdate for MessageCreateEvent {
     /// The oldest message, if the channel's message cache was already full.
     type Output = Message;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if let (Some(guild_id), Some(partial)) = (self.message.guild_id, &self.message.member) {
+            let mut member = Member::from(PartialMember::clone(partial));
+            member.user.clone_from(&self.message.author);
+            cache.record_approximate_member(guild_id, &member);
+        }
+
         // Update the relevant channel object with the new latest message if this message is newer
         let guild = self.message.guild_id.and_then(|g_id| cache.guilds.get_mut(&g_id));
 
@@ -473,6 +587,7 @@ impl CacheUpdate for ReadyEvent {
             cached_shard_data.connected.insert(shard_data.id);
         }
         cache.user.write().clone_from(&self.ready.user);
+        cache.update_ready();
 
         None
     }
@@ -558,11 +673,18 @@ impl CacheUpdate for VoiceStateUpdateEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<VoiceState> {
         if let Some(guild_id) = self.voice_state.guild_id {
+            if let Some(member) = &self.voice_state.member {
+                cache.record_approximate_member(guild_id, member);
+            }
+
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 if let Some(member) = &self.voice_state.member {
                     guild.members.insert(member.clone());
                 }
 
+                let user_id = self.voice_state.user_id;
+                let old_channel_id = guild.voice_states.get(&user_id).and_then(|s| s.channel_id);
+                guild.index_voice_state(user_id, old_channel_id, self.voice_state.channel_id);
                 if self.voice_state.channel_id.is_some() {
                     // Update or add to the voice state list
                     let old_state = guild.voice_states.remove(&self.voice_state.user_id);
@@ -24,12 +24,10 @@
 //! [`http`]: crate::http
 //! [Manage Guild]: Permissions::MANAGE_GUILD
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
 use std::num::NonZeroU16;
-#[cfg(feature = "temp_cache")]
 use std::sync::Arc;
-#[cfg(feature = "temp_cache")]
 use std::time::Duration;
 
 use dashmap::mapref::one::{MappedRef, Ref};
@@ -37,17 +35,22 @@ use dashmap::DashMap;
 #[cfg(feature = "temp_cache")]
 use mini_moka::sync::Cache as MokaCache;
 use parking_lot::RwLock;
+use tokio::sync::watch;
 
+pub use self::approximate_members::ApproximateMember;
 pub use self::cache_update::CacheUpdate;
-pub use self::settings::Settings;
+pub use self::drift::GuildCacheDrift;
+pub use self::settings::{ApproximateMemberSettings, Settings};
 use crate::model::prelude::*;
 
+mod approximate_members;
 mod cache_update;
+mod drift;
 mod event;
+mod scheduled_event_interest;
 mod settings;
 mod wrappers;
 
-#[cfg(feature = "temp_cache")]
 pub(crate) use wrappers::MaybeOwnedArc;
 use wrappers::{BuildHasher, MaybeMap, ReadOnlyMapRef};
 
@@ -109,7 +112,9 @@ impl<K: Eq + Hash, V, T> std::ops::Deref for CacheRef<'_, K, V, T> {
 type Never = std::convert::Infallible;
 
 pub type UserRef<'a> = CacheRef<'a, UserId, User, Never>;
-pub type GuildRef<'a> = CacheRef<'a, GuildId, Guild, Never>;
+pub type GuildRef<'a> = CacheRef<'a, GuildId, Guild, MaybeOwnedArc<Guild>>;
+pub type MemberRef<'a> = CacheRef<'a, GuildId, Member, MaybeOwnedArc<Guild>>;
+pub type GuildChannelRef<'a> = CacheRef<'a, GuildId, GuildChannel, MaybeOwnedArc<Guild>>;
 pub type SettingsRef<'a> = CacheRef<'a, Never, Settings, Never>;
 pub type CurrentUserRef<'a> = CacheRef<'a, Never, CurrentUser, Never>;
 pub type MessageRef<'a> = CacheRef<'a, ChannelId, Message, VecDeque<Message>>;
@@ -136,6 +141,11 @@ pub(crate) struct CachedShardData {
 ///   [`PresenceUpdateEvent`], [`ReadyEvent`]
 /// - presences: [`PresenceUpdateEvent`], [`ReadyEvent`]
 /// - messages: [`MessageCreateEvent`]
+/// - approximate members: [`InteractionCreateEvent`], [`MessageCreateEvent`],
+///   [`VoiceStateUpdateEvent`], if enabled via [`Settings::approximate_members`]
+/// - scheduled event interest: [`GuildScheduledEventCreateEvent`],
+///   [`GuildScheduledEventUserAddEvent`], [`GuildScheduledEventUserRemoveEvent`], if enabled via
+///   [`Settings::cache_scheduled_event_interest`]
 ///
 /// The documentation of each event contains the required gateway intents.
 ///
@@ -167,26 +177,51 @@ pub struct Cache {
     /// The TTL for each value is configured in CacheSettings.
     #[cfg(feature = "temp_cache")]
     pub(crate) temp_users: MokaCache<UserId, MaybeOwnedArc<User>, BuildHasher>,
+    /// Cache of members who have been fetched via member, for guilds which are not cached.
+    ///
+    /// The TTL for each value is configured in CacheSettings.
+    #[cfg(feature = "temp_cache")]
+    pub(crate) temp_members: MokaCache<(GuildId, UserId), MaybeOwnedArc<Member>, BuildHasher>,
 
     // Guilds cache:
     // ---
     /// A map of guilds with full data available. This includes data like [`Role`]s and [`Emoji`]s
     /// that are not available through the REST API.
-    pub(crate) guilds: MaybeMap<GuildId, Guild>,
+    ///
+    /// Guilds are stored behind an Arc, so [`Cache::guild_snapshot`] can hand them out cheaply.
+    pub(crate) guilds: MaybeMap<GuildId, MaybeOwnedArc<Guild>>,
     /// A list of guilds which are "unavailable".
     ///
     /// Additionally, guilds are always unavailable for bot users when a Ready is received. Guilds
     /// are "sent in" over time through the receiving of [`Event::GuildCreate`]s.
     pub(crate) unavailable_guilds: MaybeMap<GuildId, ()>,
+    /// A list of cached guilds which may be out of date, and should be refreshed over HTTP.
+    pub(crate) stale_guilds: MaybeMap<GuildId, ()>,
 
     // Messages cache:
     // ---
     pub(crate) messages: DashMap<ChannelId, VecDeque<Message>, BuildHasher>,
 
+    // Approximate members cache:
+    // ---
+    /// Members seen in payloads, for bots without the members intent. Only populated if enabled in
+    /// the settings.
+    pub(crate) approximate_members:
+        DashMap<GuildId, HashMap<UserId, ApproximateMember>, BuildHasher>,
+
+    // Scheduled event interest cache:
+    // ---
+    /// The guild and number of interested users of scheduled events whose count is known. Only
+    /// populated if enabled in the settings.
+    pub(crate) scheduled_event_interest: DashMap<ScheduledEventId, (GuildId, u64), BuildHasher>,
+
     // Miscellanous fixed-size data
     // ---
     /// Information about running shards
     pub(crate) shard_data: RwLock<CachedShardData>,
+    /// Whether all shards received their READY event, and all guilds sent in them were received.
+    #[cfg_attr(feature = "typesize", typesize(skip))]
+    ready: watch::Sender<bool>,
     /// The current user "logged in" and for which events are being received for.
     ///
     /// The current user contains information that a regular [`User`] does not, such as whether it
@@ -237,17 +272,25 @@ impl Cache {
             temp_messages: temp_cache(settings.time_to_live),
             #[cfg(feature = "temp_cache")]
             temp_users: temp_cache(settings.time_to_live),
+            #[cfg(feature = "temp_cache")]
+            temp_members: temp_cache(settings.time_to_live),
 
             guilds: MaybeMap(settings.cache_guilds.then(DashMap::default)),
             unavailable_guilds: MaybeMap(settings.cache_guilds.then(DashMap::default)),
+            stale_guilds: MaybeMap(settings.cache_guilds.then(DashMap::default)),
 
             messages: DashMap::default(),
 
+            approximate_members: DashMap::default(),
+
+            scheduled_event_interest: DashMap::default(),
+
             shard_data: RwLock::new(CachedShardData {
                 total: NonZeroU16::MIN,
                 connected: HashSet::new(),
                 has_sent_shards_ready: false,
             }),
+            ready: watch::Sender::new(false),
             user: RwLock::new(CurrentUser::default()),
             settings: RwLock::new(settings),
         }
@@ -364,7 +407,79 @@ impl Cache {
     /// };
     /// ```
     pub fn guild(&self, id: GuildId) -> Option<GuildRef<'_>> {
-        self.guilds.get(&id).map(CacheRef::from_ref)
+        let guild = self.guilds.get(&id)?;
+        Some(CacheRef::from_mapped_ref(guild.map(|guild| &**guild)))
+    }
+
+    /// Gets an owned snapshot of a guild from the cache based on the given `id`.
+    ///
+    /// Unlike the reference returned by [`Self::guild`], the snapshot doesn't lock the cache, so
+    /// it can be held across await points without blocking or deadlocking cache updates. Taking a
+    /// snapshot only clones an [`Arc`]. Updates to the guild don't change the snapshot, instead
+    /// the cache copies the guild the first time it is updated while a snapshot is held.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::GuildId;
+    /// #
+    /// # async fn run(cache: &Cache, http: &Http) -> serenity::Result<()> {
+    /// if let Some(guild) = cache.guild_snapshot(GuildId::new(7)) {
+    ///     for channel in &guild.channels {
+    ///         channel.id.say(http, format!("Hello from {}!", guild.name)).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn guild_snapshot(&self, id: GuildId) -> Option<Arc<Guild>> {
+        self.guilds.get(&id).map(|guild| guild.to_arc())
+    }
+
+    /// Gets a reference to a member of a cached guild, based on the guild's and user's Ids.
+    ///
+    /// If the `temp_cache` feature is enabled, members recently fetched over HTTP are also
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # use serenity::model::id::{GuildId, UserId};
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(member) = cache.member(GuildId::new(7), UserId::new(8)) {
+    ///     println!("Member name: {}", member.display_name());
+    /// };
+    /// ```
+    pub fn member(&self, guild_id: GuildId, user_id: UserId) -> Option<MemberRef<'_>> {
+        if let Some(guild) = self.guilds.get(&guild_id) {
+            if let Ok(member) = guild.try_map(|guild| guild.members.get(&user_id)) {
+                return Some(CacheRef::from_mapped_ref(member));
+            }
+        }
+
+        #[cfg(feature = "temp_cache")]
+        if let Some(member) = self.temp_members.get(&(guild_id, user_id)) {
+            return Some(CacheRef::from_arc(member));
+        }
+
+        None
+    }
+
+    /// Gets a reference to a channel of a cached guild, based on the guild's and channel's Ids.
+    ///
+    /// Threads are not included, see [`Guild::threads`] for those.
+    pub fn guild_channel(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+    ) -> Option<GuildChannelRef<'_>> {
+        let guild = self.guilds.get(&guild_id)?;
+        let channel = guild.try_map(|guild| guild.channels.get(&channel_id)).ok()?;
+        Some(CacheRef::from_mapped_ref(channel))
     }
 
     /// Returns the number of cached guilds.
@@ -382,6 +497,60 @@ impl Cache {
         self.shard_data.read().total
     }
 
+    /// Whether all shards have received their READY event, and the guilds sent in them have all
+    /// been received through a GUILD_CREATE event.
+    ///
+    /// Once ready, the cache stays ready, even if shards reconnect later on.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        *self.ready.borrow()
+    }
+
+    /// Waits until the cache is fully populated, see [`Self::is_ready`].
+    ///
+    /// Guilds affected by an outage are only sent once they become available again, so this waits
+    /// for at most `timeout`. Returns whether the cache became ready in time.
+    ///
+    /// # Examples
+    ///
+    /// Running a startup task which needs all guilds to be cached:
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use std::time::Duration;
+    /// # use serenity::prelude::*;
+    /// # async fn run(mut client: Client) {
+    /// let cache = Arc::clone(&client.cache);
+    /// tokio::spawn(async move {
+    ///     if !cache.wait_until_ready(Duration::from_secs(60)).await {
+    ///         println!("Some guilds are still unavailable");
+    ///     }
+    ///
+    ///     println!("{} guilds cached", cache.guild_count());
+    /// });
+    ///
+    /// client.start().await.unwrap();
+    /// # }
+    /// ```
+    pub async fn wait_until_ready(&self, timeout: Duration) -> bool {
+        let mut ready = self.ready.subscribe();
+        tokio::time::timeout(timeout, ready.wait_for(|ready| *ready))
+            .await
+            .is_ok_and(|result| result.is_ok())
+    }
+
+    /// Marks the cache as ready if all shards connected, and there are no unavailable guilds left.
+    pub(crate) fn update_ready(&self) {
+        let shards_connected = {
+            let shard_data = self.shard_data.read();
+            shard_data.connected.len() >= usize::from(shard_data.total.get())
+        };
+
+        if shards_connected && self.unavailable_guilds.len() == 0 {
+            self.ready.send_if_modified(|ready| !std::mem::replace(ready, true));
+        }
+    }
+
     /// Retrieves a [`Channel`]'s message from the cache based on the channel's and message's given
     /// Ids.
     ///
@@ -520,7 +689,7 @@ impl Default for Cache {
 
 #[cfg(test)]
 mod test {
-    use crate::cache::{Cache, CacheUpdate, Settings};
+    use crate::cache::{ApproximateMemberSettings, Cache, CacheUpdate, Settings};
     use crate::model::prelude::*;
 
     #[test]
@@ -604,4 +773,381 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId::new(2)));
     }
+
+    #[test]
+    fn test_cache_reconcile_guild() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+
+        let role = |id, name| Role {
+            id: RoleId::new(id),
+            guild_id,
+            name: FixedString::from_static_trunc(name),
+            ..Default::default()
+        };
+        let channel = |id, name| GuildChannel {
+            id: ChannelId::new(id),
+            guild_id,
+            name: FixedString::from_static_trunc(name),
+            ..Default::default()
+        };
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                roles: ExtractMap::from_iter([
+                    role(1, "kept"),
+                    role(2, "renamed"),
+                    role(3, "gone"),
+                ]),
+                channels: ExtractMap::from_iter([channel(1, "kept"), channel(2, "gone")]),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        cache.mark_guild_stale(guild_id);
+        cache.mark_guild_stale(GuildId::new(2));
+        assert_eq!(cache.take_stale_guilds(), vec![guild_id]);
+        assert!(cache.stale_guilds().is_empty());
+
+        let drift = cache
+            .reconcile_guild(guild_id, [role(1, "kept"), role(2, "new name"), role(4, "new")], [
+                channel(1, "kept"),
+                channel(3, "new"),
+            ])
+            .unwrap();
+
+        assert_eq!(drift.roles_created, vec![role(4, "new")]);
+        assert_eq!(drift.roles_updated, vec![role(2, "new name")]);
+        assert_eq!(drift.roles_deleted, vec![role(3, "gone")]);
+        assert_eq!(drift.channels_created.len(), 1);
+        assert!(drift.channels_updated.is_empty());
+        assert_eq!(drift.channels_deleted.len(), 1);
+
+        let guild = cache.guild(guild_id).unwrap();
+        assert_eq!(guild.roles.len(), 3);
+        assert_eq!(guild.roles.get(&RoleId::new(2)).unwrap().name, "new name");
+        assert!(guild.channels.contains_key(&ChannelId::new(3)));
+        assert!(!guild.channels.contains_key(&ChannelId::new(2)));
+        drop(guild);
+
+        let drift = cache.reconcile_guild(guild_id, [role(1, "kept")], [channel(1, "kept")]);
+        assert!(drift.is_some_and(|drift| !drift.is_empty()));
+        let drift = cache.reconcile_guild(guild_id, [role(1, "kept")], [channel(1, "kept")]);
+        assert!(drift.is_some_and(|drift| drift.is_empty()));
+    }
+
+    #[test]
+    fn test_cache_approximate_members() {
+        let settings = Settings {
+            approximate_members: Some(ApproximateMemberSettings {
+                max_members_per_guild: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let cache = Cache::new_with_settings(settings);
+        let guild_id = GuildId::new(1);
+
+        let message = |id| MessageCreateEvent {
+            message: Message {
+                guild_id: Some(guild_id),
+                author: User {
+                    id: UserId::new(id),
+                    ..Default::default()
+                },
+                member: Some(Box::new(Member::default().into())),
+                ..Default::default()
+            },
+        };
+
+        cache.update(&mut message(1));
+        let seen = cache.approximate_member(guild_id, UserId::new(1));
+        assert!(seen.is_some_and(|seen| seen.member.guild_id == guild_id));
+
+        // The size cap evicts the member seen the longest time ago.
+        cache.update(&mut message(2));
+        assert_eq!(cache.approximate_members(guild_id).len(), 1);
+        assert!(cache.approximate_member(guild_id, UserId::new(1)).is_none());
+
+        let mut remove = GuildMemberRemoveEvent {
+            guild_id,
+            user: User {
+                id: UserId::new(2),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut remove);
+        assert!(cache.approximate_members(guild_id).is_empty());
+    }
+
+    #[test]
+    fn test_cache_voice_channel_members() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+
+        let member = |id| Member {
+            user: User {
+                id: UserId::new(id),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let voice_state = |user_id: u64, channel_id: Option<u64>| -> VoiceState {
+            serde_json::from_value(serde_json::json!({
+                "guild_id": guild_id,
+                "channel_id": channel_id.map(|id| id.to_string()),
+                "user_id": user_id.to_string(),
+                "session_id": "session",
+                "deaf": false,
+                "mute": false,
+                "self_deaf": false,
+                "self_mute": false,
+                "self_video": false,
+                "suppress": false,
+                "request_to_speak_timestamp": null,
+            }))
+            .unwrap()
+        };
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                members: ExtractMap::from_iter([member(1), member(2), member(3)]),
+                voice_states: ExtractMap::from_iter([
+                    voice_state(1, Some(10)),
+                    voice_state(2, Some(10)),
+                ]),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let members_in = |channel_id| {
+            let guild = cache.guild(guild_id).unwrap();
+            let mut members: Vec<_> = guild
+                .voice_channel_members(ChannelId::new(channel_id))
+                .into_iter()
+                .map(|member| member.user.id.get())
+                .collect();
+            members.sort_unstable();
+            members
+        };
+        assert_eq!(members_in(10), vec![1, 2]);
+
+        cache.update(&mut VoiceStateUpdateEvent {
+            voice_state: voice_state(2, Some(20)),
+        });
+        cache.update(&mut VoiceStateUpdateEvent {
+            voice_state: voice_state(3, Some(20)),
+        });
+        assert_eq!(members_in(10), vec![1]);
+        assert_eq!(members_in(20), vec![2, 3]);
+
+        // Updates within the same channel, such as muting, don't add the user again.
+        cache.update(&mut VoiceStateUpdateEvent {
+            voice_state: voice_state(3, Some(20)),
+        });
+        assert_eq!(members_in(20), vec![2, 3]);
+
+        cache.update(&mut VoiceStateUpdateEvent {
+            voice_state: voice_state(1, None),
+        });
+        assert!(members_in(10).is_empty());
+
+        let guild = cache.guild(guild_id).unwrap();
+        assert_eq!(guild.voice_channel_of(UserId::new(2)), Some(ChannelId::new(20)));
+        assert_eq!(guild.voice_channel_of(UserId::new(1)), None);
+    }
+
+    #[test]
+    fn test_cache_member_and_guild_channel() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                members: ExtractMap::from_iter([Member {
+                    user: User {
+                        id: UserId::new(2),
+                        ..Default::default()
+                    },
+                    guild_id,
+                    ..Default::default()
+                }]),
+                channels: ExtractMap::from_iter([GuildChannel {
+                    id: ChannelId::new(3),
+                    guild_id,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let member =
+            CachedOrFetched::<_, Member>::Cached(cache.member(guild_id, UserId::new(2)).unwrap());
+        assert!(member.is_cached());
+        assert_eq!(member.user.id, UserId::new(2));
+        assert_eq!(member.into_owned().guild_id, guild_id);
+        assert!(cache.member(guild_id, UserId::new(3)).is_none());
+
+        assert!(cache.guild_channel(guild_id, ChannelId::new(3)).is_some());
+        assert!(cache.guild_channel(guild_id, ChannelId::new(2)).is_none());
+        assert!(cache.guild_channel(GuildId::new(2), ChannelId::new(3)).is_none());
+
+        #[cfg(feature = "temp_cache")]
+        {
+            use crate::cache::MaybeOwnedArc;
+
+            let (guild_id, user_id) = (GuildId::new(2), UserId::new(4));
+            let fetched = Member {
+                guild_id,
+                ..Default::default()
+            };
+            cache.temp_members.insert((guild_id, user_id), MaybeOwnedArc::new(fetched));
+            assert!(cache.member(guild_id, user_id).is_some());
+
+            cache.update(&mut GuildMemberRemoveEvent {
+                guild_id,
+                user: User {
+                    id: user_id,
+                    ..Default::default()
+                },
+            });
+            assert!(cache.member(guild_id, user_id).is_none());
+        }
+    }
+
+    #[test]
+    fn test_cache_ready() {
+        let cache = Cache::default();
+        cache.shard_data.write().connected.insert(ShardId(0));
+        for id in [1, 2] {
+            cache.unavailable_guilds.insert(GuildId::new(id), ());
+        }
+
+        let guild_create = |id| GuildCreateEvent {
+            guild: Guild {
+                id: GuildId::new(id),
+                ..Default::default()
+            },
+        };
+
+        cache.update(&mut guild_create(1));
+        assert!(!cache.is_ready());
+
+        cache.update(&mut guild_create(2));
+        assert!(cache.is_ready());
+    }
+
+    #[test]
+    fn test_scheduled_event_interest() {
+        let settings = Settings {
+            cache_scheduled_event_interest: true,
+            ..Default::default()
+        };
+        let cache = Cache::new_with_settings(settings);
+
+        let event: ScheduledEvent = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "guild_id": "2",
+            "channel_id": null,
+            "name": "Game night",
+            "scheduled_start_time": "2024-01-01T00:00:00Z",
+            "privacy_level": 2,
+            "status": 1,
+            "entity_type": 3,
+        }))
+        .unwrap();
+        let user_add = |user_id| GuildScheduledEventUserAddEvent {
+            scheduled_event_id: event.id,
+            user_id: UserId::new(user_id),
+            guild_id: event.guild_id,
+        };
+
+        // Events created before tracking started have no known count
+        cache.update(&mut user_add(1));
+        assert_eq!(event.id.interested_count(&cache), None);
+
+        cache.update(&mut GuildScheduledEventCreateEvent {
+            event: event.clone(),
+        });
+        cache.update(&mut user_add(1));
+        cache.update(&mut user_add(2));
+        cache.update(&mut GuildScheduledEventUserRemoveEvent {
+            scheduled_event_id: event.id,
+            user_id: UserId::new(1),
+            guild_id: event.guild_id,
+        });
+        assert_eq!(event.id.interested_count(&cache), Some(1));
+
+        cache.update(&mut GuildScheduledEventDeleteEvent {
+            event,
+        });
+        assert!(cache.scheduled_event_interest.is_empty());
+    }
+
+    #[test]
+    fn test_cache_guild_snapshot() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+        let role = |id| Role {
+            id: RoleId::new(id),
+            guild_id,
+            ..Default::default()
+        };
+
+        assert!(cache.guild_snapshot(guild_id).is_none());
+        cache.update(&mut GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                roles: ExtractMap::from_iter([role(1)]),
+                ..Default::default()
+            },
+        });
+
+        let snapshot = cache.guild_snapshot(guild_id).unwrap();
+        cache.update(&mut GuildRoleCreateEvent {
+            role: role(2),
+        });
+
+        // Updates copy the guild instead of changing held snapshots.
+        assert_eq!(snapshot.roles.len(), 1);
+        assert_eq!(cache.guild(guild_id).unwrap().roles.len(), 2);
+        assert_eq!(cache.guild_snapshot(guild_id).unwrap().roles.len(), 2);
+    }
+
+    #[test]
+    fn test_cache_presence_update() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+        let presence_update = |status: &str| PresenceUpdateEvent {
+            presence: serde_json::from_value(serde_json::json!({
+                "user": {"id": "2"},
+                "guild_id": guild_id,
+                "status": status,
+                "activities": [],
+            }))
+            .unwrap(),
+        };
+
+        cache.update(&mut GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                ..Default::default()
+            },
+        });
+
+        assert!(cache.update(&mut presence_update("online")).is_none());
+        let old = cache.update(&mut presence_update("idle")).unwrap();
+        assert_eq!(old.status, OnlineStatus::Online);
+
+        // Offline presences aren't cached, so coming back online has no old presence.
+        let old = cache.update(&mut presence_update("offline")).unwrap();
+        assert_eq!(old.status, OnlineStatus::Idle);
+        assert!(cache.update(&mut presence_update("online")).is_none());
+    }
 }
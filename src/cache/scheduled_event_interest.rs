@@ -0,0 +1,53 @@
+use super::Cache;
+use crate::model::prelude::*;
+
+impl Cache {
+    /// Returns the number of users interested in a scheduled event, if the count is tracked via
+    /// [`Settings::cache_scheduled_event_interest`].
+    ///
+    /// Returns [`None`] if tracking is disabled, or if the count of the event is not known. See
+    /// [`Self::seed_scheduled_event_interest`] for when counts are known.
+    ///
+    /// [`Settings::cache_scheduled_event_interest`]: super::Settings::cache_scheduled_event_interest
+    #[must_use]
+    pub fn scheduled_event_interest(&self, event_id: ScheduledEventId) -> Option<u64> {
+        self.scheduled_event_interest.get(&event_id).map(|entry| entry.1)
+    }
+
+    /// Seeds the interested user count of a scheduled event, so that it is tracked from then on.
+    ///
+    /// Counts are seeded automatically for events created while the bot is running. For events
+    /// which existed before, fetch them with the user count, such as via
+    /// [`GuildId::scheduled_events`] with `with_user_count` set, and pass them to this method.
+    ///
+    /// Does nothing if tracking is disabled via [`Settings::cache_scheduled_event_interest`], or
+    /// if the event's [`ScheduledEvent::user_count`] is [`None`].
+    ///
+    /// [`Settings::cache_scheduled_event_interest`]: super::Settings::cache_scheduled_event_interest
+    pub fn seed_scheduled_event_interest(&self, event: &ScheduledEvent) {
+        if let Some(user_count) = event.user_count {
+            self.set_scheduled_event_interest(event, user_count.get());
+        }
+    }
+
+    pub(crate) fn set_scheduled_event_interest(&self, event: &ScheduledEvent, count: u64) {
+        if self.settings().cache_scheduled_event_interest {
+            self.scheduled_event_interest.insert(event.id, (event.guild_id, count));
+        }
+    }
+
+    /// Adjusts the tracked count of a scheduled event by one user, if the count is known.
+    pub(crate) fn update_scheduled_event_interest(&self, event_id: ScheduledEventId, added: bool) {
+        if let Some(mut entry) = self.scheduled_event_interest.get_mut(&event_id) {
+            entry.1 = if added { entry.1 + 1 } else { entry.1.saturating_sub(1) };
+        }
+    }
+
+    pub(crate) fn remove_scheduled_event_interest(&self, event_id: ScheduledEventId) {
+        self.scheduled_event_interest.remove(&event_id);
+    }
+
+    pub(crate) fn remove_guild_scheduled_event_interest(&self, guild_id: GuildId) {
+        self.scheduled_event_interest.retain(|_, (event_guild_id, _)| *event_guild_id != guild_id);
+    }
+}
@@ -13,6 +13,7 @@
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug)]
 #[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Settings {
     /// How long temporarily-cached data should be stored before being thrown out.
     ///
@@ -35,6 +36,45 @@ pub struct Settings {
     ///
     /// Defaults to true.
     pub cache_users: bool,
+    /// How often guilds marked as stale should have their roles and channels re-fetched over
+    /// HTTP, with any differences applied to the cache. Guilds are marked as stale when a shard
+    /// resumes or misses gateway events, or manually via [`Cache::mark_guild_stale`].
+    ///
+    /// Differences are reported via [`EventHandler::cache_drift`]. This is only used by the
+    /// [`Client`].
+    ///
+    /// Defaults to [`None`], which disables the refresh.
+    ///
+    /// [`Cache::mark_guild_stale`]: super::Cache::mark_guild_stale
+    /// [`EventHandler::cache_drift`]: crate::gateway::client::EventHandler::cache_drift
+    /// [`Client`]: crate::gateway::client::Client
+    pub refresh_stale_guilds_interval: Option<std::time::Duration>,
+    /// Enables an approximate member cache, built from the members included in interactions,
+    /// messages and voice state updates. This is meant for bots which can't use the privileged
+    /// [`GatewayIntents::GUILD_MEMBERS`] intent, and so never receive full member lists.
+    ///
+    /// The cached members can be accessed via [`Cache::approximate_member`] and
+    /// [`Cache::approximate_members`]. They may be out of date, which is indicated by
+    /// [`ApproximateMember::last_seen_at`].
+    ///
+    /// Defaults to [`None`], which disables the approximate member cache.
+    ///
+    /// [`GatewayIntents::GUILD_MEMBERS`]: crate::model::gateway::GatewayIntents::GUILD_MEMBERS
+    /// [`Cache::approximate_member`]: super::Cache::approximate_member
+    /// [`Cache::approximate_members`]: super::Cache::approximate_members
+    /// [`ApproximateMember::last_seen_at`]: super::ApproximateMember::last_seen_at
+    pub approximate_members: Option<ApproximateMemberSettings>,
+    /// Whether to track the number of users interested in scheduled events, updated via
+    /// [`GuildScheduledEventUserAddEvent`] and [`GuildScheduledEventUserRemoveEvent`].
+    ///
+    /// The counts can be accessed via [`ScheduledEventId::interested_count`].
+    ///
+    /// Defaults to false.
+    ///
+    /// [`GuildScheduledEventUserAddEvent`]: crate::model::event::GuildScheduledEventUserAddEvent
+    /// [`GuildScheduledEventUserRemoveEvent`]: crate::model::event::GuildScheduledEventUserRemoveEvent
+    /// [`ScheduledEventId::interested_count`]: crate::model::id::ScheduledEventId::interested_count
+    pub cache_scheduled_event_interest: bool,
 }
 
 impl Default for Settings {
@@ -46,6 +86,48 @@ impl Default for Settings {
             cache_guilds: true,
             cache_channels: true,
             cache_users: true,
+            refresh_stale_guilds_interval: None,
+            approximate_members: None,
+            cache_scheduled_event_interest: false,
+        }
+    }
+}
+
+/// Settings for the approximate member cache, see [`Settings::approximate_members`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use serenity::cache::{ApproximateMemberSettings, Settings as CacheSettings};
+///
+/// let mut member_settings = ApproximateMemberSettings::default();
+/// member_settings.time_to_live = Duration::from_secs(60 * 30);
+///
+/// let mut settings = CacheSettings::default();
+/// settings.approximate_members = Some(member_settings);
+/// ```
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ApproximateMemberSettings {
+    /// How long a member is kept after it was last seen.
+    ///
+    /// Defaults to one hour.
+    pub time_to_live: std::time::Duration,
+    /// The maximum number of members kept per guild. Once reached, the member which was seen the
+    /// longest time ago is removed.
+    ///
+    /// Defaults to 1000.
+    pub max_members_per_guild: usize,
+}
+
+impl Default for ApproximateMemberSettings {
+    fn default() -> Self {
+        Self {
+            time_to_live: std::time::Duration::from_secs(60 * 60),
+            max_members_per_guild: 1000,
         }
     }
 }
@@ -1,7 +1,6 @@
 //! Wrappers around library types for easier use.
 
 use std::hash::Hash;
-#[cfg(feature = "temp_cache")]
 use std::sync::Arc;
 
 use dashmap::mapref::multiple::RefMulti;
@@ -42,6 +41,12 @@ impl<K: Eq + Hash, V> MaybeMap<K, V> {
         self.0.as_ref().map_or(0, DashMap::len)
     }
 
+    pub fn retain(&self, f: impl FnMut(&K, &mut V) -> bool) {
+        if let Some(map) = self.0.as_ref() {
+            map.retain(f);
+        }
+    }
+
     pub fn shrink_to_fit(&self) {
         if let Some(map) = self.0.as_ref() {
             map.shrink_to_fit();
@@ -112,15 +117,16 @@ impl typesize::TypeSize for BuildHasher {}
 
 /// Wrapper around `SizableArc<T, Owned>`` with support for disabling typesize.
 ///
-/// This denotes an Arc where T's size should be considered when calling `TypeSize::get_size`
+/// This denotes an Arc where T's size should be considered when calling `TypeSize::get_size`.
+///
+/// Mutable access is copy-on-write: if the Arc has been shared, such as via [`Self::to_arc`], the
+/// value is cloned first, so the shared value is never changed.
 #[derive(Debug)]
-#[cfg(feature = "temp_cache")]
-pub(crate) struct MaybeOwnedArc<T>(
+pub struct MaybeOwnedArc<T>(
     #[cfg(feature = "typesize")] typesize::ptr::SizableArc<T, typesize::ptr::Owned>,
     #[cfg(not(feature = "typesize"))] Arc<T>,
 );
 
-#[cfg(feature = "temp_cache")]
 impl<T> MaybeOwnedArc<T> {
     pub(crate) fn new(inner: T) -> Self {
         Self(Arc::new(inner).into())
@@ -134,20 +140,40 @@ impl<T> MaybeOwnedArc<T> {
 
         inner
     }
+
+    pub(crate) fn to_arc(&self) -> Arc<T> {
+        self.clone().get_inner()
+    }
+
+    fn arc_mut(&mut self) -> &mut Arc<T> {
+        #[cfg(feature = "typesize")]
+        let inner = &mut self.0 .0;
+        #[cfg(not(feature = "typesize"))]
+        let inner = &mut self.0;
+
+        inner
+    }
+}
+
+impl<T: Clone> MaybeOwnedArc<T> {
+    pub(crate) fn into_owned(self) -> T {
+        Arc::unwrap_or_clone(self.get_inner())
+    }
 }
 
-#[cfg(all(feature = "typesize", feature = "temp_cache"))]
+#[cfg(feature = "typesize")]
 impl<T: typesize::TypeSize> typesize::TypeSize for MaybeOwnedArc<T> {
     fn extra_size(&self) -> usize {
         self.0.extra_size()
     }
 
-    fn get_collection_item_count(&self) -> Option<usize> {
-        self.0.get_collection_item_count()
+    typesize::if_typesize_details! {
+        fn get_collection_item_count(&self) -> Option<usize> {
+            self.0.get_collection_item_count()
+        }
     }
 }
 
-#[cfg(feature = "temp_cache")]
 impl<T> std::ops::Deref for MaybeOwnedArc<T> {
     type Target = T;
 
@@ -156,7 +182,12 @@ impl<T> std::ops::Deref for MaybeOwnedArc<T> {
     }
 }
 
-#[cfg(feature = "temp_cache")]
+impl<T: Clone> std::ops::DerefMut for MaybeOwnedArc<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        Arc::make_mut(self.arc_mut())
+    }
+}
+
 impl<T> Clone for MaybeOwnedArc<T> {
     fn clone(&self) -> Self {
         Self(self.0.clone().into())
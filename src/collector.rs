@@ -10,6 +10,13 @@ use crate::model::prelude::*;
 /// Fundamental collector function. All collector types in this module are just wrappers around
 /// this function.
 ///
+/// Collectors subscribe to the same [`EventBus`] as [`Client::events`], and only receive events
+/// from the shard of the given [`ShardMessenger`]. Events are buffered until the stream is polled,
+/// so none are missed.
+///
+/// [`EventBus`]: crate::gateway::EventBus
+/// [`Client::events`]: crate::Client::events
+///
 /// Example: creating a collector stream over removed reactions
 /// ```rust
 /// # use std::time::Duration;
@@ -188,4 +195,19 @@ make_specific_collector!(
     author_id: UserId => message.author.id == *author_id,
     channel_id: ChannelId => message.channel_id == *channel_id,
     guild_id: GuildId => message.guild_id.map_or(true, |g| g == *guild_id),
+    nonce: Nonce => message.nonce.as_ref().is_some_and(|n| n.matches(nonce)),
+);
+make_specific_collector!(
+    ScheduledEventUserAddCollector, GuildScheduledEventUserAddEvent,
+    Event::GuildScheduledEventUserAdd(event) => event,
+    user_id: UserId => event.user_id == *user_id,
+    guild_id: GuildId => event.guild_id == *guild_id,
+    scheduled_event_id: ScheduledEventId => event.scheduled_event_id == *scheduled_event_id,
+);
+make_specific_collector!(
+    ScheduledEventUserRemoveCollector, GuildScheduledEventUserRemoveEvent,
+    Event::GuildScheduledEventUserRemove(event) => event,
+    user_id: UserId => event.user_id == *user_id,
+    guild_id: GuildId => event.guild_id == *guild_id,
+    scheduled_event_id: ScheduledEventId => event.scheduled_event_id == *scheduled_event_id,
 );
@@ -1,6 +1,6 @@
 //! A set of constants used by the library.
 
-use nonmax::NonMaxU16;
+use nonmax::{NonMaxU16, NonMaxU8};
 
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: usize = 6000;
@@ -26,6 +26,39 @@ pub const MEMBER_FETCH_LIMIT: NonMaxU16 = match NonMaxU16::new(1000) {
     None => unreachable!(),
 };
 
+/// The maximum number of guilds the current user can fetch at once, see [`Http::get_guilds`].
+///
+/// [`Http::get_guilds`]: crate::http::Http::get_guilds
+pub const GUILD_FETCH_LIMIT: NonMaxU8 = match NonMaxU8::new(200) {
+    Some(m) => m,
+    None => unreachable!(),
+};
+
+/// The maximum number of interested users of a scheduled event that can be fetched at once, see
+/// [`Http::get_scheduled_event_users`].
+///
+/// [`Http::get_scheduled_event_users`]: crate::http::Http::get_scheduled_event_users
+pub const SCHEDULED_EVENT_USER_FETCH_LIMIT: NonMaxU8 = match NonMaxU8::new(100) {
+    Some(m) => m,
+    None => unreachable!(),
+};
+
+/// The maximum number of users that can be banned at once with a bulk ban.
+pub const BULK_BAN_LIMIT: usize = 200;
+
+/// The maximum length of the username a webhook message is sent with.
+pub const WEBHOOK_USERNAME_MAX_LENGTH: usize = 80;
+
+/// Substrings Discord rejects in the username a webhook message is sent with, compared
+/// case-insensitively.
+pub const WEBHOOK_USERNAME_FORBIDDEN: &[&str] = &["clyde", "discord"];
+
+/// The default number of events buffered by the event bus for each subscriber, see
+/// [`Client::events`].
+///
+/// [`Client::events`]: crate::Client::events
+pub const EVENT_BUS_CAPACITY: usize = 1024;
+
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ::reqwest::header::USER_AGENT
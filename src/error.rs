@@ -10,7 +10,7 @@ use tokio_tungstenite::tungstenite::error::Error as TungsteniteError;
 #[cfg(feature = "gateway")]
 use crate::gateway::GatewayError;
 #[cfg(feature = "http")]
-use crate::http::HttpError;
+use crate::http::{HttpError, JsonErrorCode};
 use crate::internal::prelude::*;
 use crate::model::ModelError;
 
@@ -48,6 +48,59 @@ pub enum Error {
     Tungstenite(Box<TungsteniteError>),
 }
 
+#[cfg(feature = "http")]
+impl Error {
+    /// Returns the HTTP error, if this is one.
+    #[must_use]
+    pub fn as_http(&self) -> Option<&HttpError> {
+        match self {
+            Self::Http(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code sent by Discord, if this is an unsuccessful HTTP request. See
+    /// [`HttpError::json_error_code`].
+    #[must_use]
+    pub fn json_error_code(&self) -> Option<JsonErrorCode> {
+        self.as_http().and_then(HttpError::json_error_code)
+    }
+
+    /// Returns true when Discord responded with the given error code.
+    #[must_use]
+    pub fn is_json_error(&self, code: JsonErrorCode) -> bool {
+        self.json_error_code() == Some(code)
+    }
+
+    /// Returns true when the error is caused by a message that doesn't exist. See
+    /// [`HttpError::is_unknown_message`].
+    #[must_use]
+    pub fn is_unknown_message(&self) -> bool {
+        self.as_http().is_some_and(HttpError::is_unknown_message)
+    }
+
+    /// Returns true when the error is caused by the bot lacking access to a resource. See
+    /// [`HttpError::is_missing_access`].
+    #[must_use]
+    pub fn is_missing_access(&self) -> bool {
+        self.as_http().is_some_and(HttpError::is_missing_access)
+    }
+
+    /// Returns true when the error is caused by the bot lacking permissions for the action. See
+    /// [`HttpError::is_missing_permissions`].
+    #[must_use]
+    pub fn is_missing_permissions(&self) -> bool {
+        self.as_http().is_some_and(HttpError::is_missing_permissions)
+    }
+
+    /// Returns true when the request was ratelimited by Discord. See
+    /// [`HttpError::is_rate_limited`].
+    #[must_use]
+    pub fn is_rate_limited(&self) -> bool {
+        self.as_http().is_some_and(HttpError::is_rate_limited)
+    }
+}
+
 #[cfg(feature = "gateway")]
 impl From<GatewayError> for Error {
     fn from(e: GatewayError) -> Error {
@@ -0,0 +1,519 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use tracing::warn;
+
+use super::Framework;
+use crate::builder::{CreateCommand, CreateCommandOption};
+use crate::gateway::client::{Context, FullEvent};
+use crate::model::prelude::*;
+
+/// The error returned by command handlers registered with a [`CommandRouter`].
+pub type HandlerError = Box<dyn StdError + Send + Sync>;
+
+type Handler = Box<
+    dyn Fn(Context, CommandInteraction) -> BoxFuture<'static, Result<(), CommandError>>
+        + Send
+        + Sync,
+>;
+type Before = Box<dyn Fn(Context, CommandInteraction) -> BoxFuture<'static, bool> + Send + Sync>;
+type After = Box<dyn Fn(Context, CommandInteraction) -> BoxFuture<'static, ()> + Send + Sync>;
+type OnError =
+    Box<dyn Fn(Context, CommandInteraction, CommandError) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// An error extracting a command's arguments from the interaction, see [`CommandArgs::extract`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ArgumentError {
+    /// A required option was not provided.
+    Missing(String),
+    /// An option was provided, but its value or resolved data doesn't have the expected type.
+    Invalid {
+        /// The name of the option.
+        name: String,
+        /// The type the option was expected to have.
+        expected: CommandOptionType,
+    },
+}
+
+impl fmt::Display for ArgumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "Missing required option `{name}`"),
+            Self::Invalid {
+                name,
+                expected,
+            } => write!(f, "Option `{name}` is not of type {expected:?}"),
+        }
+    }
+}
+
+impl StdError for ArgumentError {}
+
+/// An error running a command through a [`CommandRouter`], passed to its error handler.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CommandError {
+    /// The command's arguments couldn't be extracted from the interaction.
+    Arguments(ArgumentError),
+    /// The command's handler returned an error.
+    Handler(HandlerError),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Arguments(err) => write!(f, "Invalid arguments: {err}"),
+            Self::Handler(err) => write!(f, "Command failed: {err}"),
+        }
+    }
+}
+
+impl StdError for CommandError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Arguments(err) => Some(err),
+            Self::Handler(err) => Some(&**err),
+        }
+    }
+}
+
+/// A single argument of a command, which can be extracted from an option of the interaction.
+///
+/// This is implemented for [`String`], [`i64`], [`f64`], [`bool`], [`User`], [`Role`],
+/// [`PartialChannel`] and [`Attachment`], which are required options, and for [`Option`]s of
+/// them, which are optional options.
+pub trait CommandArg: Sized {
+    /// Creates the option registered for this argument.
+    fn option(name: &'static str, description: &'static str) -> CreateCommandOption<'static>;
+
+    /// Extracts the argument from the option with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ArgumentError`] if the option is required but missing, or has the wrong type.
+    fn extract(data: &CommandData, name: &str) -> Result<Self, ArgumentError>;
+}
+
+macro_rules! command_arg {
+    ($ty:ty, $kind:ident, $get:expr) => {
+        impl CommandArg for $ty {
+            fn option(
+                name: &'static str,
+                description: &'static str,
+            ) -> CreateCommandOption<'static> {
+                <Option<$ty>>::option(name, description).required(true)
+            }
+
+            fn extract(data: &CommandData, name: &str) -> Result<Self, ArgumentError> {
+                <Option<$ty>>::extract(data, name)?
+                    .ok_or_else(|| ArgumentError::Missing(name.to_string()))
+            }
+        }
+
+        impl CommandArg for Option<$ty> {
+            fn option(
+                name: &'static str,
+                description: &'static str,
+            ) -> CreateCommandOption<'static> {
+                CreateCommandOption::new(CommandOptionType::$kind, name, description)
+            }
+
+            fn extract(data: &CommandData, name: &str) -> Result<Self, ArgumentError> {
+                if data.get_option(name).is_none() {
+                    return Ok(None);
+                }
+
+                let get: fn(&CommandData, &str) -> Option<$ty> = $get;
+                get(data, name).map(Some).ok_or_else(|| ArgumentError::Invalid {
+                    name: name.to_string(),
+                    expected: CommandOptionType::$kind,
+                })
+            }
+        }
+    };
+}
+
+command_arg!(String, String, |data, name| data.get_string(name).map(str::to_string));
+command_arg!(i64, Integer, CommandData::get_integer);
+command_arg!(f64, Number, CommandData::get_number);
+command_arg!(bool, Boolean, CommandData::get_boolean);
+command_arg!(User, User, |data, name| data.get_user(name).map(|(user, _)| user.clone()));
+command_arg!(Role, Role, |data, name| data.get_role(name).cloned());
+command_arg!(PartialChannel, Channel, |data, name| data.get_channel(name).cloned());
+command_arg!(Attachment, Attachment, |data, name| data.get_attachment(name).cloned());
+
+/// The arguments of a command, which define the options it is registered with and are extracted
+/// from the interaction before its handler runs.
+///
+/// Rather than implementing this by hand, use the [`command_args!`] macro to declare a struct
+/// whose fields are the arguments. Commands without arguments can use `()`.
+///
+/// [`command_args!`]: crate::command_args
+pub trait CommandArgs: Sized {
+    /// Creates the options registered for these arguments.
+    fn options() -> Vec<CreateCommandOption<'static>>;
+
+    /// Extracts the arguments from the interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ArgumentError`] if an option is missing or has the wrong type.
+    fn extract(data: &CommandData) -> Result<Self, ArgumentError>;
+}
+
+impl CommandArgs for () {
+    fn options() -> Vec<CreateCommandOption<'static>> {
+        Vec::new()
+    }
+
+    fn extract(_: &CommandData) -> Result<Self, ArgumentError> {
+        Ok(())
+    }
+}
+
+/// Declares a struct of command arguments, implementing [`CommandArgs`] for it.
+///
+/// Each field becomes an option of the command, named after the field and described by the string
+/// following it. Fields of an [`Option`] type are optional, all others are required. As Discord
+/// requires, required fields have to come before optional ones.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::command_args;
+/// use serenity::framework::command_router::CommandArgs;
+/// use serenity::model::user::User;
+///
+/// command_args! {
+///     pub struct BanArgs {
+///         pub user: User => "The user to ban",
+///         pub reason: Option<String> => "Why the user is banned",
+///     }
+/// }
+///
+/// assert_eq!(BanArgs::options().len(), 2);
+/// ```
+///
+/// [`CommandArgs`]: crate::framework::command_router::CommandArgs
+#[macro_export]
+macro_rules! command_args {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident: $ty:ty => $description:literal),*
+            $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($(#[$field_attr])* $field_vis $field: $ty,)*
+        }
+
+        impl $crate::framework::command_router::CommandArgs for $name {
+            fn options() -> ::std::vec::Vec<$crate::builder::CreateCommandOption<'static>> {
+                ::std::vec![$(
+                    <$ty as $crate::framework::command_router::CommandArg>::option(
+                        ::std::stringify!($field),
+                        $description,
+                    ),
+                )*]
+            }
+
+            fn extract(
+                data: &$crate::model::application::CommandData,
+            ) -> ::std::result::Result<Self, $crate::framework::command_router::ArgumentError> {
+                ::std::result::Result::Ok(Self {$(
+                    $field: <$ty as $crate::framework::command_router::CommandArg>::extract(
+                        data,
+                        ::std::stringify!($field),
+                    )?,
+                )*})
+            }
+        }
+    };
+}
+
+struct RegisteredCommand {
+    builder: CreateCommand<'static>,
+    handler: Handler,
+}
+
+/// Routes chat input and context menu commands to handlers taking typed arguments, and creates
+/// the builders to register those commands from the same definitions.
+///
+/// Handlers can be wrapped by hooks: [`Self::before`] runs before every command and may cancel it,
+/// [`Self::after`] runs after every successful command, and [`Self::on_error`] handles arguments
+/// that couldn't be extracted and errors returned by handlers. Without an error handler, errors
+/// are logged.
+///
+/// The router can either be registered as the client's framework via
+/// [`ClientBuilder::framework`], or called manually with [`Self::dispatch_interaction`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::builder::{CreateCommand, CreateInteractionResponse};
+/// use serenity::command_args;
+/// use serenity::framework::command_router::CommandRouter;
+/// use serenity::model::application::Command;
+/// use serenity::model::user::User;
+///
+/// command_args! {
+///     struct GreetArgs {
+///         user: User => "The user to greet",
+///         message: Option<String> => "The greeting",
+///     }
+/// }
+///
+/// # async fn run(http: &serenity::http::Http) -> serenity::Result<()> {
+/// let router = CommandRouter::new()
+///     .command(
+///         CreateCommand::new("greet").description("Greets a user"),
+///         |ctx, interaction, args: GreetArgs| async move {
+///             let greeting = args.message.as_deref().unwrap_or("Hello");
+///             let content = format!("{greeting}, {}!", args.user.name);
+///             let response = CreateInteractionResponse::Message(
+///                 serenity::builder::CreateInteractionResponseMessage::new().content(content),
+///             );
+///             interaction.create_response(&ctx.http, response).await?;
+///             Ok(())
+///         },
+///     )
+///     .user_command(CreateCommand::user_context("Greet"), |ctx, interaction, user| async move {
+///         println!("{} greeted {}", interaction.user.name, user.name);
+///         Ok(())
+///     })
+///     .before(|_ctx, interaction| async move {
+///         println!("{} used /{}", interaction.user.name, interaction.data.name);
+///         true
+///     });
+///
+/// Command::set_global_commands(http, &router.commands()).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ClientBuilder::framework`]: crate::gateway::client::ClientBuilder::framework
+#[derive(Default)]
+#[must_use]
+pub struct CommandRouter {
+    commands: Vec<RegisteredCommand>,
+    before: Option<Before>,
+    after: Option<After>,
+    on_error: Option<OnError>,
+}
+
+impl CommandRouter {
+    /// Creates a router without any commands.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a chat input command, which is routed to by the name of the builder.
+    ///
+    /// The options of the builder are replaced by those of the handler's arguments.
+    pub fn command<A, F, Fut>(self, builder: CreateCommand<'static>, handler: F) -> Self
+    where
+        A: CommandArgs + 'static,
+        F: Fn(Context, CommandInteraction, A) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        let builder = builder.set_options(A::options());
+        self.register(builder, |interaction| A::extract(&interaction.data), handler)
+    }
+
+    /// Registers a user context menu command, whose handler receives the user it was used on.
+    ///
+    /// The builder is turned into a user command, so it can be created with either
+    /// [`CreateCommand::new`] or [`CreateCommand::user_context`].
+    pub fn user_command<F, Fut>(self, builder: CreateCommand<'static>, handler: F) -> Self
+    where
+        F: Fn(Context, CommandInteraction, User) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        let extract = |interaction: &CommandInteraction| {
+            let target = interaction.data.target_user().map(|(user, _)| user.clone());
+            target.ok_or_else(|| ArgumentError::Missing("target".to_string()))
+        };
+        self.register(builder.kind(CommandType::User), extract, handler)
+    }
+
+    /// Registers a message context menu command, whose handler receives the message it was used
+    /// on.
+    ///
+    /// The builder is turned into a message command, so it can be created with either
+    /// [`CreateCommand::new`] or [`CreateCommand::message_context`].
+    pub fn message_command<F, Fut>(self, builder: CreateCommand<'static>, handler: F) -> Self
+    where
+        F: Fn(Context, CommandInteraction, Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        let extract = |interaction: &CommandInteraction| {
+            let target = interaction.data.target_message().cloned();
+            target.ok_or_else(|| ArgumentError::Missing("target".to_string()))
+        };
+        self.register(builder.kind(CommandType::Message), extract, handler)
+    }
+
+    fn register<T: 'static, F, Fut>(
+        mut self,
+        builder: CreateCommand<'static>,
+        extract: fn(&CommandInteraction) -> Result<T, ArgumentError>,
+        handler: F,
+    ) -> Self
+    where
+        F: Fn(Context, CommandInteraction, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        let handler: Handler = Box::new(move |ctx, interaction| match extract(&interaction) {
+            Ok(args) => {
+                let future = handler(ctx, interaction, args);
+                Box::pin(async move { future.await.map_err(CommandError::Handler) })
+            },
+            Err(err) => Box::pin(async move { Err(CommandError::Arguments(err)) }),
+        });
+
+        self.commands.push(RegisteredCommand {
+            builder,
+            handler,
+        });
+        self
+    }
+
+    /// Sets a hook to run before every command. If it returns `false`, the command is not run,
+    /// such as when the user lacks permissions or is on cooldown.
+    pub fn before<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(Context, CommandInteraction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        self.before = Some(Box::new(move |ctx, interaction| Box::pin(hook(ctx, interaction))));
+        self
+    }
+
+    /// Sets a hook to run after every command that finished successfully.
+    pub fn after<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(Context, CommandInteraction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.after = Some(Box::new(move |ctx, interaction| Box::pin(hook(ctx, interaction))));
+        self
+    }
+
+    /// Sets the handler for commands that failed, replacing the default of logging the error.
+    pub fn on_error<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Context, CommandInteraction, CommandError) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on_error =
+            Some(Box::new(move |ctx, interaction, err| Box::pin(handler(ctx, interaction, err))));
+        self
+    }
+
+    /// Returns the builders for all registered commands, to register them with Discord, such as
+    /// via [`Command::set_global_commands`] or [`Command::sync_global`].
+    #[must_use]
+    pub fn commands(&self) -> Vec<CreateCommand<'static>> {
+        self.commands.iter().map(|command| command.builder.clone()).collect()
+    }
+
+    /// Runs the command the interaction invoked, along with the hooks.
+    ///
+    /// Returns `false` if the interaction is not a command interaction, or if no command with its
+    /// name is registered.
+    pub async fn dispatch_interaction(&self, ctx: &Context, interaction: &Interaction) -> bool {
+        let Interaction::Command(interaction) = interaction else {
+            return false;
+        };
+
+        let Some(command) = self.commands.iter().find(|command| {
+            command.builder.get_kind() == interaction.data.kind
+                && command.builder.get_name() == interaction.data.name
+        }) else {
+            return false;
+        };
+
+        if let Some(before) = &self.before {
+            if !before(ctx.clone(), interaction.clone()).await {
+                return true;
+            }
+        }
+
+        match (command.handler)(ctx.clone(), interaction.clone()).await {
+            Ok(()) => {
+                if let Some(after) = &self.after {
+                    after(ctx.clone(), interaction.clone()).await;
+                }
+            },
+            Err(err) => {
+                if let Some(on_error) = &self.on_error {
+                    on_error(ctx.clone(), interaction.clone(), err).await;
+                } else {
+                    warn!("Command /{} failed: {err}", interaction.data.name);
+                }
+            },
+        }
+
+        true
+    }
+}
+
+#[async_trait]
+impl Framework for CommandRouter {
+    async fn dispatch(&self, ctx: &Context, event: &FullEvent) {
+        if let FullEvent::InteractionCreate {
+            interaction,
+        } = event
+        {
+            self.dispatch_interaction(ctx, interaction).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgumentError, CommandArgs};
+    use crate::model::application::{CommandData, CommandOptionType};
+
+    command_args! {
+        struct TestArgs {
+            text: String => "Some text",
+            count: Option<i64> => "How often",
+        }
+    }
+
+    #[test]
+    fn command_args_extract() {
+        let options = TestArgs::options();
+        assert_eq!(options.len(), 2);
+
+        let data: CommandData = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "name": "test",
+            "type": 1,
+            "options": [{"name": "text", "type": 3, "value": "hello"}],
+        }))
+        .unwrap();
+        let args = TestArgs::extract(&data).unwrap();
+        assert_eq!(args.text, "hello");
+        assert_eq!(args.count, None);
+
+        let data: CommandData = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "name": "test",
+            "type": 1,
+            "options": [{"name": "text", "type": 4, "value": 3}],
+        }))
+        .unwrap();
+        assert!(matches!(TestArgs::extract(&data), Err(ArgumentError::Invalid {
+            expected: CommandOptionType::String,
+            ..
+        })));
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+use std::future::Future;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use tracing::warn;
+
+use super::Framework;
+use crate::gateway::client::{Context, FullEvent};
+use crate::model::application::{ComponentInteraction, Interaction, ModalInteraction};
+use crate::utils::{CustomId, CustomIdError};
+
+type Handler<T> = Box<dyn Fn(Context, T, RouteParams) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// A pattern which a component or modal `custom_id` is matched against by a [`ComponentRouter`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CustomIdPattern {
+    /// Matches if the `custom_id` is exactly equal to the given string.
+    Exact(String),
+    /// Matches if the `custom_id` starts with the given string. The remainder is available via
+    /// [`RouteParams::rest`].
+    Prefix(String),
+    /// Matches if the regex matches the entire `custom_id`. Capture groups are available via
+    /// [`RouteParams::get`] and [`RouteParams::name`].
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl CustomIdPattern {
+    /// Creates a pattern matching the given `custom_id` exactly.
+    pub fn exact(custom_id: impl Into<String>) -> Self {
+        Self::Exact(custom_id.into())
+    }
+
+    /// Creates a pattern matching any `custom_id` starting with the given prefix.
+    pub fn prefix(prefix: impl Into<String>) -> Self {
+        Self::Prefix(prefix.into())
+    }
+
+    /// Creates a pattern from a regular expression. The expression is anchored, so it has to match
+    /// the entire `custom_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression is invalid.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(&format!("^(?:{pattern})$")).map(Self::Regex)
+    }
+
+    /// Matches the pattern against a `custom_id`, returning the captured parameters on success.
+    #[must_use]
+    pub fn matches(&self, custom_id: &str) -> Option<RouteParams> {
+        match self {
+            Self::Exact(exact) => (exact == custom_id).then(RouteParams::default),
+            Self::Prefix(prefix) => {
+                custom_id.strip_prefix(prefix.as_str()).map(|rest| RouteParams {
+                    rest: Some(rest.to_string()),
+                    ..Default::default()
+                })
+            },
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => {
+                let captures = regex.captures(custom_id)?;
+                let positional = captures
+                    .iter()
+                    .skip(1)
+                    .map(|group| group.map(|group| group.as_str().to_string()))
+                    .collect();
+                let named = regex
+                    .capture_names()
+                    .flatten()
+                    .filter_map(|name| {
+                        let group = captures.name(name)?;
+                        Some((name.to_string(), group.as_str().to_string()))
+                    })
+                    .collect();
+
+                Some(RouteParams {
+                    rest: None,
+                    positional,
+                    named,
+                })
+            },
+        }
+    }
+}
+
+impl From<&str> for CustomIdPattern {
+    fn from(custom_id: &str) -> Self {
+        Self::exact(custom_id)
+    }
+}
+
+impl From<String> for CustomIdPattern {
+    fn from(custom_id: String) -> Self {
+        Self::Exact(custom_id)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<regex::Regex> for CustomIdPattern {
+    fn from(regex: regex::Regex) -> Self {
+        Self::Regex(regex)
+    }
+}
+
+/// Parameters captured from a `custom_id` by a [`CustomIdPattern`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RouteParams {
+    rest: Option<String>,
+    positional: Vec<Option<String>>,
+    named: HashMap<String, String>,
+}
+
+impl RouteParams {
+    /// The remainder of the `custom_id` after the prefix, if matched by a
+    /// [`CustomIdPattern::Prefix`].
+    #[must_use]
+    pub fn rest(&self) -> Option<&str> {
+        self.rest.as_deref()
+    }
+
+    /// Gets a capture group by its index, starting at 0 for the first group. Returns [`None`] if
+    /// the group doesn't exist or didn't participate in the match.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.positional.get(index)?.as_deref()
+    }
+
+    /// Gets a named capture group.
+    #[must_use]
+    pub fn name(&self, name: &str) -> Option<&str> {
+        self.named.get(name).map(String::as_str)
+    }
+}
+
+/// Routes component and modal interactions to handlers based on their `custom_id`, instead of
+/// matching on it by hand in [`EventHandler::interaction_create`].
+///
+/// Routes are tried in the order they were registered, and only the first matching handler is
+/// run. Routes can also be registered for a [`CustomId`] type, in which case the handler receives
+/// the state decoded from the `custom_id`.
+///
+/// The router can either be registered as the client's framework via
+/// [`ClientBuilder::framework`], also alongside another framework by passing both as a tuple, or
+/// called manually with [`Self::dispatch_interaction`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::builder::{CreateInteractionResponse, CreateInteractionResponseMessage};
+/// use serenity::custom_id;
+/// use serenity::framework::component_router::{ComponentRouter, CustomIdPattern};
+///
+/// custom_id! {
+///     #[custom_id(prefix = "page", version = 1)]
+///     struct PageButton {
+///         page: u32,
+///     }
+/// }
+///
+/// let router = ComponentRouter::new()
+///     .component("ping", |ctx, interaction, _params| async move {
+///         let message = CreateInteractionResponseMessage::new().content("Pong!");
+///         let response = CreateInteractionResponse::Message(message);
+///         let _ = interaction.create_response(&ctx.http, response).await;
+///     })
+///     .component(CustomIdPattern::prefix("delete:"), |ctx, interaction, params| async move {
+///         let target = params.rest().unwrap_or_default();
+///         println!("{} wants to delete {target}", interaction.user.name);
+///     })
+///     .typed_component(|ctx, interaction, page: PageButton| async move {
+///         println!("{} opened page {}", interaction.user.name, page.page);
+///     });
+/// ```
+///
+/// [`EventHandler::interaction_create`]: crate::gateway::client::EventHandler::interaction_create
+/// [`ClientBuilder::framework`]: crate::gateway::client::ClientBuilder::framework
+#[derive(Default)]
+#[must_use]
+pub struct ComponentRouter {
+    components: Vec<(CustomIdPattern, Handler<ComponentInteraction>)>,
+    modals: Vec<(CustomIdPattern, Handler<ModalInteraction>)>,
+}
+
+impl ComponentRouter {
+    /// Creates a router without any routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a handler for component interactions whose `custom_id` matches the pattern.
+    pub fn component<F, Fut>(mut self, pattern: impl Into<CustomIdPattern>, handler: F) -> Self
+    where
+        F: Fn(Context, ComponentInteraction, RouteParams) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler: Handler<_> =
+            Box::new(move |ctx, interaction, params| Box::pin(handler(ctx, interaction, params)));
+        self.components.push((pattern.into(), handler));
+        self
+    }
+
+    /// Registers a handler for modal submissions whose `custom_id` matches the pattern.
+    pub fn modal<F, Fut>(mut self, pattern: impl Into<CustomIdPattern>, handler: F) -> Self
+    where
+        F: Fn(Context, ModalInteraction, RouteParams) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler: Handler<_> =
+            Box::new(move |ctx, interaction, params| Box::pin(handler(ctx, interaction, params)));
+        self.modals.push((pattern.into(), handler));
+        self
+    }
+
+    /// Registers a handler for component interactions whose `custom_id` was encoded from `T`,
+    /// passing the decoded state to the handler.
+    ///
+    /// Interactions with the prefix of `T` that fail to decode, such as components sent with an
+    /// older version, are logged and not passed on to later routes.
+    pub fn typed_component<T, F, Fut>(mut self, handler: F) -> Self
+    where
+        T: CustomId + Send + 'static,
+        F: Fn(Context, ComponentInteraction, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler: Handler<ComponentInteraction> = Box::new(move |ctx, interaction, _| {
+            match T::decode(&interaction.data.custom_id) {
+                Ok(state) => Box::pin(handler(ctx, interaction, state)),
+                Err(err) => undecodable(&interaction.data.custom_id, &err),
+            }
+        });
+        self.components.push((typed_pattern::<T>(), handler));
+        self
+    }
+
+    /// Registers a handler for modal submissions whose `custom_id` was encoded from `T`, passing
+    /// the decoded state to the handler. See [`Self::typed_component`].
+    pub fn typed_modal<T, F, Fut>(mut self, handler: F) -> Self
+    where
+        T: CustomId + Send + 'static,
+        F: Fn(Context, ModalInteraction, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler: Handler<ModalInteraction> = Box::new(move |ctx, interaction, _| {
+            match T::decode(&interaction.data.custom_id) {
+                Ok(state) => Box::pin(handler(ctx, interaction, state)),
+                Err(err) => undecodable(&interaction.data.custom_id, &err),
+            }
+        });
+        self.modals.push((typed_pattern::<T>(), handler));
+        self
+    }
+
+    /// Runs the first handler matching the interaction's `custom_id`.
+    ///
+    /// Returns `false` if the interaction is not a component or modal interaction, or if no route
+    /// matched it.
+    pub async fn dispatch_interaction(&self, ctx: &Context, interaction: &Interaction) -> bool {
+        match interaction {
+            Interaction::Component(interaction) => {
+                route(&self.components, ctx, interaction, &interaction.data.custom_id).await
+            },
+            Interaction::Modal(interaction) => {
+                route(&self.modals, ctx, interaction, &interaction.data.custom_id).await
+            },
+            _ => false,
+        }
+    }
+}
+
+fn typed_pattern<T: CustomId>() -> CustomIdPattern {
+    CustomIdPattern::prefix(format!("{}:", T::PREFIX))
+}
+
+fn undecodable(custom_id: &str, err: &CustomIdError) -> BoxFuture<'static, ()> {
+    warn!("Failed to decode custom_id {custom_id:?}: {err}");
+    Box::pin(async {})
+}
+
+async fn route<T: Clone>(
+    routes: &[(CustomIdPattern, Handler<T>)],
+    ctx: &Context,
+    interaction: &T,
+    custom_id: &str,
+) -> bool {
+    for (pattern, handler) in routes {
+        if let Some(params) = pattern.matches(custom_id) {
+            handler(ctx.clone(), interaction.clone(), params).await;
+            return true;
+        }
+    }
+
+    false
+}
+
+#[async_trait]
+impl Framework for ComponentRouter {
+    async fn dispatch(&self, ctx: &Context, event: &FullEvent) {
+        if let FullEvent::InteractionCreate {
+            interaction,
+        } = event
+        {
+            self.dispatch_interaction(ctx, interaction).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{typed_pattern, CustomIdPattern};
+
+    crate::custom_id! {
+        #[custom_id(prefix = "page", version = 1)]
+        struct Page {
+            number: u32,
+        }
+    }
+
+    #[test]
+    fn custom_id_patterns() {
+        let exact = CustomIdPattern::exact("ping");
+        assert!(exact.matches("ping").is_some());
+        assert!(exact.matches("ping:1").is_none());
+
+        let typed = typed_pattern::<Page>();
+        assert!(typed.matches("page:1:5").is_some());
+        assert!(typed.matches("pages:1:5").is_none());
+
+        let prefix = CustomIdPattern::prefix("delete:");
+        assert_eq!(prefix.matches("delete:42").unwrap().rest(), Some("42"));
+        assert!(prefix.matches("ping").is_none());
+
+        #[cfg(feature = "regex")]
+        {
+            let regex = CustomIdPattern::regex(r"page:(\d+):(?<user>\d+)").unwrap();
+            let params = regex.matches("page:3:1234").unwrap();
+            assert_eq!(params.get(0), Some("3"));
+            assert_eq!(params.name("user"), Some("1234"));
+            assert!(regex.matches("xpage:3:1234").is_none());
+        }
+    }
+}
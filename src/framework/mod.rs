@@ -4,6 +4,10 @@
 //!
 //! [`ClientBuilder::framework`]: crate::gateway::client::ClientBuilder::framework
 
+pub mod command_router;
+pub mod component_router;
+pub mod reaction_roles;
+
 use async_trait::async_trait;
 
 use crate::gateway::client::{Client, Context, FullEvent};
@@ -49,3 +53,90 @@ where
         (**self).dispatch(ctx, event).await;
     }
 }
+
+/// Runs both frameworks, the first one before the second, so that for example a command router
+/// and a component router can be registered together.
+#[async_trait]
+impl<A, B> Framework for (A, B)
+where
+    A: Framework,
+    B: Framework,
+{
+    async fn init(&mut self, client: &Client) {
+        self.0.init(client).await;
+        self.1.init(client).await;
+    }
+
+    async fn dispatch(&self, ctx: &Context, event: &FullEvent) {
+        self.0.dispatch(ctx, event).await;
+        self.1.dispatch(ctx, event).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+
+    use super::Framework;
+    #[cfg(feature = "http_transport")]
+    use crate::gateway::client::ClientBuilder;
+    use crate::gateway::client::{Client, Context, FullEvent};
+    #[cfg(feature = "http_transport")]
+    use crate::http::{HttpBuilder, MockTransport};
+    #[cfg(feature = "http_transport")]
+    use crate::model::gateway::GatewayIntents;
+    use crate::model::id::ShardId;
+
+    struct Recorder {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Framework for Recorder {
+        async fn init(&mut self, _: &Client) {
+            self.calls.lock().push(format!("{} init", self.name));
+        }
+
+        async fn dispatch(&self, _: &Context, event: &FullEvent) {
+            self.calls.lock().push(format!("{} {}", self.name, event.snake_case_name()));
+        }
+    }
+
+    fn frameworks() -> ((Recorder, Recorder), Arc<Mutex<Vec<String>>>) {
+        let calls = Arc::default();
+        let recorder = |name| Recorder {
+            name,
+            calls: Arc::clone(&calls),
+        };
+        ((recorder("first"), recorder("second")), calls)
+    }
+
+    #[tokio::test]
+    async fn tuple_dispatches_to_both_in_order() {
+        let (frameworks, calls) = frameworks();
+        let shard_id = ShardId(0);
+        let event = FullEvent::ShardReady {
+            shard_id,
+        };
+
+        frameworks.dispatch(&Context::new_for_test(shard_id), &event).await;
+        assert_eq!(*calls.lock(), ["first shard_ready", "second shard_ready"]);
+    }
+
+    #[cfg(feature = "http_transport")]
+    #[tokio::test]
+    async fn tuple_initializes_both_in_order() {
+        let (frameworks, calls) = frameworks();
+        let http = HttpBuilder::new("token").transport(MockTransport::new()).build();
+        ClientBuilder::new_with_http(Arc::new(http), GatewayIntents::empty())
+            .framework(frameworks)
+            .await
+            .unwrap();
+
+        assert_eq!(*calls.lock(), ["first init", "second init"]);
+    }
+}
@@ -0,0 +1,425 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error as StdError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use parking_lot::{Mutex, RwLock};
+use tracing::warn;
+
+use super::Framework;
+use crate::gateway::client::{Client, Context, FullEvent};
+use crate::http::Http;
+use crate::internal::tokio::spawn_named;
+use crate::model::prelude::*;
+
+/// The error returned by a [`ReactionRoleStore`].
+pub type StoreError = Box<dyn StdError + Send + Sync>;
+
+/// A role given to users reacting to a message with an emoji, and taken away again when they
+/// remove their reaction.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ReactionRole {
+    /// The message users react to.
+    pub message_id: MessageId,
+    /// The emoji users react with.
+    pub emoji: ReactionType,
+    /// The role given to users who reacted.
+    pub role_id: RoleId,
+}
+
+impl ReactionRole {
+    /// Creates a reaction role giving the role to users reacting to the message with the emoji.
+    #[must_use]
+    pub fn new(message_id: MessageId, emoji: impl Into<ReactionType>, role_id: RoleId) -> Self {
+        Self {
+            message_id,
+            emoji: emoji.into(),
+            role_id,
+        }
+    }
+}
+
+/// Persists the reaction roles of a [`ReactionRoleManager`], such as in a database, so they
+/// survive restarts.
+#[async_trait]
+pub trait ReactionRoleStore: Send + Sync {
+    /// Loads all reaction roles, called once when the client starts.
+    async fn load(&self) -> Result<Vec<ReactionRole>, StoreError>;
+
+    /// Saves a reaction role, replacing any with the same message and emoji.
+    async fn insert(&self, reaction_role: &ReactionRole) -> Result<(), StoreError>;
+
+    /// Deletes a reaction role.
+    async fn remove(&self, reaction_role: &ReactionRole) -> Result<(), StoreError>;
+}
+
+/// Identifies an emoji regardless of the name or animation flag sent along with custom emojis.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum EmojiKey {
+    Custom(EmojiId),
+    Unicode(String),
+}
+
+impl From<&ReactionType> for EmojiKey {
+    fn from(emoji: &ReactionType) -> Self {
+        match emoji {
+            ReactionType::Custom {
+                id, ..
+            } => Self::Custom(*id),
+            ReactionType::Unicode(emoji) => Self::Unicode(emoji.to_string()),
+        }
+    }
+}
+
+type MemberRole = (GuildId, UserId, RoleId);
+
+/// Role changes waiting to be applied, see [`ReactionRoleManager::batch_delay`].
+#[derive(Default)]
+struct Batch {
+    /// Whether each role is to be added or removed.
+    pending: Mutex<HashMap<MemberRole, bool>>,
+    /// Whether a task is already waiting to apply the pending changes.
+    scheduled: AtomicBool,
+    /// Held while applying changes, so batches are applied one after another.
+    applying: tokio::sync::Mutex<()>,
+}
+
+impl Batch {
+    /// Queues a role change. A change reverting a pending one cancels it out, such as when a user
+    /// quickly adds and removes a reaction.
+    fn queue(&self, member_role: MemberRole, add: bool) {
+        let mut pending = self.pending.lock();
+        if pending.get(&member_role) == Some(&!add) {
+            pending.remove(&member_role);
+        } else {
+            pending.insert(member_role, add);
+        }
+    }
+
+    async fn apply(&self, http: &Http) {
+        let _guard = self.applying.lock().await;
+        self.scheduled.store(false, Ordering::Release);
+
+        let pending = std::mem::take(&mut *self.pending.lock());
+        for ((guild_id, user_id, role_id), add) in pending {
+            let reason = Some("Reaction role");
+            let result = if add {
+                http.add_member_role(guild_id, user_id, role_id, reason).await
+            } else {
+                http.remove_member_role(guild_id, user_id, role_id, reason).await
+            };
+
+            if let Err(err) = result {
+                warn!("Failed to update reaction role {role_id} of user {user_id}: {err}");
+            }
+        }
+    }
+}
+
+/// Gives users roles for reacting to messages, and takes them away when the reaction is removed.
+///
+/// Reaction roles are registered with [`Self::add`], and can be persisted by setting a
+/// [`ReactionRoleStore`], from which they are loaded once the client starts. Reactions by bots are
+/// ignored. As Discord doesn't say whether the user removing a reaction is a bot, bots are
+/// recognized by their earlier reactions, or with the `cache` feature, by the cached members.
+///
+/// Role changes are collected for [`Self::batch_delay`] and then applied one after another, so a
+/// message receiving many reactions at once doesn't cause a burst of requests, and users quickly
+/// adding and removing a reaction don't cause any.
+///
+/// The manager can either be registered as the client's framework via
+/// [`ClientBuilder::framework`], also alongside another framework by passing both as a tuple, or
+/// called manually with [`Self::handle_reaction`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::framework::reaction_roles::{ReactionRole, ReactionRoleManager};
+/// use serenity::model::prelude::*;
+///
+/// # async fn run() -> Result<(), serenity::framework::reaction_roles::StoreError> {
+/// let manager = ReactionRoleManager::new();
+/// let message_id = MessageId::new(1149413154010353754);
+///
+/// manager.add(ReactionRole::new(message_id, '🎮', RoleId::new(1))).await?;
+/// manager.add(ReactionRole::new(message_id, '🎨', RoleId::new(2))).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ClientBuilder::framework`]: crate::gateway::client::ClientBuilder::framework
+#[must_use]
+pub struct ReactionRoleManager {
+    roles: RwLock<HashMap<(MessageId, EmojiKey), ReactionRole>>,
+    /// Bots seen reacting, as reaction removals don't include the member.
+    bots: Mutex<HashSet<UserId>>,
+    store: Option<Box<dyn ReactionRoleStore>>,
+    batch: Arc<Batch>,
+    batch_delay: Duration,
+}
+
+impl ReactionRoleManager {
+    /// Creates a manager without any reaction roles, which are not persisted.
+    pub fn new() -> Self {
+        Self {
+            roles: RwLock::default(),
+            bots: Mutex::default(),
+            store: None,
+            batch: Arc::default(),
+            batch_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Sets the store reaction roles are persisted in.
+    pub fn store(mut self, store: impl ReactionRoleStore + 'static) -> Self {
+        self.store = Some(Box::new(store));
+        self
+    }
+
+    /// Sets how long role changes are collected before they are applied.
+    ///
+    /// Defaults to 1 second.
+    pub fn batch_delay(mut self, batch_delay: Duration) -> Self {
+        self.batch_delay = batch_delay;
+        self
+    }
+
+    /// Loads the reaction roles from the store, replacing the registered ones.
+    ///
+    /// This is called automatically when the manager is registered as the client's framework.
+    ///
+    /// # Errors
+    ///
+    /// Returns the store's error if loading fails.
+    pub async fn load(&self) -> Result<(), StoreError> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+
+        let loaded = store.load().await?;
+        let mut roles = self.roles.write();
+        roles.clear();
+        for reaction_role in loaded {
+            let key = (reaction_role.message_id, EmojiKey::from(&reaction_role.emoji));
+            roles.insert(key, reaction_role);
+        }
+
+        Ok(())
+    }
+
+    /// Registers a reaction role, replacing any with the same message and emoji, and saves it to
+    /// the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns the store's error if saving fails, in which case the reaction role is not
+    /// registered.
+    pub async fn add(&self, reaction_role: ReactionRole) -> Result<(), StoreError> {
+        if let Some(store) = &self.store {
+            store.insert(&reaction_role).await?;
+        }
+
+        let key = (reaction_role.message_id, EmojiKey::from(&reaction_role.emoji));
+        self.roles.write().insert(key, reaction_role);
+        Ok(())
+    }
+
+    /// Unregisters the reaction role for the message and emoji, and deletes it from the store.
+    /// Returns the removed reaction role, if there was one.
+    ///
+    /// Roles already given to users are not taken away.
+    ///
+    /// # Errors
+    ///
+    /// Returns the store's error if deleting fails, in which case the reaction role stays
+    /// registered.
+    pub async fn remove(
+        &self,
+        message_id: MessageId,
+        emoji: &ReactionType,
+    ) -> Result<Option<ReactionRole>, StoreError> {
+        let key = (message_id, EmojiKey::from(emoji));
+        let Some(reaction_role) = self.roles.read().get(&key).cloned() else {
+            return Ok(None);
+        };
+
+        if let Some(store) = &self.store {
+            store.remove(&reaction_role).await?;
+        }
+
+        Ok(self.roles.write().remove(&key))
+    }
+
+    /// Returns the role given for reacting to the message with the emoji, if any.
+    #[must_use]
+    pub fn role_for(&self, message_id: MessageId, emoji: &ReactionType) -> Option<RoleId> {
+        let roles = self.roles.read();
+        roles.get(&(message_id, EmojiKey::from(emoji))).map(|reaction_role| reaction_role.role_id)
+    }
+
+    /// Queues giving or taking away the role for a reaction that was added or removed. Does
+    /// nothing if no reaction role is registered for it, or it was made by a bot.
+    pub fn handle_reaction(&self, ctx: &Context, reaction: &Reaction, added: bool) {
+        let (Some(guild_id), Some(user_id)) = (reaction.guild_id, reaction.user_id) else {
+            return;
+        };
+
+        if self.is_bot(ctx, guild_id, user_id, reaction) {
+            return;
+        }
+
+        let Some(role_id) = self.role_for(reaction.message_id, &reaction.emoji) else {
+            return;
+        };
+
+        self.batch.queue((guild_id, user_id, role_id), added);
+        if !self.batch.scheduled.swap(true, Ordering::AcqRel) {
+            let batch = Arc::clone(&self.batch);
+            let http = Arc::clone(&ctx.http);
+            let delay = self.batch_delay;
+            spawn_named("reaction_roles::apply", async move {
+                tokio::time::sleep(delay).await;
+                batch.apply(&http).await;
+            });
+        }
+    }
+
+    /// Whether the reaction was made by a bot. Only added reactions include the member, so the
+    /// bots seen there are remembered for when their reactions are removed.
+    #[cfg_attr(not(feature = "cache"), allow(unused_variables))]
+    fn is_bot(
+        &self,
+        ctx: &Context,
+        guild_id: GuildId,
+        user_id: UserId,
+        reaction: &Reaction,
+    ) -> bool {
+        if let Some(member) = &reaction.member {
+            let bot = member.user.bot();
+            if bot {
+                self.bots.lock().insert(user_id);
+            }
+
+            return bot;
+        }
+
+        if self.bots.lock().contains(&user_id) {
+            return true;
+        }
+
+        #[cfg(feature = "cache")]
+        if ctx.cache.current_user().id == user_id
+            || ctx.cache.member(guild_id, user_id).is_some_and(|member| member.user.bot())
+        {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl Default for ReactionRoleManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Framework for ReactionRoleManager {
+    async fn init(&mut self, _: &Client) {
+        if let Err(err) = self.load().await {
+            warn!("Failed to load reaction roles: {err}");
+        }
+    }
+
+    async fn dispatch(&self, ctx: &Context, event: &FullEvent) {
+        match event {
+            FullEvent::ReactionAdd {
+                add_reaction,
+            } => self.handle_reaction(ctx, add_reaction, true),
+            FullEvent::ReactionRemove {
+                removed_reaction,
+            } => self.handle_reaction(ctx, removed_reaction, false),
+            _ => {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Batch, EmojiKey, ReactionRole, ReactionRoleManager};
+    use crate::gateway::client::Context;
+    use crate::model::prelude::*;
+
+    #[test]
+    fn emoji_keys() {
+        let custom = |name: Option<&str>| ReactionType::Custom {
+            animated: false,
+            id: EmojiId::new(1),
+            name: name.map(FixedString::from_str_trunc),
+        };
+
+        assert_eq!(EmojiKey::from(&custom(Some("party"))), EmojiKey::from(&custom(None)));
+        assert_ne!(EmojiKey::from(&ReactionType::from('\u{1f3ae}')), EmojiKey::from(&custom(None)));
+    }
+
+    #[test]
+    fn batch_cancels_reverted_changes() {
+        let batch = Batch::default();
+        let member_role = (GuildId::new(1), UserId::new(2), RoleId::new(3));
+
+        batch.queue(member_role, true);
+        batch.queue(member_role, false);
+        assert!(batch.pending.lock().is_empty());
+
+        batch.queue(member_role, false);
+        batch.queue(member_role, false);
+        assert_eq!(batch.pending.lock().get(&member_role), Some(&false));
+    }
+
+    #[tokio::test]
+    async fn bot_reactions_are_ignored() {
+        let manager = ReactionRoleManager::new();
+        let role = ReactionRole::new(MessageId::new(3), '\u{1f3ae}', RoleId::new(4));
+        manager.add(role).await.unwrap();
+
+        let ctx = Context::new_for_test(ShardId(0));
+        let reaction = |user_id: u64, bot: Option<bool>| -> Reaction {
+            let member = bot.map(|bot| {
+                json!({
+                    "user": {"id": user_id.to_string(), "username": "user", "bot": bot},
+                    "roles": [],
+                    "joined_at": "2024-01-01T00:00:00Z",
+                    "deaf": false,
+                    "mute": false,
+                    "flags": 0,
+                })
+            });
+            serde_json::from_value(json!({
+                "user_id": user_id.to_string(),
+                "channel_id": "1",
+                "message_id": "3",
+                "guild_id": "2",
+                "member": member,
+                "emoji": {"id": null, "name": "\u{1f3ae}"},
+                "burst": false,
+                "type": 0,
+            }))
+            .unwrap()
+        };
+
+        // Removals don't include the member, so the bot is recognized from its earlier reaction.
+        manager.handle_reaction(&ctx, &reaction(5, Some(true)), true);
+        manager.handle_reaction(&ctx, &reaction(5, None), false);
+        assert!(manager.batch.pending.lock().is_empty());
+
+        manager.handle_reaction(&ctx, &reaction(6, Some(false)), true);
+        let member_role = (GuildId::new(2), UserId::new(6), RoleId::new(4));
+        assert_eq!(manager.batch.pending.lock().get(&member_role), Some(&true));
+    }
+}
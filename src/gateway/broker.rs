@@ -0,0 +1,227 @@
+//! Publishing gateway events to a message broker, to split gateway ingestion from the processes
+//! handling the events.
+//!
+//! The [`GatewayPublisher`] is a [`RawEventHandler`] which serializes the selected events received
+//! by the shards and publishes them via a [`Publisher`], such as a NATS, AMQP or Redis Streams
+//! client. Worker processes decode the payloads back into an [`Event`] with [`decode_event`], so
+//! both sides use serenity's models.
+//!
+//! Payloads are JSON in the same `{"t": ..., "d": ...}` format as gateway dispatch payloads, and
+//! are published to the subject `{prefix}.{name}`, where the name is the event name such as
+//! `MESSAGE_CREATE` and the prefix defaults to `discord`.
+//!
+//! # Examples
+//!
+//! Publishing messages to NATS, using the `async-nats` crate:
+//!
+//! ```rust,ignore
+//! use serenity::gateway::broker::{GatewayPublisher, Publisher};
+//! use serenity::model::event::EventTypeFlags;
+//! use serenity::prelude::*;
+//!
+//! struct Nats(async_nats::Client);
+//!
+//! #[serenity::async_trait]
+//! impl Publisher for Nats {
+//!     type Error = async_nats::PublishError;
+//!
+//!     async fn publish(&self, subject: &str, payload: Vec<u8>) -> Result<(), Self::Error> {
+//!         self.0.publish(subject.to_string(), payload.into()).await
+//!     }
+//! }
+//!
+//! let nats = async_nats::connect("localhost").await?;
+//! let publisher = GatewayPublisher::new(Nats(nats)).events(EventTypeFlags::MESSAGE_CREATE);
+//! let mut client = Client::builder(&token, GatewayIntents::non_privileged())
+//!     .raw_event_handler(publisher)
+//!     .filtered_events(EventTypeFlags::MESSAGE_CREATE)
+//!     .await?;
+//! ```
+//!
+//! [`RawEventHandler`]: super::client::RawEventHandler
+
+use std::fmt;
+
+use async_trait::async_trait;
+use tracing::warn;
+
+use super::client::{Context, RawEventHandler};
+use crate::internal::prelude::*;
+use crate::model::event::{Event, EventTypeFlags};
+
+/// A message broker client which events are published to by a [`GatewayPublisher`].
+#[async_trait]
+pub trait Publisher: Send + Sync {
+    /// The error returned if publishing failed.
+    type Error: fmt::Debug + fmt::Display + Send;
+
+    /// Publishes a payload to the given subject, also known as topic, routing key or stream key
+    /// depending on the broker.
+    async fn publish(&self, subject: &str, payload: Vec<u8>) -> StdResult<(), Self::Error>;
+}
+
+/// An error returned by [`GatewayPublisher::publish`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PublishError<E> {
+    /// The event could not be serialized.
+    Json(serde_json::Error),
+    /// The [`Publisher`] failed to publish the payload.
+    Publisher(E),
+}
+
+impl<E: fmt::Display> fmt::Display for PublishError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(why) => write!(f, "Failed to serialize event: {why}"),
+            Self::Publisher(why) => write!(f, "Failed to publish event: {why}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for PublishError<E> {}
+
+/// A [`RawEventHandler`] publishing gateway events to a message broker. See the [module-level
+/// documentation] for more info.
+///
+/// Events are published before they are applied to the cache and passed to the other handlers.
+/// Failures are logged, use [`Self::publish`] from another handler to handle them yourself.
+///
+/// [`RawEventHandler`]: super::client::RawEventHandler
+/// [module-level documentation]: self
+#[derive(Debug)]
+#[must_use]
+pub struct GatewayPublisher<P> {
+    publisher: P,
+    events: EventTypeFlags,
+    subject_prefix: String,
+}
+
+impl<P: Publisher> GatewayPublisher<P> {
+    /// Creates a publisher publishing all events to subjects prefixed with `discord`.
+    pub fn new(publisher: P) -> Self {
+        Self {
+            publisher,
+            events: EventTypeFlags::all(),
+            subject_prefix: String::from("discord"),
+        }
+    }
+
+    /// Sets which events are published. Defaults to all events.
+    ///
+    /// To avoid deserializing events which are neither published nor handled, also pass the
+    /// events to [`ClientBuilder::filtered_events`].
+    ///
+    /// [`ClientBuilder::filtered_events`]: super::client::ClientBuilder::filtered_events
+    pub fn events(mut self, events: EventTypeFlags) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Sets the prefix of the subjects events are published to. Defaults to `discord`.
+    pub fn subject_prefix(mut self, subject_prefix: impl Into<String>) -> Self {
+        self.subject_prefix = subject_prefix.into();
+        self
+    }
+
+    /// The underlying broker client.
+    #[must_use]
+    pub fn publisher(&self) -> &P {
+        &self.publisher
+    }
+
+    /// Returns the subject an event is published to.
+    #[must_use]
+    pub fn subject(&self, event: &Event) -> String {
+        format!("{}.{}", self.subject_prefix, event.name())
+    }
+
+    /// Serializes and publishes an event, returning `false` if it is not one of the selected
+    /// [`Self::events`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PublishError::Json`] if the event could not be serialized, or
+    /// [`PublishError::Publisher`] if publishing it failed.
+    pub async fn publish(&self, event: &Event) -> StdResult<bool, PublishError<P::Error>> {
+        if !self.events.contains(EventTypeFlags::from_event(event)) {
+            return Ok(false);
+        }
+
+        let payload = serde_json::to_vec(event).map_err(PublishError::Json)?;
+        self.publisher
+            .publish(&self.subject(event), payload)
+            .await
+            .map_err(PublishError::Publisher)?;
+
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl<P: Publisher> RawEventHandler for GatewayPublisher<P> {
+    async fn raw_event(&self, _ctx: Context, event: &Event) {
+        if let Err(why) = self.publish(event).await {
+            warn!("Failed to publish {} event: {why}", event.name());
+        }
+    }
+}
+
+/// Decodes an event published by a [`GatewayPublisher`].
+///
+/// # Errors
+///
+/// Returns [`Error::Json`] if the payload is not a valid event.
+pub fn decode_event(payload: &[u8]) -> Result<Event> {
+    Ok(serde_json::from_slice(payload)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+
+    use super::{decode_event, GatewayPublisher, Publisher};
+    use crate::model::prelude::*;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<(String, Vec<u8>)>>);
+
+    #[async_trait]
+    impl Publisher for Recorder {
+        type Error = std::convert::Infallible;
+
+        async fn publish(&self, subject: &str, payload: Vec<u8>) -> Result<(), Self::Error> {
+            self.0.lock().push((subject.to_string(), payload));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_selected_events() {
+        let publisher = GatewayPublisher::new(Recorder::default())
+            .events(EventTypeFlags::TYPING_START)
+            .subject_prefix("bot");
+
+        let typing = Event::TypingStart(TypingStartEvent {
+            channel_id: ChannelId::new(1),
+            guild_id: None,
+            member: None,
+            timestamp: 0,
+            user_id: UserId::new(2),
+        });
+        let resumed = Event::Resumed(ResumedEvent {});
+
+        assert!(publisher.publish(&typing).await.unwrap());
+        assert!(!publisher.publish(&resumed).await.unwrap());
+
+        let published = publisher.publisher().0.lock();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].0, "bot.TYPING_START");
+
+        let Event::TypingStart(decoded) = decode_event(&published[0].1).unwrap() else {
+            panic!("decoded the wrong event");
+        };
+        assert_eq!(decoded.user_id, UserId::new(2));
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tracing::{debug, info};
+
+/// Collects usage statistics of application commands, registered via
+/// [`ClientBuilder::command_stats`].
+///
+/// Every [`Interaction::Command`] dispatched to the framework and event handler is recorded under
+/// its [full name], along with how long the handlers took. Invocations whose handler panicked are
+/// counted as errors. Each invocation is also emitted as a `DEBUG` tracing event with the
+/// `serenity::command_stats` target.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// use serenity::gateway::client::CommandStats;
+/// use serenity::prelude::*;
+///
+/// # async fn run() -> Result<(), serenity::Error> {
+/// let stats = Arc::new(CommandStats::new());
+/// let mut client = Client::builder("token", GatewayIntents::non_privileged())
+///     .command_stats(Arc::clone(&stats))
+///     .await?;
+///
+/// tokio::spawn(async move {
+///     loop {
+///         tokio::time::sleep(Duration::from_secs(60)).await;
+///         stats.emit_metrics();
+///     }
+/// });
+///
+/// client.start().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ClientBuilder::command_stats`]: super::ClientBuilder::command_stats
+/// [`Interaction::Command`]: crate::model::application::Interaction::Command
+/// [full name]: crate::model::application::CommandInteraction::full_command_name
+#[derive(Debug, Default)]
+pub struct CommandStats {
+    commands: Mutex<HashMap<String, CommandUsage>>,
+}
+
+/// The usage statistics of a single command, as returned by [`CommandStats::snapshot`].
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CommandUsage {
+    /// How often the command was invoked.
+    pub invocations: u64,
+    /// How many of the invocations failed.
+    pub errors: u64,
+    /// The sum of the time taken to handle each invocation.
+    pub total_latency: Duration,
+    /// The longest time taken to handle an invocation.
+    pub max_latency: Duration,
+}
+
+impl CommandUsage {
+    /// Returns the average time taken to handle an invocation.
+    #[must_use]
+    pub fn average_latency(&self) -> Option<Duration> {
+        let invocations = u32::try_from(self.invocations).ok().filter(|count| *count > 0)?;
+        Some(self.total_latency / invocations)
+    }
+
+    /// Returns the share of failed invocations, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn error_rate(&self) -> f64 {
+        if self.invocations == 0 {
+            return 0.0;
+        }
+
+        let errors = u32::try_from(self.errors).unwrap_or(u32::MAX);
+        let invocations = u32::try_from(self.invocations).unwrap_or(u32::MAX);
+        (f64::from(errors) / f64::from(invocations)).min(1.0)
+    }
+}
+
+impl CommandStats {
+    /// Creates a new, empty stats collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the usage statistics of all commands invoked so far, keyed by their full name.
+    #[must_use]
+    pub fn snapshot(&self) -> HashMap<String, CommandUsage> {
+        self.commands.lock().clone()
+    }
+
+    /// Returns the usage statistics of a single command, by its full name such as `"config set"`.
+    #[must_use]
+    pub fn command(&self, name: &str) -> Option<CommandUsage> {
+        self.commands.lock().get(name).cloned()
+    }
+
+    /// Records a failed invocation of a command, for handlers which handle their errors instead of
+    /// panicking. The invocation itself is already counted when it is dispatched.
+    pub fn record_error(&self, name: &str) {
+        let mut commands = self.commands.lock();
+        if let Some(usage) = commands.get_mut(name) {
+            usage.errors += 1;
+        } else {
+            commands.insert(name.to_owned(), CommandUsage {
+                errors: 1,
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Clears the statistics of all commands.
+    pub fn reset(&self) {
+        self.commands.lock().clear();
+    }
+
+    /// Emits the statistics of each command as an `INFO` tracing event with the
+    /// `serenity::command_stats` target, for metrics exporters reading tracing events.
+    ///
+    /// This is meant to be called periodically.
+    pub fn emit_metrics(&self) {
+        for (name, usage) in self.commands.lock().iter() {
+            info!(
+                target: "serenity::command_stats",
+                command = %name,
+                invocations = usage.invocations,
+                errors = usage.errors,
+                average_latency_ms = usage.average_latency().map_or(0, |latency| latency.as_millis()),
+                max_latency_ms = usage.max_latency.as_millis(),
+                "Command usage"
+            );
+        }
+    }
+
+    pub(crate) fn record(&self, name: String, latency: Duration, failed: bool) {
+        debug!(
+            target: "serenity::command_stats",
+            command = %name,
+            latency_ms = latency.as_millis(),
+            failed,
+            "Command invoked"
+        );
+
+        let mut commands = self.commands.lock();
+        let usage = commands.entry(name).or_default();
+        usage.invocations += 1;
+        usage.errors += u64::from(failed);
+        usage.total_latency += latency;
+        usage.max_latency = usage.max_latency.max(latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{CommandStats, CommandUsage};
+
+    #[test]
+    fn record() {
+        let stats = CommandStats::new();
+        stats.record("ping".into(), Duration::from_millis(10), false);
+        stats.record("ping".into(), Duration::from_millis(30), true);
+        stats.record("config set".into(), Duration::from_millis(5), false);
+
+        let ping = stats.command("ping").unwrap();
+        assert_eq!(ping.invocations, 2);
+        assert_eq!(ping.errors, 1);
+        assert_eq!(ping.total_latency, Duration::from_millis(40));
+        assert_eq!(ping.max_latency, Duration::from_millis(30));
+
+        assert_eq!(stats.snapshot().len(), 2);
+        assert!(stats.command("config").is_none());
+
+        stats.reset();
+        assert!(stats.snapshot().is_empty());
+    }
+
+    #[test]
+    fn record_error() {
+        let stats = CommandStats::new();
+        stats.record("ping".into(), Duration::from_millis(10), false);
+        stats.record_error("ping");
+        assert_eq!(stats.command("ping").unwrap().errors, 1);
+        assert_eq!(stats.command("ping").unwrap().invocations, 1);
+
+        // Errors of commands which weren't dispatched yet are still counted.
+        stats.record_error("unknown");
+        let unknown = stats.command("unknown").unwrap();
+        assert_eq!(unknown.errors, 1);
+        assert_eq!(unknown.invocations, 0);
+    }
+
+    #[test]
+    fn average_latency() {
+        assert_eq!(CommandUsage::default().average_latency(), None);
+
+        let usage = CommandUsage {
+            invocations: 4,
+            total_latency: Duration::from_millis(100),
+            ..Default::default()
+        };
+        assert_eq!(usage.average_latency(), Some(Duration::from_millis(25)));
+    }
+
+    #[test]
+    fn error_rate() {
+        assert!(CommandUsage::default().error_rate().abs() < f64::EPSILON);
+
+        let usage = |invocations, errors| CommandUsage {
+            invocations,
+            errors,
+            ..Default::default()
+        };
+        assert!((usage(4, 1).error_rate() - 0.25).abs() < f64::EPSILON);
+        // Errors recorded via `record_error` may outnumber the invocations.
+        assert!((usage(1, 3).error_rate() - 1.0).abs() < f64::EPSILON);
+    }
+}
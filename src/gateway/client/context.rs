@@ -3,7 +3,8 @@ use std::sync::Arc;
 
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
-use crate::gateway::{ActivityData, ShardMessenger, ShardRunner};
+use super::extensions::{Extensions, ShardState};
+use crate::gateway::{ActivityData, PresenceData, ShardMessenger};
 use crate::http::Http;
 use crate::model::prelude::*;
 
@@ -27,6 +28,11 @@ pub struct Context {
     ///
     /// [`Client::data`]: super::Client::data
     data: Arc<dyn std::any::Any + Send + Sync>,
+    /// The values registered via [`ClientBuilder::extension`].
+    ///
+    /// [`ClientBuilder::extension`]: super::ClientBuilder::extension
+    extensions: Arc<Extensions>,
+    shard_state: ShardState,
     /// The messenger to communicate with the shard runner.
     pub shard: ShardMessenger,
     /// The ID of the shard this context is related to.
@@ -51,21 +57,49 @@ impl Context {
     /// Create a new Context to be passed to an event handler.
     pub(crate) fn new(
         data: Arc<dyn std::any::Any + Send + Sync>,
-        runner: &ShardRunner,
+        extensions: Arc<Extensions>,
+        shard_state: ShardState,
+        shard: ShardMessenger,
         shard_id: ShardId,
         http: Arc<Http>,
         #[cfg(feature = "cache")] cache: Arc<Cache>,
     ) -> Context {
         Context {
-            shard: ShardMessenger::new(runner),
-            shard_id,
             data,
+            extensions,
+            shard_state,
+            shard,
+            shard_id,
             http,
             #[cfg(feature = "cache")]
             cache,
         }
     }
 
+    /// Creates a context whose shard messages are dropped, for unit tests.
+    #[cfg(test)]
+    pub(crate) fn new_for_test(shard_id: ShardId) -> Context {
+        let (tx, _) = futures::channel::mpsc::unbounded();
+        let shard = ShardMessenger {
+            tx,
+            #[cfg(feature = "collector")]
+            shard_id,
+            #[cfg(feature = "collector")]
+            event_bus: crate::gateway::EventBus::new(),
+        };
+
+        Context::new(
+            Arc::new(()),
+            Arc::new(Extensions::new()),
+            ShardState::default(),
+            shard,
+            shard_id,
+            Arc::new(Http::new("token")),
+            #[cfg(feature = "cache")]
+            Arc::new(Cache::new()),
+        )
+    }
+
     /// A container for a data type that can be used across contexts.
     ///
     /// The purpose of the data field is to be accessible and persistent across contexts; that is,
@@ -84,6 +118,74 @@ impl Context {
             .expect("Type provided to Context should be the same as ClientBuilder::data.")
     }
 
+    /// Returns the value of the given type registered via [`ClientBuilder::extension`], if any.
+    ///
+    /// Extensions can't be changed once the client is built, so unlike [`Self::data`] this is a
+    /// plain map lookup, without cloning an [`Arc`] or taking a lock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::channel::Message;
+    /// struct Prefix(String);
+    ///
+    /// struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn message(&self, ctx: Context, msg: Message) {
+    ///         let prefix = ctx.data_ref::<Prefix>().map_or("!", |prefix| &prefix.0);
+    ///         if msg.content.starts_with(prefix) {
+    ///             // Handle the command.
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`ClientBuilder::extension`]: super::ClientBuilder::extension
+    #[must_use]
+    pub fn data_ref<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get()
+    }
+
+    /// Calls the function with this shard's state of the given type, starting out as its default
+    /// value.
+    ///
+    /// Each shard keeps its own state, which is only shared between the contexts of its events, so
+    /// it is cheaper to update than state shared by all shards. The state is reset when the shard
+    /// is restarted. The state is locked while the function runs, so it must not call this again.
+    ///
+    /// # Examples
+    ///
+    /// Counting the messages received by each shard:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::channel::Message;
+    /// #[derive(Default)]
+    /// struct MessageCount(u64);
+    ///
+    /// struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn message(&self, ctx: Context, _: Message) {
+    ///         let count = ctx.with_shard_state(|count: &mut MessageCount| {
+    ///             count.0 += 1;
+    ///             count.0
+    ///         });
+    ///         println!("Shard {} received {count} messages", ctx.shard_id);
+    ///     }
+    /// }
+    /// ```
+    pub fn with_shard_state<T: Default + Send + 'static, R>(
+        &self,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> R {
+        self.shard_state.with(f)
+    }
+
     /// Sets the current user as being [`Online`]. This maintains the current activity.
     ///
     /// # Examples
@@ -299,6 +401,37 @@ impl Context {
         self.shard.set_presence(activity, status);
     }
 
+    /// Replaces the current user's presence on this shard, including all activities.
+    ///
+    /// # Examples
+    ///
+    /// Setting a custom status alongside an activity:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # fn run(ctx: Context) {
+    /// use serenity::gateway::{ActivityData, PresenceBuilder};
+    ///
+    /// let presence = PresenceBuilder::new()
+    ///     .custom_status("Counting sheep", None)
+    ///     .activity(ActivityData::watching("the clock"));
+    ///
+    /// ctx.set_presence_data(presence);
+    /// # }
+    /// ```
+    pub fn set_presence_data(&self, presence: impl Into<PresenceData>) {
+        self.shard.set_presence_data(presence);
+    }
+
+    /// Replaces the current user's presence on all shards, including shards started later on.
+    ///
+    /// Refer to [`ShardManager::set_presence_all`] for more information.
+    ///
+    /// [`ShardManager::set_presence_all`]: crate::gateway::ShardManager::set_presence_all
+    pub fn set_presence_all(&self, presence: impl Into<PresenceData>) {
+        self.shard.set_presence_all(presence);
+    }
+
     /// Gets all emojis for the current application.
     ///
     /// # Errors
@@ -1,15 +1,31 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
-use super::event_handler::{EventHandler, RawEventHandler};
-use super::{Context, FullEvent};
+use futures::future::BoxFuture;
+use futures::FutureExt as _;
+use parking_lot::Mutex;
+use serde_json::value::RawValue;
+use tokio::sync::{oneshot, Semaphore};
+use tracing::warn;
+
+use super::event_handler::{EventHandler, RawEventHandler, RawPayloadHandler};
+use super::{CommandStats, Context, FullEvent};
 #[cfg(feature = "cache")]
 use crate::cache::{Cache, CacheUpdate};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
+use crate::gateway::ShardManager;
 use crate::internal::prelude::*;
+use crate::model::application::Interaction;
 use crate::model::channel::ChannelType;
 use crate::model::event::Event;
 use crate::model::guild::Member;
+use crate::model::id::ChannelId;
 #[cfg(feature = "cache")]
 use crate::model::id::GuildId;
 
@@ -39,6 +55,242 @@ macro_rules! update_cache {
     ($cache:ident, $event:ident) => {};
 }
 
+/// How events received by the shards are dispatched to the framework and event handlers, set via
+/// [`ClientBuilder::event_dispatch`].
+///
+/// [`ClientBuilder::event_dispatch`]: super::ClientBuilder::event_dispatch
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum DispatchMode {
+    /// Every event is handled in its own task, without any limit. Events may be handled in a
+    /// different order than they were received in.
+    #[default]
+    Unbounded,
+    /// Events belonging to the same channel, such as messages, reactions and typing events, are
+    /// handled one after another, in the order they were received in. Events of different
+    /// channels, and events which don't belong to a channel, are still handled concurrently.
+    PerChannel,
+    /// At most the given number of events are handled at the same time. Further events wait until
+    /// a previous event was handled, while the shard keeps receiving events.
+    Bounded(NonZeroUsize),
+}
+
+/// For each channel, the sequence number and completion of the most recently scheduled event.
+type ChannelQueues = Arc<Mutex<HashMap<ChannelId, (u64, oneshot::Receiver<()>)>>>;
+
+/// Schedules the handling of events according to a [`DispatchMode`].
+#[derive(Debug)]
+pub(crate) struct Dispatcher {
+    mode: DispatchMode,
+    semaphore: Option<Arc<Semaphore>>,
+    channels: ChannelQueues,
+    next_seq: AtomicU64,
+}
+
+impl Dispatcher {
+    pub(crate) fn new(mode: DispatchMode) -> Self {
+        let semaphore = match mode {
+            DispatchMode::Bounded(limit) => Some(Arc::new(Semaphore::new(limit.get()))),
+            DispatchMode::Unbounded | DispatchMode::PerChannel => None,
+        };
+
+        Self {
+            mode,
+            semaphore,
+            channels: Arc::default(),
+            next_seq: AtomicU64::new(0),
+        }
+    }
+
+    /// Wraps the future handling an event, so that it waits for its turn when spawned. The channel
+    /// of the event is obtained via [`event_channel_id`].
+    pub(crate) fn schedule<F>(
+        &self,
+        channel_id: Option<ChannelId>,
+        future: F,
+    ) -> BoxFuture<'static, ()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        match self.mode {
+            DispatchMode::Unbounded => future.boxed(),
+            DispatchMode::Bounded(_) => {
+                let semaphore = self.semaphore.clone().expect("set for bounded dispatch");
+                async move {
+                    // The semaphore is never closed
+                    let _permit = semaphore.acquire_owned().await;
+                    future.await;
+                }
+                .boxed()
+            },
+            DispatchMode::PerChannel => {
+                let Some(channel_id) = channel_id else {
+                    return future.boxed();
+                };
+
+                let (done_tx, done_rx) = oneshot::channel();
+                let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+                let previous = self.channels.lock().insert(channel_id, (seq, done_rx));
+                let channels = Arc::clone(&self.channels);
+
+                async move {
+                    if let Some((_, previous)) = previous {
+                        // Errors once the previous event was handled, as the sender is dropped
+                        drop(previous.await);
+                    }
+
+                    future.await;
+
+                    let mut channels = channels.lock();
+                    if channels.get(&channel_id).is_some_and(|(latest, _)| *latest == seq) {
+                        channels.remove(&channel_id);
+                    }
+                    drop(done_tx);
+                }
+                .boxed()
+            },
+        }
+    }
+}
+
+/// Returns the channel an event belongs to, for events which are ordered by
+/// [`DispatchMode::PerChannel`].
+pub(crate) fn event_channel_id(event: &Event) -> Option<ChannelId> {
+    let channel_id = match event {
+        Event::MessageCreate(event) => event.message.channel_id,
+        Event::MessageUpdate(event) => event.channel_id,
+        Event::MessageDelete(event) => event.channel_id,
+        Event::MessageDeleteBulk(event) => event.channel_id,
+        Event::ReactionAdd(event) => event.reaction.channel_id,
+        Event::ReactionRemove(event) => event.reaction.channel_id,
+        Event::ReactionRemoveAll(event) => event.channel_id,
+        Event::ReactionRemoveEmoji(event) => event.reaction.channel_id,
+        Event::TypingStart(event) => event.channel_id,
+        Event::ChannelPinsUpdate(event) => event.channel_id,
+        Event::MessagePollVoteAdd(event) => event.channel_id,
+        Event::MessagePollVoteRemove(event) => event.channel_id,
+        _ => return None,
+    };
+
+    Some(channel_id)
+}
+
+#[derive(serde::Deserialize)]
+struct RawDispatch<'a> {
+    t: &'a str,
+    #[serde(borrow)]
+    d: &'a RawValue,
+}
+
+struct QueueState<T> {
+    pending: VecDeque<T>,
+    running: bool,
+}
+
+impl<T> Default for QueueState<T> {
+    fn default() -> Self {
+        Self {
+            pending: VecDeque::new(),
+            running: false,
+        }
+    }
+}
+
+/// Handles the items queued by a shard one at a time, in the order they were queued.
+///
+/// Instead of a dedicated consumer task, a worker task is spawned whenever an item is queued
+/// while none is running, which keeps handling items until the queue is empty. This lets
+/// [`ShardManager::shutdown_graceful`] wait for it like for any other event handler task.
+pub(crate) struct SequentialQueue<T> {
+    name: &'static str,
+    handle: Box<dyn Fn(T) -> BoxFuture<'static, ()> + Send + Sync>,
+    manager: Arc<ShardManager>,
+    state: Mutex<QueueState<T>>,
+}
+
+/// Passes the dispatch payloads received by a shard to the user's raw payload handler.
+pub(crate) type RawPayloadQueue = SequentialQueue<(FixedString, Context)>;
+
+/// Passes the lifecycle events of a shard, such as [`FullEvent::ShardReady`], to the user's event
+/// handler.
+pub(crate) type LifecycleQueue = SequentialQueue<(FullEvent, Context)>;
+
+impl<T: Send + 'static> SequentialQueue<T> {
+    fn new(
+        name: &'static str,
+        manager: Arc<ShardManager>,
+        handle: impl Fn(T) -> BoxFuture<'static, ()> + Send + Sync + 'static,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            handle: Box::new(handle),
+            manager,
+            state: Mutex::default(),
+        })
+    }
+
+    /// Queues an item, taking ownership of it so it doesn't need to be copied.
+    pub(crate) fn push(self: &Arc<Self>, item: T) {
+        let mut state = self.state.lock();
+        state.pending.push_back(item);
+        if state.running {
+            return;
+        }
+
+        state.running = true;
+        drop(state);
+
+        let queue = Arc::clone(self);
+        self.manager.spawn_tracked(self.name, async move {
+            while let Some(item) = queue.next_item() {
+                // A panicking handler must not stop the items after it from being handled
+                drop(AssertUnwindSafe((queue.handle)(item)).catch_unwind().await);
+            }
+        });
+    }
+
+    /// Takes the next item, or marks the worker as finished if the queue is empty.
+    fn next_item(&self) -> Option<T> {
+        let mut state = self.state.lock();
+        let next = state.pending.pop_front();
+        state.running = next.is_some();
+        next
+    }
+}
+
+impl RawPayloadQueue {
+    pub(crate) fn raw_payloads(
+        handler: Arc<dyn RawPayloadHandler>,
+        manager: Arc<ShardManager>,
+    ) -> Arc<Self> {
+        Self::new("shard_runner::dispatch_raw_payloads", manager, move |(payload, context)| {
+            let handler = Arc::clone(&handler);
+            async move { dispatch_raw_payload(&payload, context, &*handler).await }.boxed()
+        })
+    }
+}
+
+impl LifecycleQueue {
+    pub(crate) fn lifecycle_events(
+        handler: Arc<dyn EventHandler>,
+        manager: Arc<ShardManager>,
+    ) -> Arc<Self> {
+        Self::new("dispatch::event_handler::shard_lifecycle", manager, move |(event, context)| {
+            let handler = Arc::clone(&handler);
+            async move { event.dispatch(context, &*handler).await }.boxed()
+        })
+    }
+}
+
+/// Calls the user's raw payload handler with the `t` and `d` fields of a dispatch payload,
+/// borrowed from the payload.
+async fn dispatch_raw_payload(payload: &str, context: Context, handler: &dyn RawPayloadHandler) {
+    match serde_json::from_str::<RawDispatch<'_>>(payload) {
+        Ok(dispatch) => handler.raw_payload(context, dispatch.t, dispatch.d).await,
+        Err(why) => warn!("Failed to split dispatch payload: {why:?}"),
+    }
+}
+
 /// Calls the user's event handlers and the framework handler.
 ///
 /// This MUST be called from a different task to the recv_event loop, to allow for
@@ -49,6 +301,7 @@ pub(crate) async fn dispatch_model(
     #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    command_stats: Option<Arc<CommandStats>>,
 ) {
     if let Some(raw_handler) = raw_event_handler {
         raw_handler.raw_event(context.clone(), &event).await;
@@ -60,6 +313,129 @@ pub(crate) async fn dispatch_model(
         event,
     );
 
+    dispatch_handlers(
+        context,
+        full_event,
+        extra_event,
+        #[cfg(feature = "framework")]
+        framework,
+        event_handler,
+        command_stats,
+    )
+    .await;
+}
+
+/// An event which has already been applied to the cache, waiting to be dispatched to the
+/// handlers. See [`prepare_event`].
+pub(crate) struct PreparedEvent {
+    /// The original event, only kept if there is a raw event handler to pass it to.
+    pub(crate) raw_event: Option<Event>,
+    pub(crate) full_event: FullEvent,
+    pub(crate) extra_event: Option<FullEvent>,
+    /// The channel the event belongs to, see [`event_channel_id`].
+    pub(crate) channel_id: Option<ChannelId>,
+}
+
+/// Applies an event to the cache right away, and builds the events to dispatch out of it later
+/// via [`dispatch_prepared`].
+///
+/// This keeps the cache up to date even if the handlers never see the event, such as when it is
+/// shed by a full event queue.
+#[cfg_attr(not(feature = "cache"), allow(unused_variables))]
+pub(crate) fn prepare_event(context: &Context, event: Event, keep_raw: bool) -> PreparedEvent {
+    let raw_event = keep_raw.then(|| event.clone());
+    let channel_id = event_channel_id(&event);
+    let (full_event, extra_event) = update_cache_with_event(
+        #[cfg(feature = "cache")]
+        &context.cache,
+        event,
+    );
+
+    PreparedEvent {
+        raw_event,
+        full_event,
+        extra_event,
+        channel_id,
+    }
+}
+
+/// Calls the user's event handlers and the framework handler with an event prepared by
+/// [`prepare_event`].
+///
+/// Like [`dispatch_model`], this MUST be called from a different task to the recv_event loop.
+pub(crate) async fn dispatch_prepared(
+    prepared: PreparedEvent,
+    context: Context,
+    #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
+    event_handler: Option<Arc<dyn EventHandler>>,
+    raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    command_stats: Option<Arc<CommandStats>>,
+) {
+    if let (Some(raw_handler), Some(raw_event)) = (raw_event_handler, &prepared.raw_event) {
+        raw_handler.raw_event(context.clone(), raw_event).await;
+    }
+
+    dispatch_handlers(
+        context,
+        prepared.full_event,
+        prepared.extra_event,
+        #[cfg(feature = "framework")]
+        framework,
+        event_handler,
+        command_stats,
+    )
+    .await;
+}
+
+/// Dispatches the full event to the framework and event handler, recording the command usage
+/// statistics of command interactions.
+async fn dispatch_handlers(
+    context: Context,
+    full_event: FullEvent,
+    extra_event: Option<FullEvent>,
+    #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
+    event_handler: Option<Arc<dyn EventHandler>>,
+    command_stats: Option<Arc<CommandStats>>,
+) {
+    let command_name = match (&command_stats, &full_event) {
+        (
+            Some(_),
+            FullEvent::InteractionCreate {
+                interaction: Interaction::Command(command),
+            },
+        ) => Some(command.full_command_name()),
+        _ => None,
+    };
+
+    let handlers = dispatch_full_event(
+        context,
+        full_event,
+        extra_event,
+        #[cfg(feature = "framework")]
+        framework,
+        event_handler,
+    );
+
+    if let (Some(stats), Some(command_name)) = (command_stats, command_name) {
+        let start = Instant::now();
+        let result = AssertUnwindSafe(handlers).catch_unwind().await;
+        stats.record(command_name, start.elapsed(), result.is_err());
+
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    } else {
+        handlers.await;
+    }
+}
+
+async fn dispatch_full_event(
+    context: Context,
+    full_event: FullEvent,
+    extra_event: Option<FullEvent>,
+    #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
+    event_handler: Option<Arc<dyn EventHandler>>,
+) {
     #[cfg(feature = "framework")]
     if let Some(framework) = framework {
         if let Some(extra_event) = &extra_event {
@@ -290,15 +666,33 @@ fn update_cache_with_event(
                 new_message: event.message,
             }
         },
-        Event::MessageDeleteBulk(event) => FullEvent::MessageDeleteBulk {
-            channel_id: event.channel_id,
-            multiple_deleted_messages_ids: event.ids.into_vec(),
-            guild_id: event.guild_id,
+        Event::MessageDeleteBulk(event) => {
+            #[cfg(feature = "cache")]
+            let deleted_messages = event
+                .ids
+                .iter()
+                .filter_map(|id| cache.message(event.channel_id, *id).map(|m| m.clone()))
+                .collect();
+            #[cfg(not(feature = "cache"))]
+            let deleted_messages = Vec::new();
+
+            FullEvent::MessageDeleteBulk {
+                channel_id: event.channel_id,
+                multiple_deleted_messages_ids: event.ids.into_vec(),
+                guild_id: event.guild_id,
+                deleted_messages_if_available: deleted_messages,
+            }
         },
-        Event::MessageDelete(event) => FullEvent::MessageDelete {
-            channel_id: event.channel_id,
-            deleted_message_id: event.message_id,
-            guild_id: event.guild_id,
+        Event::MessageDelete(event) => {
+            let deleted_message =
+                if_cache!(cache.message(event.channel_id, event.message_id).map(|m| m.clone()));
+
+            FullEvent::MessageDelete {
+                channel_id: event.channel_id,
+                deleted_message_id: event.message_id,
+                guild_id: event.guild_id,
+                deleted_message_if_available: deleted_message,
+            }
         },
         Event::MessageUpdate(mut event) => {
             let before = if_cache!(event.update(cache));
@@ -391,8 +785,12 @@ fn update_cache_with_event(
             guild_id: event.guild_id,
             belongs_to_channel_id: event.channel_id,
         },
-        Event::InteractionCreate(event) => FullEvent::InteractionCreate {
-            interaction: event.interaction,
+        Event::InteractionCreate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::InteractionCreate {
+                interaction: event.interaction,
+            }
         },
         Event::IntegrationCreate(event) => FullEvent::IntegrationCreate {
             integration: event.integration,
@@ -446,20 +844,40 @@ fn update_cache_with_event(
         Event::ThreadMembersUpdate(event) => FullEvent::ThreadMembersUpdate {
             thread_members_update: event,
         },
-        Event::GuildScheduledEventCreate(event) => FullEvent::GuildScheduledEventCreate {
-            event: event.event,
+        Event::GuildScheduledEventCreate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::GuildScheduledEventCreate {
+                event: event.event,
+            }
         },
-        Event::GuildScheduledEventUpdate(event) => FullEvent::GuildScheduledEventUpdate {
-            event: event.event,
+        Event::GuildScheduledEventUpdate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::GuildScheduledEventUpdate {
+                event: event.event,
+            }
         },
-        Event::GuildScheduledEventDelete(event) => FullEvent::GuildScheduledEventDelete {
-            event: event.event,
+        Event::GuildScheduledEventDelete(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::GuildScheduledEventDelete {
+                event: event.event,
+            }
         },
-        Event::GuildScheduledEventUserAdd(event) => FullEvent::GuildScheduledEventUserAdd {
-            subscribed: event,
+        Event::GuildScheduledEventUserAdd(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::GuildScheduledEventUserAdd {
+                subscribed: event,
+            }
         },
-        Event::GuildScheduledEventUserRemove(event) => FullEvent::GuildScheduledEventUserRemove {
-            unsubscribed: event,
+        Event::GuildScheduledEventUserRemove(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::GuildScheduledEventUserRemove {
+                unsubscribed: event,
+            }
         },
         Event::EntitlementCreate(event) => FullEvent::EntitlementCreate {
             entitlement: event.entitlement,
@@ -480,3 +898,134 @@ fn update_cache_with_event(
 
     (event, extra_event)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::model::id::ShardId;
+
+    #[tokio::test]
+    async fn per_channel_dispatch_is_ordered() {
+        let dispatcher = Dispatcher::new(DispatchMode::PerChannel);
+        let handled = Arc::new(Mutex::new(Vec::new()));
+
+        let tasks = (0..5u64)
+            .map(|i| {
+                let handled = Arc::clone(&handled);
+                tokio::spawn(dispatcher.schedule(Some(ChannelId::new(1)), async move {
+                    // Earlier events take longer, so they would finish last if run concurrently
+                    tokio::time::sleep(Duration::from_millis(10 * (5 - i))).await;
+                    handled.lock().push(i);
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(*handled.lock(), vec![0, 1, 2, 3, 4]);
+        assert!(dispatcher.channels.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn bounded_dispatch_limits_concurrency() {
+        let dispatcher = Dispatcher::new(DispatchMode::Bounded(NonZeroUsize::MIN));
+        let (done_tx, done_rx) = oneshot::channel();
+        let (second_tx, second_rx) = oneshot::channel();
+
+        // Scheduling never waits, so the shard isn't blocked by slow handlers
+        let first = tokio::spawn(dispatcher.schedule(None, async move { done_rx.await.unwrap() }));
+        let second = tokio::spawn(dispatcher.schedule(None, async move {
+            second_tx.send(()).unwrap();
+        }));
+
+        // The second event isn't handled until the first one was
+        tokio::pin!(second_rx);
+        assert!(tokio::time::timeout(Duration::from_millis(50), &mut second_rx).await.is_err());
+
+        done_tx.send(()).unwrap();
+        first.await.unwrap();
+        second.await.unwrap();
+        second_rx.await.unwrap();
+        assert_eq!(dispatcher.semaphore.as_ref().unwrap().available_permits(), 1);
+    }
+
+    #[derive(Default)]
+    struct RecordingHandler {
+        handled: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RawPayloadHandler for RecordingHandler {
+        async fn raw_payload(&self, _: Context, name: &str, payload: &RawValue) {
+            // Earlier payloads take longer, so they would finish last if handled concurrently
+            let delay: u64 = serde_json::from_str(payload.get()).unwrap();
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            self.handled.lock().push(name.to_owned());
+        }
+    }
+
+    #[tokio::test]
+    async fn raw_payloads_are_ordered() {
+        let manager = ShardManager::new_for_test(DispatchMode::Unbounded, None);
+        let handler = Arc::new(RecordingHandler::default());
+        let queue = RawPayloadQueue::raw_payloads(Arc::clone(&handler) as _, Arc::clone(&manager));
+
+        for i in 0..5 {
+            let payload = format!(r#"{{"op":0,"s":{i},"t":"EVENT_{i}","d":{}}}"#, 10 * (5 - i));
+            let payload = FixedString::from_string_trunc(payload);
+            queue.push((payload, Context::new_for_test(ShardId(0))));
+        }
+
+        assert!(manager.shutdown_graceful(Duration::from_secs(5)).await);
+        let expected = ["EVENT_0", "EVENT_1", "EVENT_2", "EVENT_3", "EVENT_4"];
+        assert_eq!(*handler.handled.lock(), expected);
+        assert!(!queue.state.lock().running);
+    }
+
+    #[derive(Default)]
+    struct LifecycleHandler {
+        handled: Mutex<Vec<&'static str>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for LifecycleHandler {
+        async fn shard_ready(&self, _: Context, _: ShardId) {
+            // Takes longer, so it would finish last if handled concurrently
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.handled.lock().push("ready");
+        }
+
+        async fn shard_resume(&self, _: Context, _: ShardId) {
+            self.handled.lock().push("resume");
+        }
+    }
+
+    #[tokio::test]
+    async fn lifecycle_events_are_ordered() {
+        let manager = ShardManager::new_for_test(DispatchMode::Unbounded, None);
+        let handler = Arc::new(LifecycleHandler::default());
+        let queue =
+            LifecycleQueue::lifecycle_events(Arc::clone(&handler) as _, Arc::clone(&manager));
+
+        let shard_id = ShardId(0);
+        queue.push((
+            FullEvent::ShardReady {
+                shard_id,
+            },
+            Context::new_for_test(shard_id),
+        ));
+        queue.push((
+            FullEvent::ShardResume {
+                shard_id,
+            },
+            Context::new_for_test(shard_id),
+        ));
+
+        assert!(manager.shutdown_graceful(Duration::from_secs(5)).await);
+        assert_eq!(*handler.handled.lock(), ["ready", "resume"]);
+    }
+}
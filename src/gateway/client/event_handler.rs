@@ -3,10 +3,13 @@ use std::collections::VecDeque;
 use std::num::NonZeroU16;
 
 use async_trait::async_trait;
+use serde_json::value::RawValue;
 use strum::{EnumCount, IntoStaticStr, VariantNames};
 
 use super::context::Context;
-use crate::gateway::ShardStageUpdateEvent;
+#[cfg(feature = "cache")]
+use crate::cache::GuildCacheDrift;
+use crate::gateway::{ShardDisconnectEvent, ShardStageUpdateEvent};
 use crate::http::RatelimitInfo;
 use crate::model::prelude::*;
 
@@ -294,13 +297,15 @@ event_handler! {
 
     /// Dispatched when a message is deleted.
     ///
-    /// Provides the guild's id, the channel's id and the message's id.
-    MessageDelete { channel_id: ChannelId, deleted_message_id: MessageId, guild_id: Option<GuildId> } => async fn message_delete(&self, ctx: Context);
+    /// Provides the guild's id, the channel's id and the message's id, as well as the deleted
+    /// message if cache feature is enabled and the message was cached.
+    MessageDelete { channel_id: ChannelId, deleted_message_id: MessageId, guild_id: Option<GuildId>, deleted_message_if_available: Option<Message> } => async fn message_delete(&self, ctx: Context);
 
     /// Dispatched when multiple messages were deleted at once.
     ///
-    /// Provides the guild's id, channel's id and the deleted messages' ids.
-    MessageDeleteBulk { channel_id: ChannelId, multiple_deleted_messages_ids: Vec<MessageId>, guild_id: Option<GuildId> } => async fn message_delete_bulk(&self, ctx: Context);
+    /// Provides the guild's id, channel's id and the deleted messages' ids, as well as those
+    /// deleted messages which were cached if cache feature is enabled.
+    MessageDeleteBulk { channel_id: ChannelId, multiple_deleted_messages_ids: Vec<MessageId>, guild_id: Option<GuildId>, deleted_messages_if_available: Vec<Message> } => async fn message_delete_bulk(&self, ctx: Context);
 
     /// Dispatched when a message is updated.
     ///
@@ -333,6 +338,13 @@ event_handler! {
     /// Provides the presence's new data, as well as the old presence data if the
     /// cache feature is enabled and the data is available.
     ///
+    /// The old presence is only available if the guild is cached. Offline users are removed from
+    /// [`Guild::presences`], so it is also [`None`] if the user was offline before, such as when
+    /// they come online. Comparing both presences allows to tell what changed, e.g. the status or
+    /// activities.
+    ///
+    /// [`Guild::presences`]: crate::model::guild::Guild::presences
+    ///
     /// Note: This event will not trigger unless the "guild presences" privileged intent is enabled
     /// on the bot application page.
     PresenceUpdate { old_data: Option<Presence>, new_data: Presence } => async fn presence_update(&self, ctx: Context);
@@ -350,6 +362,24 @@ event_handler! {
     /// Provides the context of the shard and the event information about the update.
     ShardStageUpdate { event: ShardStageUpdateEvent } => async fn shard_stage_update(&self, ctx: Context);
 
+    /// Dispatched when a shard identified and started a new session.
+    ///
+    /// Unlike [`Self::ready`], this is dispatched even if the READY event is ignored by
+    /// [`Self::filter_event`].
+    ShardReady { shard_id: ShardId } => async fn shard_ready(&self, ctx: Context);
+
+    /// Dispatched when a shard resumed its previous session after reconnecting.
+    ///
+    /// Unlike [`Self::resume`], this is dispatched even if the RESUMED event is ignored by
+    /// [`Self::filter_event`].
+    ShardResume { shard_id: ShardId } => async fn shard_resume(&self, ctx: Context);
+
+    /// Dispatched when a shard lost its session or gateway connection, along with the reason and
+    /// whether the session will be resumed.
+    ///
+    /// Provides the context of the shard and the event information about the disconnect.
+    ShardDisconnect { event: ShardDisconnectEvent } => async fn shard_disconnect(&self, ctx: Context);
+
     /// Dispatched when a user starts typing.
     TypingStart { event: TypingStartEvent } => async fn typing_start(&self, ctx: Context);
 
@@ -367,6 +397,9 @@ event_handler! {
     ///
     /// Provides the guild's id (if available) and the old state (if cache feature is enabled and
     /// [`GatewayIntents::GUILDS`] is enabled) and the new state of the guild's voice channels.
+    ///
+    /// The old state is [`None`] if the user wasn't in a voice channel before, so joins, leaves
+    /// and moves can be told apart by whether `old` and `new.channel_id` are set.
     VoiceStateUpdate { old: Option<VoiceState>, new: VoiceState } => async fn voice_state_update(&self, ctx: Context);
 
     /// Dispatched when a voice channel's status is updated.
@@ -506,6 +539,15 @@ event_handler! {
 
     /// Dispatched when an HTTP rate limit is hit
     Ratelimit { data: RatelimitInfo } => async fn ratelimit(&self);
+
+    /// Dispatched when a guild marked as stale was refreshed over HTTP and its cached roles or
+    /// channels differed from Discord. The differences have already been applied to the cache.
+    ///
+    /// Only dispatched if [`Settings::refresh_stale_guilds_interval`] is set.
+    ///
+    /// [`Settings::refresh_stale_guilds_interval`]: crate::cache::Settings::refresh_stale_guilds_interval
+    #[cfg(feature = "cache")]
+    CacheDrift { drift: GuildCacheDrift } => async fn cache_drift(&self);
 }
 
 /// This core trait for handling raw events
@@ -534,3 +576,51 @@ pub trait RawEventHandler: Send + Sync {
         true
     }
 }
+
+/// A handler receiving the JSON payloads of dispatched gateway events before they are
+/// deserialized, registered via [`ClientBuilder::raw_payload_handler`].
+///
+/// This allows implementing custom deserialization, or forwarding payloads to another process such
+/// as a message broker, without serializing the parsed [`Event`] again.
+///
+/// The handler is called for every dispatch received by a shard, including events which are not
+/// deserialized due to [`ClientBuilder::filtered_events`], and before
+/// [`RawEventHandler::filter_event`] and [`EventHandler::filter_event`] run. To only forward
+/// payloads, the other events can be filtered out so serenity doesn't deserialize them at all.
+///
+/// The payloads of each shard are passed to the handler one at a time, in the order they were
+/// received.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serde_json::value::RawValue;
+/// use serenity::model::event::EventTypeFlags;
+/// use serenity::prelude::*;
+///
+/// struct Forwarder;
+///
+/// #[serenity::async_trait]
+/// impl RawPayloadHandler for Forwarder {
+///     async fn raw_payload(&self, _ctx: Context, name: &str, payload: &RawValue) {
+///         println!("{name}: {}", payload.get());
+///     }
+/// }
+///
+/// # async fn run() -> serenity::Result<()> {
+/// let mut client = Client::builder("token", GatewayIntents::non_privileged())
+///     .raw_payload_handler(Forwarder)
+///     .filtered_events(EventTypeFlags::empty())
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ClientBuilder::raw_payload_handler`]: super::ClientBuilder::raw_payload_handler
+/// [`ClientBuilder::filtered_events`]: super::ClientBuilder::filtered_events
+#[async_trait]
+pub trait RawPayloadHandler: Send + Sync {
+    /// Dispatched for every event received from the gateway, with the name of the event, such as
+    /// `MESSAGE_CREATE`, and the JSON of its data.
+    async fn raw_payload(&self, ctx: Context, name: &str, payload: &RawValue);
+}
@@ -0,0 +1,132 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+/// A map holding at most one value of each type, registered via [`ClientBuilder::extension`] and
+/// read in handlers via [`Context::data_ref`].
+///
+/// Unlike [`ClientBuilder::data`], any number of values can be registered, and each is looked up
+/// by its own type. The extensions can't be changed once the client is built, so reading them
+/// doesn't take any lock. Values which change need to bring their own interior mutability, such as
+/// atomics, or can be kept per shard via [`Context::with_shard_state`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::gateway::client::Extensions;
+///
+/// struct Prefix(&'static str);
+///
+/// let mut extensions = Extensions::new();
+/// extensions.insert(Prefix("!"));
+/// extensions.insert(42_u64);
+///
+/// assert_eq!(extensions.get::<Prefix>().map(|prefix| prefix.0), Some("!"));
+/// assert_eq!(extensions.get::<u64>(), Some(&42));
+/// assert!(extensions.get::<String>().is_none());
+/// ```
+///
+/// [`ClientBuilder::extension`]: super::ClientBuilder::extension
+/// [`ClientBuilder::data`]: super::ClientBuilder::data
+/// [`Context::data_ref`]: super::Context::data_ref
+/// [`Context::with_shard_state`]: super::Context::with_shard_state
+#[derive(Default)]
+pub struct Extensions {
+    map: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl Extensions {
+    /// Creates an empty map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value, returning the previous value of the same type, if there was one.
+    pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) -> Option<T> {
+        let previous = self.map.insert(TypeId::of::<T>(), Box::new(value))?;
+        previous.downcast().ok().map(|previous| *previous)
+    }
+
+    /// Returns the value of the given type, if one was inserted.
+    #[must_use]
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.map.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns whether a value of the given type was inserted.
+    #[must_use]
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.map.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns whether no values were inserted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extensions").field("len", &self.map.len()).finish_non_exhaustive()
+    }
+}
+
+/// Mutable state of a single shard, holding at most one value of each type. See
+/// [`Context::with_shard_state`].
+///
+/// [`Context::with_shard_state`]: super::Context::with_shard_state
+#[derive(Clone, Default)]
+pub(crate) struct ShardState(Arc<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>>);
+
+impl ShardState {
+    /// Calls the function with the value of the given type, inserting its default value first if
+    /// there is none yet.
+    pub(crate) fn with<T: Default + Send + 'static, R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut map = self.0.lock();
+        let value = map.entry(TypeId::of::<T>()).or_insert_with(|| Box::new(T::default()));
+        f(value.downcast_mut().expect("value is stored under its own type id"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Extensions, ShardState};
+
+    #[test]
+    fn extensions_by_type() {
+        let mut extensions = Extensions::new();
+        assert!(extensions.is_empty());
+
+        assert_eq!(extensions.insert(1_u32), None);
+        assert_eq!(extensions.insert(String::from("a")), None);
+        assert_eq!(extensions.insert(2_u32), Some(1));
+
+        assert_eq!(extensions.len(), 2);
+        assert_eq!(extensions.get::<u32>(), Some(&2));
+        assert_eq!(extensions.get::<String>().map(String::as_str), Some("a"));
+        assert!(!extensions.contains::<u64>());
+    }
+
+    #[test]
+    fn shard_state_defaults() {
+        let state = ShardState::default();
+
+        assert_eq!(state.with(|count: &mut u32| *count), 0);
+        state.with(|count: &mut u32| *count += 2);
+        state.clone().with(|count: &mut u32| *count += 1);
+
+        assert_eq!(state.with(|count: &mut u32| *count), 3);
+        assert_eq!(state.with(|name: &mut String| name.len()), 0);
+    }
+}
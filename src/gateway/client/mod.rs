@@ -21,9 +21,11 @@
 //!
 //! [`sharding`]: crate::gateway::sharding
 
+mod command_stats;
 mod context;
 pub(crate) mod dispatch;
 mod event_handler;
+mod extensions;
 
 use std::future::IntoFuture;
 use std::num::NonZeroU16;
@@ -31,27 +33,46 @@ use std::ops::Range;
 use std::sync::Arc;
 #[cfg(feature = "framework")]
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use futures::channel::mpsc::UnboundedReceiver as Receiver;
 use futures::future::BoxFuture;
 use futures::StreamExt as _;
+use tokio::sync::broadcast;
 use tracing::debug;
 
+pub use self::command_stats::{CommandStats, CommandUsage};
 pub use self::context::Context;
-pub use self::event_handler::{EventHandler, FullEvent, RawEventHandler};
+pub use self::dispatch::DispatchMode;
+pub use self::event_handler::{EventHandler, FullEvent, RawEventHandler, RawPayloadHandler};
+pub use self::extensions::Extensions;
+pub(crate) use self::extensions::ShardState;
+use crate::builder::CreateAllowedMentions;
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
 #[cfg(feature = "cache")]
 use crate::cache::Settings as CacheSettings;
+use crate::constants::EVENT_BUS_CAPACITY;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 #[cfg(feature = "voice")]
 use crate::gateway::VoiceGatewayManager;
-use crate::gateway::{ActivityData, GatewayError, PresenceData, ShardManager, ShardManagerOptions};
+use crate::gateway::{
+    ActivityData,
+    EventBus,
+    EventQueueOptions,
+    GatewayError,
+    PresenceData,
+    RawGatewayHook,
+    ShardManager,
+    ShardManagerOptions,
+};
 use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
+use crate::model::event::{Event, EventTypeFlags};
 use crate::model::gateway::GatewayIntents;
+use crate::model::id::ShardId;
 #[cfg(feature = "voice")]
 use crate::model::id::UserId;
 use crate::model::user::OnlineStatus;
@@ -61,6 +82,7 @@ use crate::utils::check_shard_total;
 #[must_use = "Builders do nothing unless they are awaited"]
 pub struct ClientBuilder {
     data: Option<Arc<dyn std::any::Any + Send + Sync>>,
+    extensions: Extensions,
     http: Arc<Http>,
     intents: GatewayIntents,
     #[cfg(feature = "cache")]
@@ -71,7 +93,15 @@ pub struct ClientBuilder {
     voice_manager: Option<Arc<dyn VoiceGatewayManager>>,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
+    command_stats: Option<Arc<CommandStats>>,
+    event_dispatch: DispatchMode,
+    event_queue: Option<EventQueueOptions>,
     presence: PresenceData,
+    filtered_events: EventTypeFlags,
+    event_bus_capacity: usize,
+    gateway_url: Option<Arc<str>>,
+    raw_gateway_hook: Option<RawGatewayHook>,
 }
 
 impl ClientBuilder {
@@ -96,6 +126,7 @@ impl ClientBuilder {
             http,
             intents,
             data: None,
+            extensions: Extensions::new(),
             #[cfg(feature = "cache")]
             cache_settings: CacheSettings::default(),
             #[cfg(feature = "framework")]
@@ -104,7 +135,15 @@ impl ClientBuilder {
             voice_manager: None,
             event_handler: None,
             raw_event_handler: None,
+            raw_payload_handler: None,
+            command_stats: None,
+            event_dispatch: DispatchMode::Unbounded,
+            event_queue: None,
             presence: PresenceData::default(),
+            filtered_events: EventTypeFlags::all(),
+            event_bus_capacity: EVENT_BUS_CAPACITY,
+            gateway_url: None,
+            raw_gateway_hook: None,
         }
     }
 
@@ -114,6 +153,17 @@ impl ClientBuilder {
         self
     }
 
+    /// Registers a value that can be accessed by its type from [`Context::data_ref`], replacing
+    /// any previously registered value of the same type.
+    ///
+    /// Any number of values of different types can be registered. They can't be changed once the
+    /// client is built, so they are read without taking a lock. See [`Extensions`] for more
+    /// information.
+    pub fn extension<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
+
     /// Sets the settings of the cache. Refer to [`Settings`] for more information.
     ///
     /// [`Settings`]: CacheSettings
@@ -233,9 +283,127 @@ impl ClientBuilder {
         self.raw_event_handler.as_ref()
     }
 
+    /// Adds a handler which receives the JSON payload of every dispatched gateway event before it
+    /// is deserialized. See [`RawPayloadHandler`] for more info.
+    pub fn raw_payload_handler<H>(mut self, raw_payload_handler: impl Into<Arc<H>>) -> Self
+    where
+        H: RawPayloadHandler + 'static,
+    {
+        self.raw_payload_handler = Some(raw_payload_handler.into());
+        self
+    }
+
+    /// Gets the added raw payload handler. See [`Self::raw_payload_handler`] for more info.
+    #[must_use]
+    pub fn get_raw_payload_handler(&self) -> Option<&Arc<dyn RawPayloadHandler>> {
+        self.raw_payload_handler.as_ref()
+    }
+
+    /// Sets the [`CreateAllowedMentions`] used by default when sending or editing messages, if
+    /// the builder has none set, such as for [`CreateMessage`], [`EditMessage`] and
+    /// [`ExecuteWebhook`].
+    ///
+    /// This is stored on the client's [`Http`], including one passed to [`Self::new_with_http`]
+    /// which is still shared elsewhere, see [`Http::set_default_allowed_mentions`].
+    ///
+    /// # Examples
+    ///
+    /// Disabling all mentions unless a message explicitly allows them:
+    ///
+    /// ```rust,no_run
+    /// use serenity::builder::CreateAllowedMentions;
+    /// use serenity::prelude::*;
+    ///
+    /// let builder = Client::builder("token", GatewayIntents::default())
+    ///     .default_allowed_mentions(CreateAllowedMentions::new());
+    /// ```
+    ///
+    /// [`CreateMessage`]: crate::builder::CreateMessage
+    /// [`EditMessage`]: crate::builder::EditMessage
+    /// [`ExecuteWebhook`]: crate::builder::ExecuteWebhook
+    pub fn default_allowed_mentions(
+        self,
+        allowed_mentions: CreateAllowedMentions<'static>,
+    ) -> Self {
+        self.http.set_default_allowed_mentions(Some(allowed_mentions));
+        self
+    }
+
+    /// Gets the default allowed mentions of the client's [`Http`]. See
+    /// [`Self::default_allowed_mentions`] for more info.
+    #[must_use]
+    pub fn get_default_allowed_mentions(&self) -> Option<CreateAllowedMentions<'static>> {
+        self.http.default_allowed_mentions()
+    }
+
+    /// Sets the collector recording the usage of application commands. See [`CommandStats`] for
+    /// more info.
+    pub fn command_stats(mut self, command_stats: Arc<CommandStats>) -> Self {
+        self.command_stats = Some(command_stats);
+        self
+    }
+
+    /// Gets the command usage collector. See [`Self::command_stats`] for more info.
+    #[must_use]
+    pub fn get_command_stats(&self) -> Option<&Arc<CommandStats>> {
+        self.command_stats.as_ref()
+    }
+
+    /// Sets how events received by the shards are dispatched to the framework and event
+    /// handlers. Defaults to [`DispatchMode::Unbounded`].
+    ///
+    /// # Examples
+    ///
+    /// Handling the messages of each channel in the order they were sent:
+    ///
+    /// ```rust,no_run
+    /// use serenity::gateway::client::DispatchMode;
+    /// use serenity::model::gateway::GatewayIntents;
+    /// use serenity::Client;
+    ///
+    /// let builder =
+    ///     Client::builder("token", GatewayIntents::all()).event_dispatch(DispatchMode::PerChannel);
+    /// ```
+    pub fn event_dispatch(mut self, mode: DispatchMode) -> Self {
+        self.event_dispatch = mode;
+        self
+    }
+
+    /// Gets how events are dispatched. See [`Self::event_dispatch`] for more info.
+    #[must_use]
+    pub fn get_event_dispatch(&self) -> DispatchMode {
+        self.event_dispatch
+    }
+
+    /// Bounds the number of events each shard keeps in memory, shedding or waiting for events
+    /// according to an [`OverflowPolicy`] once the bound is reached. By default, events are never
+    /// shed. See [`EventQueueOptions`] for more info.
+    ///
+    /// [`OverflowPolicy`]: crate::gateway::OverflowPolicy
+    pub fn event_queue(mut self, options: EventQueueOptions) -> Self {
+        self.event_queue = Some(options);
+        self
+    }
+
+    /// Gets the options of the shards' event queues. See [`Self::event_queue`] for more info.
+    #[must_use]
+    pub fn get_event_queue(&self) -> Option<&EventQueueOptions> {
+        self.event_queue.as_ref()
+    }
+
     /// Sets the initial activity.
     pub fn activity(mut self, activity: ActivityData) -> Self {
-        self.presence.activity = Some(activity);
+        self.presence.activities = vec![activity];
+
+        self
+    }
+
+    /// Sets the initial presence, including all activities. See [`PresenceBuilder`] for building
+    /// one.
+    ///
+    /// [`PresenceBuilder`]: crate::gateway::PresenceBuilder
+    pub fn presence(mut self, presence: impl Into<PresenceData>) -> Self {
+        self.presence = presence.into();
 
         self
     }
@@ -247,11 +415,176 @@ impl ClientBuilder {
         self
     }
 
-    /// Gets the initial presence. See [`Self::activity`] and [`Self::status`] for more info.
+    /// Gets the initial presence. See [`Self::presence`], [`Self::activity`] and [`Self::status`]
+    /// for more info.
     #[must_use]
     pub fn get_presence(&self) -> &PresenceData {
         &self.presence
     }
+
+    /// Only deserializes and dispatches the given events, dropping all others as soon as they are
+    /// received. This saves the cost of deserializing events the bot doesn't handle, such as
+    /// presence updates or typing events.
+    ///
+    /// [`Event::Ready`] and [`Event::Resumed`] are always dispatched, as they are required to
+    /// maintain the connection.
+    ///
+    /// **Note**: Dropped events are not applied to the cache either, so filtering out events such
+    /// as [`Event::GuildCreate`] will leave the cache incomplete.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::event::EventTypeFlags;
+    /// use serenity::model::gateway::GatewayIntents;
+    /// use serenity::Client;
+    ///
+    /// let builder = Client::builder("token", GatewayIntents::all())
+    ///     .filtered_events(EventTypeFlags::MESSAGE_CREATE | EventTypeFlags::INTERACTION_CREATE);
+    /// ```
+    pub fn filtered_events(mut self, events: EventTypeFlags) -> Self {
+        self.filtered_events = events;
+
+        self
+    }
+
+    /// Gets the events which are deserialized and dispatched. See [`Self::filtered_events`] for
+    /// more info.
+    #[must_use]
+    pub fn get_filtered_events(&self) -> EventTypeFlags {
+        self.filtered_events
+    }
+
+    /// Sets the URL shards connect to, instead of the URL returned by [`Http::get_bot_gateway`].
+    /// This is intended for gateway proxies shared by multiple processes, and also stops shards
+    /// from resuming sessions directly via Discord's resume URL.
+    ///
+    /// The recommended shard count is still fetched via [`Http::get_bot_gateway`]. To route REST
+    /// requests through a ratelimiting proxy as well, such as [`twilight-http-proxy`], build the
+    /// [`Http`] with [`HttpBuilder::proxy`] and [`HttpBuilder::ratelimiter_disabled`], and pass
+    /// it to [`Self::new_with_http`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::sync::Arc;
+    ///
+    /// use serenity::gateway::client::ClientBuilder;
+    /// use serenity::http::HttpBuilder;
+    /// use serenity::model::gateway::GatewayIntents;
+    ///
+    /// let http =
+    ///     HttpBuilder::new("token").proxy("http://localhost:3000").ratelimiter_disabled(true).build();
+    /// let builder = ClientBuilder::new_with_http(Arc::new(http), GatewayIntents::non_privileged())
+    ///     .gateway_url("ws://localhost:7878");
+    /// ```
+    ///
+    /// [`twilight-http-proxy`]: https://github.com/twilight-rs/http-proxy
+    /// [`HttpBuilder::proxy`]: crate::http::HttpBuilder::proxy
+    /// [`HttpBuilder::ratelimiter_disabled`]: crate::http::HttpBuilder::ratelimiter_disabled
+    pub fn gateway_url(mut self, gateway_url: impl Into<Arc<str>>) -> Self {
+        self.gateway_url = Some(gateway_url.into());
+        self
+    }
+
+    /// Gets the URL shards connect to, if overridden. See [`Self::gateway_url`] for more info.
+    #[must_use]
+    pub fn get_gateway_url(&self) -> Option<&str> {
+        self.gateway_url.as_deref()
+    }
+
+    /// Sets a function invoked with the payload of every text and binary websocket frame sent or
+    /// received by the shards, for dumping gateway traffic when debugging protocol issues.
+    /// Inbound compressed frames are passed after decompression.
+    ///
+    /// The hook is called from within the shard loop, so it should return quickly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::gateway::GatewayDirection;
+    /// use serenity::model::gateway::GatewayIntents;
+    /// use serenity::model::id::ShardId;
+    /// use serenity::Client;
+    ///
+    /// fn dump(shard_id: ShardId, direction: GatewayDirection, payload: &[u8]) {
+    ///     println!("[{shard_id}] {direction:?}: {}", String::from_utf8_lossy(payload));
+    /// }
+    ///
+    /// let builder = Client::builder("token", GatewayIntents::all()).raw_gateway_hook(dump);
+    /// ```
+    pub fn raw_gateway_hook(mut self, hook: RawGatewayHook) -> Self {
+        self.raw_gateway_hook = Some(hook);
+        self
+    }
+
+    /// Gets the gateway traffic hook. See [`Self::raw_gateway_hook`] for more info.
+    #[must_use]
+    pub fn get_raw_gateway_hook(&self) -> Option<RawGatewayHook> {
+        self.raw_gateway_hook
+    }
+
+    /// Sets the number of events buffered for each subscriber of [`Client::events`]. Subscribers
+    /// which fall further behind will miss events.
+    ///
+    /// A capacity of 0 is treated as 1, as at least one event must be buffered. Defaults to
+    /// [`EVENT_BUS_CAPACITY`].
+    pub fn event_bus_capacity(mut self, capacity: usize) -> Self {
+        self.event_bus_capacity = capacity.max(1);
+
+        self
+    }
+
+    /// Gets the event bus capacity. See [`Self::event_bus_capacity`] for more info.
+    #[must_use]
+    pub fn get_event_bus_capacity(&self) -> usize {
+        self.event_bus_capacity
+    }
+
+    /// Builds an [`EventReplayer`] instead of a [`Client`], which dispatches recorded events to
+    /// the configured event handlers, framework and cache without connecting to the gateway.
+    ///
+    /// [`EventReplayer`]: crate::testing::EventReplayer
+    #[cfg(feature = "testing")]
+    pub fn into_replayer(self) -> crate::testing::EventReplayer {
+        crate::testing::EventReplayer::new(
+            self.data.unwrap_or(Arc::new(())),
+            Arc::new(self.extensions),
+            self.http,
+            #[cfg(feature = "cache")]
+            Arc::new(Cache::new_with_settings(self.cache_settings)),
+            #[cfg(feature = "framework")]
+            self.framework.map(Arc::from),
+            self.event_handler,
+            self.raw_event_handler,
+            self.command_stats,
+        )
+    }
+
+    /// Builds an [`InteractionsServer`] instead of a [`Client`], which receives interactions via
+    /// HTTP requests to the interactions endpoint URL instead of the gateway, and dispatches them
+    /// to the configured event handlers, framework and cache.
+    ///
+    /// [`InteractionsServer`]: crate::interactions_endpoint::InteractionsServer
+    #[cfg(feature = "interactions_server")]
+    pub fn into_interactions_server(
+        self,
+        verifier: crate::interactions_endpoint::Verifier,
+    ) -> crate::interactions_endpoint::InteractionsServer {
+        crate::interactions_endpoint::InteractionsServer::new(
+            verifier,
+            self.data.unwrap_or(Arc::new(())),
+            Arc::new(self.extensions),
+            self.http,
+            #[cfg(feature = "cache")]
+            Arc::new(Cache::new_with_settings(self.cache_settings)),
+            #[cfg(feature = "framework")]
+            self.framework.map(Arc::from),
+            self.event_handler,
+            self.raw_event_handler,
+            self.command_stats,
+        )
+    }
 }
 
 impl IntoFuture for ClientBuilder {
@@ -262,11 +595,23 @@ impl IntoFuture for ClientBuilder {
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
     fn into_future(self) -> Self::IntoFuture {
         let data = self.data.unwrap_or(Arc::new(()));
+        let extensions = Arc::new(self.extensions);
         #[cfg(feature = "framework")]
         let framework = self.framework;
         let intents = self.intents;
         let presence = self.presence;
         let http = self.http;
+        let event_bus = EventBus::new();
+        let (event_sender, _) = broadcast::channel(self.event_bus_capacity);
+        let sender = event_sender.clone();
+        event_bus.subscribe(move |shard_id, event| {
+            // Only clone the event if anyone is listening
+            if sender.receiver_count() > 0 {
+                // This can only fail if all receivers were dropped in the meantime
+                drop(sender.send((shard_id, event.clone())));
+            }
+            true
+        });
 
         if let Some(ratelimiter) = &http.ratelimiter {
             if let Some(event_handler) = &self.event_handler {
@@ -286,6 +631,19 @@ impl IntoFuture for ClientBuilder {
         #[cfg(feature = "cache")]
         let cache = Arc::new(Cache::new_with_settings(self.cache_settings));
 
+        #[cfg(feature = "cache")]
+        if let Some(interval) = cache.settings().refresh_stale_guilds_interval {
+            spawn_named(
+                "cache::refresh_stale_guilds",
+                refresh_stale_guilds(
+                    Arc::downgrade(&cache),
+                    Arc::clone(&http),
+                    self.event_handler.clone(),
+                    interval,
+                ),
+            );
+        }
+
         Box::pin(async move {
             let (ws_url, shard_total, max_concurrency) = match http.get_bot_gateway().await {
                 Ok(response) => (
@@ -298,13 +656,20 @@ impl IntoFuture for ClientBuilder {
                     (Arc::from("wss://gateway.discord.gg"), NonZeroU16::MIN, NonZeroU16::MIN)
                 },
             };
+            let use_resume_url = self.gateway_url.is_none();
+            let ws_url = self.gateway_url.unwrap_or(ws_url);
 
             #[cfg(feature = "framework")]
             let framework_cell = Arc::new(OnceLock::new());
             let (shard_manager, shard_manager_ret_value) = ShardManager::new(ShardManagerOptions {
                 data: Arc::clone(&data),
+                extensions: Arc::clone(&extensions),
                 event_handler: self.event_handler,
                 raw_event_handler: self.raw_event_handler,
+                raw_payload_handler: self.raw_payload_handler,
+                command_stats: self.command_stats,
+                event_dispatch: self.event_dispatch,
+                event_queue: self.event_queue,
                 #[cfg(feature = "framework")]
                 framework: Arc::clone(&framework_cell),
                 #[cfg(feature = "voice")]
@@ -316,11 +681,16 @@ impl IntoFuture for ClientBuilder {
                 http: Arc::clone(&http),
                 intents,
                 presence: Some(presence),
+                filtered_events: self.filtered_events,
+                use_resume_url,
+                raw_gateway_hook: self.raw_gateway_hook,
                 max_concurrency,
+                event_bus,
             });
 
             let client = Client {
                 data,
+                extensions,
                 shard_manager,
                 shard_manager_return_value: shard_manager_ret_value,
                 #[cfg(feature = "voice")]
@@ -329,6 +699,7 @@ impl IntoFuture for ClientBuilder {
                 #[cfg(feature = "cache")]
                 cache,
                 http,
+                event_sender,
             };
             #[cfg(feature = "framework")]
             if let Some(mut framework) = framework {
@@ -342,6 +713,49 @@ impl IntoFuture for ClientBuilder {
     }
 }
 
+/// Periodically refreshes the roles and channels of guilds marked as stale in the cache,
+/// dispatching any differences to the event handler. Stops once the cache has been dropped.
+#[cfg(feature = "cache")]
+async fn refresh_stale_guilds(
+    cache: std::sync::Weak<Cache>,
+    http: Arc<Http>,
+    event_handler: Option<Arc<dyn EventHandler>>,
+    interval: std::time::Duration,
+) {
+    loop {
+        tokio::time::sleep(interval).await;
+        let Some(cache) = cache.upgrade() else {
+            return;
+        };
+
+        for guild_id in cache.take_stale_guilds() {
+            let fetched =
+                tokio::try_join!(http.get_guild_roles(guild_id), http.get_channels(guild_id));
+            let (roles, channels) = match fetched {
+                Ok(data) => data,
+                Err(why) => {
+                    tracing::warn!("Failed to refresh stale guild {guild_id}: {why:?}");
+                    cache.mark_guild_stale(guild_id);
+                    continue;
+                },
+            };
+
+            let Some(drift) = cache.reconcile_guild(guild_id, roles, channels) else {
+                continue;
+            };
+
+            if drift.is_empty() {
+                continue;
+            }
+
+            debug!("Detected cache drift in guild {guild_id}: {drift:?}");
+            if let Some(event_handler) = &event_handler {
+                event_handler.cache_drift(drift).await;
+            }
+        }
+    }
+}
+
 /// The Client is the way to be able to start sending authenticated requests over the REST API, as
 /// well as initializing a WebSocket connection through [`Shard`]s. Refer to the [documentation on
 /// using sharding][super::sharding] for more information.
@@ -388,6 +802,7 @@ impl IntoFuture for ClientBuilder {
 /// [`Event::MessageCreate`]: crate::model::event::Event::MessageCreate
 pub struct Client {
     data: Arc<dyn std::any::Any + Send + Sync>,
+    extensions: Arc<Extensions>,
     /// A HashMap of all shards instantiated by the Client.
     ///
     /// The key is the shard ID and the value is the shard itself.
@@ -442,6 +857,9 @@ pub struct Client {
     /// });
     /// # }
     /// ```
+    ///
+    /// To let in-flight event handlers finish first, use [`ShardManager::shutdown_graceful`]
+    /// instead.
     pub shard_manager: Arc<ShardManager>,
     shard_manager_return_value: Receiver<Result<(), GatewayError>>,
     /// The voice manager for the client.
@@ -457,6 +875,7 @@ pub struct Client {
     pub cache: Arc<Cache>,
     /// An HTTP client.
     pub http: Arc<Http>,
+    event_sender: broadcast::Sender<(ShardId, Event)>,
 }
 
 impl Client {
@@ -481,6 +900,60 @@ impl Client {
         Arc::clone(&self.data).downcast().ok()
     }
 
+    /// Returns the value of the given type provided to [`ClientBuilder::extension`], if any.
+    ///
+    /// See the documentation for [`Context::data_ref`] for more information.
+    #[must_use]
+    pub fn data_ref<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get()
+    }
+
+    /// Subscribes to all events received by the client's shards, alongside the Id of the shard
+    /// which received them.
+    ///
+    /// This can be used instead of, or alongside, an [`EventHandler`]. Events filtered out by
+    /// [`EventHandler::filter_event`] are not sent. Only events received after subscribing are
+    /// sent to the returned receiver.
+    ///
+    /// If the receiver falls behind by more than [`ClientBuilder::event_bus_capacity`] events,
+    /// the oldest events are dropped, and the next call to [`broadcast::Receiver::recv`] returns
+    /// an error reporting how many events were missed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # async fn run(mut client: Client) {
+    /// use serenity::model::event::Event;
+    ///
+    /// let mut events = client.events();
+    /// tokio::spawn(async move {
+    ///     while let Ok((shard_id, event)) = events.recv().await {
+    ///         if let Event::MessageCreate(event) = event {
+    ///             println!("[{shard_id}] {}", event.message.content);
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// client.start().await.unwrap();
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn events(&self) -> broadcast::Receiver<(ShardId, Event)> {
+        self.event_sender.subscribe()
+    }
+
+    /// Gracefully shuts down all shards, by first letting in-flight event handlers finish, for at
+    /// most `timeout`. This makes [`Self::start`] and its variants return.
+    ///
+    /// Returns whether all event handlers finished before the timeout. See
+    /// [`ShardManager::shutdown_graceful`] for more information, which can also be called while
+    /// the client is running.
+    #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
+    pub async fn shutdown_graceful(&self, timeout: Duration) -> bool {
+        self.shard_manager.shutdown_graceful(timeout).await
+    }
+
     /// Establish the connection and start listening for events.
     ///
     /// This will start receiving events in a loop and start dispatching the events to your
@@ -762,3 +1235,67 @@ impl Client {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "http_transport"))]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::{Client, ClientBuilder};
+    use crate::builder::CreateAllowedMentions;
+    use crate::http::{HttpBuilder, MockTransport};
+    use crate::model::gateway::GatewayIntents;
+
+    async fn client() -> Client {
+        let http = HttpBuilder::new("token").transport(MockTransport::new()).build();
+        ClientBuilder::new_with_http(Arc::new(http), GatewayIntents::empty()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn shutdown_graceful_waits_for_handlers() {
+        let client = client().await;
+        let finished = Arc::new(AtomicBool::new(false));
+        let handler_finished = Arc::clone(&finished);
+        client.shard_manager.spawn_tracked("test", async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            handler_finished.store(true, Ordering::SeqCst);
+        });
+
+        assert!(client.shutdown_graceful(Duration::from_secs(5)).await);
+        assert!(finished.load(Ordering::SeqCst));
+        assert!(client.shard_manager.is_shutting_down());
+    }
+
+    #[tokio::test]
+    async fn shutdown_graceful_times_out() {
+        let client = client().await;
+        client.shard_manager.spawn_tracked("test", std::future::pending());
+
+        assert!(!client.shutdown_graceful(Duration::from_millis(10)).await);
+    }
+
+    #[tokio::test]
+    async fn default_allowed_mentions_with_shared_http() {
+        let http = Arc::new(HttpBuilder::new("token").transport(MockTransport::new()).build());
+        let builder = ClientBuilder::new_with_http(Arc::clone(&http), GatewayIntents::empty())
+            .default_allowed_mentions(CreateAllowedMentions::new().everyone(false));
+        assert!(builder.get_default_allowed_mentions().is_some());
+
+        let client = builder.await.unwrap();
+        assert!(client.http.default_allowed_mentions().is_some());
+        assert!(http.default_allowed_mentions().is_some());
+    }
+
+    #[tokio::test]
+    async fn zero_event_bus_capacity_is_clamped() {
+        let http = HttpBuilder::new("token").transport(MockTransport::new()).build();
+        let builder = ClientBuilder::new_with_http(Arc::new(http), GatewayIntents::empty())
+            .event_bus_capacity(0);
+        assert_eq!(builder.get_event_bus_capacity(), 1);
+
+        let client = builder.await.unwrap();
+        let mut events = client.events();
+        assert!(events.try_recv().is_err());
+    }
+}
@@ -11,6 +11,8 @@
 //!
 //! [`Client`]: client::Client
 
+#[cfg(feature = "broker")]
+pub mod broker;
 pub mod client;
 mod error;
 pub mod sharding;
@@ -26,21 +28,104 @@ pub use self::error::Error as GatewayError;
 pub use self::sharding::*;
 #[cfg(feature = "voice")]
 pub use self::voice::VoiceGatewayManager;
-pub use self::ws::WsClient;
+pub use self::ws::{GatewayDirection, RawGatewayHook, WsClient};
 use crate::internal::prelude::*;
-use crate::model::gateway::{Activity, ActivityType};
+use crate::model::channel::ReactionType;
+use crate::model::fixed::IntoFixedString;
+use crate::model::gateway::{Activity, ActivityEmoji, ActivityType};
 use crate::model::id::UserId;
 use crate::model::user::OnlineStatus;
 
 /// Presence data of the current user.
 #[derive(Clone, Debug, Default)]
 pub struct PresenceData {
-    /// The current activity, if present
-    pub activity: Option<ActivityData>,
+    /// The current activities, in the order they are sent to Discord
+    pub activities: Vec<ActivityData>,
     /// The current online status
     pub status: OnlineStatus,
 }
 
+impl From<PresenceBuilder> for PresenceData {
+    fn from(builder: PresenceBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// A builder for the [`PresenceData`] of the current user, supporting multiple activities and a
+/// custom status.
+///
+/// The presence can be applied to a single shard via [`Context::set_presence_data`], or to all
+/// shards via [`Context::set_presence_all`] or [`ShardManager::set_presence_all`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # fn run(ctx: Context) {
+/// use serenity::gateway::{ActivityData, PresenceBuilder};
+/// use serenity::model::user::OnlineStatus;
+///
+/// let presence = PresenceBuilder::new()
+///     .status(OnlineStatus::Idle)
+///     .custom_status("Taking a break", Some('☕'.into()))
+///     .activity(ActivityData::listening("the rain"));
+///
+/// ctx.set_presence_all(presence);
+/// # }
+/// ```
+///
+/// [`Context::set_presence_data`]: client::Context::set_presence_data
+/// [`Context::set_presence_all`]: client::Context::set_presence_all
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PresenceBuilder {
+    presence: PresenceData,
+}
+
+impl PresenceBuilder {
+    /// Creates a builder for an [`OnlineStatus::Online`] presence without any activities.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the online status. [`OnlineStatus::Offline`] is sent as [`OnlineStatus::Invisible`].
+    pub fn status(mut self, status: OnlineStatus) -> Self {
+        self.presence.status = status;
+        self
+    }
+
+    /// Adds an activity. Activities are shown in the order they were added.
+    pub fn activity(mut self, activity: ActivityData) -> Self {
+        self.presence.activities.push(activity);
+        self
+    }
+
+    /// Sets the custom status, optionally with an emoji, replacing any previously set custom
+    /// status.
+    ///
+    /// **Note**: Discord may not display the emoji for bot users.
+    pub fn custom_status(
+        mut self,
+        state: impl IntoFixedString,
+        emoji: Option<ReactionType>,
+    ) -> Self {
+        let mut activity = ActivityData::custom(state);
+        if let Some(emoji) = emoji {
+            activity = activity.emoji(emoji);
+        }
+
+        self.presence.activities.retain(|activity| activity.kind != ActivityType::Custom);
+        self.presence.activities.push(activity);
+        self
+    }
+
+    /// Builds the presence.
+    #[must_use]
+    pub fn build(self) -> PresenceData {
+        self.presence
+    }
+}
+
 /// Activity data of the current user.
 #[derive(Clone, Debug, Serialize)]
 pub struct ActivityData {
@@ -53,17 +138,21 @@ pub struct ActivityData {
     pub state: Option<FixedString>,
     /// The url of the activity, if the type is [`ActivityType::Streaming`]
     pub url: Option<Url>,
+    /// The emoji of the activity, if the type is [`ActivityType::Custom`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ActivityEmoji>,
 }
 
 impl ActivityData {
     /// Creates an activity that appears as `Playing <name>`.
     #[must_use]
-    pub fn playing(name: impl Into<String>) -> Self {
+    pub fn playing(name: impl IntoFixedString) -> Self {
         Self {
-            name: name.into().trunc_into(),
+            name: name.into_fixed_trunc(),
             kind: ActivityType::Playing,
             state: None,
             url: None,
+            emoji: None,
         }
     }
 
@@ -73,60 +162,89 @@ impl ActivityData {
     ///
     /// Returns an error if the URL parsing fails.
     #[cfg(feature = "http")]
-    pub fn streaming(name: impl Into<String>, url: impl IntoUrl) -> Result<Self> {
+    pub fn streaming(name: impl IntoFixedString, url: impl IntoUrl) -> Result<Self> {
         Ok(Self {
-            name: name.into().trunc_into(),
+            name: name.into_fixed_trunc(),
             kind: ActivityType::Streaming,
             state: None,
             url: Some(url.into_url()?),
+            emoji: None,
         })
     }
 
     /// Creates an activity that appears as `Listening to <name>`.
     #[must_use]
-    pub fn listening(name: impl Into<String>) -> Self {
+    pub fn listening(name: impl IntoFixedString) -> Self {
         Self {
-            name: name.into().trunc_into(),
+            name: name.into_fixed_trunc(),
             kind: ActivityType::Listening,
             state: None,
             url: None,
+            emoji: None,
         }
     }
 
     /// Creates an activity that appears as `Watching <name>`.
     #[must_use]
-    pub fn watching(name: impl Into<String>) -> Self {
+    pub fn watching(name: impl IntoFixedString) -> Self {
         Self {
-            name: name.into().trunc_into(),
+            name: name.into_fixed_trunc(),
             kind: ActivityType::Watching,
             state: None,
             url: None,
+            emoji: None,
         }
     }
 
     /// Creates an activity that appears as `Competing in <name>`.
     #[must_use]
-    pub fn competing(name: impl Into<String>) -> Self {
+    pub fn competing(name: impl IntoFixedString) -> Self {
         Self {
-            name: name.into().trunc_into(),
+            name: name.into_fixed_trunc(),
             kind: ActivityType::Competing,
             state: None,
             url: None,
+            emoji: None,
         }
     }
 
     /// Creates an activity that appears as `<state>`.
     #[must_use]
-    pub fn custom(state: impl Into<String>) -> Self {
+    pub fn custom(state: impl IntoFixedString) -> Self {
         Self {
             // discord seems to require a name for custom activities
             // even though it's not displayed
             name: FixedString::from_static_trunc("~"),
             kind: ActivityType::Custom,
-            state: Some(state.into().trunc_into()),
+            state: Some(state.into_fixed_trunc()),
             url: None,
+            emoji: None,
         }
     }
+
+    /// Sets the emoji shown alongside a [custom status](Self::custom).
+    ///
+    /// **Note**: Discord may not display the emoji for bot users.
+    #[must_use]
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        self.emoji = Some(match emoji.into() {
+            ReactionType::Custom {
+                animated,
+                id,
+                name,
+            } => ActivityEmoji {
+                name: name.unwrap_or_default(),
+                id: Some(id),
+                animated: Some(animated),
+            },
+            ReactionType::Unicode(name) => ActivityEmoji {
+                name,
+                id: None,
+                animated: None,
+            },
+        });
+        self
+    }
 }
 
 impl From<Activity> for ActivityData {
@@ -136,6 +254,7 @@ impl From<Activity> for ActivityData {
             kind: activity.kind,
             state: activity.state,
             url: activity.url,
+            emoji: activity.emoji,
         }
     }
 }
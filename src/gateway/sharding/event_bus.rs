@@ -0,0 +1,93 @@
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::model::event::Event;
+use crate::model::id::ShardId;
+
+type Subscriber = Arc<dyn Fn(ShardId, &Event) -> bool + Send + Sync>;
+
+/// Fans out every event received by the shards to its subscribers, such as collectors and
+/// [`Client::events`].
+///
+/// Subscribers are called with each event as it's received, so none of them miss an event. A
+/// subscriber is kept as long as it returns `true`, and removed once it returns `false`.
+///
+/// [`Client::events`]: crate::Client::events
+#[derive(Clone, Default)]
+pub struct EventBus {
+    subscribers: Arc<RwLock<Vec<Subscriber>>>,
+}
+
+impl EventBus {
+    /// Creates an event bus without any subscribers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a subscriber, which is called with every event and the shard it was received on.
+    pub fn subscribe(&self, subscriber: impl Fn(ShardId, &Event) -> bool + Send + Sync + 'static) {
+        self.subscribers.write().push(Arc::new(subscriber));
+    }
+
+    /// Returns the number of subscribers, for tests to wait until a collector is listening.
+    #[cfg(test)]
+    pub(crate) fn subscriber_count(&self) -> usize {
+        self.subscribers.read().len()
+    }
+
+    /// Calls every subscriber with the event, removing those which don't want to receive any more
+    /// events.
+    pub(crate) fn publish(&self, shard_id: ShardId, event: &Event) {
+        let read_lock = self.subscribers.read();
+        // Clone the Arcs of the subscribers to be removed, so they can be compared by pointer
+        let to_remove: Vec<_> =
+            read_lock.iter().filter(|subscriber| !subscriber(shard_id, event)).cloned().collect();
+        drop(read_lock);
+
+        if !to_remove.is_empty() {
+            self.subscribers.write().retain(|subscriber| {
+                !to_remove.iter().any(|removed| Arc::ptr_eq(subscriber, removed))
+            });
+        }
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus").field("subscribers", &self.subscribers.read().len()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::EventBus;
+    use crate::model::event::{Event, ResumedEvent};
+    use crate::model::id::ShardId;
+
+    #[test]
+    fn subscribers_are_removed_once_done() {
+        let bus = EventBus::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let event = Event::Resumed(ResumedEvent {});
+
+        let counter = Arc::clone(&calls);
+        bus.subscribe(move |_, _| counter.fetch_add(1, Ordering::SeqCst) < 1);
+        let counter = Arc::clone(&calls);
+        bus.subscribe(move |shard_id, _| {
+            counter.fetch_add(10, Ordering::SeqCst);
+            shard_id == ShardId(0)
+        });
+
+        bus.publish(ShardId(0), &event);
+        bus.publish(ShardId(1), &event);
+        bus.publish(ShardId(0), &event);
+        assert_eq!(calls.load(Ordering::SeqCst), 22);
+        assert_eq!(bus.subscriber_count(), 0);
+    }
+}
@@ -0,0 +1,496 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::FutureExt as _;
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+use tracing::debug;
+
+use super::{ShardManager, ShardStats};
+use crate::gateway::client::dispatch::PreparedEvent;
+use crate::gateway::client::{Context, FullEvent};
+use crate::model::id::ShardId;
+
+/// The callback invoked for every event shed by an [`EventQueueOptions::on_shed`] queue.
+type ShedCallback = Arc<dyn Fn(ShardId, &FullEvent) + Send + Sync>;
+
+/// Creates the future handling an event taken from the queue.
+type DispatchFn = Box<dyn Fn(PreparedEvent, Context) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// What a shard does with a newly received event when its event queue is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Sheds the oldest queued presence update. If none is queued, the new event is shed if it is
+    /// a presence update itself, and otherwise the oldest queued event is shed.
+    #[default]
+    DropPresencesFirst,
+    /// Sheds the oldest queued event.
+    DropOldest,
+    /// Waits until there is space in the queue, without shedding any events.
+    ///
+    /// **Note**: The shard neither reads from the gateway nor heartbeats while waiting, so slow
+    /// event handlers may cause it to be disconnected.
+    Block,
+}
+
+/// Bounds the number of events each shard keeps in memory, set via
+/// [`ClientBuilder::event_queue`].
+///
+/// At most `capacity` events of a shard are handled at the same time, and at most `capacity`
+/// further events wait to be handled. Once both are full, newly received events are handled
+/// according to the [`OverflowPolicy`].
+///
+/// Events are applied to the cache when they are received, before they are queued. Shedding an
+/// event only skips dispatching it to the framework and event handlers, so the cache stays up to
+/// date.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::num::NonZeroUsize;
+///
+/// use serenity::gateway::{EventQueueOptions, OverflowPolicy};
+/// use serenity::model::gateway::GatewayIntents;
+/// use serenity::Client;
+///
+/// let options = EventQueueOptions::new(NonZeroUsize::new(1000).unwrap())
+///     .overflow_policy(OverflowPolicy::DropOldest)
+///     .on_shed(|shard_id, event| {
+///         println!("Shard {shard_id} shed a {} event", event.snake_case_name());
+///     });
+///
+/// let builder = Client::builder("token", GatewayIntents::all()).event_queue(options);
+/// ```
+///
+/// [`ClientBuilder::event_queue`]: crate::gateway::client::ClientBuilder::event_queue
+#[derive(Clone)]
+#[must_use]
+pub struct EventQueueOptions {
+    capacity: NonZeroUsize,
+    overflow_policy: OverflowPolicy,
+    on_shed: Option<ShedCallback>,
+}
+
+impl EventQueueOptions {
+    /// Creates options for a queue of the given capacity, using
+    /// [`OverflowPolicy::DropPresencesFirst`].
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            overflow_policy: OverflowPolicy::default(),
+            on_shed: None,
+        }
+    }
+
+    /// Sets what happens to new events once the queue is full.
+    pub fn overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Sets a callback which is invoked for every shed event. This is called from within the shard
+    /// loop, so it should return quickly.
+    ///
+    /// The number of shed events is also available via [`ShardStats::events_shed`].
+    ///
+    /// [`ShardStats::events_shed`]: super::ShardStats::events_shed
+    pub fn on_shed(
+        mut self,
+        callback: impl Fn(ShardId, &FullEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_shed = Some(Arc::new(callback));
+        self
+    }
+
+    /// Gets the capacity of the queue.
+    #[must_use]
+    pub fn get_capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    /// Gets the overflow policy of the queue.
+    #[must_use]
+    pub fn get_overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+}
+
+impl fmt::Debug for EventQueueOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventQueueOptions")
+            .field("capacity", &self.capacity)
+            .field("overflow_policy", &self.overflow_policy)
+            .field("on_shed", &self.on_shed.is_some())
+            .finish()
+    }
+}
+
+#[derive(Default)]
+struct QueueState {
+    pending: VecDeque<(PreparedEvent, Context)>,
+    running: usize,
+}
+
+/// The bounded queue of events waiting to be handled for a single shard.
+///
+/// Instead of a dedicated consumer task, up to `capacity` worker tasks are spawned, each of which
+/// keeps handling queued events until the queue is empty.
+pub(crate) struct EventQueue {
+    shard_id: ShardId,
+    options: EventQueueOptions,
+    manager: Arc<ShardManager>,
+    stats: Arc<Mutex<ShardStats>>,
+    dispatch: DispatchFn,
+    state: Mutex<QueueState>,
+    space: Notify,
+}
+
+impl EventQueue {
+    pub(crate) fn new(
+        shard_id: ShardId,
+        options: EventQueueOptions,
+        manager: Arc<ShardManager>,
+        dispatch: DispatchFn,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            shard_id,
+            options,
+            stats: manager.stats_handle(shard_id),
+            manager,
+            dispatch,
+            state: Mutex::default(),
+            space: Notify::new(),
+        })
+    }
+
+    /// Queues an event to be handled, waiting for space if the queue is full and the
+    /// [`OverflowPolicy::Block`] policy is used.
+    pub(crate) async fn push(self: &Arc<Self>, event: PreparedEvent, context: Context) {
+        let mut item = (event, context);
+        loop {
+            match self.try_push(item) {
+                Some(blocked) => item = blocked,
+                None => return,
+            }
+
+            self.space.notified().await;
+        }
+    }
+
+    /// Queues an event, returning it if it has to wait for space.
+    fn try_push(
+        self: &Arc<Self>,
+        item: (PreparedEvent, Context),
+    ) -> Option<(PreparedEvent, Context)> {
+        let capacity = self.options.capacity.get();
+        let mut state = self.state.lock();
+
+        if state.running < capacity {
+            state.running += 1;
+            drop(state);
+
+            self.spawn_worker(item);
+            return None;
+        }
+
+        if state.pending.len() < capacity {
+            state.pending.push_back(item);
+            return None;
+        }
+
+        let shed = match self.options.overflow_policy {
+            OverflowPolicy::Block => return Some(item),
+            OverflowPolicy::DropOldest => state.pending.pop_front(),
+            OverflowPolicy::DropPresencesFirst => {
+                let presence = state
+                    .pending
+                    .iter()
+                    .position(|(event, _)| is_presence_update(event));
+                match presence {
+                    Some(position) => state.pending.remove(position),
+                    None if is_presence_update(&item.0) => {
+                        drop(state);
+
+                        self.shed(&item.0);
+                        return None;
+                    },
+                    None => state.pending.pop_front(),
+                }
+            },
+        };
+
+        state.pending.push_back(item);
+        drop(state);
+
+        if let Some((event, _)) = shed {
+            self.shed(&event);
+        }
+        None
+    }
+
+    fn shed(&self, event: &PreparedEvent) {
+        let name = event.full_event.snake_case_name();
+        debug!("[Shard {}] Event queue is full, shedding {name} event", self.shard_id);
+
+        self.stats.lock().events_shed += 1;
+        if let Some(callback) = &self.options.on_shed {
+            callback(self.shard_id, &event.full_event);
+        }
+    }
+
+    fn spawn_worker(self: &Arc<Self>, item: (PreparedEvent, Context)) {
+        let queue = Arc::clone(self);
+        self.manager.spawn_tracked("shard_runner::dispatch", async move {
+            let mut next = Some(item);
+            while let Some((event, context)) = next {
+                let channel_id = event.channel_id;
+                let future =
+                    queue.manager.schedule_dispatch(channel_id, (queue.dispatch)(event, context));
+                // A panicking handler must not leak its slot in the queue
+                drop(AssertUnwindSafe(future).catch_unwind().await);
+
+                next = queue.next_event();
+            }
+        });
+    }
+
+    /// Takes the next event for a worker that finished handling its event, or releases the
+    /// worker's slot if the queue is empty.
+    fn next_event(&self) -> Option<(PreparedEvent, Context)> {
+        let mut state = self.state.lock();
+
+        // Events queued during a graceful shutdown are dropped
+        if self.manager.is_shutting_down() {
+            state.pending.clear();
+        }
+
+        let next = state.pending.pop_front();
+        if next.is_none() {
+            state.running -= 1;
+        }
+        drop(state);
+
+        self.space.notify_one();
+        next
+    }
+}
+
+fn is_presence_update(event: &PreparedEvent) -> bool {
+    matches!(event.full_event, FullEvent::PresenceUpdate { .. })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::{mpsc, Semaphore};
+
+    use super::*;
+    use crate::gateway::client::dispatch::prepare_event;
+    use crate::gateway::client::DispatchMode;
+    use crate::model::prelude::*;
+
+    struct TestQueue {
+        queue: Arc<EventQueue>,
+        manager: Arc<ShardManager>,
+        context: Context,
+        /// Each permit lets a worker finish handling one event.
+        permits: Arc<Semaphore>,
+        handled: mpsc::UnboundedReceiver<u64>,
+        shed: Arc<Mutex<Vec<u64>>>,
+    }
+
+    impl TestQueue {
+        /// Creates a queue of capacity 1, so one event is handled while one more is pending.
+        fn new(overflow_policy: OverflowPolicy) -> Self {
+            let manager = ShardManager::new_for_test(DispatchMode::Unbounded, None);
+            let permits = Arc::new(Semaphore::new(0));
+            let (tx, handled) = mpsc::unbounded_channel();
+            let shed = Arc::new(Mutex::new(Vec::new()));
+
+            let shed_clone = Arc::clone(&shed);
+            let options = EventQueueOptions::new(NonZeroUsize::MIN)
+                .overflow_policy(overflow_policy)
+                .on_shed(move |shard_id, event| {
+                    assert_eq!(shard_id, ShardId(0));
+                    shed_clone.lock().push(event_id(event));
+                });
+
+            let permits_clone = Arc::clone(&permits);
+            let dispatch: DispatchFn = Box::new(move |event, _| {
+                let permits = Arc::clone(&permits_clone);
+                let tx = tx.clone();
+                Box::pin(async move {
+                    permits.acquire().await.unwrap().forget();
+                    tx.send(event_id(&event.full_event)).unwrap();
+                })
+            });
+
+            Self {
+                queue: EventQueue::new(ShardId(0), options, Arc::clone(&manager), dispatch),
+                manager,
+                context: Context::new_for_test(ShardId(0)),
+                permits,
+                handled,
+                shed,
+            }
+        }
+
+        async fn push_typing(&self, user_id: u64) {
+            let event = Event::TypingStart(
+                serde_json::from_value(serde_json::json!({
+                    "channel_id": "1",
+                    "user_id": user_id.to_string(),
+                    "timestamp": 0,
+                }))
+                .unwrap(),
+            );
+            self.push(event).await;
+        }
+
+        async fn push_presence(&self, user_id: u64) {
+            let event = Event::PresenceUpdate(PresenceUpdateEvent {
+                presence: serde_json::from_value(serde_json::json!({
+                    "user": {"id": user_id.to_string()},
+                    "guild_id": "1",
+                    "status": "online",
+                    "activities": [],
+                }))
+                .unwrap(),
+            });
+            self.push(event).await;
+        }
+
+        async fn push(&self, event: Event) {
+            let prepared = prepare_event(&self.context, event, false);
+            self.queue.push(prepared, self.context.clone()).await;
+        }
+
+        /// Lets all queued events be handled, returning the order they were handled in.
+        async fn drain(&mut self, expected: usize) -> Vec<u64> {
+            self.permits.add_permits(expected);
+
+            let mut handled = Vec::new();
+            while handled.len() < expected {
+                let next = tokio::time::timeout(Duration::from_secs(5), self.handled.recv());
+                handled.push(next.await.unwrap().unwrap());
+            }
+            handled
+        }
+
+        fn shed(&self) -> Vec<u64> {
+            self.shed.lock().clone()
+        }
+
+        fn events_shed(&self) -> u64 {
+            self.manager.shard_stats(ShardId(0)).map_or(0, |stats| stats.events_shed)
+        }
+    }
+
+    fn event_id(event: &FullEvent) -> u64 {
+        match event {
+            FullEvent::TypingStart {
+                event,
+            } => event.user_id.get(),
+            FullEvent::PresenceUpdate {
+                new_data, ..
+            } => new_data.user.id.get(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_sheds_oldest_pending() {
+        let mut queue = TestQueue::new(OverflowPolicy::DropOldest);
+        queue.push_typing(1).await;
+        queue.push_typing(2).await;
+        queue.push_typing(3).await;
+
+        assert_eq!(queue.shed(), vec![2]);
+        assert_eq!(queue.events_shed(), 1);
+        assert_eq!(queue.drain(2).await, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn drop_presences_first_sheds_pending_presence() {
+        let mut queue = TestQueue::new(OverflowPolicy::DropPresencesFirst);
+        queue.push_typing(1).await;
+        queue.push_presence(2).await;
+        queue.push_typing(3).await;
+
+        assert_eq!(queue.shed(), vec![2]);
+        assert_eq!(queue.drain(2).await, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn drop_presences_first_sheds_new_presence() {
+        let mut queue = TestQueue::new(OverflowPolicy::DropPresencesFirst);
+        queue.push_typing(1).await;
+        queue.push_typing(2).await;
+        queue.push_presence(3).await;
+
+        assert_eq!(queue.shed(), vec![3]);
+        assert_eq!(queue.drain(2).await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn drop_presences_first_falls_back_to_oldest() {
+        let mut queue = TestQueue::new(OverflowPolicy::DropPresencesFirst);
+        queue.push_typing(1).await;
+        queue.push_typing(2).await;
+        queue.push_typing(3).await;
+
+        assert_eq!(queue.shed(), vec![2]);
+        assert_eq!(queue.drain(2).await, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn block_waits_for_space() {
+        let mut queue = TestQueue::new(OverflowPolicy::Block);
+        queue.push_typing(1).await;
+        queue.push_typing(2).await;
+
+        {
+            let blocked = queue.push_typing(3);
+            tokio::pin!(blocked);
+            assert!(tokio::time::timeout(Duration::from_millis(50), &mut blocked).await.is_err());
+
+            queue.permits.add_permits(1);
+            blocked.await;
+        }
+
+        let mut handled = vec![queue.handled.recv().await.unwrap()];
+        handled.extend(queue.drain(2).await);
+        assert_eq!(handled, vec![1, 2, 3]);
+        assert!(queue.shed().is_empty());
+        assert_eq!(queue.events_shed(), 0);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn shed_events_update_cache() {
+        let mut queue = TestQueue::new(OverflowPolicy::DropPresencesFirst);
+        queue.context.cache.update(&mut GuildCreateEvent {
+            guild: Guild {
+                id: GuildId::new(1),
+                ..Default::default()
+            },
+        });
+
+        queue.push_typing(1).await;
+        queue.push_typing(2).await;
+        queue.push_presence(3).await;
+
+        assert_eq!(queue.shed(), vec![3]);
+        let guild = queue.context.cache.guild(GuildId::new(1)).unwrap();
+        assert!(guild.presences.contains_key(&UserId::new(3)));
+        drop(guild);
+
+        assert_eq!(queue.drain(2).await, vec![1, 2]);
+    }
+}
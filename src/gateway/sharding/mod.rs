@@ -33,10 +33,13 @@
 //!
 //! [docs]: https://discordapp.com/developers/docs/topics/gateway#sharding
 
+mod event_bus;
+mod event_queue;
 mod shard_manager;
 mod shard_messenger;
 mod shard_queuer;
 mod shard_runner;
+mod shard_stats;
 
 use std::fmt;
 use std::sync::Arc;
@@ -48,15 +51,19 @@ use tokio_tungstenite::tungstenite::protocol::frame::CloseFrame;
 use tracing::{debug, error, info, trace, warn};
 use url::Url;
 
+pub use self::event_bus::EventBus;
+pub(crate) use self::event_queue::EventQueue;
+pub use self::event_queue::{EventQueueOptions, OverflowPolicy};
 pub use self::shard_manager::{ShardManager, ShardManagerOptions};
-pub use self::shard_messenger::ShardMessenger;
+pub use self::shard_messenger::{ShardMessenger, ShardSendError};
 pub use self::shard_queuer::{ShardQueue, ShardQueuer, ShardQueuerMessage};
 pub use self::shard_runner::{ShardRunner, ShardRunnerMessage, ShardRunnerOptions};
-use super::{ActivityData, ChunkGuildFilter, GatewayError, PresenceData, WsClient};
+pub use self::shard_stats::{LatencyHistogram, ShardStats};
+use super::{ActivityData, ChunkGuildFilter, GatewayError, PresenceData, RawGatewayHook, WsClient};
 use crate::constants::{self, close_codes};
 use crate::http::Token;
 use crate::internal::prelude::*;
-use crate::model::event::{Event, GatewayEvent};
+use crate::model::event::{Event, EventTypeFlags, GatewayEvent};
 use crate::model::gateway::{GatewayIntents, ShardInfo};
 use crate::model::id::{ApplicationId, GuildId, ShardId};
 use crate::model::user::OnlineStatus;
@@ -110,6 +117,9 @@ pub struct Shard {
     token: Secret<Token>,
     ws_url: Arc<str>,
     resume_ws_url: Option<FixedString>,
+    use_resume_url: bool,
+    filtered_events: EventTypeFlags,
+    raw_gateway_hook: Option<RawGatewayHook>,
     pub intents: GatewayIntents,
 }
 
@@ -189,6 +199,9 @@ impl Shard {
             shard_info,
             ws_url,
             resume_ws_url: None,
+            use_resume_url: true,
+            filtered_events: EventTypeFlags::all(),
+            raw_gateway_hook: None,
             intents,
         })
     }
@@ -203,6 +216,41 @@ impl Shard {
         self.application_id_callback = Some(Box::new(callback));
     }
 
+    /// Sets the events which are deserialized and returned by the shard. Dispatches of other
+    /// events are dropped before being deserialized, which saves the cost of doing so for events
+    /// the bot doesn't handle.
+    ///
+    /// [`Event::Ready`] and [`Event::Resumed`] are always returned, as they are required to
+    /// maintain the connection. Events unknown to serenity are not affected.
+    ///
+    /// **Note**: Dropped events are not applied to the cache either.
+    pub fn set_filtered_events(&mut self, events: EventTypeFlags) {
+        self.filtered_events = events | EventTypeFlags::READY | EventTypeFlags::RESUMED;
+        self.client.set_filtered_events(self.filtered_events);
+    }
+
+    /// Retrieves the events which are deserialized and returned by the shard. See
+    /// [`Self::set_filtered_events`].
+    pub fn filtered_events(&self) -> EventTypeFlags {
+        self.filtered_events
+    }
+
+    /// Sets whether the shard reconnects to the resume URL sent by Discord when resuming a
+    /// session. Defaults to `true`.
+    ///
+    /// When connecting through a gateway proxy, this should be disabled, so that the shard always
+    /// reconnects to the proxy instead of directly to Discord.
+    pub fn set_use_resume_url(&mut self, use_resume_url: bool) {
+        self.use_resume_url = use_resume_url;
+    }
+
+    /// Sets a hook invoked with every frame sent or received by the shard, including across
+    /// reconnects. See [`RawGatewayHook`] for more info.
+    pub fn set_raw_gateway_hook(&mut self, hook: Option<RawGatewayHook>) {
+        self.raw_gateway_hook = hook;
+        self.client.set_raw_hook(self.shard_info.id, hook);
+    }
+
     /// Retrieves the current presence of the shard.
     pub fn presence(&self) -> &PresenceData {
         &self.presence
@@ -270,7 +318,7 @@ impl Shard {
 
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
     pub fn set_activity(&mut self, activity: Option<ActivityData>) {
-        self.presence.activity = activity;
+        self.presence.activities = activity.into_iter().collect();
     }
 
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
@@ -279,6 +327,16 @@ impl Shard {
         self.set_status(status);
     }
 
+    /// Replaces the presence, including all activities. See [`PresenceBuilder`] for building
+    /// one.
+    ///
+    /// [`PresenceBuilder`]: super::PresenceBuilder
+    #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
+    pub fn set_presence_data(&mut self, presence: PresenceData) {
+        self.presence.activities = presence.activities;
+        self.set_status(presence.status);
+    }
+
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
     pub fn set_status(&mut self, mut status: OnlineStatus) {
         if status == OnlineStatus::Offline {
@@ -313,13 +371,20 @@ impl Shard {
         }
 
         self.seq = seq;
+        // The websocket client leaves the data of filtered out events empty
+        if event.is_empty() {
+            return Ok((None, None));
+        }
+
         let event = Event::deserialize_and_log(event, original_str)?;
 
         match &event {
             Event::Ready(ready) => {
                 debug!("[{:?}] Received Ready", self.shard_info);
 
-                self.resume_ws_url = Some(ready.ready.resume_gateway_url.clone());
+                if self.use_resume_url {
+                    self.resume_ws_url = Some(ready.ready.resume_gateway_url.clone());
+                }
                 self.session_id = Some(ready.ready.session_id.clone());
                 self.stage = ConnectionStage::Connected;
 
@@ -744,7 +809,9 @@ impl Shard {
         // Hello is received.
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
-        let client = connect(ws_url).await?;
+        let mut client = connect(ws_url).await?;
+        client.set_raw_hook(self.shard_info.id, self.raw_gateway_hook);
+        client.set_filtered_events(self.filtered_events);
         self.stage = ConnectionStage::Handshake;
 
         Ok(client)
@@ -852,6 +919,76 @@ pub struct ShardStageUpdateEvent {
     pub shard_id: ShardId,
 }
 
+/// An event denoting that a shard lost its session or gateway connection, as passed to
+/// [`EventHandler::shard_disconnect`].
+///
+/// [`EventHandler::shard_disconnect`]: crate::gateway::client::EventHandler::shard_disconnect
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ShardDisconnectEvent {
+    /// The ID of the shard that disconnected.
+    pub shard_id: ShardId,
+    /// Why the shard disconnected.
+    pub reason: ShardDisconnectReason,
+    /// Whether the shard will attempt to resume its session. Otherwise, it will start a new
+    /// session by identifying again.
+    pub resumable: bool,
+}
+
+/// The reason a shard disconnected, as part of a [`ShardDisconnectEvent`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ShardDisconnectReason {
+    /// The gateway closed the connection.
+    Closed {
+        /// The close code sent by Discord, if the connection was closed cleanly. See
+        /// [`constants::close_codes`] for the known codes.
+        code: Option<u16>,
+    },
+    /// The gateway invalidated the session. Repeatedly receiving this may indicate that the shard
+    /// is stuck in an identify loop.
+    InvalidSession,
+    /// The gateway requested the shard to reconnect.
+    ReconnectRequested,
+    /// The connection was lost due to a network error.
+    ConnectionLost,
+    /// A heartbeat could not be sent, or the previous heartbeat was not acknowledged in time.
+    HeartbeatFailed,
+}
+
+/// A change in the lifecycle of a shard's connection, as streamed by
+/// [`ShardManager::lifecycle_events`].
+///
+/// Unlike [`ShardStageUpdateEvent`], which is only delivered to the [`EventHandler`], these are
+/// meant to be consumed by external orchestration, such as readiness probes.
+///
+/// [`EventHandler`]: crate::gateway::client::EventHandler
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ShardLifecycleEvent {
+    /// The shard is opening a new connection to the gateway.
+    Connecting,
+    /// The shard is attempting to resume its previous session on a new connection.
+    Resuming,
+    /// The shard identified and started a new session, upon receiving a READY event.
+    Identified,
+    /// The shard resumed its previous session, upon receiving a RESUMED event.
+    Resumed,
+    /// The gateway connection was lost.
+    Disconnected {
+        /// The close code sent by Discord, if the connection was closed cleanly.
+        code: Option<u16>,
+    },
+    /// The shard is being restarted, and will start connecting again after the backoff.
+    Reconnecting {
+        /// How long the shard waits before connecting, to respect the identify ratelimit.
+        backoff: StdDuration,
+    },
+    /// The shard was shut down, or stopped due to a fatal error such as invalid authentication.
+    /// It will not reconnect unless it is restarted.
+    Dead,
+}
+
 /// Indicates the current connection stage of a [`Shard`].
 ///
 /// This can be useful for knowing which shards are currently "down"/"up".
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::num::NonZeroU16;
 use std::sync::Arc;
 #[cfg(feature = "framework")]
@@ -6,24 +7,50 @@ use std::sync::OnceLock;
 use std::time::Duration;
 
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
-use futures::{SinkExt, StreamExt};
-use tokio::sync::Mutex;
+use futures::future::BoxFuture;
+use futures::{SinkExt, Stream, StreamExt};
+use tokio::sync::{broadcast, Mutex};
 use tokio::time::timeout;
+use tokio_util::task::TaskTracker;
 use tracing::{info, warn};
 
-use super::{ShardId, ShardQueue, ShardQueuer, ShardQueuerMessage, ShardRunnerInfo};
+use super::{
+    EventBus,
+    EventQueueOptions,
+    ShardId,
+    ShardLifecycleEvent,
+    ShardQueue,
+    ShardQueuer,
+    ShardQueuerMessage,
+    ShardRunnerInfo,
+    ShardStats,
+};
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::client::{EventHandler, RawEventHandler};
+use crate::gateway::client::dispatch::Dispatcher;
+use crate::gateway::client::{
+    CommandStats,
+    DispatchMode,
+    EventHandler,
+    Extensions,
+    RawEventHandler,
+    RawPayloadHandler,
+};
 #[cfg(feature = "voice")]
 use crate::gateway::VoiceGatewayManager;
-use crate::gateway::{ConnectionStage, GatewayError, PresenceData};
+use crate::gateway::{ConnectionStage, GatewayError, PresenceData, RawGatewayHook};
 use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
+use crate::model::event::EventTypeFlags;
 use crate::model::gateway::GatewayIntents;
+use crate::model::id::ChannelId;
+
+/// The number of lifecycle events buffered for each subscriber of
+/// [`ShardManager::lifecycle_events`].
+const LIFECYCLE_EVENTS_CAPACITY: usize = 64;
 
 /// A manager for handling the status of shards by starting them, restarting them, and stopping
 /// them when required.
@@ -49,9 +76,10 @@ use crate::model::gateway::GatewayIntents;
 /// use std::env;
 /// use std::sync::{Arc, OnceLock};
 ///
-/// use serenity::gateway::client::EventHandler;
-/// use serenity::gateway::{ShardManager, ShardManagerOptions};
+/// use serenity::gateway::client::{DispatchMode, EventHandler, Extensions};
+/// use serenity::gateway::{EventBus, ShardManager, ShardManagerOptions};
 /// use serenity::http::Http;
+/// use serenity::model::event::EventTypeFlags;
 /// use serenity::model::gateway::GatewayIntents;
 /// use serenity::prelude::*;
 /// use tokio::sync::{Mutex, RwLock};
@@ -64,6 +92,7 @@ use crate::model::gateway::GatewayIntents;
 /// let gateway_info = http.get_bot_gateway().await?;
 ///
 /// let data = Arc::new(());
+/// let extensions = Arc::new(Extensions::new());
 /// let shard_total = gateway_info.shards;
 /// let ws_url = Arc::from(gateway_info.url);
 /// let event_handler = Arc::new(Handler);
@@ -71,8 +100,13 @@ use crate::model::gateway::GatewayIntents;
 ///
 /// ShardManager::new(ShardManagerOptions {
 ///     data,
+///     extensions,
 ///     event_handler: Some(event_handler),
 ///     raw_event_handler: None,
+///     raw_payload_handler: None,
+///     command_stats: None,
+///     event_dispatch: DispatchMode::Unbounded,
+///     event_queue: None,
 ///     framework: Arc::new(OnceLock::new()),
 ///     # #[cfg(feature = "voice")]
 ///     # voice_manager: None,
@@ -83,7 +117,11 @@ use crate::model::gateway::GatewayIntents;
 ///     # http,
 ///     intents: GatewayIntents::non_privileged(),
 ///     presence: None,
+///     filtered_events: EventTypeFlags::all(),
+///     use_resume_url: true,
+///     raw_gateway_hook: None,
 ///     max_concurrency,
+///     event_bus: EventBus::new(),
 /// });
 /// # Ok(())
 /// # }
@@ -104,6 +142,15 @@ pub struct ShardManager {
     shard_shutdown: Mutex<Receiver<ShardId>>,
     shard_shutdown_send: Sender<ShardId>,
     gateway_intents: GatewayIntents,
+    lifecycle: broadcast::Sender<(ShardId, ShardLifecycleEvent)>,
+    /// The health statistics of each shard. Shard runners keep a handle to the statistics of
+    /// their own shard, see [`Self::stats_handle`], so recording an event only locks those.
+    stats: parking_lot::RwLock<HashMap<ShardId, Arc<parking_lot::Mutex<ShardStats>>>>,
+    /// The event handler tasks spawned by the shard runners, closed once a graceful shutdown
+    /// starts.
+    tasks: TaskTracker,
+    dispatcher: Dispatcher,
+    event_queue: Option<EventQueueOptions>,
 }
 
 impl ShardManager {
@@ -124,12 +171,20 @@ impl ShardManager {
             shard_shutdown_send: shutdown_send,
             runners: Arc::clone(&runners),
             gateway_intents: opt.intents,
+            lifecycle: broadcast::channel(LIFECYCLE_EVENTS_CAPACITY).0,
+            stats: parking_lot::RwLock::default(),
+            tasks: TaskTracker::new(),
+            dispatcher: Dispatcher::new(opt.event_dispatch),
+            event_queue: opt.event_queue,
         });
 
         let mut shard_queuer = ShardQueuer {
             data: opt.data,
+            extensions: opt.extensions,
             event_handler: opt.event_handler,
             raw_event_handler: opt.raw_event_handler,
+            raw_payload_handler: opt.raw_payload_handler,
+            command_stats: opt.command_stats,
             #[cfg(feature = "framework")]
             framework: opt.framework,
             last_start: None,
@@ -146,6 +201,10 @@ impl ShardManager {
             http: opt.http,
             intents: opt.intents,
             presence: opt.presence,
+            filtered_events: opt.filtered_events,
+            use_resume_url: opt.use_resume_url,
+            raw_gateway_hook: opt.raw_gateway_hook,
+            event_bus: opt.event_bus,
         };
 
         spawn_named("shard_queuer::run", async move {
@@ -200,7 +259,8 @@ impl ShardManager {
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
     pub async fn restart(&self, shard_id: ShardId) {
         info!("Restarting shard {shard_id}");
-        self.shutdown(shard_id, 4000).await;
+        // The shard isn't dead, the queuer emits `Reconnecting` once it's booted again
+        self.stop(shard_id, 4000).await;
         self.boot(shard_id, false);
     }
 
@@ -221,8 +281,16 @@ impl ShardManager {
     /// **Note**: If the receiving end of an mpsc channel - owned by the shard runner - no longer
     /// exists, then the shard runner will not know it should shut down. This _should never happen_.
     /// It may already be stopped.
+    ///
+    /// Once shut down, the shard emits [`ShardLifecycleEvent::Dead`].
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
     pub async fn shutdown(&self, shard_id: ShardId, code: u16) {
+        self.stop(shard_id, code).await;
+        self.emit_lifecycle(shard_id, ShardLifecycleEvent::Dead);
+    }
+
+    /// Stops the shard runner and waits for it to finish, without emitting a lifecycle event.
+    async fn stop(&self, shard_id: ShardId, code: u16) {
         const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_secs(5);
 
         info!("Shutting down shard {}", shard_id);
@@ -289,6 +357,93 @@ impl ShardManager {
         drop(self.return_value_tx.lock().await.unbounded_send(Ok(())));
     }
 
+    /// Gracefully shuts down all shards, by first letting in-flight events finish being handled.
+    ///
+    /// Once called, new events received by the shards are no longer dispatched. This then waits
+    /// for the already dispatched event handler tasks to complete, for at most `timeout`, before
+    /// closing the connections via [`Self::shutdown_all`].
+    ///
+    /// Returns whether all event handler tasks completed before the timeout.
+    ///
+    /// # Examples
+    ///
+    /// Shutting down gracefully after one minute of operation:
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use serenity::prelude::*;
+    /// # async fn run(mut client: Client) {
+    /// let shard_manager = client.shard_manager.clone();
+    ///
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_secs(60)).await;
+    ///
+    ///     if !shard_manager.shutdown_graceful(Duration::from_secs(10)).await {
+    ///         println!("Some event handlers were still running");
+    ///     }
+    /// });
+    ///
+    /// client.start().await.unwrap();
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
+    pub async fn shutdown_graceful(&self, timeout_duration: Duration) -> bool {
+        info!("Gracefully shutting down all shards");
+
+        self.tasks.close();
+        let finished = timeout(timeout_duration, self.tasks.wait()).await.is_ok();
+        if !finished {
+            warn!(
+                "{} event handler tasks did not finish within {:?}",
+                self.tasks.len(),
+                timeout_duration
+            );
+        }
+
+        self.shutdown_all().await;
+        finished
+    }
+
+    /// Whether a graceful shutdown was started, after which events are no longer dispatched.
+    pub(crate) fn is_shutting_down(&self) -> bool {
+        self.tasks.is_closed()
+    }
+
+    /// Spawns an event handler task, which is waited for by [`Self::shutdown_graceful`].
+    pub(crate) fn spawn_tracked<F>(&self, name: &str, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        spawn_named(name, self.tasks.track_future(future));
+    }
+
+    /// Spawns the task handling an event of the given channel, according to the configured
+    /// [`DispatchMode`].
+    pub(crate) fn spawn_dispatch<F>(&self, channel_id: Option<ChannelId>, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_tracked("shard_runner::dispatch", self.schedule_dispatch(channel_id, future));
+    }
+
+    /// Wraps the future handling an event of the given channel, so that it waits for its turn
+    /// according to the configured [`DispatchMode`].
+    pub(crate) fn schedule_dispatch<F>(
+        &self,
+        channel_id: Option<ChannelId>,
+        future: F,
+    ) -> BoxFuture<'static, ()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.dispatcher.schedule(channel_id, future)
+    }
+
+    /// The options of the event queue each shard runner creates, if any.
+    pub(crate) fn event_queue_options(&self) -> Option<&EventQueueOptions> {
+        self.event_queue.as_ref()
+    }
+
     fn set_shard_total(&self, shard_total: NonZeroU16) {
         info!("Setting shard total to {shard_total}");
 
@@ -306,12 +461,138 @@ impl ShardManager {
         }));
     }
 
+    /// Replaces the presence of a single shard. See [`PresenceBuilder`] for building one.
+    ///
+    /// Does nothing if the shard is not running. If the shard is restarted, it goes back to the
+    /// presence set via [`Self::set_presence_all`] or the client builder.
+    ///
+    /// [`PresenceBuilder`]: crate::gateway::PresenceBuilder
+    pub async fn set_presence(&self, shard_id: ShardId, presence: impl Into<PresenceData>) {
+        if let Some(runner) = self.runners.lock().await.get(&shard_id) {
+            runner.runner_tx.set_presence_data(presence);
+        }
+    }
+
+    /// Replaces the presence of all shards, including shards started later on. See
+    /// [`PresenceBuilder`] for building one.
+    ///
+    /// [`PresenceBuilder`]: crate::gateway::PresenceBuilder
+    pub async fn set_presence_all(&self, presence: impl Into<PresenceData>) {
+        let presence = presence.into();
+        drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::SetPresence(presence.clone())));
+
+        for runner in self.runners.lock().await.values() {
+            runner.runner_tx.set_presence_data(presence.clone());
+        }
+    }
+
     /// Returns the gateway intents used for this gateway connection.
     #[must_use]
     pub fn intents(&self) -> GatewayIntents {
         self.gateway_intents
     }
 
+    /// Returns a stream of the lifecycle events of all shards, along with the Id of the shard
+    /// they belong to. Only events which happen after this is called are received.
+    ///
+    /// # Examples
+    ///
+    /// Reporting when a shard is ready to receive events:
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use serenity::gateway::ShardLifecycleEvent;
+    /// use serenity::prelude::*;
+    ///
+    /// # async fn run(client: Client) {
+    /// let mut events = std::pin::pin!(client.shard_manager.lifecycle_events());
+    /// while let Some((shard_id, event)) = events.next().await {
+    ///     if let ShardLifecycleEvent::Identified | ShardLifecycleEvent::Resumed = event {
+    ///         println!("Shard {shard_id} is ready");
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn lifecycle_events(&self) -> impl Stream<Item = (ShardId, ShardLifecycleEvent)> {
+        futures::stream::unfold(self.lifecycle.subscribe(), |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((event, receiver)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Lifecycle event stream lagged behind, skipped {skipped} events");
+                    },
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    pub(crate) fn emit_lifecycle(&self, shard_id: ShardId, event: ShardLifecycleEvent) {
+        {
+            let stats = self.stats_handle(shard_id);
+            let mut stats = stats.lock();
+            if let ShardLifecycleEvent::Resuming
+            | ShardLifecycleEvent::Reconnecting {
+                ..
+            } = event
+            {
+                stats.reconnects += 1;
+            }
+            stats.lifecycle = Some(event.clone());
+        }
+
+        // This only fails if nobody is subscribed to the lifecycle events
+        drop(self.lifecycle.send((shard_id, event)));
+    }
+
+    /// Returns the health statistics of a shard, such as its latency histogram and event rates.
+    ///
+    /// Returns [`None`] if the shard was never started by this manager. Use
+    /// [`Self::lifecycle_events`] to be notified of changes to a shard's connection instead of
+    /// polling [`ShardStats::lifecycle`].
+    ///
+    /// # Examples
+    ///
+    /// Reporting the 95th percentile latency and message rate of a shard:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::ShardId;
+    /// use serenity::prelude::*;
+    ///
+    /// # async fn run(client: Client) {
+    /// if let Some(stats) = client.shard_manager.shard_stats(ShardId(0)) {
+    ///     println!(
+    ///         "p95 latency: {:?}, messages/s: {:.2}, reconnects: {}",
+    ///         stats.latency.percentile(95.0),
+    ///         stats.events_per_second("MESSAGE_CREATE"),
+    ///         stats.reconnects,
+    ///     );
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn shard_stats(&self, shard_id: ShardId) -> Option<ShardStats> {
+        self.stats.read().get(&shard_id).map(|stats| stats.lock().clone())
+    }
+
+    /// Returns the health statistics of all shards started by this manager. See
+    /// [`Self::shard_stats`].
+    #[must_use]
+    pub fn all_shard_stats(&self) -> HashMap<ShardId, ShardStats> {
+        let stats = self.stats.read();
+        stats.iter().map(|(shard_id, stats)| (*shard_id, stats.lock().clone())).collect()
+    }
+
+    /// Returns the statistics of a shard, creating them if the shard has none yet, for the shard's
+    /// runner to record into.
+    pub(crate) fn stats_handle(&self, shard_id: ShardId) -> Arc<parking_lot::Mutex<ShardStats>> {
+        if let Some(stats) = self.stats.read().get(&shard_id) {
+            return Arc::clone(stats);
+        }
+
+        Arc::clone(self.stats.write().entry(shard_id).or_default())
+    }
+
     pub async fn return_with_value(&self, ret: Result<(), GatewayError>) {
         if let Err(e) = self.return_value_tx.lock().await.send(ret).await {
             tracing::warn!("failed to send return value: {}", e);
@@ -344,6 +625,43 @@ impl ShardManager {
     }
 }
 
+#[cfg(test)]
+impl ShardManager {
+    /// Creates a manager which never starts any shards, to test how events are dispatched.
+    pub(crate) fn new_for_test(
+        event_dispatch: DispatchMode,
+        event_queue: Option<EventQueueOptions>,
+    ) -> Arc<Self> {
+        let (manager, _) = Self::new(ShardManagerOptions {
+            data: Arc::new(()),
+            extensions: Arc::new(Extensions::new()),
+            event_handler: None,
+            raw_event_handler: None,
+            raw_payload_handler: None,
+            command_stats: None,
+            event_dispatch,
+            event_queue,
+            #[cfg(feature = "framework")]
+            framework: Arc::new(OnceLock::new()),
+            #[cfg(feature = "voice")]
+            voice_manager: None,
+            ws_url: Arc::from("wss://gateway.discord.gg"),
+            shard_total: NonZeroU16::MIN,
+            #[cfg(feature = "cache")]
+            cache: Arc::new(Cache::new()),
+            http: Arc::new(Http::new("token")),
+            intents: GatewayIntents::empty(),
+            presence: None,
+            filtered_events: EventTypeFlags::all(),
+            use_resume_url: true,
+            raw_gateway_hook: None,
+            max_concurrency: NonZeroU16::MIN,
+            event_bus: EventBus::new(),
+        });
+        manager
+    }
+}
+
 impl Drop for ShardManager {
     /// A custom drop implementation to clean up after the manager.
     ///
@@ -358,8 +676,21 @@ impl Drop for ShardManager {
 
 pub struct ShardManagerOptions {
     pub data: Arc<dyn std::any::Any + Send + Sync>,
+    pub extensions: Arc<Extensions>,
     pub event_handler: Option<Arc<dyn EventHandler>>,
     pub raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    pub raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
+    pub command_stats: Option<Arc<CommandStats>>,
+    /// How events are dispatched to the framework and event handlers, see
+    /// [`ClientBuilder::event_dispatch`].
+    ///
+    /// [`ClientBuilder::event_dispatch`]: crate::gateway::client::ClientBuilder::event_dispatch
+    pub event_dispatch: DispatchMode,
+    /// Bounds the number of events each shard keeps in memory, see
+    /// [`ClientBuilder::event_queue`].
+    ///
+    /// [`ClientBuilder::event_queue`]: crate::gateway::client::ClientBuilder::event_queue
+    pub event_queue: Option<EventQueueOptions>,
     #[cfg(feature = "framework")]
     pub framework: Arc<OnceLock<Arc<dyn Framework>>>,
     #[cfg(feature = "voice")]
@@ -371,5 +702,58 @@ pub struct ShardManagerOptions {
     pub http: Arc<Http>,
     pub intents: GatewayIntents,
     pub presence: Option<PresenceData>,
+    /// The events which are deserialized and dispatched, see [`Shard::set_filtered_events`].
+    ///
+    /// [`Shard::set_filtered_events`]: super::Shard::set_filtered_events
+    pub filtered_events: EventTypeFlags,
+    /// Whether shards resume sessions via the resume URL sent by Discord, see
+    /// [`Shard::set_use_resume_url`].
+    ///
+    /// [`Shard::set_use_resume_url`]: super::Shard::set_use_resume_url
+    pub use_resume_url: bool,
+    /// The hook invoked with every frame sent or received by the shards, see
+    /// [`ClientBuilder::raw_gateway_hook`].
+    ///
+    /// [`ClientBuilder::raw_gateway_hook`]: crate::gateway::client::ClientBuilder::raw_gateway_hook
+    pub raw_gateway_hook: Option<RawGatewayHook>,
     pub max_concurrency: NonZeroU16,
+    /// The bus all events received by the shards are published to, such as for collectors and
+    /// [`Client::events`].
+    ///
+    /// [`Client::events`]: crate::Client::events
+    pub event_bus: EventBus,
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::ShardManager;
+    use crate::gateway::client::DispatchMode;
+    use crate::gateway::ShardLifecycleEvent;
+    use crate::model::id::ShardId;
+
+    #[tokio::test]
+    async fn only_shutdowns_emit_dead() {
+        let manager = ShardManager::new_for_test(DispatchMode::Unbounded, None);
+        let mut events = std::pin::pin!(manager.lifecycle_events());
+
+        // No runners are started, so report the shutdowns as finished ahead of time
+        manager.shutdown_finished(ShardId(0));
+        manager.restart(ShardId(0)).await;
+        let (shard_id, event) = events.next().await.unwrap();
+        assert_eq!(shard_id, ShardId(0));
+        assert!(matches!(event, ShardLifecycleEvent::Reconnecting { .. }));
+
+        manager.shutdown_finished(ShardId(1));
+        manager.shutdown(ShardId(1), 1000).await;
+        loop {
+            let (shard_id, event) = events.next().await.unwrap();
+            if shard_id == ShardId(1) {
+                assert!(matches!(event, ShardLifecycleEvent::Dead));
+                break;
+            }
+            assert!(!matches!(event, ShardLifecycleEvent::Dead));
+        }
+    }
 }
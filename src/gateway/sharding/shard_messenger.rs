@@ -1,13 +1,13 @@
-#[cfg(feature = "collector")]
-use std::sync::Arc;
+use std::fmt;
 
 use futures::channel::mpsc::UnboundedSender as Sender;
+use tokio::sync::oneshot;
 use tokio_tungstenite::tungstenite::Message;
 
 #[cfg(feature = "collector")]
-use super::CollectorCallback;
+use super::{CollectorCallback, EventBus};
 use super::{ChunkGuildFilter, ShardRunner, ShardRunnerMessage};
-use crate::gateway::ActivityData;
+use crate::gateway::{ActivityData, PresenceData};
 use crate::model::prelude::*;
 
 /// A handle to a [`ShardRunner`].
@@ -21,7 +21,9 @@ use crate::model::prelude::*;
 pub struct ShardMessenger {
     pub(crate) tx: Sender<ShardRunnerMessage>,
     #[cfg(feature = "collector")]
-    pub(crate) collectors: Arc<parking_lot::RwLock<Vec<CollectorCallback>>>,
+    pub(crate) shard_id: ShardId,
+    #[cfg(feature = "collector")]
+    pub(crate) event_bus: EventBus,
 }
 
 impl ShardMessenger {
@@ -35,7 +37,9 @@ impl ShardMessenger {
         Self {
             tx: shard.runner_tx(),
             #[cfg(feature = "collector")]
-            collectors: Arc::clone(&shard.collectors),
+            shard_id: shard.shard.shard_info().id,
+            #[cfg(feature = "collector")]
+            event_bus: shard.event_bus.clone(),
         }
     }
 
@@ -98,7 +102,7 @@ impl ShardMessenger {
         filter: ChunkGuildFilter,
         nonce: Option<String>,
     ) {
-        self.send_to_shard(ShardRunnerMessage::ChunkGuild {
+        self.send_logged(ShardRunnerMessage::ChunkGuild {
             guild_id,
             limit,
             presences,
@@ -125,7 +129,7 @@ impl ShardMessenger {
     /// # }
     /// ```
     pub fn set_activity(&self, activity: Option<ActivityData>) {
-        self.send_to_shard(ShardRunnerMessage::SetActivity(activity));
+        self.send_logged(ShardRunnerMessage::SetActivity(activity));
     }
 
     /// Sets the user's full presence information.
@@ -152,7 +156,7 @@ impl ShardMessenger {
             status = OnlineStatus::Invisible;
         }
 
-        self.send_to_shard(ShardRunnerMessage::SetPresence(activity, status));
+        self.send_logged(ShardRunnerMessage::SetPresence(activity, status));
     }
 
     /// Sets the user's current online status.
@@ -184,12 +188,28 @@ impl ShardMessenger {
             online_status = OnlineStatus::Invisible;
         }
 
-        self.send_to_shard(ShardRunnerMessage::SetStatus(online_status));
+        self.send_logged(ShardRunnerMessage::SetStatus(online_status));
+    }
+
+    /// Replaces the user's presence on this shard, including all activities. See
+    /// [`PresenceBuilder`] for building one.
+    ///
+    /// [`PresenceBuilder`]: crate::gateway::PresenceBuilder
+    pub fn set_presence_data(&self, presence: impl Into<PresenceData>) {
+        self.send_logged(ShardRunnerMessage::SetPresenceData(presence.into()));
+    }
+
+    /// Replaces the user's presence on all shards of the [`ShardManager`] which is running this
+    /// shard, including shards started later on.
+    ///
+    /// [`ShardManager`]: super::ShardManager
+    pub fn set_presence_all(&self, presence: impl Into<PresenceData>) {
+        self.send_logged(ShardRunnerMessage::SetPresenceAll(presence.into()));
     }
 
     /// Shuts down the websocket by attempting to cleanly close the connection.
     pub fn shutdown_clean(&self) {
-        self.send_to_shard(ShardRunnerMessage::Close(1000, None));
+        self.send_logged(ShardRunnerMessage::Close(1000, None));
     }
 
     /// Sends a raw message over the WebSocket.
@@ -199,18 +219,95 @@ impl ShardMessenger {
     /// You should only use this if you know what you're doing. If you're wanting to, for example,
     /// send a presence update, prefer the usage of the [`Self::set_presence`] method.
     pub fn websocket_message(&self, message: Message) {
-        self.send_to_shard(ShardRunnerMessage::Message(message));
+        self.send_logged(ShardRunnerMessage::Message(message));
     }
 
-    /// Sends a message to the shard.
-    pub fn send_to_shard(&self, msg: ShardRunnerMessage) {
-        if let Err(e) = self.tx.unbounded_send(msg) {
+    /// Queues a message to be handled by the shard.
+    ///
+    /// Unlike the other methods, which log failures, this returns whether the message was queued.
+    /// The queue is unbounded, so queueing only fails once the shard's runner has stopped, such as
+    /// after the shard was shut down or restarted. Use [`Self::send_and_flush`] to also find out
+    /// whether the message was sent over the WebSocket.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShardSendError::Closed`] with the message if the shard's runner has stopped.
+    pub fn send_to_shard(&self, msg: ShardRunnerMessage) -> Result<(), ShardSendError> {
+        self.tx.unbounded_send(msg).map_err(|e| ShardSendError::Closed(Box::new(e.into_inner())))
+    }
+
+    /// Queues a message to be handled by the shard, and waits until it has been handled, such as
+    /// sent over the WebSocket.
+    ///
+    /// # Examples
+    ///
+    /// Reporting a presence update which could not be sent:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::gateway::{ActivityData, ShardMessenger, ShardRunnerMessage};
+    /// # async fn run(shard: ShardMessenger) {
+    /// let msg = ShardRunnerMessage::SetActivity(Some(ActivityData::playing("a game")));
+    /// if let Err(why) = shard.send_and_flush(msg).await {
+    ///     println!("Failed to set the activity: {why}");
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShardSendError::Closed`] with the message if the shard's runner has stopped, or
+    /// [`ShardSendError::Failed`] if the runner failed to handle it.
+    pub async fn send_and_flush(&self, msg: ShardRunnerMessage) -> Result<(), ShardSendError> {
+        let (tx, rx) = oneshot::channel();
+        self.send_to_shard(ShardRunnerMessage::Flush(Box::new(msg), tx)).map_err(|e| match e {
+            ShardSendError::Closed(msg) => match *msg {
+                ShardRunnerMessage::Flush(msg, _) => ShardSendError::Closed(msg),
+                msg => ShardSendError::Closed(Box::new(msg)),
+            },
+            e => e,
+        })?;
+
+        match rx.await {
+            Ok(true) => Ok(()),
+            Ok(false) | Err(_) => Err(ShardSendError::Failed),
+        }
+    }
+
+    fn send_logged(&self, msg: ShardRunnerMessage) {
+        if let Err(e) = self.send_to_shard(msg) {
             tracing::warn!("failed to send ShardRunnerMessage to shard: {}", e);
         }
     }
 
+    /// Subscribes the collector to the events received by this shard, see [`EventBus`].
     #[cfg(feature = "collector")]
     pub fn add_collector(&self, collector: CollectorCallback) {
-        self.collectors.write().push(collector);
+        let shard_id = self.shard_id;
+        self.event_bus.subscribe(move |event_shard_id, event| {
+            event_shard_id != shard_id || collector.0(event)
+        });
+    }
+}
+
+/// An error returned when a [`ShardRunnerMessage`] could not be handled by a shard, see
+/// [`ShardMessenger::send_to_shard`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ShardSendError {
+    /// The shard's runner has stopped, so the message was not queued. Contains the message.
+    Closed(Box<ShardRunnerMessage>),
+    /// The message was queued, but the shard's runner failed to handle it, for example because the
+    /// WebSocket connection was lost. The shard will be restarted.
+    Failed,
+}
+
+impl fmt::Display for ShardSendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed(_) => f.write_str("the shard runner has stopped"),
+            Self::Failed => f.write_str("the shard runner failed to handle the message"),
+        }
     }
 }
+
+impl std::error::Error for ShardSendError {}
@@ -11,7 +11,9 @@ use tokio::time::{sleep, timeout, Duration, Instant};
 use tracing::{debug, info, warn};
 
 use super::{
+    EventBus,
     ShardId,
+    ShardLifecycleEvent,
     ShardManager,
     ShardMessenger,
     ShardRunner,
@@ -22,13 +24,20 @@ use super::{
 use crate::cache::Cache;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::client::{EventHandler, RawEventHandler};
+use crate::gateway::client::{
+    CommandStats,
+    EventHandler,
+    Extensions,
+    RawEventHandler,
+    RawPayloadHandler,
+};
 #[cfg(feature = "voice")]
 use crate::gateway::VoiceGatewayManager;
-use crate::gateway::{ConnectionStage, PresenceData, Shard, ShardRunnerMessage};
+use crate::gateway::{ConnectionStage, PresenceData, RawGatewayHook, Shard, ShardRunnerMessage};
 use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
+use crate::model::event::EventTypeFlags;
 use crate::model::gateway::{GatewayIntents, ShardInfo};
 
 const WAIT_BETWEEN_BOOTS_IN_SECONDS: u64 = 5;
@@ -42,10 +51,19 @@ pub struct ShardQueuer {
     ///
     /// [`Client::data`]: crate::Client::data
     pub data: Arc<dyn std::any::Any + Send + Sync>,
+    /// A copy of the extensions registered via [`ClientBuilder::extension`], to be given to
+    /// runners for contextual dispatching.
+    ///
+    /// [`ClientBuilder::extension`]: crate::gateway::client::ClientBuilder::extension
+    pub extensions: Arc<Extensions>,
     /// A reference to an [`EventHandler`].
     pub event_handler: Option<Arc<dyn EventHandler>>,
     /// A reference to a [`RawEventHandler`].
     pub raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    /// A reference to a [`RawPayloadHandler`].
+    pub raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
+    /// The collector recording the usage of application commands.
+    pub command_stats: Option<Arc<CommandStats>>,
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<OnceLock<Arc<dyn Framework>>>,
@@ -73,6 +91,14 @@ pub struct ShardQueuer {
     pub http: Arc<Http>,
     pub intents: GatewayIntents,
     pub presence: Option<PresenceData>,
+    /// The events which are deserialized and dispatched by the shards.
+    pub filtered_events: EventTypeFlags,
+    /// Whether the shards resume sessions via the resume URL sent by Discord.
+    pub use_resume_url: bool,
+    /// The hook invoked with every frame sent or received by the shards.
+    pub raw_gateway_hook: Option<RawGatewayHook>,
+    /// The bus all events received by the shards are published to.
+    pub event_bus: EventBus,
 }
 
 impl ShardQueuer {
@@ -106,6 +132,9 @@ impl ShardQueuer {
                     Some(ShardQueuerMessage::SetShardTotal(shard_total)) => {
                         self.shard_total = shard_total;
                     },
+                    Some(ShardQueuerMessage::SetPresence(presence)) => {
+                        self.presence = Some(presence);
+                    },
                     Some(ShardQueuerMessage::Start {
                         shard_id,
                         concurrent,
@@ -173,6 +202,15 @@ impl ShardQueuer {
     async fn checked_start(&mut self, shard_id: ShardId) {
         debug!("[Shard Queuer] Checked start for shard {shard_id}");
 
+        // Single shards are only started when restarting them
+        let boot_wait = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
+        let backoff = self
+            .last_start
+            .map_or(Duration::ZERO, |instant| boot_wait.saturating_sub(instant.elapsed()));
+        self.manager.emit_lifecycle(shard_id, ShardLifecycleEvent::Reconnecting {
+            backoff,
+        });
+
         self.check_last_start().await;
         self.try_start(shard_id).await;
 
@@ -207,6 +245,8 @@ impl ShardQueuer {
 
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
     async fn start(&mut self, shard_id: ShardId) -> Result<()> {
+        self.manager.emit_lifecycle(shard_id, ShardLifecycleEvent::Connecting);
+
         let mut shard = Shard::new(
             Arc::clone(&self.ws_url),
             Arc::clone(self.http.token()),
@@ -216,13 +256,20 @@ impl ShardQueuer {
         )
         .await?;
 
+        shard.set_filtered_events(self.filtered_events);
+        shard.set_use_resume_url(self.use_resume_url);
+        shard.set_raw_gateway_hook(self.raw_gateway_hook);
+
         let cloned_http = Arc::clone(&self.http);
         shard.set_application_id_callback(move |id| cloned_http.set_application_id(id));
 
         let mut runner = ShardRunner::new(ShardRunnerOptions {
             data: Arc::clone(&self.data),
+            extensions: Arc::clone(&self.extensions),
             event_handler: self.event_handler.clone(),
             raw_event_handler: self.raw_event_handler.clone(),
+            raw_payload_handler: self.raw_payload_handler.clone(),
+            command_stats: self.command_stats.clone(),
             #[cfg(feature = "framework")]
             framework: self.framework.get().cloned(),
             manager: Arc::clone(&self.manager),
@@ -232,6 +279,7 @@ impl ShardQueuer {
             #[cfg(feature = "cache")]
             cache: Arc::clone(&self.cache),
             http: Arc::clone(&self.http),
+            event_bus: self.event_bus.clone(),
         });
 
         let runner_info = ShardRunnerInfo {
@@ -342,6 +390,8 @@ impl ShardQueue {
 pub enum ShardQueuerMessage {
     /// Message to set the shard total.
     SetShardTotal(NonZeroU16),
+    /// Message to set the presence used by shards started from now on.
+    SetPresence(PresenceData),
     /// Message to start a shard.
     Start { shard_id: ShardId, concurrent: bool },
     /// Message to shutdown the shard queuer.
@@ -1,28 +1,58 @@
 use std::borrow::Cow;
 use std::sync::Arc;
+use std::time::Instant;
 
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
+use futures::FutureExt as _;
+use tokio::sync::oneshot;
 use tokio_tungstenite::tungstenite;
 use tokio_tungstenite::tungstenite::error::Error as TungsteniteError;
 use tokio_tungstenite::tungstenite::protocol::frame::CloseFrame;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, trace, warn};
 
-#[cfg(feature = "collector")]
-use super::CollectorCallback;
-use super::{ReconnectType, Shard, ShardAction, ShardId, ShardManager, ShardStageUpdateEvent};
+use super::{
+    EventBus,
+    EventQueue,
+    ReconnectType,
+    Shard,
+    ShardAction,
+    ShardDisconnectEvent,
+    ShardDisconnectReason,
+    ShardId,
+    ShardLifecycleEvent,
+    ShardManager,
+    ShardMessenger,
+    ShardStageUpdateEvent,
+    ShardStats,
+};
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::client::dispatch::dispatch_model;
-use crate::gateway::client::{Context, EventHandler, RawEventHandler};
+use crate::gateway::client::dispatch::{
+    dispatch_model,
+    dispatch_prepared,
+    event_channel_id,
+    prepare_event,
+    LifecycleQueue,
+    RawPayloadQueue,
+};
+use crate::gateway::client::{
+    CommandStats,
+    Context,
+    EventHandler,
+    Extensions,
+    FullEvent,
+    RawEventHandler,
+    RawPayloadHandler,
+    ShardState,
+};
 #[cfg(feature = "voice")]
 use crate::gateway::VoiceGatewayManager;
-use crate::gateway::{ActivityData, ChunkGuildFilter, GatewayError};
+use crate::gateway::{ActivityData, ChunkGuildFilter, GatewayError, PresenceData};
 use crate::http::Http;
 use crate::internal::prelude::*;
-use crate::internal::tokio::spawn_named;
 use crate::model::event::{Event, GatewayEvent};
 use crate::model::id::GuildId;
 use crate::model::user::OnlineStatus;
@@ -30,11 +60,17 @@ use crate::model::user::OnlineStatus;
 /// A runner for managing a [`Shard`] and its respective WebSocket client.
 pub struct ShardRunner {
     data: Arc<dyn std::any::Any + Send + Sync>,
+    extensions: Arc<Extensions>,
+    shard_state: ShardState,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    raw_payloads: Option<Arc<RawPayloadQueue>>,
+    lifecycle_events: Option<Arc<LifecycleQueue>>,
+    command_stats: Option<Arc<CommandStats>>,
     #[cfg(feature = "framework")]
     framework: Option<Arc<dyn Framework>>,
     manager: Arc<ShardManager>,
+    stats: Arc<parking_lot::Mutex<ShardStats>>,
     // channel to receive messages from the shard manager and dispatches
     runner_rx: Receiver<ShardRunnerMessage>,
     // channel to send messages to the shard runner from the shard manager
@@ -45,8 +81,8 @@ pub struct ShardRunner {
     #[cfg(feature = "cache")]
     pub cache: Arc<Cache>,
     pub http: Arc<Http>,
-    #[cfg(feature = "collector")]
-    pub(crate) collectors: Arc<parking_lot::RwLock<Vec<CollectorCallback>>>,
+    pub(crate) event_bus: EventBus,
+    event_queue: Option<Arc<EventQueue>>,
 }
 
 impl ShardRunner {
@@ -54,14 +90,53 @@ impl ShardRunner {
     pub fn new(opt: ShardRunnerOptions) -> Self {
         let (tx, rx) = mpsc::unbounded();
 
+        let event_queue = opt.manager.event_queue_options().map(|options| {
+            #[cfg(feature = "framework")]
+            let framework = opt.framework.clone();
+            let event_handler = opt.event_handler.clone();
+            let raw_event_handler = opt.raw_event_handler.clone();
+            let command_stats = opt.command_stats.clone();
+
+            EventQueue::new(
+                opt.shard.shard_info().id,
+                options.clone(),
+                Arc::clone(&opt.manager),
+                Box::new(move |prepared, context| {
+                    dispatch_prepared(
+                        prepared,
+                        context,
+                        #[cfg(feature = "framework")]
+                        framework.clone(),
+                        event_handler.clone(),
+                        raw_event_handler.clone(),
+                        command_stats.clone(),
+                    )
+                    .boxed()
+                }),
+            )
+        });
+
+        let raw_payloads = opt
+            .raw_payload_handler
+            .map(|handler| RawPayloadQueue::raw_payloads(handler, Arc::clone(&opt.manager)));
+        let lifecycle_events = opt.event_handler.clone().map(|handler| {
+            LifecycleQueue::lifecycle_events(handler, Arc::clone(&opt.manager))
+        });
+
         Self {
             runner_rx: rx,
             runner_tx: tx,
             data: opt.data,
+            extensions: opt.extensions,
+            shard_state: ShardState::default(),
             event_handler: opt.event_handler,
             raw_event_handler: opt.raw_event_handler,
+            raw_payloads,
+            lifecycle_events,
+            command_stats: opt.command_stats,
             #[cfg(feature = "framework")]
             framework: opt.framework,
+            stats: opt.manager.stats_handle(opt.shard.shard_info().id),
             manager: opt.manager,
             shard: opt.shard,
             #[cfg(feature = "voice")]
@@ -69,8 +144,8 @@ impl ShardRunner {
             #[cfg(feature = "cache")]
             cache: opt.cache,
             http: opt.http,
-            #[cfg(feature = "collector")]
-            collectors: Arc::new(parking_lot::RwLock::new(vec![])),
+            event_bus: opt.event_bus,
+            event_queue,
         }
     }
 
@@ -111,6 +186,7 @@ impl ShardRunner {
             if !self.shard.do_heartbeat().await {
                 warn!("[ShardRunner {:?}] Error heartbeating", self.shard.shard_info(),);
 
+                self.dispatch_disconnect(ShardDisconnectReason::HeartbeatFailed, false);
                 self.request_restart().await;
                 return Ok(());
             }
@@ -131,12 +207,34 @@ impl ShardRunner {
                         shard_id: self.shard.shard_info().id,
                     };
 
-                    spawn_named("dispatch::event_handler::shard_stage_update", async move {
-                        event_handler.shard_stage_update(context, event).await;
-                    });
+                    self.manager.spawn_tracked(
+                        "dispatch::event_handler::shard_stage_update",
+                        async move {
+                            event_handler.shard_stage_update(context, event).await;
+                        },
+                    );
                 }
             }
 
+            let shard_id = self.shard.shard_info().id;
+            let lifecycle_event = match &event {
+                Some(Event::Ready(_)) => {
+                    Some((ShardLifecycleEvent::Identified, FullEvent::ShardReady {
+                        shard_id,
+                    }))
+                },
+                Some(Event::Resumed(_)) => {
+                    Some((ShardLifecycleEvent::Resumed, FullEvent::ShardResume {
+                        shard_id,
+                    }))
+                },
+                _ => None,
+            };
+            if let Some((lifecycle_event, full_event)) = lifecycle_event {
+                self.manager.emit_lifecycle(shard_id, lifecycle_event);
+                self.dispatch_lifecycle(full_event);
+            }
+
             match action {
                 Some(ShardAction::Reconnect(ReconnectType::Reidentify)) => {
                     self.request_restart().await;
@@ -156,7 +254,7 @@ impl ShardRunner {
                                 return Ok(());
                             },
                             ReconnectType::Resume => {
-                                if let Err(why) = self.shard.resume().await {
+                                if let Err(why) = self.resume().await {
                                     warn!(
                                         "[ShardRunner {:?}] Resume failed, reidentifying: {:?}",
                                         self.shard.shard_info(),
@@ -173,7 +271,8 @@ impl ShardRunner {
                 None => {},
             }
 
-            if let Some(event) = event {
+            // Events received during a graceful shutdown are dropped
+            if let Some(event) = event.filter(|_| !self.manager.is_shutting_down()) {
                 let context = self.make_context();
                 let can_dispatch = self
                     .event_handler
@@ -185,33 +284,26 @@ impl ShardRunner {
                         .map_or(true, |handler| handler.filter_event(&context, &event));
 
                 if can_dispatch {
-                    #[cfg(feature = "collector")]
-                    {
-                        let read_lock = self.collectors.read();
-                        // search all collectors to be removed and clone the Arcs
-                        let to_remove: Vec<_> = read_lock
-                            .iter()
-                            .filter(|callback| !callback.0(&event))
-                            .cloned()
-                            .collect();
-                        drop(read_lock);
-                        // remove all found arcs from the collection
-                        // this compares the inner pointer of the Arc
-                        if !to_remove.is_empty() {
-                            self.collectors.write().retain(|f| !to_remove.contains(f));
-                        }
+                    self.event_bus.publish(self.shard.shard_info().id, &event);
+                    if let Some(event_queue) = &self.event_queue {
+                        // The cache is updated before queueing, so shed events still reach it
+                        let keep_raw = self.raw_event_handler.is_some();
+                        let prepared = prepare_event(&context, event, keep_raw);
+                        event_queue.push(prepared, context).await;
+                    } else {
+                        self.manager.spawn_dispatch(
+                            event_channel_id(&event),
+                            dispatch_model(
+                                event,
+                                context,
+                                #[cfg(feature = "framework")]
+                                self.framework.clone(),
+                                self.event_handler.clone(),
+                                self.raw_event_handler.clone(),
+                                self.command_stats.clone(),
+                            ),
+                        );
                     }
-                    spawn_named(
-                        "shard_runner::dispatch",
-                        dispatch_model(
-                            event,
-                            context,
-                            #[cfg(feature = "framework")]
-                            self.framework.clone(),
-                            self.event_handler.clone(),
-                            self.raw_event_handler.clone(),
-                        ),
-                    );
                 }
             }
 
@@ -243,7 +335,7 @@ impl ShardRunner {
                 self.request_restart().await;
                 Ok(())
             },
-            ShardAction::Reconnect(ReconnectType::Resume) => self.shard.resume().await,
+            ShardAction::Reconnect(ReconnectType::Resume) => self.resume().await,
             ShardAction::Heartbeat => self.shard.heartbeat().await,
             ShardAction::Identify => self.shard.identify().await,
         }
@@ -295,10 +387,28 @@ impl ShardRunner {
         false
     }
 
+    /// Marks all cached guilds handled by this shard as stale, so they are refreshed over HTTP, if
+    /// enabled in the cache settings.
+    #[cfg(feature = "cache")]
+    fn mark_guilds_stale(&self) {
+        if self.cache.settings().refresh_stale_guilds_interval.is_none() {
+            return;
+        }
+
+        let shard_info = self.shard.shard_info();
+        for guild_id in self.cache.guilds() {
+            if guild_id.shard_id(shard_info.total) == shard_info.id.0 {
+                self.cache.mark_guild_stale(guild_id);
+            }
+        }
+    }
+
     fn make_context(&self) -> Context {
         Context::new(
             Arc::clone(&self.data),
-            self,
+            Arc::clone(&self.extensions),
+            self.shard_state.clone(),
+            ShardMessenger::new(self),
             self.shard.shard_info().id,
             Arc::clone(&self.http),
             #[cfg(feature = "cache")]
@@ -349,6 +459,20 @@ impl ShardRunner {
                 self.shard.set_status(status);
                 self.shard.update_presence().await.is_ok()
             },
+            ShardRunnerMessage::SetPresenceData(presence) => {
+                self.shard.set_presence_data(presence);
+                self.shard.update_presence().await.is_ok()
+            },
+            ShardRunnerMessage::SetPresenceAll(presence) => {
+                self.manager.set_presence_all(presence).await;
+                true
+            },
+            ShardRunnerMessage::Flush(msg, handled) => {
+                let can_continue = Box::pin(self.handle_rx_value(*msg)).await;
+                // The messenger may have stopped waiting for the result.
+                handled.send(can_continue).ok();
+                can_continue
+            },
         }
     }
 
@@ -422,10 +546,18 @@ impl ShardRunner {
             Err(Error::Tungstenite(tung_err)) if matches!(*tung_err, TungsteniteError::Io(_)) => {
                 debug!("Attempting to auto-reconnect");
 
+                let shard_id = self.shard.shard_info().id;
+                self.manager.emit_lifecycle(shard_id, ShardLifecycleEvent::Disconnected {
+                    code: None,
+                });
+
+                let resumable = matches!(self.shard.reconnection_type(), ReconnectType::Resume);
+                self.dispatch_disconnect(ShardDisconnectReason::ConnectionLost, resumable);
+
                 match self.shard.reconnection_type() {
                     ReconnectType::Reidentify => return Ok((None, None, false)),
                     ReconnectType::Resume => {
-                        if let Err(why) = self.shard.resume().await {
+                        if let Err(why) = self.resume().await {
                             warn!("Failed to resume: {:?}", why);
 
                             // Don't spam reattempts on internet connection loss
@@ -441,8 +573,52 @@ impl ShardRunner {
             Err(why) => Err(why),
         };
 
+        if let Err(Error::Gateway(GatewayError::Closed(frame))) = &gateway_event {
+            let code = frame.as_ref().map(|frame| frame.code.into());
+            let shard_id = self.shard.shard_info().id;
+            self.manager.emit_lifecycle(shard_id, ShardLifecycleEvent::Disconnected {
+                code,
+            });
+        }
+
         let is_ack = matches!(gateway_event, Ok(GatewayEvent::HeartbeatAck));
-        let (action, event) = match self.shard.handle_event(gateway_event) {
+        let disconnect_reason = match &gateway_event {
+            Ok(GatewayEvent::InvalidateSession(_)) => Some(ShardDisconnectReason::InvalidSession),
+            Ok(GatewayEvent::Reconnect) => Some(ShardDisconnectReason::ReconnectRequested),
+            Err(Error::Gateway(GatewayError::Closed(frame))) => {
+                Some(ShardDisconnectReason::Closed {
+                    code: frame.as_ref().map(|frame| frame.code.into()),
+                })
+            },
+            _ => None,
+        };
+        #[cfg(feature = "cache")]
+        let seq_before = self.shard.seq();
+        let raw_payloads = self.raw_payloads.as_ref().filter(|_| !self.manager.is_shutting_down());
+        let result = match (raw_payloads, gateway_event) {
+            (
+                Some(raw_payloads),
+                Ok(GatewayEvent::Dispatch {
+                    seq,
+                    data,
+                    original_str,
+                }),
+            ) => {
+                // Only move the payload to the raw payload handler once the event is
+                // deserialized, so it's still logged if that fails, without copying it.
+                let result = self.shard.handle_gateway_dispatch(seq, data, &original_str);
+                raw_payloads.push((original_str, self.make_context()));
+                result
+            },
+            (_, gateway_event) => self.shard.handle_event(gateway_event),
+        };
+        if let Some(reason) = disconnect_reason {
+            let resumable =
+                matches!(result, Ok((Some(ShardAction::Reconnect(ReconnectType::Resume)), _)));
+            self.dispatch_disconnect(reason, resumable);
+        }
+
+        let (action, event) = match result {
             Ok((action, event)) => (action, event),
             Err(Error::Gateway(
                 why @ (GatewayError::InvalidAuthentication
@@ -451,6 +627,8 @@ impl ShardRunner {
             )) => {
                 error!("Shard handler received fatal err: {why:?}");
 
+                let shard_id = self.shard.shard_info().id;
+                self.manager.emit_lifecycle(shard_id, ShardLifecycleEvent::Dead);
                 self.manager.return_with_value(Err(why.clone())).await;
                 return Err(Error::Gateway(why));
             },
@@ -462,9 +640,22 @@ impl ShardRunner {
         };
 
         if is_ack {
+            self.stats.lock().record_heartbeat_ack(self.shard.latency());
             self.update_manager().await;
         }
 
+        if let Some(event) = &event {
+            self.stats.lock().record_event(event.name(), Instant::now());
+        }
+
+        #[cfg(feature = "cache")]
+        if let Some(event) = &event {
+            let missed_events = self.shard.seq() > seq_before + 1;
+            if missed_events || matches!(event, Event::Resumed(_)) {
+                self.mark_guilds_stale();
+            }
+        }
+
         #[cfg(feature = "voice")]
         {
             if let Some(event) = &event {
@@ -475,6 +666,29 @@ impl ShardRunner {
         Ok((event, action, true))
     }
 
+    fn dispatch_disconnect(&self, reason: ShardDisconnectReason, resumable: bool) {
+        self.dispatch_lifecycle(FullEvent::ShardDisconnect {
+            event: ShardDisconnectEvent {
+                shard_id: self.shard.shard_info().id,
+                reason,
+                resumable,
+            },
+        });
+    }
+
+    /// Dispatches a lifecycle event to the event handler, after the ones dispatched before it.
+    fn dispatch_lifecycle(&self, event: FullEvent) {
+        if let Some(lifecycle_events) = &self.lifecycle_events {
+            lifecycle_events.push((event, self.make_context()));
+        }
+    }
+
+    #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
+    async fn resume(&mut self) -> Result<()> {
+        self.manager.emit_lifecycle(self.shard.shard_info().id, ShardLifecycleEvent::Resuming);
+        self.shard.resume().await
+    }
+
     #[cfg_attr(feature = "tracing_instrument", instrument(skip(self)))]
     async fn request_restart(&mut self) {
         debug!("[ShardRunner {:?}] Requesting restart", self.shard.shard_info());
@@ -505,8 +719,11 @@ impl ShardRunner {
 /// Options to be passed to [`ShardRunner::new`].
 pub struct ShardRunnerOptions {
     pub data: Arc<dyn std::any::Any + Send + Sync>,
+    pub extensions: Arc<Extensions>,
     pub event_handler: Option<Arc<dyn EventHandler>>,
     pub raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    pub raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
+    pub command_stats: Option<Arc<CommandStats>>,
     #[cfg(feature = "framework")]
     pub framework: Option<Arc<dyn Framework>>,
     pub manager: Arc<ShardManager>,
@@ -516,6 +733,7 @@ pub struct ShardRunnerOptions {
     #[cfg(feature = "cache")]
     pub cache: Arc<Cache>,
     pub http: Arc<Http>,
+    pub event_bus: EventBus,
 }
 
 /// A message to send from a shard over a WebSocket.
@@ -563,4 +781,16 @@ pub enum ShardRunnerMessage {
     SetPresence(Option<ActivityData>, OnlineStatus),
     /// Indicates that the client is to update the shard's presence's status.
     SetStatus(OnlineStatus),
+    /// Indicates that the client is to replace the shard's presence, including all activities.
+    SetPresenceData(PresenceData),
+    /// Indicates that the client is to replace the presence of all shards, via the
+    /// [`ShardManager`].
+    ///
+    /// [`ShardManager`]: super::ShardManager
+    SetPresenceAll(PresenceData),
+    /// Indicates that the client is to handle the given message, and then report whether it
+    /// succeeded, such as whether it was sent over the WebSocket.
+    ///
+    /// Used by [`ShardMessenger::send_and_flush`].
+    Flush(Box<ShardRunnerMessage>, oneshot::Sender<bool>),
 }
@@ -0,0 +1,269 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use super::ShardLifecycleEvent;
+
+/// The number of heartbeat latencies kept by a [`LatencyHistogram`].
+const LATENCY_SAMPLES: usize = 100;
+
+/// The timespan over which event rates are calculated.
+const EVENT_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Health statistics of a single shard, as returned by [`ShardManager::shard_stats`].
+///
+/// Statistics are kept across restarts of the shard, so e.g. [`Self::reconnects`] keeps growing
+/// for a shard that is repeatedly restarted.
+///
+/// [`ShardManager::shard_stats`]: super::ShardManager::shard_stats
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ShardStats {
+    /// When the last heartbeat acknowledgement was received from the gateway.
+    pub last_heartbeat_ack: Option<Instant>,
+    /// The heartbeat latencies of the most recent heartbeats.
+    pub latency: LatencyHistogram,
+    /// The number of times the shard reconnected, either by resuming or by restarting.
+    pub reconnects: u64,
+    /// The number of events shed because the shard's event queue was full, see
+    /// [`EventQueueOptions`].
+    ///
+    /// [`EventQueueOptions`]: super::EventQueueOptions
+    pub events_shed: u64,
+    /// The most recent lifecycle event of the shard.
+    pub lifecycle: Option<ShardLifecycleEvent>,
+    events: HashMap<&'static str, EventCounter>,
+}
+
+impl ShardStats {
+    /// Returns the total number of events received with the given name, such as
+    /// `"MESSAGE_CREATE"`.
+    ///
+    /// See [`Event::name`] for the names of events.
+    ///
+    /// [`Event::name`]: crate::model::event::Event::name
+    #[must_use]
+    pub fn event_count(&self, name: &str) -> u64 {
+        self.events.get(name).map_or(0, |counter| counter.total)
+    }
+
+    /// Returns the average number of events received per second with the given name, over the
+    /// last minute.
+    #[must_use]
+    pub fn events_per_second(&self, name: &str) -> f64 {
+        let now = Instant::now();
+        self.events.get(name).map_or(0.0, |counter| counter.per_second(now))
+    }
+
+    /// Returns the average number of events received per second over the last minute, regardless
+    /// of their type.
+    #[must_use]
+    pub fn total_events_per_second(&self) -> f64 {
+        let now = Instant::now();
+        self.events.values().map(|counter| counter.per_second(now)).sum()
+    }
+
+    /// Returns the names of all events received by the shard so far.
+    pub fn event_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.events.keys().copied()
+    }
+
+    pub(crate) fn record_event(&mut self, name: &'static str, now: Instant) {
+        self.events.entry(name).or_default().record(now);
+    }
+
+    pub(crate) fn record_heartbeat_ack(&mut self, latency: Option<Duration>) {
+        self.last_heartbeat_ack = Some(Instant::now());
+        if let Some(latency) = latency {
+            self.latency.record(latency);
+        }
+    }
+}
+
+/// A rolling histogram of the heartbeat latencies of a shard, keeping the most recent 100
+/// samples.
+#[derive(Clone, Debug, Default)]
+pub struct LatencyHistogram {
+    samples: VecDeque<Duration>,
+}
+
+impl LatencyHistogram {
+    /// The upper bounds of the buckets returned by [`Self::buckets`], excluding the final bucket
+    /// which holds all latencies above the last bound.
+    pub const BUCKET_BOUNDS: [Duration; 6] = [
+        Duration::from_millis(25),
+        Duration::from_millis(50),
+        Duration::from_millis(100),
+        Duration::from_millis(250),
+        Duration::from_millis(500),
+        Duration::from_secs(1),
+    ];
+
+    /// Returns the number of latencies in the histogram.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether no latencies were recorded yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the most recently recorded latency.
+    #[must_use]
+    pub fn latest(&self) -> Option<Duration> {
+        self.samples.back().copied()
+    }
+
+    /// Returns the lowest latency in the histogram.
+    #[must_use]
+    pub fn min(&self) -> Option<Duration> {
+        self.samples.iter().min().copied()
+    }
+
+    /// Returns the highest latency in the histogram.
+    #[must_use]
+    pub fn max(&self) -> Option<Duration> {
+        self.samples.iter().max().copied()
+    }
+
+    /// Returns the average latency in the histogram.
+    #[must_use]
+    pub fn mean(&self) -> Option<Duration> {
+        let len = u32::try_from(self.samples.len()).ok().filter(|len| *len > 0)?;
+        Some(self.samples.iter().sum::<Duration>() / len)
+    }
+
+    /// Returns the latency at the given percentile, from `0.0` to `100.0`, using the nearest-rank
+    /// method. For example, a percentile of `50.0` returns the median.
+    #[must_use]
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = self.samples.iter().copied().collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        // The histogram never holds more than LATENCY_SAMPLES latencies, so this can't truncate
+        let len = f64::from(u32::try_from(sorted.len()).unwrap_or(u32::MAX));
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * len).ceil() as usize;
+        Some(sorted[rank.saturating_sub(1)])
+    }
+
+    /// Returns the number of latencies falling into each bucket, along with the upper bound of
+    /// that bucket. The final bucket has an upper bound of [`Duration::MAX`].
+    ///
+    /// See [`Self::BUCKET_BOUNDS`] for the bounds used.
+    #[must_use]
+    pub fn buckets(&self) -> Vec<(Duration, usize)> {
+        let mut buckets = Self::BUCKET_BOUNDS
+            .iter()
+            .chain(std::iter::once(&Duration::MAX))
+            .map(|bound| (*bound, 0))
+            .collect::<Vec<_>>();
+
+        for sample in &self.samples {
+            if let Some((_, count)) = buckets.iter_mut().find(|(bound, _)| sample <= bound) {
+                *count += 1;
+            }
+        }
+
+        buckets
+    }
+
+    /// Returns an iterator over the latencies in the histogram, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = Duration> + '_ {
+        self.samples.iter().copied()
+    }
+
+    pub(crate) fn record(&mut self, latency: Duration) {
+        if self.samples.len() == LATENCY_SAMPLES {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back(latency);
+    }
+}
+
+/// Counts the events of a single type, in buckets of one second for calculating its rate.
+#[derive(Clone, Debug, Default)]
+struct EventCounter {
+    total: u64,
+    buckets: VecDeque<(Instant, u64)>,
+}
+
+impl EventCounter {
+    fn record(&mut self, now: Instant) {
+        self.total += 1;
+
+        match self.buckets.back_mut() {
+            Some((start, count)) if now.duration_since(*start) < Duration::from_secs(1) => {
+                *count += 1;
+            },
+            _ => self.buckets.push_back((now, 1)),
+        }
+
+        while self
+            .buckets
+            .front()
+            .is_some_and(|(start, _)| now.duration_since(*start) >= EVENT_RATE_WINDOW)
+        {
+            self.buckets.pop_front();
+        }
+    }
+
+    fn per_second(&self, now: Instant) -> f64 {
+        let count: u64 = self
+            .buckets
+            .iter()
+            .filter(|(start, _)| now.duration_since(*start) < EVENT_RATE_WINDOW)
+            .map(|(_, count)| count)
+            .sum();
+
+        f64::from(u32::try_from(count).unwrap_or(u32::MAX)) / EVENT_RATE_WINDOW.as_secs_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_histogram() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.percentile(50.0), None);
+
+        for millis in 1..=(LATENCY_SAMPLES as u64 + 10) {
+            histogram.record(Duration::from_millis(millis));
+        }
+
+        assert_eq!(histogram.len(), LATENCY_SAMPLES);
+        assert_eq!(histogram.min(), Some(Duration::from_millis(11)));
+        assert_eq!(histogram.latest(), Some(Duration::from_millis(110)));
+        assert_eq!(histogram.percentile(50.0), Some(Duration::from_millis(60)));
+        assert_eq!(histogram.percentile(100.0), Some(Duration::from_millis(110)));
+
+        let buckets = histogram.buckets();
+        assert_eq!(buckets[0], (Duration::from_millis(25), 15));
+        assert_eq!(buckets[2], (Duration::from_millis(100), 50));
+        assert_eq!(buckets[3], (Duration::from_millis(250), 10));
+        assert_eq!(buckets.iter().map(|(_, count)| count).sum::<usize>(), LATENCY_SAMPLES);
+    }
+
+    #[test]
+    fn event_counter_window() {
+        let start = Instant::now();
+        let mut counter = EventCounter::default();
+
+        for _ in 0..30 {
+            counter.record(start);
+        }
+        counter.record(start + Duration::from_secs(30));
+
+        assert_eq!(counter.total, 31);
+        assert!((counter.per_second(start + Duration::from_secs(30)) - 31.0 / 60.0).abs() < 1e-9);
+        assert!((counter.per_second(start + Duration::from_secs(70)) - 1.0 / 60.0).abs() < 1e-9);
+    }
+}
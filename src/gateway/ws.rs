@@ -1,9 +1,11 @@
+use std::borrow::Cow;
 use std::env::consts;
 use std::io::Read;
 use std::time::SystemTime;
 
 use flate2::read::ZlibDecoder;
 use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
 use small_fixed_array::FixedString;
 use tokio::net::TcpStream;
 use tokio::time::{timeout, Duration};
@@ -15,9 +17,10 @@ use url::Url;
 
 use super::{ActivityData, ChunkGuildFilter, GatewayError, PresenceData};
 use crate::constants::{self, Opcode};
-use crate::model::event::GatewayEvent;
+use crate::internal::prelude::JsonMap;
+use crate::model::event::{EventTypeFlags, GatewayEvent};
 use crate::model::gateway::{GatewayIntents, ShardInfo};
-use crate::model::id::{GuildId, UserId};
+use crate::model::id::{GuildId, ShardId, UserId};
 use crate::{Error, Result};
 
 #[derive(Serialize)]
@@ -75,7 +78,71 @@ struct WebSocketMessage<'a> {
     d: WebSocketMessageData<'a>,
 }
 
-pub struct WsClient(WebSocketStream<MaybeTlsStream<TcpStream>>);
+/// The direction of a websocket frame passed to a [`RawGatewayHook`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GatewayDirection {
+    /// A frame received from the gateway.
+    Inbound,
+    /// A frame sent to the gateway.
+    Outbound,
+}
+
+/// A function invoked with the payload of every text and binary websocket frame sent or received
+/// by a shard, set via [`ClientBuilder::raw_gateway_hook`]. Inbound compressed frames are passed
+/// after decompression.
+///
+/// [`ClientBuilder::raw_gateway_hook`]: crate::gateway::client::ClientBuilder::raw_gateway_hook
+pub type RawGatewayHook = fn(ShardId, GatewayDirection, &[u8]);
+
+pub struct WsClient {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    raw_hook: Option<(ShardId, RawGatewayHook)>,
+    filtered_events: EventTypeFlags,
+}
+
+/// The fields of a gateway payload needed to decide whether to deserialize it, borrowed from the
+/// frame so checking them doesn't allocate.
+#[derive(Deserialize)]
+struct PayloadHeader<'a> {
+    op: Opcode,
+    s: Option<u64>,
+    #[serde(borrow)]
+    t: Option<Cow<'a, str>>,
+}
+
+/// Returns the sequence number of a dispatch payload if its event is not in `filtered_events`.
+/// Returns [`None`] for other payloads, and for events unknown to serenity.
+fn filtered_dispatch_seq(payload: &str, filtered_events: EventTypeFlags) -> Option<u64> {
+    let header: PayloadHeader<'_> = serde_json::from_str(payload).ok()?;
+    if header.op != Opcode::Dispatch {
+        return None;
+    }
+
+    let kind = EventTypeFlags::from_dispatch_name(header.t.as_deref()?)?;
+    if filtered_events.contains(kind) {
+        return None;
+    }
+
+    header.s
+}
+
+/// Replaces the token of an outbound IDENTIFY or RESUME payload, so it is never passed to the raw
+/// gateway hook. Returns [`None`] if the payload does not contain a token.
+fn redact_token(payload: &str) -> Option<String> {
+    if !payload.contains("\"token\"") {
+        return None;
+    }
+
+    let mut value: serde_json::Value = serde_json::from_str(payload).ok()?;
+    let op = Opcode::deserialize(value.get("op")?).ok()?;
+    if !matches!(op, Opcode::Identify | Opcode::Resume) {
+        return None;
+    }
+
+    *value.get_mut("d")?.get_mut("token")? = serde_json::Value::from("[redacted]");
+    serde_json::to_string(&value).ok()
+}
 
 const TIMEOUT: Duration = Duration::from_millis(500);
 const DECOMPRESSION_MULTIPLIER: usize = 3;
@@ -89,11 +156,48 @@ impl WsClient {
         };
         let (stream, _) = connect_async_with_config(url, Some(config), false).await?;
 
-        Ok(Self(stream))
+        Ok(Self {
+            stream,
+            raw_hook: None,
+            filtered_events: EventTypeFlags::all(),
+        })
+    }
+
+    /// Sets the events which are deserialized. Dispatches of other events are returned without
+    /// any data, see [`GatewayEvent::Dispatch`].
+    pub(crate) fn set_filtered_events(&mut self, events: EventTypeFlags) {
+        self.filtered_events = events;
+    }
+
+    /// Sets the hook invoked with every frame sent or received by this client.
+    pub(crate) fn set_raw_hook(&mut self, shard_id: ShardId, hook: Option<RawGatewayHook>) {
+        self.raw_hook = hook.map(|hook| (shard_id, hook));
+    }
+
+    fn call_raw_hook(&self, direction: GatewayDirection, payload: &[u8]) {
+        if let Some((shard_id, hook)) = self.raw_hook {
+            hook(shard_id, direction, payload);
+        }
+    }
+
+    fn call_outbound_hook(&self, message: &Message) {
+        if self.raw_hook.is_none() {
+            return;
+        }
+
+        match message {
+            Message::Text(payload) => {
+                let redacted = redact_token(payload);
+                let payload = redacted.as_deref().unwrap_or(payload);
+                self.call_raw_hook(GatewayDirection::Outbound, payload.as_bytes());
+            },
+            Message::Binary(payload) => self.call_raw_hook(GatewayDirection::Outbound, payload),
+            _ => {},
+        }
     }
 
     pub(crate) async fn recv_json(&mut self) -> Result<Option<GatewayEvent>> {
-        let message = match timeout(TIMEOUT, self.0.next()).await {
+        let message = match timeout(TIMEOUT, self.stream.next()).await {
             Ok(Some(Ok(msg))) => msg,
             Ok(Some(Err(e))) => return Err(e.into()),
             Ok(None) | Err(_) => return Ok(None),
@@ -120,6 +224,19 @@ impl WsClient {
             _ => return Ok(None),
         };
 
+        self.call_raw_hook(GatewayDirection::Inbound, json_str.as_bytes());
+
+        // Peek at the event name, to skip building the JSON map of filtered out events
+        if !self.filtered_events.is_all() {
+            if let Some(seq) = filtered_dispatch_seq(&json_str, self.filtered_events) {
+                return Ok(Some(GatewayEvent::Dispatch {
+                    seq,
+                    data: JsonMap::new(),
+                    original_str: FixedString::from_string_trunc(json_str),
+                }));
+            }
+        }
+
         match serde_json::from_str(&json_str) {
             Ok(mut event) => {
                 if let GatewayEvent::Dispatch {
@@ -141,24 +258,24 @@ impl WsClient {
     pub(crate) async fn send_json(&mut self, value: &impl serde::Serialize) -> Result<()> {
         let message = serde_json::to_string(value).map(Message::Text)?;
 
-        self.0.send(message).await?;
-        Ok(())
+        self.send(message).await
     }
 
     /// Delegate to `StreamExt::next`
     pub(crate) async fn next(&mut self) -> Option<std::result::Result<Message, WsError>> {
-        self.0.next().await
+        self.stream.next().await
     }
 
     /// Delegate to `SinkExt::send`
     pub(crate) async fn send(&mut self, message: Message) -> Result<()> {
-        self.0.send(message).await?;
+        self.call_outbound_hook(&message);
+        self.stream.send(message).await?;
         Ok(())
     }
 
     /// Delegate to `WebSocketStream::close`
     pub(crate) async fn close(&mut self, msg: Option<CloseFrame<'_>>) -> Result<()> {
-        self.0.close(msg).await?;
+        self.stream.close(msg).await?;
         Ok(())
     }
 
@@ -220,7 +337,6 @@ impl WsClient {
         presence: &PresenceData,
     ) -> Result<()> {
         let now = SystemTime::now();
-        let activities = presence.activity.as_ref().map(std::slice::from_ref).unwrap_or_default();
 
         debug!("[{:?}] Identifying", shard);
 
@@ -241,7 +357,7 @@ impl WsClient {
                     afk: false,
                     since: now,
                     status: presence.status.name(),
-                    activities,
+                    activities: &presence.activities,
                 },
             },
         };
@@ -259,7 +375,6 @@ impl WsClient {
         presence: &PresenceData,
     ) -> Result<()> {
         let now = SystemTime::now();
-        let activities = presence.activity.as_ref().map(std::slice::from_ref).unwrap_or_default();
 
         debug!("[{shard_info:?}] Sending presence update");
 
@@ -268,7 +383,7 @@ impl WsClient {
             d: WebSocketMessageData::PresenceUpdate(PresenceUpdateMessage {
                 afk: false,
                 since: now,
-                activities,
+                activities: &presence.activities,
                 status: presence.status.name(),
             }),
         })
@@ -299,3 +414,38 @@ impl WsClient {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_identify_and_resume_tokens() {
+        let identify = r#"{"op":2,"d":{"token":"secret","intents":0}}"#;
+        let redacted = redact_token(identify).unwrap();
+        assert!(!redacted.contains("secret"));
+        assert!(redacted.contains(r#""token":"[redacted]""#));
+
+        let resume = r#"{"op":6,"d":{"token":"secret","session_id":"a","seq":1}}"#;
+        assert!(!redact_token(resume).unwrap().contains("secret"));
+
+        assert_eq!(redact_token(r#"{"op":1,"d":null}"#), None);
+        assert_eq!(redact_token(r#"{"op":3,"d":{"token":"not a token"}}"#), None);
+    }
+
+    #[test]
+    fn peeks_filtered_dispatches() {
+        let filtered_events = EventTypeFlags::MESSAGE_CREATE;
+        let typing = r#"{"op":0,"s":5,"t":"TYPING_START","d":{"channel_id":"1"}}"#;
+        assert_eq!(filtered_dispatch_seq(typing, filtered_events), Some(5));
+
+        let message = r#"{"op":0,"s":6,"t":"MESSAGE_CREATE","d":{"id":"1"}}"#;
+        assert_eq!(filtered_dispatch_seq(message, filtered_events), None);
+
+        let unknown = r#"{"op":0,"s":7,"t":"SOME_NEW_EVENT","d":{}}"#;
+        assert_eq!(filtered_dispatch_seq(unknown, filtered_events), None);
+
+        let heartbeat_ack = r#"{"op":11,"s":null,"t":null,"d":null}"#;
+        assert_eq!(filtered_dispatch_seq(heartbeat_ack, filtered_events), None);
+    }
+}
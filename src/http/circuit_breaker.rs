@@ -0,0 +1,187 @@
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use reqwest::Response as ReqwestResponse;
+use tracing::warn;
+
+use super::HttpError;
+use crate::internal::prelude::*;
+
+/// Settings for the circuit breaker of an [`Http`] client, set via
+/// [`HttpBuilder::circuit_breaker`].
+///
+/// Once requests to a route fail [`Self::failure_threshold`] times in a row, with a server error or
+/// a timeout, the circuit for the route opens: further requests to it fail immediately with
+/// [`HttpError::CircuitOpen`] instead of waiting on a degraded endpoint. After the cooldown, a
+/// single request is let through as a probe. If it succeeds the circuit closes again, otherwise it
+/// stays open for another cooldown.
+///
+/// Routes are identified by [`Route::name`], so all channels share the circuit for sending
+/// messages, for example.
+///
+/// [`Http`]: super::Http
+/// [`HttpBuilder::circuit_breaker`]: super::HttpBuilder::circuit_breaker
+/// [`Route::name`]: super::Route::name
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct CircuitBreakerConfig {
+    failure_threshold: u32,
+    cooldown: Duration,
+}
+
+impl CircuitBreakerConfig {
+    /// Creates a config opening the circuit after 5 consecutive failures, for 30 seconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many consecutive failures open the circuit for a route. Values below 1 are raised
+    /// to 1.
+    pub fn failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold.max(1);
+        self
+    }
+
+    /// Sets how long requests fail immediately once the circuit opened, before a probe is let
+    /// through.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RouteCircuit {
+    failures: u32,
+    /// When the circuit last opened, or when the last probe was let through.
+    opened_at: Option<Instant>,
+}
+
+/// Tracks consecutive failures per route, see [`CircuitBreakerConfig`].
+#[derive(Debug)]
+pub(super) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    routes: DashMap<&'static str, RouteCircuit>,
+}
+
+impl CircuitBreaker {
+    pub(super) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            routes: DashMap::new(),
+        }
+    }
+
+    /// Checks whether a request to the route may be performed.
+    ///
+    /// Once the cooldown has passed, this lets a single request through as a probe and restarts
+    /// the cooldown, so other requests keep failing until the probe's result is recorded.
+    pub(super) fn check(&self, route: &'static str) -> StdResult<(), HttpError> {
+        let Some(mut circuit) = self.routes.get_mut(route) else {
+            return Ok(());
+        };
+
+        let Some(opened_at) = circuit.opened_at else {
+            return Ok(());
+        };
+
+        let remaining = self.config.cooldown.checked_sub(opened_at.elapsed());
+        if let Some(retry_after) = remaining.filter(|remaining| !remaining.is_zero()) {
+            return Err(HttpError::CircuitOpen {
+                route,
+                retry_after,
+            });
+        }
+
+        circuit.opened_at = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Records the result of a request to the route, counting server errors, timeouts and
+    /// connection failures as failures.
+    pub(super) fn record(&self, route: &'static str, result: &Result<ReqwestResponse>) {
+        let failed = match result {
+            Ok(response) => response.status().is_server_error(),
+            Err(Error::Http(HttpError::Request(err))) => err.is_timeout() || err.is_connect(),
+            Err(_) => return,
+        };
+
+        if failed {
+            self.record_failure(route);
+        } else {
+            self.routes.remove(route);
+        }
+    }
+
+    fn record_failure(&self, route: &'static str) {
+        let mut circuit = self.routes.entry(route).or_default();
+        circuit.failures += 1;
+
+        if circuit.failures >= self.config.failure_threshold {
+            if circuit.opened_at.is_none() {
+                warn!(
+                    "Opening circuit for route {route} after {} consecutive failures",
+                    circuit.failures
+                );
+            }
+
+            circuit.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Response as ReqwestResponse;
+
+    use super::{CircuitBreaker, CircuitBreakerConfig};
+    use crate::http::HttpError;
+
+    fn response(status: u16) -> ReqwestResponse {
+        let response = http_crate::Response::builder().status(status).body(Vec::new());
+        response.expect("response should be valid").into()
+    }
+
+    #[test]
+    fn circuit_opens_and_probes() {
+        let config = CircuitBreakerConfig::new().failure_threshold(2).cooldown(Duration::ZERO);
+        let breaker = CircuitBreaker::new(config);
+
+        // Client errors mean the endpoint responded, which breaks the run of failures.
+        breaker.record("Channel", &Ok(response(500)));
+        breaker.record("Channel", &Ok(response(404)));
+        breaker.record("Channel", &Ok(response(500)));
+        assert!(breaker.routes.get("Channel").is_some_and(|circuit| circuit.opened_at.is_none()));
+
+        breaker.record("Channel", &Ok(response(503)));
+        assert!(breaker.routes.get("Channel").is_some_and(|circuit| circuit.opened_at.is_some()));
+
+        // The cooldown passed immediately, so a probe is let through, which closes the circuit.
+        assert!(breaker.check("Channel").is_ok());
+        breaker.record("Channel", &Ok(response(200)));
+        assert!(breaker.routes.get("Channel").is_none());
+
+        let breaker = CircuitBreaker::new(config.cooldown(Duration::from_secs(60)));
+        breaker.record("Channel", &Ok(response(500)));
+        breaker.record("Channel", &Ok(response(500)));
+        assert!(matches!(
+            breaker.check("Channel"),
+            Err(HttpError::CircuitOpen {
+                route: "Channel",
+                ..
+            })
+        ));
+        assert!(breaker.check("ChannelMessages").is_ok());
+    }
+}
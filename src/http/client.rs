@@ -2,11 +2,15 @@
 
 use std::borrow::Cow;
 use std::cell::Cell;
+use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use arrayvec::ArrayVec;
+use futures::StreamExt as _;
 use nonmax::{NonMaxU16, NonMaxU8};
+use parking_lot::RwLock;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::header::{HeaderMap as Headers, HeaderValue};
 #[cfg(feature = "utils")]
@@ -18,10 +22,16 @@ use serde::ser::SerializeSeq as _;
 use serde_json::{from_value, json, to_string, to_vec};
 use tracing::{debug, trace};
 
+use super::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use super::deprecation::{DeprecatedRoute, DeprecationTracker};
+use super::idempotency::IdempotencyStore;
+use super::middleware::{MiddlewareChain, RequestMiddleware};
 use super::multipart::{Multipart, MultipartUpload};
 use super::ratelimiting::Ratelimiter;
 use super::request::Request;
 use super::routing::Route;
+#[cfg(feature = "http_transport")]
+use super::HttpTransport;
 use super::{
     ErrorResponse,
     GuildPagination,
@@ -108,12 +118,62 @@ where
 #[must_use]
 pub struct HttpBuilder {
     client: Option<Client>,
+    cdn_client: Option<Client>,
+    cdn_host: Option<FixedString<u16>>,
     ratelimiter: Option<Ratelimiter>,
     ratelimiter_disabled: bool,
     token: Arc<str>,
     proxy: Option<FixedString<u16>>,
     application_id: Option<ApplicationId>,
     default_allowed_mentions: Option<CreateAllowedMentions<'static>>,
+    idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    middleware: MiddlewareChain,
+    #[cfg(feature = "http_transport")]
+    transport: Option<Arc<dyn HttpTransport>>,
+    client_options: ClientOptions,
+}
+
+/// Settings applied to the [`reqwest::Client`] created by [`HttpBuilder::build`].
+struct ClientOptions {
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            pool_max_idle_per_host: None,
+            // The default of reqwest.
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            timeout: None,
+            connect_timeout: None,
+            tcp_keepalive: None,
+        }
+    }
+}
+
+impl ClientOptions {
+    fn apply(self, mut builder: ClientBuilder) -> ClientBuilder {
+        builder = builder.pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(keepalive) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(keepalive);
+        }
+
+        builder
+    }
 }
 
 impl HttpBuilder {
@@ -122,12 +182,20 @@ impl HttpBuilder {
     pub fn new(token: &str) -> Self {
         Self {
             client: None,
+            cdn_client: None,
+            cdn_host: None,
             ratelimiter: None,
             ratelimiter_disabled: false,
             token: parse_token(token),
             proxy: None,
             application_id: None,
             default_allowed_mentions: None,
+            idempotency_store: None,
+            circuit_breaker: None,
+            middleware: MiddlewareChain::default(),
+            #[cfg(feature = "http_transport")]
+            transport: None,
+            client_options: ClientOptions::default(),
         }
     }
 
@@ -145,11 +213,103 @@ impl HttpBuilder {
     }
 
     /// Sets the [`reqwest::Client`]. If one isn't provided, a default one will be used.
+    ///
+    /// This allows sharing a client, and its connection pool, with the rest of an application, or
+    /// configuring settings which aren't exposed by this builder, such as HTTP/2 settings. The
+    /// client's TLS backend must be configured by the caller.
+    ///
+    /// **Note**: The connection pool and timeout settings of this builder, such as
+    /// [`Self::pool_max_idle_per_host`], only apply to the default client, and are ignored if a
+    /// client is provided.
     pub fn client(mut self, client: Client) -> Self {
         self.client = Some(client);
         self
     }
 
+    /// Sets the [`reqwest::Client`] used to download attachments and other assets from Discord's
+    /// CDN, such as via [`Attachment::stream`]. If one isn't provided, the client used for API
+    /// requests is shared.
+    ///
+    /// This keeps large downloads from occupying the connection pool used for API requests, and
+    /// allows configuring it separately, such as with a longer timeout.
+    ///
+    /// [`Attachment::stream`]: crate::model::channel::Attachment::stream
+    pub fn cdn_client(mut self, client: Client) -> Self {
+        self.cdn_client = Some(client);
+        self
+    }
+
+    /// Sets the host that downloads from Discord's CDN are sent to instead, such as a caching
+    /// proxy or a mirror closer to the bot. Only URLs of `cdn.discordapp.com` and
+    /// `media.discordapp.net` are rewritten, see [`Http::cdn_url`].
+    ///
+    /// The host should be in the form of the protocol and hostname, e.g.
+    /// `https://cdn.example.com`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the host is larger than u16::MAX characters.
+    pub fn cdn_host<'a>(mut self, host: impl Into<Cow<'a, str>>) -> Self {
+        let host = host.into();
+        u16::try_from(host.len()).expect("CDN host should be less than u16::MAX characters");
+
+        let host = match host {
+            Cow::Owned(host) => FixedString::from_string_trunc(host),
+            Cow::Borrowed(host) => FixedString::from_str_trunc(host),
+        };
+
+        self.cdn_host = Some(host);
+        self
+    }
+
+    /// Sets the maximum number of idle connections to Discord kept open by the default client.
+    /// Defaults to no limit.
+    ///
+    /// See [`reqwest::ClientBuilder::pool_max_idle_per_host`].
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.client_options.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Sets how long idle connections of the default client are kept open, or [`None`] to keep
+    /// them open indefinitely. Defaults to 90 seconds.
+    ///
+    /// See [`reqwest::ClientBuilder::pool_idle_timeout`].
+    pub fn pool_idle_timeout(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.client_options.pool_idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Sets the timeout of requests performed by the default client, from connecting until the
+    /// response body is read. Defaults to no timeout.
+    ///
+    /// **Note**: Requests waiting for a rate limit to reset are not affected, as the timeout only
+    /// starts once a request is sent.
+    ///
+    /// See [`reqwest::ClientBuilder::timeout`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.client_options.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for connecting to Discord used by the default client. Defaults to no
+    /// timeout.
+    ///
+    /// See [`reqwest::ClientBuilder::connect_timeout`].
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.client_options.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets how long connections of the default client are idle before TCP keepalive probes are
+    /// sent. Defaults to 15 seconds.
+    ///
+    /// See [`reqwest::ClientBuilder::tcp_keepalive`].
+    pub fn tcp_keepalive(mut self, keepalive: Duration) -> Self {
+        self.client_options.tcp_keepalive = Some(keepalive);
+        self
+    }
+
     /// Sets the ratelimiter to be used. If one isn't provided, a default one will be used.
     pub fn ratelimiter(mut self, ratelimiter: Ratelimiter) -> Self {
         self.ratelimiter = Some(ratelimiter);
@@ -210,29 +370,80 @@ impl HttpBuilder {
         self
     }
 
+    /// Sets the [`IdempotencyStore`] remembering the messages sent with an idempotency key, see
+    /// [`CreateMessage::idempotency_key`].
+    ///
+    /// If none is set, idempotency keys are ignored.
+    ///
+    /// [`CreateMessage::idempotency_key`]: crate::builder::CreateMessage::idempotency_key
+    pub fn idempotency_store(mut self, store: impl IdempotencyStore + 'static) -> Self {
+        self.idempotency_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Enables a circuit breaker, which makes requests to a route fail immediately with
+    /// [`HttpError::CircuitOpen`] while the route is failing, such as during a partial outage of
+    /// Discord. See [`CircuitBreakerConfig`] for details.
+    ///
+    /// By default, no circuit breaker is used.
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Adds a [`RequestMiddleware`] which observes and modifies every request performed by the
+    /// client. Middleware run in the order they were added.
+    pub fn middleware(mut self, middleware: impl RequestMiddleware + 'static) -> Self {
+        self.middleware.0.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the [`HttpTransport`] used to perform requests, including those made through the
+    /// ratelimiter. If one isn't provided, requests are performed by the [`reqwest::Client`].
+    #[cfg(feature = "http_transport")]
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     /// Use the given configuration to build the `Http` client.
     #[must_use]
     pub fn build(self) -> Http {
         let application_id =
             AtomicU64::new(self.application_id.map_or(u64::MAX, ApplicationId::get));
 
+        let client_options = self.client_options;
         let client = self.client.unwrap_or_else(|| {
-            let builder = configure_client_backend(Client::builder());
+            let builder = client_options.apply(configure_client_backend(Client::builder()));
             builder.build().expect("Cannot build reqwest::Client")
         });
 
-        let ratelimiter = (!self.ratelimiter_disabled).then(|| {
+        #[cfg_attr(not(feature = "http_transport"), allow(unused_mut))]
+        let mut ratelimiter = (!self.ratelimiter_disabled).then(|| {
             self.ratelimiter
                 .unwrap_or_else(|| Ratelimiter::new(client.clone(), Arc::clone(&self.token)))
         });
 
+        #[cfg(feature = "http_transport")]
+        if let (Some(ratelimiter), Some(transport)) = (&mut ratelimiter, &self.transport) {
+            ratelimiter.set_transport(Arc::clone(transport));
+        }
+
         Http {
+            cdn_client: self.cdn_client.unwrap_or_else(|| client.clone()),
+            cdn_host: self.cdn_host,
             client,
             ratelimiter,
             proxy: self.proxy,
             token: Token::new(self.token),
             application_id,
-            default_allowed_mentions: self.default_allowed_mentions,
+            default_allowed_mentions: RwLock::new(self.default_allowed_mentions),
+            idempotency_store: self.idempotency_store,
+            deprecations: DeprecationTracker::default(),
+            circuit_breaker: self.circuit_breaker.map(CircuitBreaker::new),
+            middleware: self.middleware,
+            #[cfg(feature = "http_transport")]
+            transport: self.transport,
         }
     }
 }
@@ -267,11 +478,19 @@ fn reason_into_header(reason: &str) -> Headers {
 #[derive(Debug)]
 pub struct Http {
     pub(crate) client: Client,
+    cdn_client: Client,
+    cdn_host: Option<FixedString<u16>>,
     pub ratelimiter: Option<Ratelimiter>,
     pub proxy: Option<FixedString<u16>>,
     token: Secret<Token>,
     application_id: AtomicU64,
-    pub default_allowed_mentions: Option<CreateAllowedMentions<'static>>,
+    default_allowed_mentions: RwLock<Option<CreateAllowedMentions<'static>>>,
+    pub(crate) idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    deprecations: DeprecationTracker,
+    circuit_breaker: Option<CircuitBreaker>,
+    middleware: MiddlewareChain,
+    #[cfg(feature = "http_transport")]
+    transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl Http {
@@ -280,6 +499,49 @@ impl Http {
         HttpBuilder::new(token).build()
     }
 
+    /// The [`reqwest::Client`] used to perform requests, which can be shared with the rest of an
+    /// application.
+    #[must_use]
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The [`reqwest::Client`] used to download assets from Discord's CDN, see
+    /// [`HttpBuilder::cdn_client`].
+    #[must_use]
+    pub fn cdn_client(&self) -> &Client {
+        &self.cdn_client
+    }
+
+    /// Rewrites a URL of Discord's CDN to the host set via [`HttpBuilder::cdn_host`], if any.
+    /// Other URLs are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::http::HttpBuilder;
+    ///
+    /// let http = HttpBuilder::new("token").cdn_host("https://cdn.example.com").build();
+    /// let url = http.cdn_url("https://cdn.discordapp.com/attachments/1/2/file.png");
+    /// assert_eq!(url, "https://cdn.example.com/attachments/1/2/file.png");
+    /// ```
+    #[must_use]
+    pub fn cdn_url<'a>(&self, url: &'a str) -> Cow<'a, str> {
+        const CDN_HOSTS: [&str; 2] =
+            ["https://cdn.discordapp.com/", "https://media.discordapp.net/"];
+
+        let Some(host) = &self.cdn_host else {
+            return Cow::Borrowed(url);
+        };
+
+        CDN_HOSTS
+            .iter()
+            .find_map(|cdn_host| url.strip_prefix(cdn_host))
+            .map_or(Cow::Borrowed(url), |path| {
+                Cow::Owned(format!("{}/{path}", host.trim_end_matches('/')))
+            })
+    }
+
     pub fn application_id(&self) -> Option<ApplicationId> {
         let application_id = self.application_id.load(Ordering::Relaxed);
         if application_id == u64::MAX {
@@ -289,7 +551,7 @@ impl Http {
         }
     }
 
-    fn try_application_id(&self) -> Result<ApplicationId> {
+    pub(crate) fn try_application_id(&self) -> Result<ApplicationId> {
         self.application_id().ok_or_else(|| HttpError::ApplicationIdMissing.into())
     }
 
@@ -297,10 +559,36 @@ impl Http {
         self.application_id.store(application_id.get(), Ordering::Relaxed);
     }
 
+    /// The [`CreateAllowedMentions`] used by default for each request that would use it, see
+    /// [`HttpBuilder::default_allowed_mentions`].
+    #[must_use]
+    pub fn default_allowed_mentions(&self) -> Option<CreateAllowedMentions<'static>> {
+        self.default_allowed_mentions.read().clone()
+    }
+
+    /// Sets the [`CreateAllowedMentions`] used by default, or removes them if [`None`] is passed.
+    /// See [`HttpBuilder::default_allowed_mentions`] for more info.
+    pub fn set_default_allowed_mentions(
+        &self,
+        allowed_mentions: Option<CreateAllowedMentions<'static>>,
+    ) {
+        *self.default_allowed_mentions.write() = allowed_mentions;
+    }
+
     pub(crate) fn token(&self) -> &Arc<str> {
         self.token.expose_secret().get_inner()
     }
 
+    /// Returns all deprecated routes this client has requested, either because Discord sent
+    /// deprecation or termination headers in the response, or because the route is known to be
+    /// deprecated.
+    ///
+    /// A warning is also logged the first time each deprecated route is requested.
+    #[must_use]
+    pub fn deprecation_report(&self) -> Vec<DeprecatedRoute> {
+        self.deprecations.report()
+    }
+
     /// Adds a [`User`] to a [`Guild`] with a valid OAuth2 access token.
     ///
     /// Returns the created [`Member`] object, or nothing if the user is already a guild member.
@@ -578,6 +866,19 @@ impl Http {
         interaction_token: &str,
         map: &impl serde::Serialize,
         files: Vec<CreateAttachment<'_>>,
+    ) -> Result<Message> {
+        self.create_followup_message_for(self.try_application_id()?, interaction_token, map, files)
+            .await
+    }
+
+    /// Like [`Self::create_followup_message`], but for the given application instead of
+    /// [`Self::application_id`].
+    pub(crate) async fn create_followup_message_for(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: &str,
+        map: &impl serde::Serialize,
+        files: Vec<CreateAttachment<'_>>,
     ) -> Result<Message> {
         let mut request = Request {
             body: None,
@@ -585,7 +886,7 @@ impl Http {
             headers: None,
             method: LightMethod::Post,
             route: Route::WebhookFollowupMessages {
-                application_id: self.try_application_id()?,
+                application_id,
                 token: interaction_token,
             },
             params: None,
@@ -1063,6 +1364,18 @@ impl Http {
         &self,
         interaction_token: &str,
         message_id: MessageId,
+    ) -> Result<()> {
+        self.delete_followup_message_for(self.try_application_id()?, interaction_token, message_id)
+            .await
+    }
+
+    /// Like [`Self::delete_followup_message`], but for the given application instead of
+    /// [`Self::application_id`].
+    pub(crate) async fn delete_followup_message_for(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: &str,
+        message_id: MessageId,
     ) -> Result<()> {
         self.wind(204, Request {
             body: None,
@@ -1070,7 +1383,7 @@ impl Http {
             headers: None,
             method: LightMethod::Delete,
             route: Route::WebhookFollowupMessage {
-                application_id: self.try_application_id()?,
+                application_id,
                 token: interaction_token,
                 message_id,
             },
@@ -1258,6 +1571,17 @@ impl Http {
     pub async fn delete_original_interaction_response(
         &self,
         interaction_token: &str,
+    ) -> Result<()> {
+        self.delete_original_interaction_response_for(self.try_application_id()?, interaction_token)
+            .await
+    }
+
+    /// Like [`Self::delete_original_interaction_response`], but for the given application instead
+    /// of [`Self::application_id`].
+    pub(crate) async fn delete_original_interaction_response_for(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: &str,
     ) -> Result<()> {
         self.wind(204, Request {
             body: None,
@@ -1265,7 +1589,7 @@ impl Http {
             headers: None,
             method: LightMethod::Delete,
             route: Route::WebhookOriginalInteractionResponse {
-                application_id: self.try_application_id()?,
+                application_id,
                 token: interaction_token,
             },
             params: None,
@@ -1558,6 +1882,26 @@ impl Http {
         message_id: MessageId,
         map: &impl serde::Serialize,
         new_attachments: Vec<CreateAttachment<'_>>,
+    ) -> Result<Message> {
+        self.edit_followup_message_for(
+            self.try_application_id()?,
+            interaction_token,
+            message_id,
+            map,
+            new_attachments,
+        )
+        .await
+    }
+
+    /// Like [`Self::edit_followup_message`], but for the given application instead of
+    /// [`Self::application_id`].
+    pub(crate) async fn edit_followup_message_for(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: &str,
+        message_id: MessageId,
+        map: &impl serde::Serialize,
+        new_attachments: Vec<CreateAttachment<'_>>,
     ) -> Result<Message> {
         let mut request = Request {
             body: None,
@@ -1565,7 +1909,7 @@ impl Http {
             headers: None,
             method: LightMethod::Patch,
             route: Route::WebhookFollowupMessage {
-                application_id: self.try_application_id()?,
+                application_id,
                 token: interaction_token,
                 message_id,
             },
@@ -1590,6 +1934,18 @@ impl Http {
         &self,
         interaction_token: &str,
         message_id: MessageId,
+    ) -> Result<Message> {
+        self.get_followup_message_for(self.try_application_id()?, interaction_token, message_id)
+            .await
+    }
+
+    /// Like [`Self::get_followup_message`], but for the given application instead of
+    /// [`Self::application_id`].
+    pub(crate) async fn get_followup_message_for(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: &str,
+        message_id: MessageId,
     ) -> Result<Message> {
         self.fire(Request {
             body: None,
@@ -1597,7 +1953,7 @@ impl Http {
             headers: None,
             method: LightMethod::Get,
             route: Route::WebhookFollowupMessage {
-                application_id: self.try_application_id()?,
+                application_id,
                 token: interaction_token,
                 message_id,
             },
@@ -1747,7 +2103,7 @@ impl Http {
         guild_id: GuildId,
         map: &impl serde::Serialize,
         audit_log_reason: Option<&str>,
-    ) -> Result<GuildWidget> {
+    ) -> Result<GuildWidgetSettings> {
         let body = to_vec(map)?;
 
         self.fire(Request {
@@ -1940,6 +2296,17 @@ impl Http {
     pub async fn get_original_interaction_response(
         &self,
         interaction_token: &str,
+    ) -> Result<Message> {
+        self.get_original_interaction_response_for(self.try_application_id()?, interaction_token)
+            .await
+    }
+
+    /// Like [`Self::get_original_interaction_response`], but for the given application instead of
+    /// [`Self::application_id`].
+    pub(crate) async fn get_original_interaction_response_for(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: &str,
     ) -> Result<Message> {
         self.fire(Request {
             body: None,
@@ -1947,7 +2314,7 @@ impl Http {
             headers: None,
             method: LightMethod::Get,
             route: Route::WebhookOriginalInteractionResponse {
-                application_id: self.try_application_id()?,
+                application_id,
                 token: interaction_token,
             },
             params: None,
@@ -1961,6 +2328,24 @@ impl Http {
         interaction_token: &str,
         map: &impl serde::Serialize,
         new_attachments: Vec<CreateAttachment<'_>>,
+    ) -> Result<Message> {
+        self.edit_original_interaction_response_for(
+            self.try_application_id()?,
+            interaction_token,
+            map,
+            new_attachments,
+        )
+        .await
+    }
+
+    /// Like [`Self::edit_original_interaction_response`], but for the given application instead of
+    /// [`Self::application_id`].
+    pub(crate) async fn edit_original_interaction_response_for(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: &str,
+        map: &impl serde::Serialize,
+        new_attachments: Vec<CreateAttachment<'_>>,
     ) -> Result<Message> {
         let mut request = Request {
             body: None,
@@ -1968,7 +2353,7 @@ impl Http {
             headers: None,
             method: LightMethod::Patch,
             route: Route::WebhookOriginalInteractionResponse {
-                application_id: self.try_application_id()?,
+                application_id,
                 token: interaction_token,
             },
             params: None,
@@ -2184,6 +2569,25 @@ impl Http {
         .await
     }
 
+    /// Sets the status of a voice channel.
+    pub async fn edit_voice_status(
+        &self,
+        channel_id: ChannelId,
+        map: &impl serde::Serialize,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Put,
+            route: Route::ChannelVoiceStatus {
+                channel_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Edits a the webhook with the given data.
     pub async fn edit_webhook(
         &self,
@@ -3304,10 +3708,26 @@ impl Http {
         .await
     }
 
-    /// Gets a guild widget information.
-    // TODO: according to Discord, this returns different data; namely https://discord.com/developers/docs/resources/guild#guild-widget-object-guild-widget-structure.
-    // Should investigate if this endpoint actually works
+    /// Gets the public data of a guild's widget. Fails if the widget is disabled.
     pub async fn get_guild_widget(&self, guild_id: GuildId) -> Result<GuildWidget> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildWidgetJson {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
+    /// Gets the settings of a guild's widget.
+    pub async fn get_guild_widget_settings(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<GuildWidgetSettings> {
         self.fire(Request {
             body: None,
             multipart: None,
@@ -3321,6 +3741,28 @@ impl Http {
         .await
     }
 
+    /// Gets the PNG image of a guild's widget in the given style. Fails if the widget is disabled.
+    pub async fn get_guild_widget_image(
+        &self,
+        guild_id: GuildId,
+        style: GuildWidgetStyle,
+    ) -> Result<Vec<u8>> {
+        let response = self
+            .request(Request {
+                body: None,
+                multipart: None,
+                headers: None,
+                method: LightMethod::Get,
+                route: Route::GuildWidgetImage {
+                    guild_id,
+                },
+                params: Some(&[("style", &style.to_string())]),
+            })
+            .await?;
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
     /// Gets a guild preview.
     pub async fn get_guild_preview(&self, guild_id: GuildId) -> Result<GuildPreview> {
         self.fire(Request {
@@ -3503,6 +3945,29 @@ impl Http {
         from_value(value).map_err(From::from)
     }
 
+    /// Gets a single role of a guild.
+    pub async fn get_guild_role(&self, guild_id: GuildId, role_id: RoleId) -> Result<Role> {
+        let mut value: Value = self
+            .fire(Request {
+                body: None,
+                multipart: None,
+                headers: None,
+                method: LightMethod::Get,
+                route: Route::GuildRole {
+                    guild_id,
+                    role_id,
+                },
+                params: None,
+            })
+            .await?;
+
+        if let Some(map) = value.as_object_mut() {
+            map.insert("guild_id".to_string(), guild_id.get().into());
+        }
+
+        from_value(value).map_err(From::from)
+    }
+
     /// Gets a scheduled event by Id.
     pub async fn get_scheduled_event(
         &self,
@@ -3690,6 +4155,76 @@ impl Http {
         .await
     }
 
+    /// Gets all guilds of the current user, by requesting pages of [`GUILD_FETCH_LIMIT`] guilds
+    /// via [`Self::get_guilds`] until the last page is reached.
+    ///
+    /// Each page is requested after the last guild of the previous page, so the pages are
+    /// requested one after another. Ratelimits are handled as usual.
+    ///
+    /// [`GUILD_FETCH_LIMIT`]: constants::GUILD_FETCH_LIMIT
+    pub async fn get_guilds_all(&self) -> Result<Vec<GuildInfo>> {
+        let limit = constants::GUILD_FETCH_LIMIT;
+        let mut guilds = Vec::new();
+        loop {
+            let target = guilds.last().map(|guild: &GuildInfo| GuildPagination::After(guild.id));
+            let page = self.get_guilds(target, Some(limit)).await?;
+
+            let is_last = page.len() < usize::from(limit.get());
+            guilds.extend(page);
+            if is_last {
+                return Ok(guilds);
+            }
+        }
+    }
+
+    /// Gets all guilds of the current user via [`Self::get_guilds_all`], then requests each of
+    /// them via [`Self::get_guild`], with at most `concurrency` requests in flight at once.
+    ///
+    /// The `progress` callback is called after each guild was requested, with the number of
+    /// guilds requested so far and the total number of guilds. The guilds are returned in the
+    /// same order as by [`Self::get_guilds_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::num::NonZeroUsize;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run(http: &Http) -> Result<(), serenity::Error> {
+    /// let concurrency = NonZeroUsize::new(8).unwrap();
+    /// let guilds = http
+    ///     .get_guilds_all_hydrated(concurrency, |done, total| {
+    ///         println!("Fetched {done}/{total} guilds");
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered, in which case no further guilds are requested.
+    pub async fn get_guilds_all_hydrated(
+        &self,
+        concurrency: NonZeroUsize,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Result<Vec<PartialGuild>> {
+        let guild_ids = self.get_guilds_all().await?.into_iter().map(|guild| guild.id);
+        let total = guild_ids.len();
+
+        let mut requests = futures::stream::iter(guild_ids)
+            .map(|guild_id| self.get_guild(guild_id))
+            .buffered(concurrency.get());
+
+        let mut guilds = Vec::with_capacity(total);
+        while let Some(guild) = requests.next().await {
+            guilds.push(guild?);
+            progress(guilds.len(), total);
+        }
+
+        Ok(guilds)
+    }
+
     /// Returns a guild [`Member`] object for the current user.
     ///
     /// This method only works for user tokens with the [`GuildsMembersRead`] OAuth2 scope.
@@ -3871,16 +4406,22 @@ impl Http {
     }
 
     /// Gets user Ids based on their reaction to a message. This endpoint is dumb.
+    ///
+    /// Normal reactions and super reactions are listed separately, as selected by `kind`.
     pub async fn get_reaction_users(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         reaction_type: &ReactionType,
+        kind: ReactionTypes,
         limit: u8,
         after: Option<UserId>,
     ) -> Result<Vec<User>> {
-        let (limit_str, after_str);
-        let mut params = ArrayVec::<_, 2>::new();
+        let (kind_str, limit_str, after_str);
+        let mut params = ArrayVec::<_, 3>::new();
+
+        kind_str = kind.0.to_arraystring();
+        params.push(("type", kind_str.as_str()));
 
         limit_str = limit.to_arraystring();
         params.push(("limit", limit_str.as_str()));
@@ -3998,6 +4539,43 @@ impl Http {
         .await
     }
 
+    /// Gets the voice state of a user in a guild. Fails if the user is not connected to a voice
+    /// channel.
+    pub async fn get_user_voice_state(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<VoiceState> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildVoiceStates {
+                guild_id,
+                user_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
+    /// Gets the current user's voice state in a guild. Fails if the current user is not connected
+    /// to a voice channel.
+    pub async fn get_current_user_voice_state(&self, guild_id: GuildId) -> Result<VoiceState> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildVoiceStateMe {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Gets the current user's third party connections.
     ///
     /// This method only works for user tokens with the [`Connections`] OAuth2 scope.
@@ -4346,20 +4924,40 @@ impl Http {
 
     /// Performs a request, ratelimiting it if necessary.
     ///
+    /// The request passes through the [`RequestMiddleware`] registered via
+    /// [`HttpBuilder::middleware`], if any.
+    ///
     /// Returns the raw reqwest Response. Use [`Self::fire`] to deserialize the response into some
     /// type.
     #[cfg_attr(feature = "tracing_instrument", instrument)]
-    pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
-        let method = req.method.reqwest_method();
+    pub async fn request(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
+        self.middleware.on_request(&mut req);
+
+        let light_method = req.method;
+        let method = light_method.reqwest_method();
+        let route = req.route.name();
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.check(route)?;
+        }
+
         let response = if let Some(ratelimiter) = &self.ratelimiter {
-            ratelimiter.perform(req).await?
+            ratelimiter.perform(req).await
         } else {
             let request = req
                 .build(&self.client, self.token.expose_secret(), self.proxy.as_deref())?
                 .build()?;
-            self.client.execute(request).await?
+            self.execute(request).await
         };
 
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.record(route, &response);
+        }
+
+        let response = response?;
+
+        self.deprecations.record(light_method, route, response.headers());
+        self.middleware.on_response(light_method, route, &response);
+
         if response.status().is_success() {
             Ok(response)
         } else {
@@ -4369,6 +4967,27 @@ impl Http {
         }
     }
 
+    pub(crate) async fn execute(&self, request: reqwest::Request) -> Result<ReqwestResponse> {
+        #[cfg(feature = "http_transport")]
+        if let Some(transport) = &self.transport {
+            return transport.execute(request).await;
+        }
+
+        Ok(self.client.execute(request).await?)
+    }
+
+    /// Performs a request to Discord's CDN, using the client set via [`HttpBuilder::cdn_client`].
+    /// Like API requests, it goes through the transport set via [`HttpBuilder::transport`] instead,
+    /// if any.
+    pub(crate) async fn execute_cdn(&self, request: reqwest::Request) -> Result<ReqwestResponse> {
+        #[cfg(feature = "http_transport")]
+        if let Some(transport) = &self.transport {
+            return transport.execute(request).await;
+        }
+
+        Ok(self.cdn_client.execute(request).await?)
+    }
+
     /// Performs a request and then verifies that the response status code is equal to the expected
     /// value.
     ///
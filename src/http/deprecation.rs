@@ -0,0 +1,156 @@
+use dashmap::DashMap;
+use reqwest::header::HeaderMap;
+use tracing::warn;
+
+use super::LightMethod;
+use crate::model::Timestamp;
+
+/// Headers Discord may send to announce that a route is deprecated, or when it will be removed.
+const DEPRECATION_HEADERS: &[&str] = &["X-Discord-Deprecation", "Deprecation"];
+const TERMINATION_HEADERS: &[&str] = &["X-Discord-Termination", "X-Discord-Sunset", "Sunset"];
+
+/// Routes which Discord has announced as deprecated, even if no headers are sent for them, with a
+/// note on what to use instead. The route is identified by [`Route::name`].
+///
+/// [`Route::name`]: super::Route::name
+const KNOWN_DEPRECATED_ROUTES: &[(LightMethod, &str, &str)] = &[
+    (LightMethod::Get, "ChannelPins", "Use GET /channels/{channel.id}/messages/pins instead"),
+    (
+        LightMethod::Put,
+        "ChannelPin",
+        "Use PUT /channels/{channel.id}/messages/pins/{message.id} instead",
+    ),
+    (
+        LightMethod::Delete,
+        "ChannelPin",
+        "Use DELETE /channels/{channel.id}/messages/pins/{message.id} instead",
+    ),
+];
+
+/// A deprecated route which was requested by the application, as reported by
+/// [`Http::deprecation_report`].
+///
+/// [`Http::deprecation_report`]: super::Http::deprecation_report
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct DeprecatedRoute {
+    /// The method the route was requested with.
+    pub method: LightMethod,
+    /// The name of the route, see [`Route::name`].
+    ///
+    /// [`Route::name`]: super::Route::name
+    pub route: &'static str,
+    /// The value of the deprecation header sent by Discord, or a note if the route is known to be
+    /// deprecated.
+    pub deprecation: Option<String>,
+    /// The value of the termination header sent by Discord, usually the date after which the
+    /// route will stop working.
+    pub termination: Option<String>,
+    /// The number of times the route was requested since it was first found to be deprecated.
+    pub hits: u64,
+    /// When the route was first found to be deprecated.
+    pub first_seen: Timestamp,
+    /// When the route was last requested.
+    pub last_seen: Timestamp,
+}
+
+/// Keeps track of deprecated routes requested by an [`Http`] client, warning once per route.
+///
+/// [`Http`]: super::Http
+#[derive(Debug, Default)]
+pub(super) struct DeprecationTracker {
+    routes: DashMap<(LightMethod, &'static str), DeprecatedRoute>,
+}
+
+impl DeprecationTracker {
+    /// Records a response to the given route, if the route is deprecated.
+    pub(super) fn record(&self, method: LightMethod, route: &'static str, headers: &HeaderMap) {
+        let known = KNOWN_DEPRECATED_ROUTES
+            .iter()
+            .find(|(known_method, name, _)| *known_method == method && *name == route)
+            .map(|(_, _, note)| (*note).to_string());
+
+        let deprecation = find_header(headers, DEPRECATION_HEADERS).or(known);
+        let termination = find_header(headers, TERMINATION_HEADERS);
+        if deprecation.is_none() && termination.is_none() {
+            return;
+        }
+
+        let now = Timestamp::now();
+        self.routes
+            .entry((method, route))
+            .and_modify(|entry| {
+                entry.hits += 1;
+                entry.last_seen = now;
+            })
+            .or_insert_with(|| {
+                warn!(
+                    "Requested deprecated route {method:?} {route} (deprecation: {}, termination: {})",
+                    deprecation.as_deref().unwrap_or("unknown"),
+                    termination.as_deref().unwrap_or("unknown"),
+                );
+
+                DeprecatedRoute {
+                    method,
+                    route,
+                    deprecation,
+                    termination,
+                    hits: 1,
+                    first_seen: now,
+                    last_seen: now,
+                }
+            });
+    }
+
+    pub(super) fn report(&self) -> Vec<DeprecatedRoute> {
+        self.routes.iter().map(|entry| entry.value().clone()).collect()
+    }
+}
+
+fn find_header(headers: &HeaderMap, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| {
+        let value = headers.get(*name)?.to_str().ok()?;
+        Some(value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    use super::DeprecationTracker;
+    use crate::http::LightMethod;
+
+    #[test]
+    fn records_deprecated_routes() {
+        let tracker = DeprecationTracker::default();
+        tracker.record(LightMethod::Get, "Channel", &HeaderMap::new());
+        assert!(tracker.report().is_empty());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Discord-Termination", HeaderValue::from_static("2025-01-01"));
+        tracker.record(LightMethod::Get, "GuildRegions", &headers);
+        tracker.record(LightMethod::Get, "GuildRegions", &headers);
+
+        let report = tracker.report();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].route, "GuildRegions");
+        assert_eq!(report[0].termination.as_deref(), Some("2025-01-01"));
+        assert_eq!(report[0].deprecation, None);
+        assert_eq!(report[0].hits, 2);
+    }
+
+    #[test]
+    fn records_known_deprecated_routes() {
+        let tracker = DeprecationTracker::default();
+        tracker.record(LightMethod::Get, "ChannelPins", &HeaderMap::new());
+        tracker.record(LightMethod::Post, "ChannelPin", &HeaderMap::new());
+
+        let report = tracker.report();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].route, "ChannelPins");
+        let deprecation = report[0].deprecation.as_deref().unwrap();
+        assert!(deprecation.contains("/messages/pins"));
+        assert_eq!(report[0].termination, None);
+    }
+}
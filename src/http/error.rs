@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 
 use reqwest::header::InvalidHeaderValue;
 use reqwest::{Error as ReqwestError, Method, Response, StatusCode};
@@ -11,7 +12,7 @@ use url::ParseError as UrlError;
 use crate::internal::prelude::*;
 
 enum_number! {
-    #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
     #[non_exhaustive]
     pub enum JsonErrorCode {
         General = 0,
@@ -257,6 +258,21 @@ pub struct DiscordJsonError {
     pub errors: FixedArray<DiscordJsonSingleError>,
 }
 
+impl DiscordJsonError {
+    /// Groups the messages of the explained [`Self::errors`] by their path in the request body.
+    ///
+    /// For example, an invalid embed title would be found under the `embeds.0.title` key.
+    #[must_use]
+    pub fn errors_by_path(&self) -> HashMap<&str, Vec<&str>> {
+        let mut errors: HashMap<&str, Vec<&str>> = HashMap::new();
+        for error in &self.errors {
+            errors.entry(&error.path).or_default().push(&error.message);
+        }
+
+        errors
+    }
+}
+
 #[derive(serde::Deserialize)]
 struct RawDiscordJsonSingleError {
     code: FixedString<u8>,
@@ -319,8 +335,71 @@ pub enum HttpError {
     Request(ReqwestError),
     /// When an application id was expected but missing.
     ApplicationIdMissing,
+    /// When the reader of a streamed attachment was already consumed, for example because the
+    /// request had to be retried. See [`CreateAttachment::from_reader`].
+    ///
+    /// [`CreateAttachment::from_reader`]: crate::builder::CreateAttachment::from_reader
+    AttachmentReaderConsumed,
+    /// When downloading an attachment via [`CreateAttachment::url_with_options`] violated the
+    /// download options.
+    ///
+    /// [`CreateAttachment::url_with_options`]: crate::builder::CreateAttachment::url_with_options
+    AttachmentDownload(AttachmentDownloadError),
+    /// When requests to a route failed too often in a row, so the request was not performed. See
+    /// [`CircuitBreakerConfig`].
+    ///
+    /// [`CircuitBreakerConfig`]: super::CircuitBreakerConfig
+    CircuitOpen { route: &'static str, retry_after: Duration },
 }
 
+/// Why downloading an attachment via [`CreateAttachment::url_with_options`] or
+/// [`Attachment::download_parallel`] failed.
+///
+/// [`CreateAttachment::url_with_options`]: crate::builder::CreateAttachment::url_with_options
+/// [`Attachment::download_parallel`]: crate::model::channel::Attachment::download_parallel
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AttachmentDownloadError {
+    /// The remote responded with a non-successful status code.
+    UnsuccessfulStatus(StatusCode),
+    /// The file is larger than the maximum size, in bytes.
+    TooLarge { max_size: u64 },
+    /// The download didn't finish in time.
+    TimedOut,
+    /// The content type of the file is not allowed. Contains the received content type, if any.
+    DisallowedContentType(Option<String>),
+    /// The remote redirected more often than allowed.
+    TooManyRedirects,
+    /// The remote does not support downloading parts of the file via range requests.
+    RangeNotSupported,
+    /// The remote sent a different number of bytes than requested.
+    SizeMismatch { expected: u64, actual: u64 },
+}
+
+impl fmt::Display for AttachmentDownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsuccessfulStatus(status) => write!(f, "Download failed with status {status}"),
+            Self::TooLarge {
+                max_size,
+            } => write!(f, "File is larger than {max_size} bytes"),
+            Self::TimedOut => f.write_str("Download timed out"),
+            Self::DisallowedContentType(Some(content_type)) => {
+                write!(f, "Content type {content_type} is not allowed")
+            },
+            Self::DisallowedContentType(None) => f.write_str("Content type is missing"),
+            Self::TooManyRedirects => f.write_str("Too many redirects"),
+            Self::RangeNotSupported => f.write_str("Range requests are not supported"),
+            Self::SizeMismatch {
+                expected,
+                actual,
+            } => write!(f, "Expected {expected} bytes, but received {actual}"),
+        }
+    }
+}
+
+impl StdError for AttachmentDownloadError {}
+
 impl HttpError {
     /// Returns true when the error is caused by an unsuccessful request
     #[must_use]
@@ -348,6 +427,52 @@ impl HttpError {
             _ => None,
         }
     }
+
+    /// Returns the error sent by Discord if the error is an unsuccessful request
+    #[must_use]
+    pub fn json_error(&self) -> Option<&DiscordJsonError> {
+        match self {
+            Self::UnsuccessfulRequest(res) => Some(&res.error),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code sent by Discord if the error is an unsuccessful request
+    #[must_use]
+    pub fn json_error_code(&self) -> Option<JsonErrorCode> {
+        self.json_error().map(|error| error.code)
+    }
+
+    /// Returns true when Discord responded with the given error code
+    #[must_use]
+    pub fn is_json_error(&self, code: JsonErrorCode) -> bool {
+        self.json_error_code() == Some(code)
+    }
+
+    /// Returns true when the error is caused by a message that doesn't exist
+    #[must_use]
+    pub fn is_unknown_message(&self) -> bool {
+        self.is_json_error(JsonErrorCode::UnknownMessage)
+    }
+
+    /// Returns true when the error is caused by the bot lacking access to a resource, such as a
+    /// channel it can't view
+    #[must_use]
+    pub fn is_missing_access(&self) -> bool {
+        self.is_json_error(JsonErrorCode::MissingAccess)
+    }
+
+    /// Returns true when the error is caused by the bot lacking permissions for the action
+    #[must_use]
+    pub fn is_missing_permissions(&self) -> bool {
+        self.is_json_error(JsonErrorCode::LackPermissionsForAction)
+    }
+
+    /// Returns true when the request was ratelimited by Discord
+    #[must_use]
+    pub fn is_rate_limited(&self) -> bool {
+        self.status_code() == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
 }
 
 impl From<ErrorResponse> for HttpError {
@@ -405,6 +530,18 @@ impl fmt::Display for HttpError {
             Self::InvalidHeader(_) => f.write_str("Provided value is an invalid header value."),
             Self::Request(_) => f.write_str("Error while sending HTTP request."),
             Self::ApplicationIdMissing => f.write_str("Application id was expected but missing."),
+            Self::AttachmentReaderConsumed => {
+                f.write_str("The reader of a streamed attachment was already consumed.")
+            },
+            Self::AttachmentDownload(inner) => fmt::Display::fmt(inner, f),
+            Self::CircuitOpen {
+                route,
+                retry_after,
+            } => write!(
+                f,
+                "Requests to {route} are failing, retry after {:.1}s.",
+                retry_after.as_secs_f64()
+            ),
         }
     }
 }
@@ -414,6 +551,7 @@ impl StdError for HttpError {
         match self {
             Self::Url(inner) => Some(inner),
             Self::Request(inner) => Some(inner),
+            Self::AttachmentDownload(inner) => Some(inner),
             _ => None,
         }
     }
@@ -510,4 +648,29 @@ mod test {
 
         assert_eq!(error_response, known);
     }
+
+    #[test]
+    fn test_errors_by_path() {
+        let error: DiscordJsonError = serde_json::from_str(
+            r#"{
+                "code": 50035,
+                "message": "Invalid Form Body",
+                "errors": {
+                    "embeds": {"0": {"title": {"_errors": [
+                        {"code": "BASE_TYPE_MAX_LENGTH", "message": "Too long"}
+                    ]}}},
+                    "content": {"_errors": [
+                        {"code": "BASE_TYPE_REQUIRED", "message": "Required"},
+                        {"code": "BASE_TYPE_BAD", "message": "Bad"}
+                    ]}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(error.code, JsonErrorCode::InvalidFormBody);
+        let errors = error.errors_by_path();
+        assert_eq!(errors["embeds.0.title"], ["Too long"]);
+        assert_eq!(errors["content"], ["Required", "Bad"]);
+    }
 }
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::model::id::MessageId;
+
+/// Remembers the messages sent with an idempotency key, so that sending a [`CreateMessage`] with
+/// the same key again returns the existing message instead of posting it twice. Set via
+/// [`HttpBuilder::idempotency_store`].
+///
+/// This makes retrying a send after an ambiguous failure, such as a timeout, safe as long as the
+/// first attempt completed. [`MemoryIdempotencyStore`] keeps keys in memory; implement this trait
+/// on top of a shared database to deduplicate sends across multiple processes.
+///
+/// [`CreateMessage`]: crate::builder::CreateMessage
+/// [`HttpBuilder::idempotency_store`]: super::HttpBuilder::idempotency_store
+#[async_trait]
+pub trait IdempotencyStore: fmt::Debug + Send + Sync {
+    /// Returns the message previously sent with the key, if it's still remembered.
+    async fn get(&self, key: &str) -> Option<MessageId>;
+
+    /// Remembers that the message was sent with the key.
+    async fn insert(&self, key: &str, message_id: MessageId);
+}
+
+/// An [`IdempotencyStore`] keeping keys in memory, forgetting them after a time to live.
+#[derive(Debug)]
+pub struct MemoryIdempotencyStore {
+    ttl: Duration,
+    keys: parking_lot::Mutex<HashMap<String, (MessageId, Instant)>>,
+}
+
+impl MemoryIdempotencyStore {
+    /// Creates a store remembering keys for the given duration.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            keys: parking_lot::Mutex::default(),
+        }
+    }
+}
+
+impl Default for MemoryIdempotencyStore {
+    /// Creates a store remembering keys for 10 minutes.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(10 * 60))
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for MemoryIdempotencyStore {
+    async fn get(&self, key: &str) -> Option<MessageId> {
+        let keys = self.keys.lock();
+        let (message_id, sent_at) = keys.get(key)?;
+        (sent_at.elapsed() < self.ttl).then_some(*message_id)
+    }
+
+    async fn insert(&self, key: &str, message_id: MessageId) {
+        let mut keys = self.keys.lock();
+        keys.retain(|_, (_, sent_at)| sent_at.elapsed() < self.ttl);
+        keys.insert(key.to_owned(), (message_id, Instant::now()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{IdempotencyStore, MemoryIdempotencyStore};
+    use crate::model::id::MessageId;
+
+    #[tokio::test]
+    async fn memory_idempotency_store() {
+        let store = MemoryIdempotencyStore::default();
+        assert_eq!(store.get("announcement").await, None);
+
+        store.insert("announcement", MessageId::new(1)).await;
+        assert_eq!(store.get("announcement").await, Some(MessageId::new(1)));
+        assert_eq!(store.get("other").await, None);
+
+        let store = MemoryIdempotencyStore::new(Duration::ZERO);
+        store.insert("announcement", MessageId::new(1)).await;
+        assert_eq!(store.get("announcement").await, None);
+    }
+}
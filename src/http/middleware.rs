@@ -0,0 +1,87 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Response;
+
+use super::{LightMethod, Request};
+
+/// Observes and modifies the requests performed by an [`Http`] client, for example to log them or
+/// add custom headers. Registered via [`HttpBuilder::middleware`].
+///
+/// Every request performed through [`Http::request`], which includes all endpoint methods, passes
+/// through the registered middleware in the order they were added.
+///
+/// # Examples
+///
+/// Adding a header to every request, and logging the status of each response:
+///
+/// ```rust
+/// use serenity::http::{HttpBuilder, LightMethod, Request, RequestMiddleware};
+///
+/// struct Tracing;
+///
+/// impl RequestMiddleware for Tracing {
+///     fn on_request(&self, request: &mut Request<'_>) {
+///         let headers = request.headers_mut().get_or_insert_with(Default::default);
+///         headers.insert("X-Trace-Id", "my-bot".parse().unwrap());
+///     }
+///
+///     fn on_response(
+///         &self,
+///         method: LightMethod,
+///         route: &'static str,
+///         response: &reqwest::Response,
+///     ) {
+///         println!("{method:?} {route} returned {}", response.status());
+///     }
+/// }
+///
+/// let http = HttpBuilder::new("token").middleware(Tracing).build();
+/// ```
+///
+/// [`Http`]: super::Http
+/// [`Http::request`]: super::Http::request
+/// [`HttpBuilder::middleware`]: super::HttpBuilder::middleware
+pub trait RequestMiddleware: Send + Sync {
+    /// Called before a request is performed, allowing it to be modified.
+    #[allow(unused_variables)]
+    fn on_request(&self, request: &mut Request<'_>) {}
+
+    /// Called once a response was received, before its status is checked. The route is identified
+    /// by [`Route::name`].
+    ///
+    /// [`Route::name`]: super::Route::name
+    #[allow(unused_variables)]
+    fn on_response(&self, method: LightMethod, route: &'static str, response: &Response) {}
+}
+
+/// The middleware registered on an [`Http`] client, in the order they run.
+///
+/// [`Http`]: super::Http
+#[derive(Clone, Default)]
+pub(super) struct MiddlewareChain(pub(super) Vec<Arc<dyn RequestMiddleware>>);
+
+impl MiddlewareChain {
+    pub(super) fn on_request(&self, request: &mut Request<'_>) {
+        for middleware in &self.0 {
+            middleware.on_request(request);
+        }
+    }
+
+    pub(super) fn on_response(
+        &self,
+        method: LightMethod,
+        route: &'static str,
+        response: &Response,
+    ) {
+        for middleware in &self.0 {
+            middleware.on_response(method, route, response);
+        }
+    }
+}
+
+impl fmt::Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter().map(|_| "RequestMiddleware")).finish()
+    }
+}
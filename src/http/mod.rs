@@ -19,12 +19,18 @@
 //! [`Client`]: crate::Client
 //! [model]: crate::model
 
+mod circuit_breaker;
 mod client;
+mod deprecation;
 mod error;
+mod idempotency;
+mod middleware;
 mod multipart;
 mod ratelimiting;
 mod request;
 mod routing;
+#[cfg(feature = "http_transport")]
+mod transport;
 mod typing;
 
 use std::sync::Arc;
@@ -32,12 +38,18 @@ use std::sync::Arc;
 use reqwest::Method;
 pub use reqwest::StatusCode;
 
+pub use self::circuit_breaker::CircuitBreakerConfig;
 pub use self::client::*;
+pub use self::deprecation::*;
 pub use self::error::*;
+pub use self::idempotency::*;
+pub use self::middleware::RequestMiddleware;
 pub use self::multipart::*;
 pub use self::ratelimiting::*;
 pub use self::request::*;
 pub use self::routing::*;
+#[cfg(feature = "http_transport")]
+pub use self::transport::*;
 pub use self::typing::*;
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
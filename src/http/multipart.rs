@@ -1,13 +1,22 @@
 use std::borrow::Cow;
 
 use reqwest::multipart::{Form, Part};
+use reqwest::Body;
+use tokio::io::AsyncReadExt as _;
 
-use crate::builder::CreateAttachment;
+use super::HttpError;
+use crate::builder::{BoxedReader, CreateAttachment};
 use crate::internal::prelude::*;
 
 impl<'a> CreateAttachment<'a> {
     fn into_part(self) -> Result<Part> {
-        let mut part = Part::bytes(self.data);
+        let mut part = match self.reader {
+            Some(reader) => {
+                let stream = reader.take().ok_or(HttpError::AttachmentReaderConsumed)?;
+                Part::stream_with_length(Body::wrap_stream(read_chunks(stream)), reader.len)
+            },
+            None => Part::bytes(self.data),
+        };
         part = guess_mime_str(part, &self.filename)?;
         part = part.file_name(self.filename);
         Ok(part)
@@ -61,6 +70,25 @@ impl<'a> Multipart<'a> {
     }
 }
 
+/// Converts a reader into a stream of chunks, to be used as a streaming request body.
+fn read_chunks(reader: BoxedReader) -> impl futures::Stream<Item = std::io::Result<Vec<u8>>> {
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    futures::stream::unfold(Some(reader), |reader| async move {
+        let mut reader = reader?;
+        let mut chunk = vec![0; CHUNK_SIZE];
+        match reader.read(&mut chunk).await {
+            Ok(0) => None,
+            Ok(len) => {
+                chunk.truncate(len);
+                Some((Ok(chunk), Some(reader)))
+            },
+            // Stop reading after the first error
+            Err(why) => Some((Err(why), None)),
+        }
+    })
+}
+
 fn guess_mime_str(part: Part, filename: &str) -> Result<Part> {
     // This is required for certain endpoints like create sticker, otherwise the Discord API will
     // respond with a 500 Internal Server Error. The mime type chosen is the same as what reqwest
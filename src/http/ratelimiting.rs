@@ -50,6 +50,8 @@ use tokio::time::{sleep, Duration};
 use tracing::debug;
 
 pub use super::routing::RatelimitingBucket;
+#[cfg(feature = "http_transport")]
+use super::HttpTransport;
 use super::{HttpError, LightMethod, Request, Token};
 use crate::internal::prelude::*;
 
@@ -89,18 +91,23 @@ pub struct Ratelimiter {
     token: Secret<Token>,
     absolute_ratelimits: bool,
     ratelimit_callback: parking_lot::RwLock<Box<dyn Fn(RatelimitInfo) + Send + Sync>>,
+    #[cfg(feature = "http_transport")]
+    transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl fmt::Debug for Ratelimiter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Ratelimiter")
+        let mut debug = f.debug_struct("Ratelimiter");
+        debug
             .field("client", &self.client)
             .field("global", &self.global)
             .field("routes", &self.routes)
             .field("token", &self.token)
             .field("absolute_ratelimits", &self.absolute_ratelimits)
-            .field("ratelimit_callback", &"Fn(RatelimitInfo)")
-            .finish()
+            .field("ratelimit_callback", &"Fn(RatelimitInfo)");
+        #[cfg(feature = "http_transport")]
+        debug.field("transport", &self.transport);
+        debug.finish()
     }
 }
 
@@ -117,6 +124,8 @@ impl Ratelimiter {
             routes: DashMap::new(),
             absolute_ratelimits: false,
             ratelimit_callback: parking_lot::RwLock::new(Box::new(|_| {})),
+            #[cfg(feature = "http_transport")]
+            transport: None,
         }
     }
 
@@ -128,6 +137,21 @@ impl Ratelimiter {
         *self.ratelimit_callback.write() = ratelimit_callback;
     }
 
+    /// Sets the transport used to perform requests, instead of the [`reqwest`] client.
+    #[cfg(feature = "http_transport")]
+    pub(super) fn set_transport(&mut self, transport: Arc<dyn HttpTransport>) {
+        self.transport = Some(transport);
+    }
+
+    async fn execute(&self, request: reqwest::Request) -> Result<Response> {
+        #[cfg(feature = "http_transport")]
+        if let Some(transport) = &self.transport {
+            return transport.execute(request).await;
+        }
+
+        Ok(self.client.execute(request).await?)
+    }
+
     // Sets whether absolute ratelimits should be used.
     pub fn set_absolute_ratelimits(&mut self, absolute_ratelimits: bool) {
         self.absolute_ratelimits = absolute_ratelimits;
@@ -195,7 +219,7 @@ impl Ratelimiter {
             }
 
             let request = req.clone().build(&self.client, self.token.expose_secret(), None)?;
-            let response = self.client.execute(request.build()?).await?;
+            let response = self.execute(request.build()?).await?;
 
             // Check if the request got ratelimited by checking for status 429, and if so, sleep
             // for the value of the header 'retry-after' - which is in milliseconds - and then
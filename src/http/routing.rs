@@ -53,6 +53,16 @@ macro_rules! routes {
                 }
             }
 
+            /// Returns the name of the route, which is the same for all requests to this endpoint.
+            #[must_use]
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$name {..} => stringify!($name),
+                    )+
+                }
+            }
+
             #[must_use]
             pub fn path(self) -> Cow<'static, str> {
                 match self {
@@ -197,6 +207,10 @@ routes! ('a, {
     api!("/channels/{}/polls/{}/expire", channel_id, message_id),
     Some(RatelimitingKind::PathAndId(GenericId::new(channel_id.get())));
 
+    ChannelVoiceStatus { channel_id: ChannelId },
+    api!("/channels/{}/voice-status", channel_id),
+    Some(RatelimitingKind::PathAndId(GenericId::new(channel_id.get())));
+
     Gateway,
     api!("/gateway"),
     Some(RatelimitingKind::Path);
@@ -241,6 +255,14 @@ routes! ('a, {
     api!("/guilds/{}/widget", guild_id),
     Some(RatelimitingKind::PathAndId(GenericId::new(guild_id.get())));
 
+    GuildWidgetJson { guild_id: GuildId },
+    api!("/guilds/{}/widget.json", guild_id),
+    Some(RatelimitingKind::PathAndId(GenericId::new(guild_id.get())));
+
+    GuildWidgetImage { guild_id: GuildId },
+    api!("/guilds/{}/widget.png", guild_id),
+    Some(RatelimitingKind::PathAndId(GenericId::new(guild_id.get())));
+
     GuildPreview { guild_id: GuildId },
     api!("/guilds/{}/preview", guild_id),
     Some(RatelimitingKind::PathAndId(GenericId::new(guild_id.get())));
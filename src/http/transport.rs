@@ -0,0 +1,291 @@
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, RANGE};
+use reqwest::{
+    Client,
+    Method,
+    Request as ReqwestRequest,
+    Response,
+    ResponseBuilderExt as _,
+    StatusCode,
+};
+use serde_json::{json, Value};
+
+use super::LightMethod;
+use crate::internal::prelude::*;
+
+/// Performs the HTTP requests built by an [`Http`] client. Set via [`HttpBuilder::transport`].
+///
+/// By default, requests are performed by the [`reqwest::Client`] of the [`Http`] client. A custom
+/// transport can be used to record requests, or to avoid hitting Discord at all in tests, see
+/// [`MockTransport`].
+///
+/// [`Http`]: super::Http
+/// [`HttpBuilder::transport`]: super::HttpBuilder::transport
+#[async_trait]
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    /// Performs the request and returns the response.
+    async fn execute(&self, request: ReqwestRequest) -> Result<Response>;
+}
+
+#[async_trait]
+impl HttpTransport for Client {
+    async fn execute(&self, request: ReqwestRequest) -> Result<Response> {
+        Ok(Client::execute(self, request).await?)
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    async fn execute(&self, request: ReqwestRequest) -> Result<Response> {
+        (**self).execute(request).await
+    }
+}
+
+/// A request which was performed through a [`MockTransport`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct MockRequest {
+    /// The method of the request.
+    pub method: Method,
+    /// The path of the request, such as `/api/v10/channels/1/messages`.
+    pub path: String,
+    /// The headers of the request, such as the `Range` of a CDN download.
+    pub headers: HeaderMap,
+    /// The body of the request, if it wasn't a multipart upload.
+    pub body: Option<Vec<u8>>,
+}
+
+impl MockRequest {
+    /// Deserializes the JSON body of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the request has no body, or it's not valid JSON.
+    pub fn json(&self) -> Result<Value> {
+        Ok(serde_json::from_slice(self.body.as_deref().unwrap_or_default())?)
+    }
+}
+
+#[derive(Debug)]
+enum MockBody {
+    Json(Value),
+    Bytes(Vec<u8>),
+    File(Vec<u8>),
+}
+
+#[derive(Debug)]
+struct MockRoute {
+    method: Method,
+    path: String,
+    status: StatusCode,
+    body: MockBody,
+}
+
+impl MockRoute {
+    /// Checks if the route matches the request path, comparing segments from the end so the API
+    /// version prefix can be left out. A `*` segment matches any value.
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        if self.method != *method {
+            return false;
+        }
+
+        let mut actual = path.trim_matches('/').split('/').rev();
+        self.path.trim_matches('/').split('/').rev().all(|expected| {
+            actual.next().is_some_and(|actual| expected == "*" || expected == actual)
+        })
+    }
+
+    /// Builds the status, content type and body of the response to a matching request.
+    fn respond(&self, request: &ReqwestRequest) -> Result<(StatusCode, &'static str, Vec<u8>)> {
+        const OCTET_STREAM: &str = "application/octet-stream";
+
+        Ok(match &self.body {
+            MockBody::Json(body) => (self.status, "application/json", serde_json::to_vec(body)?),
+            MockBody::Bytes(body) => (self.status, OCTET_STREAM, body.clone()),
+            MockBody::File(data) => {
+                let range = request.headers().get(RANGE).and_then(|range| range.to_str().ok());
+                match range.and_then(|range| parse_range(range, data.len())) {
+                    Some((start, end)) => {
+                        (StatusCode::PARTIAL_CONTENT, OCTET_STREAM, data[start..end].to_vec())
+                    },
+                    None => (self.status, OCTET_STREAM, data.clone()),
+                }
+            },
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<MockRoute>,
+    requests: Vec<MockRequest>,
+}
+
+/// An [`HttpTransport`] which responds to requests with canned responses instead of sending them to
+/// Discord, for unit testing code that uses [`Http`].
+///
+/// Responses are matched by method and the end of the request path, so `/channels/*/messages`
+/// matches a request to `/api/v10/channels/1/messages`. A `*` segment matches any value. Routes
+/// are tried in the order they were added. Requests which don't match any route are answered with
+/// a 404 error.
+///
+/// The transport can be cloned, and all clones share the same routes and recorded requests.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::http::{HttpBuilder, LightMethod, MockTransport, StatusCode};
+/// use serenity::model::id::ChannelId;
+/// use serde_json::json;
+///
+/// # async fn run() -> serenity::Result<()> {
+/// let transport = MockTransport::new().respond(
+///     LightMethod::Get,
+///     "/channels/*",
+///     StatusCode::OK,
+///     json!({"id": "1", "type": 0, "guild_id": "2", "name": "general"}),
+/// );
+///
+/// let http = HttpBuilder::new("token").transport(transport.clone()).build();
+/// let channel = http.get_channel(ChannelId::new(1)).await?;
+///
+/// assert_eq!(channel.id(), ChannelId::new(1));
+/// assert_eq!(transport.requests()[0].path, "/api/v10/channels/1");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Http`]: super::Http
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MockTransport {
+    state: Arc<parking_lot::Mutex<MockState>>,
+}
+
+impl MockTransport {
+    /// Creates a transport without any routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Responds to requests matching the method and path with the given status and JSON body.
+    pub fn respond(
+        self,
+        method: LightMethod,
+        path: impl Into<String>,
+        status: StatusCode,
+        body: Value,
+    ) -> Self {
+        self.route(method, path.into(), status, MockBody::Json(body))
+    }
+
+    /// Responds to requests matching the method and path with the given status and raw body.
+    pub fn respond_bytes(
+        self,
+        method: LightMethod,
+        path: impl Into<String>,
+        status: StatusCode,
+        body: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.route(method, path.into(), status, MockBody::Bytes(body.into()))
+    }
+
+    /// Serves a file to GET requests matching the path, like Discord's CDN. Requests with a
+    /// `Range` header receive only the requested bytes, with a `206 Partial Content` status.
+    pub fn serve_file(self, path: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        self.route(LightMethod::Get, path.into(), StatusCode::OK, MockBody::File(data.into()))
+    }
+
+    fn route(self, method: LightMethod, path: String, status: StatusCode, body: MockBody) -> Self {
+        self.state.lock().routes.push(MockRoute {
+            method: method.reqwest_method(),
+            path,
+            status,
+            body,
+        });
+        self
+    }
+
+    /// Returns all requests which were performed through this transport so far.
+    #[must_use]
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().requests.clone()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MockTransport {
+    async fn execute(&self, request: ReqwestRequest) -> Result<Response> {
+        let method = request.method().clone();
+        let path = request.url().path().to_string();
+
+        let mut state = self.state.lock();
+        let route = state.routes.iter().find(|route| route.matches(&method, &path));
+        let (status, content_type, body) = if let Some(route) = route {
+            route.respond(&request)?
+        } else {
+            let message = format!("No mock response for {method} {path}");
+            let body = json!({"code": 0, "message": message});
+            (StatusCode::NOT_FOUND, "application/json", serde_json::to_vec(&body)?)
+        };
+
+        state.requests.push(MockRequest {
+            method,
+            path,
+            headers: request.headers().clone(),
+            body: request.body().and_then(|body| body.as_bytes()).map(<[u8]>::to_vec),
+        });
+        drop(state);
+
+        let response = http_crate::Response::builder()
+            .status(status)
+            .url(request.url().clone())
+            .header(CONTENT_TYPE, HeaderValue::from_static(content_type))
+            .body(body)
+            .expect("mock response should be valid");
+
+        Ok(response.into())
+    }
+}
+
+/// Parses a `Range` header of the form `bytes=start-end` into the exclusive range of bytes to
+/// send, clamped to the length of the file.
+fn parse_range(range: &str, len: usize) -> Option<(usize, usize)> {
+    let (start, end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    let start: usize = start.parse().ok()?;
+    let end = end.parse::<usize>().ok()?.saturating_add(1).min(len);
+    (start < end).then_some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::MockTransport;
+    use crate::http::{HttpBuilder, HttpError, LightMethod, StatusCode};
+    use crate::model::id::{ChannelId, MessageId};
+
+    #[tokio::test]
+    async fn mock_transport_responses() {
+        let transport = MockTransport::new().respond(
+            LightMethod::Get,
+            "/channels/*",
+            StatusCode::OK,
+            json!({"id": "1", "type": 0, "guild_id": "2", "name": "general"}),
+        );
+        let http = HttpBuilder::new("token").transport(transport.clone()).build();
+
+        let channel = http.get_channel(ChannelId::new(1)).await.unwrap();
+        assert_eq!(channel.id(), ChannelId::new(1));
+
+        let error = http.get_message(ChannelId::new(1), MessageId::new(2)).await.unwrap_err();
+        assert_eq!(error.as_http().and_then(HttpError::status_code), Some(StatusCode::NOT_FOUND));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].path, "/api/v10/channels/1/messages/2");
+    }
+}
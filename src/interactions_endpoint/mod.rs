@@ -0,0 +1,243 @@
+//! Contains tools related to Discord's Interactions Endpoint URL feature.
+//!
+//! "You can optionally configure an interactions endpoint to receive interactions via HTTP POSTs
+//! rather than over Gateway with a bot user."
+//!
+//! <https://discord.com/developers/docs/tutorials/upgrading-to-application-commands#adding-an-interactions-endpoint-url>
+//!
+//! The endpoint can be served by any HTTP server library: verify each request with a
+//! [`Verifier`], then parse the body with [`parse_interaction`]. Alternatively, enable the
+//! `interactions_server` feature and use an [`InteractionsServer`] to dispatch the received
+//! interactions to an [`EventHandler`].
+//!
+//! Requests to the Webhook Events URL, which receives events such as an application being
+//! authorized, are signed the same way. After verifying them, parse them with
+//! [`parse_webhook_event`].
+//!
+//! <https://discord.com/developers/docs/events/webhook-events>
+
+#[cfg(feature = "interactions_server")]
+mod server;
+
+#[cfg(feature = "interactions_server")]
+pub use self::server::*;
+use crate::model::application::{Interaction, WebhookEventPayload};
+
+/// Parses a hex string into an array of `[u8]`
+fn parse_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
+    if s.len() != N * 2 {
+        return None;
+    }
+
+    let mut res = [0; N];
+    for (i, byte) in res.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(s.get(2 * i..2 * (i + 1))?, 16).ok()?;
+    }
+    Some(res)
+}
+
+/// The byte array couldn't be parsed into a valid cryptographic public key.
+#[derive(Debug)]
+pub struct InvalidKey(ed25519_dalek::SignatureError);
+impl std::fmt::Display for InvalidKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid bot public key: {}", self.0)
+    }
+}
+impl std::error::Error for InvalidKey {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Used to cryptographically verify incoming interactions HTTP request for authenticity.
+///
+/// If incoming requests are not verified, Discord will reject the URL for security reasons.
+///
+/// ```rust
+/// use serenity::interactions_endpoint::Verifier;
+///
+/// let verifier =
+///     Verifier::new("67c6bd767ca099e79efac9fcce4d2022a63bf7dea780e7f3d813f694c1597089");
+///
+/// // When receiving an HTTP request:
+/// # let http_headers = std::collections::HashMap::from([("X-Signature-Ed25519", ""), ("X-Signature-Timestamp", "")]);
+/// # let request_body = &[];
+/// let signature = http_headers["X-Signature-Ed25519"];
+/// let timestamp = http_headers["X-Signature-Timestamp"];
+/// if verifier.verify(signature, timestamp, request_body).is_err() {
+///     // Send HTTP 401 Unauthorized response
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Verifier {
+    public_key: ed25519_dalek::VerifyingKey,
+}
+
+impl Verifier {
+    /// Creates a new [`Verifier`] from the given public key hex string.
+    ///
+    /// Panics if the given key is invalid. For a low-level, non-panicking variant, see
+    /// [`Self::try_new()`].
+    #[must_use]
+    pub fn new(public_key: &str) -> Self {
+        Self::try_new(parse_hex(public_key).expect("public key must be a 64 digit hex string"))
+            .expect("invalid public key")
+    }
+
+    /// Creates a new [`Verifier`] from the public key bytes.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidKey`] if the key isn't cryptographically valid.
+    pub fn try_new(public_key: [u8; 32]) -> Result<Self, InvalidKey> {
+        Ok(Self {
+            public_key: ed25519_dalek::VerifyingKey::from_bytes(&public_key).map_err(InvalidKey)?,
+        })
+    }
+
+    /// Verifies a Discord request for authenticity, given the `X-Signature-Ed25519` HTTP header,
+    /// `X-Signature-Timestamp` HTTP headers and request body.
+    // We just need to differentiate "pass" and "failure". There's deliberately no data besides ().
+    #[allow(clippy::result_unit_err, clippy::missing_errors_doc)]
+    pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> Result<(), ()> {
+        use ed25519_dalek::Verifier as _;
+
+        // Extract and parse signature
+        let signature_bytes = parse_hex(signature).ok_or(())?;
+        let signature = ed25519_dalek::Signature::from_bytes(&signature_bytes);
+
+        // Verify
+        let message_to_verify = [timestamp.as_bytes(), body].concat();
+        self.public_key.verify(&message_to_verify, &signature).map_err(|_| ())
+    }
+}
+
+/// Parses the body of a request sent to the interactions endpoint URL. The request must be
+/// verified with [`Verifier::verify`] first.
+///
+/// Discord expects [`Interaction::Ping`]s to be answered with a
+/// [`CreateInteractionResponse::Pong`], which is also required to set the URL in the application's
+/// settings.
+///
+/// ```rust
+/// use serenity::interactions_endpoint::{parse_interaction, Verifier};
+/// use serenity::model::application::Interaction;
+///
+/// # fn handle(verifier: &Verifier, signature: &str, timestamp: &str, body: &[u8]) {
+/// if verifier.verify(signature, timestamp, body).is_err() {
+///     // Send HTTP 401 Unauthorized response
+///     return;
+/// }
+///
+/// match parse_interaction(body) {
+///     Ok(Interaction::Ping(_)) => {
+///         // Send HTTP 200 OK response with the body `{"type":1}`
+///     },
+///     Ok(Interaction::Command(command)) => println!("Received /{}", command.data.name),
+///     Ok(_) => {},
+///     Err(_) => {
+///         // Send HTTP 400 Bad Request response
+///     },
+/// }
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the body is not a valid interaction.
+///
+/// [`CreateInteractionResponse::Pong`]: crate::builder::CreateInteractionResponse::Pong
+pub fn parse_interaction(body: &[u8]) -> serde_json::Result<Interaction> {
+    serde_json::from_slice(body)
+}
+
+/// Parses the body of a request sent to the Webhook Events URL. The request must be verified with
+/// [`Verifier::verify`] first.
+///
+/// Discord expects a `204 No Content` response to every event, including pings.
+///
+/// ```rust
+/// use serenity::interactions_endpoint::{parse_webhook_event, Verifier};
+/// use serenity::model::application::{WebhookEvent, WebhookEventType};
+///
+/// # fn handle(verifier: &Verifier, signature: &str, timestamp: &str, body: &[u8]) {
+/// if verifier.verify(signature, timestamp, body).is_err() {
+///     // Send HTTP 401 Unauthorized response
+///     return;
+/// }
+///
+/// let Ok(payload) = parse_webhook_event(body) else {
+///     // Send HTTP 400 Bad Request response
+///     return;
+/// };
+/// if let Some(WebhookEvent::ApplicationAuthorized(event)) = payload.event.map(|body| body.event) {
+///     println!("Authorized by {}", event.user.name);
+/// }
+/// // Send HTTP 204 No Content response
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the body is not a valid webhook event payload.
+pub fn parse_webhook_event(body: &[u8]) -> serde_json::Result<WebhookEventPayload> {
+    serde_json::from_slice(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex::<4>("bf7dea78"), Some([0xBF, 0x7D, 0xEA, 0x78]));
+        assert_eq!(parse_hex::<4>("bf7dea7"), None);
+        assert_eq!(parse_hex::<4>("bf7dea789"), None);
+        assert_eq!(parse_hex::<4>("bf7dea7x"), None);
+        assert_eq!(parse_hex(""), Some([]));
+        assert_eq!(
+            parse_hex("67c6bd767ca099e79efac9fcce4d2022a63bf7dea780e7f3d813f694c1597089"),
+            Some([
+                0x67, 0xC6, 0xBD, 0x76, 0x7C, 0xA0, 0x99, 0xE7, 0x9E, 0xFA, 0xC9, 0xFC, 0xCE, 0x4D,
+                0x20, 0x22, 0xA6, 0x3B, 0xF7, 0xDE, 0xA7, 0x80, 0xE7, 0xF3, 0xD8, 0x13, 0xF6, 0x94,
+                0xC1, 0x59, 0x70, 0x89
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_webhook_event() {
+        use crate::model::application::{WebhookEvent, WebhookEventType};
+
+        let ping = parse_webhook_event(br#"{"version":1,"application_id":"1","type":0}"#).unwrap();
+        assert_eq!(ping.kind, WebhookEventType::Ping);
+        assert!(ping.event.is_none());
+
+        let body = br#"{
+            "version": 1,
+            "application_id": "1",
+            "type": 1,
+            "event": {
+                "type": "APPLICATION_AUTHORIZED",
+                "timestamp": "2024-10-18T14:42:53.064834",
+                "data": {
+                    "integration_type": 1,
+                    "scopes": ["applications.commands"],
+                    "user": {"id": "2", "username": "user", "discriminator": "0", "avatar": null}
+                }
+            }
+        }"#;
+        let payload = parse_webhook_event(body).unwrap();
+        let Some(WebhookEvent::ApplicationAuthorized(event)) = payload.event.map(|e| e.event)
+        else {
+            panic!("parsed the wrong event");
+        };
+        assert_eq!(event.user.name, "user");
+        assert!(event.guild.is_none());
+
+        let body = br#"{"version":1,"application_id":"1","type":1,"event":{"type":"NEW_EVENT","timestamp":"2024-10-18T14:42:53Z","data":{"a":1}}}"#;
+        let event = parse_webhook_event(body).unwrap().event.unwrap().event;
+        assert_eq!(event.name(), "NEW_EVENT");
+    }
+}
@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::channel::mpsc;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Request as ReqwestRequest, Response, ResponseBuilderExt as _, StatusCode};
+use tokio::sync::oneshot;
+use tracing::warn;
+
+use super::Verifier;
+#[cfg(feature = "cache")]
+use crate::cache::Cache;
+#[cfg(feature = "framework")]
+use crate::framework::Framework;
+use crate::gateway::client::dispatch::dispatch_model;
+use crate::gateway::client::{
+    CommandStats,
+    Context,
+    EventHandler,
+    Extensions,
+    RawEventHandler,
+    ShardState,
+};
+#[cfg(feature = "collector")]
+use crate::gateway::EventBus;
+use crate::gateway::ShardMessenger;
+use crate::http::{Http, HttpBuilder, HttpTransport};
+use crate::internal::prelude::*;
+use crate::model::application::Interaction;
+use crate::model::event::{Event, InteractionCreateEvent};
+use crate::model::id::{InteractionId, ShardId};
+
+/// The default time handlers have to respond to an interaction, see
+/// [`InteractionsServer::timeout`].
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// The initial response to an interaction, captured from the handler's call to the interaction
+/// callback endpoint.
+struct CapturedResponse {
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+/// The interactions waiting for their initial response. [`None`] is sent if the response was sent
+/// to Discord via the API instead.
+type PendingResponses =
+    Arc<parking_lot::Mutex<HashMap<InteractionId, oneshot::Sender<Option<CapturedResponse>>>>>;
+
+/// Captures requests to the callback endpoint of interactions received by an
+/// [`InteractionsServer`], so they can be sent back as the HTTP response instead. All other
+/// requests are performed by the original [`Http`] client.
+struct CallbackTransport {
+    pending: PendingResponses,
+    inner: Arc<Http>,
+}
+
+impl CallbackTransport {
+    /// Extracts the interaction ID from a request to `/interactions/{id}/{token}/callback`.
+    fn interaction_id(request: &ReqwestRequest) -> Option<InteractionId> {
+        let mut segments = request.url().path_segments()?.rev();
+        if segments.next()? != "callback" {
+            return None;
+        }
+
+        segments.nth(1)?.parse().ok()
+    }
+}
+
+impl fmt::Debug for CallbackTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackTransport").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for CallbackTransport {
+    async fn execute(&self, request: ReqwestRequest) -> Result<Response> {
+        let sender = Self::interaction_id(&request).and_then(|id| self.pending.lock().remove(&id));
+        let Some(sender) = sender else {
+            return self.inner.execute(request).await;
+        };
+
+        // Multipart responses can't be captured, as their body is streamed.
+        let Some(body) = request.body().and_then(|body| body.as_bytes()).map(<[u8]>::to_vec) else {
+            let response = self.inner.execute(request).await;
+            drop(sender.send(None));
+            return response;
+        };
+
+        let content_type = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+        drop(sender.send(Some(CapturedResponse {
+            content_type,
+            body,
+        })));
+
+        let response = http_crate::Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .url(request.url().clone())
+            .body(Vec::new())
+            .expect("callback response should be valid");
+
+        Ok(response.into())
+    }
+}
+
+/// The HTTP response to send back to Discord for a request to the interactions endpoint, as
+/// returned by [`InteractionsServer::handle`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct InteractionsResponse {
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// The value of the `Content-Type` header, if the response has a body.
+    pub content_type: Option<String>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+
+impl InteractionsResponse {
+    fn status(status: StatusCode) -> Self {
+        Self {
+            status,
+            content_type: None,
+            body: Vec::new(),
+        }
+    }
+}
+
+/// Receives interactions via HTTP instead of the gateway, by handling requests sent by Discord to
+/// the application's interactions endpoint URL. Created via
+/// [`ClientBuilder::into_interactions_server`].
+///
+/// Interactions are dispatched to the same event handlers, framework and cache as a gateway
+/// [`Client`], so [`EventHandler::interaction_create`] works the same in both modes. The initial
+/// response created by the handler, for example via [`CommandInteraction::create_response`], is
+/// sent back as the HTTP response. Everything else, such as followup messages, is sent to Discord
+/// as usual.
+///
+/// The server is independent of any HTTP server library. Pass the `X-Signature-Ed25519` and
+/// `X-Signature-Timestamp` headers and the body of each `POST` request to [`Self::handle`], and
+/// reply with the returned [`InteractionsResponse`].
+///
+/// **Note**: Initial responses with attachments can't be sent back as the HTTP response. They are
+/// sent to Discord via the API instead, and the request is answered with `202 Accepted`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::interactions_endpoint::Verifier;
+/// use serenity::prelude::*;
+/// # struct Handler;
+/// # impl EventHandler for Handler {}
+///
+/// # async fn run(signature: &str, timestamp: &str, body: &[u8]) {
+/// let verifier =
+///     Verifier::new("67c6bd767ca099e79efac9fcce4d2022a63bf7dea780e7f3d813f694c1597089");
+/// let server = Client::builder("token", GatewayIntents::empty())
+///     .event_handler(Handler)
+///     .into_interactions_server(verifier);
+///
+/// // For each POST request to the interactions endpoint:
+/// let response = server.handle(signature, timestamp, body).await;
+/// // Reply with response.status, response.content_type and response.body
+/// # }
+/// ```
+///
+/// [`ClientBuilder::into_interactions_server`]: crate::gateway::client::ClientBuilder::into_interactions_server
+/// [`Client`]: crate::Client
+/// [`CommandInteraction::create_response`]: crate::model::application::CommandInteraction::create_response
+pub struct InteractionsServer {
+    verifier: Verifier,
+    data: Arc<dyn std::any::Any + Send + Sync>,
+    extensions: Arc<Extensions>,
+    shard_state: ShardState,
+    http: Arc<Http>,
+    #[cfg(feature = "cache")]
+    cache: Arc<Cache>,
+    #[cfg(feature = "framework")]
+    framework: Option<Arc<dyn Framework>>,
+    event_handler: Option<Arc<dyn EventHandler>>,
+    raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    command_stats: Option<Arc<CommandStats>>,
+    #[cfg(feature = "collector")]
+    event_bus: EventBus,
+    pending: PendingResponses,
+    timeout: Duration,
+}
+
+impl InteractionsServer {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        verifier: Verifier,
+        data: Arc<dyn std::any::Any + Send + Sync>,
+        extensions: Arc<Extensions>,
+        http: Arc<Http>,
+        #[cfg(feature = "cache")] cache: Arc<Cache>,
+        #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
+        event_handler: Option<Arc<dyn EventHandler>>,
+        raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+        command_stats: Option<Arc<CommandStats>>,
+    ) -> Self {
+        let pending = PendingResponses::default();
+
+        let mut builder =
+            HttpBuilder::new(http.token()).ratelimiter_disabled(http.ratelimiter.is_none());
+        if let Some(application_id) = http.application_id() {
+            builder = builder.application_id(application_id);
+        }
+        if let Some(proxy) = &http.proxy {
+            builder = builder.proxy(proxy.as_str());
+        }
+        if let Some(allowed_mentions) = http.default_allowed_mentions() {
+            builder = builder.default_allowed_mentions(allowed_mentions);
+        }
+        let builder = builder.transport(CallbackTransport {
+            pending: Arc::clone(&pending),
+            inner: http,
+        });
+
+        Self {
+            verifier,
+            data,
+            extensions,
+            shard_state: ShardState::default(),
+            http: Arc::new(builder.build()),
+            #[cfg(feature = "cache")]
+            cache,
+            #[cfg(feature = "framework")]
+            framework,
+            event_handler,
+            raw_event_handler,
+            command_stats,
+            #[cfg(feature = "collector")]
+            event_bus: EventBus::new(),
+            pending,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Sets how long handlers have to create the initial response to an interaction. Defaults to
+    /// 3 seconds, which is the time Discord waits for the response.
+    ///
+    /// Handlers which need more time should defer the response first.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The HTTP client passed to handlers.
+    #[must_use]
+    pub fn http(&self) -> &Arc<Http> {
+        &self.http
+    }
+
+    /// The cache which received interactions are applied to.
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn cache(&self) -> &Arc<Cache> {
+        &self.cache
+    }
+
+    fn context(&self) -> Context {
+        // There is no shard, so messages to it are dropped.
+        let (tx, _) = mpsc::unbounded();
+        let shard = ShardMessenger {
+            tx,
+            #[cfg(feature = "collector")]
+            shard_id: ShardId(0),
+            #[cfg(feature = "collector")]
+            event_bus: self.event_bus.clone(),
+        };
+
+        Context::new(
+            Arc::clone(&self.data),
+            Arc::clone(&self.extensions),
+            self.shard_state.clone(),
+            shard,
+            ShardId(0),
+            Arc::clone(&self.http),
+            #[cfg(feature = "cache")]
+            Arc::clone(&self.cache),
+        )
+    }
+
+    /// Handles a request to the interactions endpoint, given the `X-Signature-Ed25519` and
+    /// `X-Signature-Timestamp` headers and the request body.
+    ///
+    /// Responds with `401 Unauthorized` if the signature is invalid, and to pings as required by
+    /// Discord. Other interactions are dispatched to the handlers, and their initial response is
+    /// returned. If the handlers don't respond within the [`Self::timeout`], `500 Internal Server
+    /// Error` is returned.
+    pub async fn handle(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        body: &[u8],
+    ) -> InteractionsResponse {
+        if self.verifier.verify(signature, timestamp, body).is_err() {
+            return InteractionsResponse::status(StatusCode::UNAUTHORIZED);
+        }
+
+        let interaction = match super::parse_interaction(body) {
+            Ok(interaction) => interaction,
+            Err(why) => {
+                warn!("Failed to deserialize interaction: {why:?}");
+                return InteractionsResponse::status(StatusCode::BAD_REQUEST);
+            },
+        };
+
+        if let Interaction::Ping(_) = interaction {
+            return InteractionsResponse {
+                status: StatusCode::OK,
+                content_type: Some(String::from("application/json")),
+                body: br#"{"type":1}"#.to_vec(),
+            };
+        }
+
+        let id = interaction.id();
+        let (tx, mut rx) = oneshot::channel();
+        self.pending.lock().insert(id, tx);
+
+        let event = Event::InteractionCreate(InteractionCreateEvent {
+            interaction,
+        });
+        #[cfg(feature = "collector")]
+        self.event_bus.publish(ShardId(0), &event);
+
+        let mut dispatch = tokio::spawn(dispatch_model(
+            event,
+            self.context(),
+            #[cfg(feature = "framework")]
+            self.framework.clone(),
+            self.event_handler.clone(),
+            self.raw_event_handler.clone(),
+            self.command_stats.clone(),
+        ));
+
+        let response = tokio::time::timeout(self.timeout, async {
+            tokio::select! {
+                biased;
+                response = &mut rx => response.ok(),
+                // The handlers may have finished without responding.
+                _ = &mut dispatch => rx.try_recv().ok(),
+            }
+        })
+        .await;
+        self.pending.lock().remove(&id);
+
+        match response {
+            Ok(Some(Some(response))) => InteractionsResponse {
+                status: StatusCode::OK,
+                content_type: response.content_type,
+                body: response.body,
+            },
+            Ok(Some(None)) => InteractionsResponse::status(StatusCode::ACCEPTED),
+            _ => InteractionsResponse::status(StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+}
+
+impl fmt::Debug for InteractionsServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InteractionsServer").field("timeout", &self.timeout).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, Request, StatusCode, Url};
+
+    use super::CallbackTransport;
+    use crate::interactions_endpoint::Verifier;
+    use crate::model::gateway::GatewayIntents;
+    use crate::model::id::InteractionId;
+    use crate::Client;
+
+    #[test]
+    fn callback_interaction_id() {
+        let id = |url: &str| {
+            CallbackTransport::interaction_id(&Request::new(Method::POST, Url::parse(url).unwrap()))
+        };
+
+        let base = "https://discord.com/api/v10";
+        assert_eq!(
+            id(&format!("{base}/interactions/1/token/callback")),
+            Some(InteractionId::new(1))
+        );
+        assert_eq!(id(&format!("{base}/interactions/1/token")), None);
+        assert_eq!(id(&format!("{base}/webhooks/1/token/messages/@original")), None);
+    }
+
+    #[tokio::test]
+    async fn reject_invalid_signature() {
+        let verifier =
+            Verifier::new("67c6bd767ca099e79efac9fcce4d2022a63bf7dea780e7f3d813f694c1597089");
+        let server =
+            Client::builder("token", GatewayIntents::empty()).into_interactions_server(verifier);
+
+        let response = server.handle(&"0".repeat(128), "1", br#"{"type":1}"#).await;
+        assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -101,6 +101,8 @@ pub mod gateway;
 pub mod http;
 #[cfg(feature = "interactions_endpoint")]
 pub mod interactions_endpoint;
+#[cfg(feature = "testing")]
+pub mod testing;
 #[cfg(feature = "utils")]
 pub mod utils;
 
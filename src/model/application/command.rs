@@ -1,13 +1,26 @@
 use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt;
+#[cfg(feature = "model")]
+use std::future::Future;
 
+#[cfg(feature = "model")]
+use futures::{stream, StreamExt as _};
 use serde::Serialize;
 
 #[cfg(feature = "unstable")]
 use super::{InstallationContext, InteractionContext};
 #[cfg(feature = "model")]
-use crate::builder::CreateCommand;
+use crate::builder::{
+    overwrite_commands,
+    overwrite_commands_partial,
+    sync_commands,
+    CreateCommand,
+};
 #[cfg(feature = "model")]
 use crate::http::Http;
+#[cfg(feature = "http")]
+use crate::http::{HttpError, JsonErrorCode};
 use crate::internal::prelude::*;
 use crate::model::channel::ChannelType;
 use crate::model::id::{
@@ -97,6 +110,118 @@ pub struct Command {
     pub version: CommandVersionId,
 }
 
+/// A command which Discord rejected while overwriting commands in bulk, for example via
+/// [`GuildId::set_commands`]. Returned as part of [`ModelError::InvalidCommands`].
+///
+/// [`ModelError::InvalidCommands`]: crate::model::ModelError::InvalidCommands
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct InvalidCommand {
+    /// The index of the command in the list of commands which was passed.
+    pub index: usize,
+    /// The name of the command.
+    pub name: FixedString,
+    /// Why the command was rejected, as pairs of the path to the invalid field within the command,
+    /// such as `options.0.description`, and the error message.
+    pub errors: Vec<(FixedString, FixedString)>,
+}
+
+/// The outcome of overwriting commands in bulk while skipping invalid ones, for example via
+/// [`GuildId::set_commands_partial`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CommandRegistration {
+    /// The commands which are now registered.
+    pub registered: Vec<Command>,
+    /// The commands which were rejected by Discord and left out.
+    pub invalid: Vec<InvalidCommand>,
+}
+
+/// The changes needed to bring the registered commands in line with a list of commands, as
+/// returned by [`Command::sync_global`] and [`Command::sync_global_dry_run`].
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CommandSyncPlan {
+    /// The names of the commands which don't exist yet and are created.
+    pub created: Vec<FixedString<u8>>,
+    /// The commands which differ from the given ones and are edited.
+    pub edited: Vec<(CommandId, FixedString<u8>)>,
+    /// The commands which are not in the list and are deleted.
+    pub deleted: Vec<(CommandId, FixedString<u8>)>,
+    /// The commands which already match and are left untouched.
+    pub unchanged: Vec<(CommandId, FixedString<u8>)>,
+}
+
+impl CommandSyncPlan {
+    /// Whether the registered commands already match, so nothing needs to change.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.edited.is_empty() && self.deleted.is_empty()
+    }
+}
+
+/// The outcome of applying a change to the commands of many guilds, for example via
+/// [`Command::deploy_to_guilds`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CommandDeployment<T> {
+    /// The guilds where the change succeeded, with its result, in the order the guilds were given.
+    pub succeeded: Vec<(GuildId, T)>,
+    /// The guilds where the change failed, with the reason, in the order the guilds were given.
+    pub failed: Vec<(GuildId, CommandDeploymentError)>,
+}
+
+impl<T> CommandDeployment<T> {
+    /// Whether the change succeeded in every guild.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Why changing the commands of a single guild failed, as part of a [`CommandDeployment`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CommandDeploymentError {
+    /// The bot is not in the guild, or was not authorized with the `applications.commands`
+    /// scope in it.
+    MissingAccess,
+    /// The guild has reached the maximum number of commands for the application.
+    CommandLimitReached,
+    /// Any other error, such as Discord rejecting an invalid command.
+    Other(Error),
+}
+
+#[cfg(feature = "http")]
+impl From<Error> for CommandDeploymentError {
+    fn from(error: Error) -> Self {
+        match error.as_http().and_then(HttpError::json_error_code) {
+            Some(JsonErrorCode::MissingAccess) => Self::MissingAccess,
+            Some(JsonErrorCode::MaxApplicationCommandsReached) => Self::CommandLimitReached,
+            _ => Self::Other(error),
+        }
+    }
+}
+
+impl fmt::Display for CommandDeploymentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingAccess => f.write_str("Missing access to the guild's commands"),
+            Self::CommandLimitReached => f.write_str("Maximum number of commands reached"),
+            Self::Other(error) => error.fmt(f),
+        }
+    }
+}
+
+impl StdError for CommandDeploymentError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Other(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "model")]
 impl Command {
     /// Create a global [`Command`], overriding an existing one with the same name if it exists.
@@ -162,12 +287,34 @@ impl Command {
     ///
     /// # Errors
     ///
-    /// Returns the same errors as [`Self::create_global_command`].
+    /// Returns the same errors as [`Self::create_global_command`]. If Discord rejects some of the
+    /// commands, returns [`ModelError::InvalidCommands`] naming them.
+    ///
+    /// [`ModelError::InvalidCommands`]: crate::model::ModelError::InvalidCommands
     pub async fn set_global_commands(
         http: &Http,
         commands: &[CreateCommand<'_>],
     ) -> Result<Vec<Command>> {
-        http.create_global_commands(&commands).await
+        overwrite_commands(http, None, commands).await
+    }
+
+    /// Override all global application commands, leaving out commands which Discord rejects
+    /// instead of failing the whole request.
+    ///
+    /// **Note**: If every command is rejected, all global commands are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::set_global_commands`], except for
+    /// [`ModelError::InvalidCommands`], which is reported via [`CommandRegistration::invalid`]
+    /// instead.
+    ///
+    /// [`ModelError::InvalidCommands`]: crate::model::ModelError::InvalidCommands
+    pub async fn set_global_commands_partial(
+        http: &Http,
+        commands: &[CreateCommand<'_>],
+    ) -> Result<CommandRegistration> {
+        overwrite_commands_partial(http, None, commands).await
     }
 
     /// Edit a global command, given its Id.
@@ -183,6 +330,54 @@ impl Command {
         builder.execute(http, None, Some(command_id)).await
     }
 
+    /// Brings the global commands in line with the given ones, by only creating, editing and
+    /// deleting the commands that differ. Unlike [`Self::set_global_commands`], unchanged commands
+    /// are not touched, which avoids the delay of Discord propagating them again.
+    ///
+    /// Commands are matched by name and type. Only fields set on the builders are compared, so
+    /// fields left unset keep whatever value they are registered with.
+    ///
+    /// Returns the changes which were made. Use [`Self::sync_global_dry_run`] to only plan them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # async fn run(http: &Http) -> serenity::Result<()> {
+    /// use serenity::builder::CreateCommand;
+    /// use serenity::model::application::Command;
+    ///
+    /// let commands = [CreateCommand::new("ping").description("A simple ping command")];
+    ///
+    /// let plan = Command::sync_global(http, &commands).await?;
+    /// println!("Created {:?}, edited {:?}", plan.created, plan.edited);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if fetching the registered commands or changing a command fails. In
+    /// that case, the changes made before are kept.
+    pub async fn sync_global(
+        http: &Http,
+        commands: &[CreateCommand<'_>],
+    ) -> Result<CommandSyncPlan> {
+        sync_commands(http, None, commands, false).await
+    }
+
+    /// Plans the changes [`Self::sync_global`] would make, without making them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if fetching the registered commands fails.
+    pub async fn sync_global_dry_run(
+        http: &Http,
+        commands: &[CreateCommand<'_>],
+    ) -> Result<CommandSyncPlan> {
+        sync_commands(http, None, commands, true).await
+    }
+
     /// Gets all global commands.
     ///
     /// # Errors
@@ -218,6 +413,101 @@ impl Command {
     pub async fn delete_global_command(http: &Http, command_id: CommandId) -> Result<()> {
         http.delete_global_command(command_id).await
     }
+
+    /// Overwrites the commands of each of the given guilds with the given commands, working on up
+    /// to `concurrency` guilds at a time. Useful for rolling out guild commands to a set of beta
+    /// guilds.
+    ///
+    /// Failures are captured per guild instead of stopping the deployment, see
+    /// [`CommandDeployment::failed`].
+    ///
+    /// **Note**: Like [`GuildId::set_commands`], this removes any other commands of the guilds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # async fn run() {
+    /// # let http: Http = unimplemented!();
+    /// use serenity::builder::CreateCommand;
+    /// use serenity::model::application::Command;
+    /// use serenity::model::id::GuildId;
+    ///
+    /// let beta_guilds = [GuildId::new(1), GuildId::new(2), GuildId::new(3)];
+    /// let commands = [CreateCommand::new("preview").description("Try out new features")];
+    ///
+    /// let deployment = Command::deploy_to_guilds(&http, beta_guilds, &commands, 4).await;
+    /// for (guild_id, error) in &deployment.failed {
+    ///     println!("Failed to deploy to {guild_id}: {error}");
+    /// }
+    /// # }
+    /// ```
+    pub async fn deploy_to_guilds(
+        http: &Http,
+        guild_ids: impl IntoIterator<Item = GuildId>,
+        commands: &[CreateCommand<'_>],
+        concurrency: usize,
+    ) -> CommandDeployment<Vec<Command>> {
+        for_each_guild(guild_ids, concurrency, |guild_id| {
+            overwrite_commands(http, Some(guild_id), commands)
+        })
+        .await
+    }
+
+    /// Deletes the command with the given name from each of the given guilds, working on up to
+    /// `concurrency` guilds at a time. This is the inverse of [`Self::deploy_to_guilds`].
+    ///
+    /// Each successful guild is reported with the Id of the deleted command, or [`None`] if the
+    /// guild had no such command.
+    pub async fn remove_from_guilds(
+        http: &Http,
+        guild_ids: impl IntoIterator<Item = GuildId>,
+        name: &str,
+        concurrency: usize,
+    ) -> CommandDeployment<Option<CommandId>> {
+        for_each_guild(guild_ids, concurrency, |guild_id| async move {
+            let commands = http.get_guild_commands(guild_id).await?;
+            let Some(command) = commands.into_iter().find(|command| command.name == name) else {
+                return Ok(None);
+            };
+
+            http.delete_guild_command(guild_id, command.id).await?;
+            Ok(Some(command.id))
+        })
+        .await
+    }
+}
+
+/// Runs `f` for each guild, with at most `concurrency` guilds at a time, collecting the results.
+#[cfg(feature = "model")]
+async fn for_each_guild<T, F, Fut>(
+    guild_ids: impl IntoIterator<Item = GuildId>,
+    concurrency: usize,
+    f: F,
+) -> CommandDeployment<T>
+where
+    F: Fn(GuildId) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut results = stream::iter(guild_ids)
+        .map(|guild_id| {
+            let future = f(guild_id);
+            async move { (guild_id, future.await) }
+        })
+        .buffered(concurrency.max(1));
+
+    let mut deployment = CommandDeployment {
+        succeeded: Vec::new(),
+        failed: Vec::new(),
+    };
+    while let Some((guild_id, result)) = results.next().await {
+        match result {
+            Ok(value) => deployment.succeeded.push((guild_id, value)),
+            Err(error) => deployment.failed.push((guild_id, error.into())),
+        }
+    }
+
+    deployment
 }
 
 enum_number! {
@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use super::{AuthorizingIntegrationOwners, InteractionContext};
 #[cfg(feature = "model")]
 use crate::builder::{
+    AutocompleteChoice,
+    CreateAutocompleteResponse,
     CreateInteractionResponse,
     CreateInteractionResponseFollowup,
     CreateInteractionResponseMessage,
@@ -35,6 +37,7 @@ use crate::model::id::{
     TargetId,
     UserId,
 };
+use crate::model::locale::Locale;
 use crate::model::monetization::Entitlement;
 use crate::model::user::User;
 use crate::model::Permissions;
@@ -78,9 +81,9 @@ pub struct CommandInteraction {
     // TODO(next): This is now always serialized.
     pub app_permissions: Option<Permissions>,
     /// The selected language of the invoking user.
-    pub locale: FixedString,
+    pub locale: Locale,
     /// The guild's preferred locale.
-    pub guild_locale: Option<FixedString>,
+    pub guild_locale: Option<Locale>,
     /// For monetized applications, any entitlements of the invoking user.
     pub entitlements: Vec<Entitlement>,
     /// The owners of the applications that authorized the interaction, such as a guild or user.
@@ -120,6 +123,21 @@ impl CommandInteraction {
         builder.execute(http, self.id, &self.token).await
     }
 
+    /// Responds to an autocomplete interaction with the given suggestions, keeping only as many as
+    /// Discord accepts. See [`CreateAutocompleteResponse::set_choices_from_iter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the API returns an error.
+    pub async fn respond_with_choices<'a>(
+        &self,
+        http: &Http,
+        choices: impl IntoIterator<Item = impl Into<AutocompleteChoice<'a>>>,
+    ) -> Result<()> {
+        let response = CreateAutocompleteResponse::new().set_choices_from_iter(choices);
+        self.create_response(http, CreateInteractionResponse::Autocomplete(response)).await
+    }
+
     /// Edits the initial interaction response.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -243,6 +261,32 @@ impl CommandInteraction {
     }
 }
 
+impl CommandInteraction {
+    /// Returns the full name of the invoked command. See [`CommandData::full_command_name`].
+    #[must_use]
+    pub fn full_command_name(&self) -> String {
+        self.data.full_command_name()
+    }
+
+    /// Returns the invoked subcommand group and subcommand. See [`CommandData::subcommand_path`].
+    #[must_use]
+    pub fn subcommand_path(&self) -> (Option<&str>, Option<&str>) {
+        self.data.subcommand_path()
+    }
+
+    /// Returns the options of the invoked subcommand. See [`CommandData::subcommand_options`].
+    #[must_use]
+    pub fn subcommand_options(&self) -> &[CommandDataOption] {
+        self.data.subcommand_options()
+    }
+
+    /// Returns the user or message a context menu command was used on. See [`CommandData::target`].
+    #[must_use]
+    pub fn target(&self) -> Option<ResolvedTarget<'_>> {
+        self.data.target()
+    }
+}
+
 // Manual impl needed to insert guild_id into resolved Role's
 impl<'de> Deserialize<'de> for CommandInteraction {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
@@ -400,6 +444,141 @@ impl CommandData {
         resolve_options(&self.options, &self.resolved)
     }
 
+    /// Returns the names of the invoked subcommand group and subcommand, in that order, if any.
+    ///
+    /// For a command invoked as `/parent group sub`, this returns `(Some("group"), Some("sub"))`.
+    /// For `/parent sub`, this returns `(None, Some("sub"))`.
+    #[must_use]
+    pub fn subcommand_path(&self) -> (Option<&str>, Option<&str>) {
+        match self.options.first().map(|opt| (&opt.name, &opt.value)) {
+            Some((name, CommandDataOptionValue::SubCommandGroup(opts))) => {
+                (Some(name.as_str()), opts.first().map(|opt| opt.name.as_str()))
+            },
+            Some((name, CommandDataOptionValue::SubCommand(_))) => (None, Some(name.as_str())),
+            _ => (None, None),
+        }
+    }
+
+    /// Returns the full name of the invoked command, including the subcommand group and
+    /// subcommand names, separated by spaces, e.g. `"parent group sub"`.
+    #[must_use]
+    pub fn full_command_name(&self) -> String {
+        let (group, subcommand) = self.subcommand_path();
+
+        let mut name = self.name.to_string();
+        for part in [group, subcommand].into_iter().flatten() {
+            name.push(' ');
+            name.push_str(part);
+        }
+
+        name
+    }
+
+    /// Returns the options passed to the invoked subcommand, or the top level options if the
+    /// command has no subcommands.
+    #[must_use]
+    pub fn subcommand_options(&self) -> &[CommandDataOption] {
+        let mut options = &*self.options;
+        while let [CommandDataOption {
+            value:
+                CommandDataOptionValue::SubCommand(opts) | CommandDataOptionValue::SubCommandGroup(opts),
+            ..
+        }] = options
+        {
+            options = opts;
+        }
+
+        options
+    }
+
+    /// Returns the value of the option with the given name passed to the invoked subcommand, see
+    /// [`Self::subcommand_options`].
+    #[must_use]
+    pub fn get_option(&self, name: &str) -> Option<&CommandDataOptionValue> {
+        let option = self.subcommand_options().iter().find(|option| option.name == name)?;
+        Some(&option.value)
+    }
+
+    /// Returns the value of the string option with the given name, see [`Self::get_option`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::application::CommandInteraction;
+    /// # fn run(interaction: &CommandInteraction) {
+    /// let reason = interaction.data.get_string("reason").unwrap_or("No reason given");
+    /// let days = interaction.data.get_integer("days").unwrap_or(1);
+    /// if let Some((user, _member)) = interaction.data.get_user("user") {
+    ///     println!("Banning {} for {days} days: {reason}", user.name);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        self.get_option(name)?.as_str()
+    }
+
+    /// Returns the value of the integer option with the given name, see [`Self::get_option`].
+    #[must_use]
+    pub fn get_integer(&self, name: &str) -> Option<i64> {
+        self.get_option(name)?.as_i64()
+    }
+
+    /// Returns the value of the number option with the given name, see [`Self::get_option`].
+    #[must_use]
+    pub fn get_number(&self, name: &str) -> Option<f64> {
+        self.get_option(name)?.as_f64()
+    }
+
+    /// Returns the value of the boolean option with the given name, see [`Self::get_option`].
+    #[must_use]
+    pub fn get_boolean(&self, name: &str) -> Option<bool> {
+        self.get_option(name)?.as_bool()
+    }
+
+    /// Returns the user, and their member if invoked in a guild, passed to the user or
+    /// mentionable option with the given name, as resolved by Discord.
+    #[must_use]
+    pub fn get_user(&self, name: &str) -> Option<(&User, Option<&PartialMember>)> {
+        let user_id = match self.get_option(name)? {
+            CommandDataOptionValue::User(id) => *id,
+            CommandDataOptionValue::Mentionable(id) => UserId::new(id.get()),
+            _ => return None,
+        };
+
+        let user = self.resolved.users.get(&user_id)?;
+        Some((user, self.resolved.members.get(&user_id)))
+    }
+
+    /// Returns the role passed to the role or mentionable option with the given name, as resolved
+    /// by Discord.
+    #[must_use]
+    pub fn get_role(&self, name: &str) -> Option<&Role> {
+        let role_id = match self.get_option(name)? {
+            CommandDataOptionValue::Role(id) => *id,
+            CommandDataOptionValue::Mentionable(id) => RoleId::new(id.get()),
+            _ => return None,
+        };
+
+        self.resolved.roles.get(&role_id)
+    }
+
+    /// Returns the channel passed to the channel option with the given name, as resolved by
+    /// Discord.
+    #[must_use]
+    pub fn get_channel(&self, name: &str) -> Option<&PartialChannel> {
+        let channel_id = self.get_option(name)?.as_channel_id()?;
+        self.resolved.channels.get(&channel_id)
+    }
+
+    /// Returns the attachment passed to the attachment option with the given name, as resolved by
+    /// Discord.
+    #[must_use]
+    pub fn get_attachment(&self, name: &str) -> Option<&Attachment> {
+        let attachment_id = self.get_option(name)?.as_attachment_id()?;
+        self.resolved.attachments.get(&attachment_id)
+    }
+
     /// The target resolved data of [`target_id`]
     ///
     /// [`target_id`]: Self::target_id
@@ -423,6 +602,26 @@ impl CommandData {
             _ => None,
         }
     }
+
+    /// The user a user context menu command was used on, along with their member data if used in
+    /// a guild. Returns [`None`] for other types of commands.
+    #[must_use]
+    pub fn target_user(&self) -> Option<(&User, Option<&PartialMember>)> {
+        match self.target()? {
+            ResolvedTarget::User(user, member) => Some((user, member)),
+            ResolvedTarget::Message(_) => None,
+        }
+    }
+
+    /// The message a message context menu command was used on. Returns [`None`] for other types
+    /// of commands.
+    #[must_use]
+    pub fn target_message(&self) -> Option<&Message> {
+        match self.target()? {
+            ResolvedTarget::Message(message) => Some(message),
+            ResolvedTarget::User(..) => None,
+        }
+    }
 }
 
 /// The focused option for autocomplete interactions return by [`CommandData::autocomplete`].
@@ -832,6 +1031,61 @@ mod tests {
     use super::*;
     use crate::model::utils::assert_json;
 
+    #[test]
+    fn context_menu_target() {
+        let data: CommandData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "Greet",
+            "type": 2,
+            "target_id": "2",
+            "resolved": {
+                "users": {
+                    "2": {"id": "2", "username": "friend", "discriminator": "0", "avatar": null},
+                },
+            },
+        }))
+        .unwrap();
+
+        let (user, member) = data.target_user().unwrap();
+        assert_eq!(user.id, UserId::new(2));
+        assert!(member.is_none());
+        assert!(data.target_message().is_none());
+    }
+
+    #[test]
+    fn typed_option_getters() {
+        let data: CommandData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "ban",
+            "type": 1,
+            "options": [{
+                "name": "user",
+                "type": 1,
+                "options": [
+                    {"name": "target", "type": 6, "value": "2"},
+                    {"name": "days", "type": 4, "value": 7},
+                    {"name": "reason", "type": 3, "value": "spam"},
+                ],
+            }],
+            "resolved": {
+                "users": {
+                    "2": {"id": "2", "username": "spammer", "discriminator": "0", "avatar": null},
+                },
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(data.get_string("reason"), Some("spam"));
+        assert_eq!(data.get_integer("days"), Some(7));
+        assert_eq!(data.get_string("days"), None);
+        assert_eq!(data.get_boolean("missing"), None);
+
+        let (user, member) = data.get_user("target").unwrap();
+        assert_eq!(user.id, UserId::new(2));
+        assert!(member.is_none());
+        assert!(data.get_role("target").is_none());
+    }
+
     #[test]
     fn nested_options() {
         let value = CommandDataOption {
@@ -909,4 +1163,42 @@ mod tests {
             ]),
         );
     }
+
+    #[test]
+    fn subcommand_path() {
+        let data: CommandData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "parent",
+            "type": 1,
+            "options": [{
+                "name": "group",
+                "type": 2,
+                "options": [{
+                    "name": "sub",
+                    "type": 1,
+                    "options": [{"name": "channel", "type": 7, "value": "3"}],
+                }]
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(data.subcommand_path(), (Some("group"), Some("sub")));
+        assert_eq!(data.full_command_name(), "parent group sub");
+        assert_eq!(data.subcommand_options(), &[CommandDataOption {
+            name: FixedString::from_static_trunc("channel"),
+            value: CommandDataOptionValue::Channel(ChannelId::new(3)),
+        }]);
+
+        let data: CommandData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "parent",
+            "type": 1,
+            "options": [{"name": "string", "type": 3, "value": "foo"}]
+        }))
+        .unwrap();
+
+        assert_eq!(data.subcommand_path(), (None, None));
+        assert_eq!(data.full_command_name(), "parent");
+        assert_eq!(data.subcommand_options().len(), 1);
+    }
 }
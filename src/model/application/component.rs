@@ -20,10 +20,82 @@ enum_number! {
         RoleSelect = 6,
         MentionableSelect = 7,
         ChannelSelect = 8,
+        Section = 9,
+        TextDisplay = 10,
+        Thumbnail = 11,
+        MediaGallery = 12,
+        File = 13,
+        Separator = 14,
+        Container = 17,
         _ => Unknown(u8),
     }
 }
 
+/// A top-level component of a message.
+///
+/// All components other than [`Component::ActionRow`] are layout components, which can only be
+/// sent if the message has the [`MessageFlags::IS_COMPONENTS_V2`] flag set.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#component-object-component-types).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Component {
+    ActionRow(ActionRow),
+    Section(Section),
+    TextDisplay(TextDisplay),
+    Thumbnail(Thumbnail),
+    MediaGallery(MediaGallery),
+    File(FileComponent),
+    Separator(Separator),
+    Container(Container),
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let map = JsonMap::deserialize(deserializer)?;
+
+        let raw_kind = map.get("type").ok_or_else(|| DeError::missing_field("type"))?.clone();
+        let value = Value::from(map);
+
+        match deserialize_val(raw_kind)? {
+            ComponentType::ActionRow => from_value(value).map(Component::ActionRow),
+            ComponentType::Section => from_value(value).map(Component::Section),
+            ComponentType::TextDisplay => from_value(value).map(Component::TextDisplay),
+            ComponentType::Thumbnail => from_value(value).map(Component::Thumbnail),
+            ComponentType::MediaGallery => from_value(value).map(Component::MediaGallery),
+            ComponentType::File => from_value(value).map(Component::File),
+            ComponentType::Separator => from_value(value).map(Component::Separator),
+            ComponentType::Container => from_value(value).map(Component::Container),
+            ComponentType(i) => {
+                return Err(DeError::custom(format_args!("Invalid top-level component type {i}")))
+            },
+        }
+        .map_err(DeError::custom)
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::ActionRow(c) => c.serialize(serializer),
+            Self::Section(c) => c.serialize(serializer),
+            Self::TextDisplay(c) => c.serialize(serializer),
+            Self::Thumbnail(c) => c.serialize(serializer),
+            Self::MediaGallery(c) => c.serialize(serializer),
+            Self::File(c) => c.serialize(serializer),
+            Self::Separator(c) => c.serialize(serializer),
+            Self::Container(c) => c.serialize(serializer),
+        }
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(component: ActionRow) -> Self {
+        Component::ActionRow(component)
+    }
+}
+
 /// An action row.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/message-components#action-rows).
@@ -304,6 +376,213 @@ enum_number! {
     }
 }
 
+/// A section, which displays text alongside an accessory.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#section).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Section {
+    /// Always [`ComponentType::Section`]
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The text displays of this section, between one and three.
+    pub components: FixedArray<TextDisplay>,
+    /// The component displayed next to the text.
+    pub accessory: SectionAccessory,
+}
+
+/// A component which can be the accessory of a [`Section`].
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum SectionAccessory {
+    Button(Button),
+    Thumbnail(Thumbnail),
+}
+
+impl<'de> Deserialize<'de> for SectionAccessory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let map = JsonMap::deserialize(deserializer)?;
+
+        let raw_kind = map.get("type").ok_or_else(|| DeError::missing_field("type"))?.clone();
+        let value = Value::from(map);
+
+        match deserialize_val(raw_kind)? {
+            ComponentType::Button => from_value(value).map(SectionAccessory::Button),
+            ComponentType::Thumbnail => from_value(value).map(SectionAccessory::Thumbnail),
+            ComponentType(i) => {
+                return Err(DeError::custom(format_args!("Invalid section accessory type {i}")))
+            },
+        }
+        .map_err(DeError::custom)
+    }
+}
+
+impl Serialize for SectionAccessory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Button(c) => c.serialize(serializer),
+            Self::Thumbnail(c) => c.serialize(serializer),
+        }
+    }
+}
+
+/// A text display component, which displays markdown text.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#text-display).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TextDisplay {
+    /// Always [`ComponentType::TextDisplay`]
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The markdown text to display.
+    pub content: FixedString,
+}
+
+/// A thumbnail component, which can only be used as the accessory of a [`Section`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#thumbnail).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Thumbnail {
+    /// Always [`ComponentType::Thumbnail`]
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The image of the thumbnail.
+    pub media: UnfurledMediaItem,
+    /// The alt text of the thumbnail.
+    pub description: Option<FixedString>,
+    /// Whether the thumbnail is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A media gallery component, which displays up to 10 images or videos in a grid.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MediaGallery {
+    /// Always [`ComponentType::MediaGallery`]
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The items of the gallery.
+    pub items: FixedArray<MediaGalleryItem>,
+}
+
+/// An item of a [`MediaGallery`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery-media-gallery-item-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MediaGalleryItem {
+    /// The image or video of the item.
+    pub media: UnfurledMediaItem,
+    /// The alt text of the item.
+    pub description: Option<FixedString>,
+    /// Whether the item is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A file component, which displays an attachment of the message.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#file).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FileComponent {
+    /// Always [`ComponentType::File`]
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The attachment, referenced with an `attachment://<filename>` URL.
+    pub file: UnfurledMediaItem,
+    /// Whether the file is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A separator component, which adds vertical padding and an optional divider between
+/// components.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#separator).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Separator {
+    /// Always [`ComponentType::Separator`]
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// Whether a visual divider is displayed.
+    #[serde(default = "default_true")]
+    pub divider: bool,
+    /// The amount of padding.
+    pub spacing: Option<SeparatorSpacing>,
+}
+
+enum_number! {
+    /// The amount of padding of a [`Separator`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/components/reference#separator).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[non_exhaustive]
+    pub enum SeparatorSpacing {
+        Small = 1,
+        Large = 2,
+        _ => Unknown(u8),
+    }
+}
+
+/// A container component, which visually groups components together, similar to an embed.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#container).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Container {
+    /// Always [`ComponentType::Container`]
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The components inside of the container.
+    pub components: FixedArray<Component>,
+    /// The colour of the left-hand side of the container.
+    pub accent_color: Option<Colour>,
+    /// Whether the container is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A media item referenced by a URL, which is either an arbitrary URL or an
+/// `attachment://<filename>` reference.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#unfurled-media-item).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct UnfurledMediaItem {
+    /// The URL of the media.
+    pub url: FixedString,
+    /// A proxied URL of the media. Only sent by Discord.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<FixedString>,
+    /// The height of the media. Only sent by Discord.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// The width of the media. Only sent by Discord.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// The media type of the media. Only sent by Discord.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<FixedString>,
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -344,4 +623,37 @@ mod tests {
             json!({"type": 2, "style": 6, "sku_id": "1234965026943668316", "label": "a", "disabled": false}),
         );
     }
+
+    #[test]
+    fn test_layout_component_deserialize() {
+        let value = json!([
+            {"type": 10, "content": "Hello"},
+            {"type": 17, "accent_color": 16711680, "components": [
+                {"type": 9, "components": [{"type": 10, "content": "Section"}], "accessory": {
+                    "type": 11, "media": {"url": "https://example.com/a.png"}
+                }},
+                {"type": 14, "spacing": 2},
+                {"type": 12, "items": [{"media": {"url": "attachment://b.png"}, "spoiler": true}]},
+                {"type": 1, "components": [{"type": 2, "style": 1, "custom_id": "c"}]},
+            ]},
+        ]);
+
+        let components: Vec<Component> = serde_json::from_value(value).unwrap();
+        assert!(matches!(&components[0], Component::TextDisplay(text) if text.content == "Hello"));
+
+        let Component::Container(container) = &components[1] else { panic!("not a container") };
+        assert_eq!(container.accent_color, Some(Colour::new(0xFF0000)));
+        assert!(matches!(
+            &container.components[0],
+            Component::Section(section) if matches!(section.accessory, SectionAccessory::Thumbnail(_))
+        ));
+        assert!(matches!(
+            &container.components[1],
+            Component::Separator(separator) if separator.divider && separator.spacing == Some(SeparatorSpacing::Large)
+        ));
+        assert!(
+            matches!(&container.components[2], Component::MediaGallery(gallery) if gallery.items[0].spoiler)
+        );
+        assert!(matches!(&container.components[3], Component::ActionRow(_)));
+    }
 }
@@ -17,6 +17,8 @@ use crate::internal::prelude::*;
 use crate::model::prelude::*;
 #[cfg(all(feature = "collector", feature = "utils"))]
 use crate::utils::{CreateQuickModal, QuickModalResponse};
+#[cfg(feature = "utils")]
+use crate::utils::{CustomId, CustomIdError};
 
 /// An interaction triggered by a message component.
 ///
@@ -56,9 +58,9 @@ pub struct ComponentInteraction {
     /// Permissions the app or bot has within the channel the interaction was sent from.
     pub app_permissions: Option<Permissions>,
     /// The selected language of the invoking user.
-    pub locale: FixedString,
+    pub locale: Locale,
     /// The guild's preferred locale.
-    pub guild_locale: Option<FixedString>,
+    pub guild_locale: Option<Locale>,
     /// For monetized applications, any entitlements of the invoking user.
     pub entitlements: Vec<Entitlement>,
     /// The owners of the applications that authorized the interaction, such as a guild or user.
@@ -70,6 +72,18 @@ pub struct ComponentInteraction {
     pub context: Option<InteractionContext>,
 }
 
+#[cfg(feature = "utils")]
+impl ComponentInteraction {
+    /// Decodes the state stored in the component's `custom_id`, see [`CustomId`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CustomIdError`] if the `custom_id` belongs to another type or is invalid.
+    pub fn parse_custom_id<T: CustomId>(&self) -> StdResult<T, CustomIdError> {
+        T::decode(&self.data.custom_id)
+    }
+}
+
 #[cfg(feature = "model")]
 impl ComponentInteraction {
     /// Gets the interaction response.
@@ -0,0 +1,291 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CommandInteraction, ComponentInteraction, Interaction, ModalInteraction};
+#[cfg(feature = "model")]
+use crate::builder::{CreateInteractionResponseFollowup, EditInteractionResponse};
+#[cfg(feature = "model")]
+use crate::http::Http;
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::channel::Message;
+#[cfg(feature = "model")]
+use crate::model::id::MessageId;
+use crate::model::id::{ApplicationId, InteractionId};
+use crate::model::Timestamp;
+
+/// The duration in seconds for which an interaction token is valid.
+const INTERACTION_TOKEN_LIFETIME: i64 = 15 * 60;
+
+/// A minimal handle to an interaction, which can be used to respond to it after the interaction
+/// itself has been dropped.
+///
+/// The handle can be serialized and stored, for example to let a web dashboard send a followup
+/// once a long-running task finishes. Interaction tokens are only valid for 15 minutes after the
+/// interaction was created, see [`Self::is_expired`].
+///
+/// **Note**: The initial response must still be sent with the interaction itself, for example
+/// with [`CommandInteraction::create_response`] or [`CommandInteraction::defer`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::application::CommandInteraction;
+/// # async fn run(http: &Http, interaction: CommandInteraction) -> serenity::Result<()> {
+/// use serenity::builder::CreateInteractionResponseFollowup;
+/// use serenity::model::application::InteractionFollowupHandle;
+///
+/// interaction.defer(http).await?;
+/// let handle = InteractionFollowupHandle::from(&interaction);
+/// let stored = serde_json::to_string(&handle)?;
+///
+/// // Later, possibly somewhere else:
+/// let handle: InteractionFollowupHandle = serde_json::from_str(&stored)?;
+/// let builder = CreateInteractionResponseFollowup::new().content("Done!");
+/// handle.create_followup(http, builder).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct InteractionFollowupHandle {
+    /// Id of the application the interaction is for.
+    pub application_id: ApplicationId,
+    /// Id of the interaction.
+    pub interaction_id: InteractionId,
+    /// The continuation token for responding to the interaction.
+    pub token: FixedString,
+}
+
+impl InteractionFollowupHandle {
+    /// Creates a new handle from the parts of an interaction.
+    #[must_use]
+    pub fn new(
+        application_id: ApplicationId,
+        interaction_id: InteractionId,
+        token: FixedString,
+    ) -> Self {
+        Self {
+            application_id,
+            interaction_id,
+            token,
+        }
+    }
+
+    /// Returns the time at which the interaction token expires.
+    #[must_use]
+    pub fn expires_at(&self) -> Timestamp {
+        let created_at = self.interaction_id.created_at().unix_timestamp();
+        Timestamp::from_unix_timestamp(created_at + INTERACTION_TOKEN_LIFETIME)
+            .expect("a snowflake timestamp plus 15 minutes is always in range")
+    }
+
+    /// Returns `true` if the interaction token has expired, after which it can no longer be used.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        Timestamp::now().unix_timestamp() >= self.expires_at().unix_timestamp()
+    }
+}
+
+#[cfg(feature = "model")]
+impl InteractionFollowupHandle {
+    /// Gets the interaction response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if there is no interaction response.
+    pub async fn get_response(&self, http: &Http) -> Result<Message> {
+        http.get_original_interaction_response_for(self.application_id, &self.token).await
+    }
+
+    /// Edits the initial interaction response.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the message content is too long. May also return an
+    /// [`Error::Http`] if the API returns an error, or an [`Error::Json`] if there is an error in
+    /// deserializing the API response.
+    pub async fn edit_response(
+        &self,
+        http: &Http,
+        builder: EditInteractionResponse<'_>,
+    ) -> Result<Message> {
+        builder.execute_for(http, Some(self.application_id), &self.token).await
+    }
+
+    /// Deletes the initial interaction response.
+    ///
+    /// Does not work on ephemeral messages.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error. Such as if the response was already
+    /// deleted.
+    pub async fn delete_response(&self, http: &Http) -> Result<()> {
+        http.delete_original_interaction_response_for(self.application_id, &self.token).await
+    }
+
+    /// Creates a followup response to the response sent.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Model`] if the content is too long. May also return [`Error::Http`] if the
+    /// API returns an error, or [`Error::Json`] if there is an error in deserializing the
+    /// response.
+    pub async fn create_followup(
+        &self,
+        http: &Http,
+        builder: CreateInteractionResponseFollowup<'_>,
+    ) -> Result<Message> {
+        builder.execute_for(http, Some(self.application_id), None, &self.token).await
+    }
+
+    /// Edits a followup response to the response sent.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Model`] if the content is too long. May also return [`Error::Http`] if the
+    /// API returns an error, or [`Error::Json`] if there is an error in deserializing the
+    /// response.
+    pub async fn edit_followup(
+        &self,
+        http: &Http,
+        message_id: MessageId,
+        builder: CreateInteractionResponseFollowup<'_>,
+    ) -> Result<Message> {
+        builder.execute_for(http, Some(self.application_id), Some(message_id), &self.token).await
+    }
+
+    /// Deletes a followup message.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error. Such as if the response was already
+    /// deleted.
+    pub async fn delete_followup(&self, http: &Http, message_id: MessageId) -> Result<()> {
+        http.delete_followup_message_for(self.application_id, &self.token, message_id).await
+    }
+
+    /// Gets a followup message.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error. Such as if the response was
+    /// deleted.
+    pub async fn get_followup(&self, http: &Http, message_id: MessageId) -> Result<Message> {
+        http.get_followup_message_for(self.application_id, &self.token, message_id).await
+    }
+}
+
+impl From<&Interaction> for InteractionFollowupHandle {
+    fn from(interaction: &Interaction) -> Self {
+        let token = FixedString::from_str_trunc(interaction.token());
+        Self::new(interaction.application_id(), interaction.id(), token)
+    }
+}
+
+impl From<&CommandInteraction> for InteractionFollowupHandle {
+    fn from(interaction: &CommandInteraction) -> Self {
+        Self::new(interaction.application_id, interaction.id, interaction.token.clone())
+    }
+}
+
+impl From<&ComponentInteraction> for InteractionFollowupHandle {
+    fn from(interaction: &ComponentInteraction) -> Self {
+        Self::new(interaction.application_id, interaction.id, interaction.token.clone())
+    }
+}
+
+impl From<&ModalInteraction> for InteractionFollowupHandle {
+    fn from(interaction: &ModalInteraction) -> Self {
+        Self::new(interaction.application_id, interaction.id, interaction.token.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::InteractionFollowupHandle;
+    use crate::internal::prelude::*;
+    use crate::model::id::{ApplicationId, InteractionId};
+
+    #[test]
+    fn serde_round_trip() {
+        let handle = InteractionFollowupHandle::new(
+            ApplicationId::new(2),
+            InteractionId::new(3),
+            FixedString::from_static_trunc("token"),
+        );
+
+        let value = serde_json::to_value(&handle).unwrap();
+        assert_eq!(value, json!({"application_id": "2", "interaction_id": "3", "token": "token"}));
+
+        let handle: InteractionFollowupHandle = serde_json::from_value(value).unwrap();
+        assert_eq!(handle.application_id, ApplicationId::new(2));
+        assert_eq!(handle.interaction_id, InteractionId::new(3));
+        assert_eq!(handle.token, "token");
+    }
+
+    #[cfg(feature = "http_transport")]
+    #[tokio::test]
+    async fn requests_use_the_handle_application() {
+        use serde_json::Value;
+
+        use crate::builder::CreateInteractionResponseFollowup;
+        use crate::http::{HttpBuilder, LightMethod, MockTransport, StatusCode};
+        use crate::model::id::MessageId;
+
+        let message = json!({
+            "id": "4",
+            "channel_id": "1",
+            "author": {"id": "2", "username": "bot", "discriminator": "0", "avatar": null},
+            "content": "Done!",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        });
+        let messages = "/webhooks/*/*/messages/*";
+        let transport = MockTransport::new()
+            .respond(LightMethod::Post, "/webhooks/*/*", StatusCode::OK, message.clone())
+            .respond(LightMethod::Get, messages, StatusCode::OK, message)
+            .respond(LightMethod::Delete, messages, StatusCode::NO_CONTENT, Value::Null);
+        // The client is set up for a different application than the interaction was sent to
+        let http = HttpBuilder::new("token")
+            .application_id(ApplicationId::new(1))
+            .transport(transport.clone())
+            .build();
+
+        let handle = InteractionFollowupHandle::new(
+            ApplicationId::new(2),
+            InteractionId::new(3),
+            FixedString::from_static_trunc("abc"),
+        );
+        let builder = CreateInteractionResponseFollowup::new().content("Done!");
+        handle.create_followup(&http, builder).await.unwrap();
+        handle.get_response(&http).await.unwrap();
+        handle.delete_followup(&http, MessageId::new(4)).await.unwrap();
+
+        let paths: Vec<_> = transport.requests().into_iter().map(|request| request.path).collect();
+        assert_eq!(paths, [
+            "/api/v10/webhooks/2/abc",
+            "/api/v10/webhooks/2/abc/messages/@original",
+            "/api/v10/webhooks/2/abc/messages/4",
+        ]);
+        assert_eq!(http.application_id(), Some(ApplicationId::new(1)));
+    }
+}
@@ -9,6 +9,7 @@ use crate::internal::prelude::*;
 #[cfg(not(feature = "unstable"))]
 use crate::model::guild::PartialMember;
 use crate::model::id::{ApplicationId, InteractionId};
+use crate::model::locale::Locale;
 #[cfg(feature = "unstable")]
 use crate::model::id::{GuildId, MessageId, UserId};
 use crate::model::monetization::Entitlement;
@@ -87,14 +88,39 @@ impl Interaction {
         }
     }
 
+    /// Gets the selected language of the invoking user.
+    #[must_use]
+    pub fn locale(&self) -> Option<&Locale> {
+        match self {
+            Self::Ping(_) => None,
+            Self::Command(i) | Self::Autocomplete(i) => Some(&i.locale),
+            Self::Component(i) => Some(&i.locale),
+            Self::Modal(i) => Some(&i.locale),
+        }
+    }
+
     /// Gets the invoked guild locale.
     #[must_use]
-    pub fn guild_locale(&self) -> Option<&str> {
+    pub fn guild_locale(&self) -> Option<&Locale> {
         match self {
             Self::Ping(_) => None,
-            Self::Command(i) | Self::Autocomplete(i) => i.guild_locale.as_deref(),
-            Self::Component(i) => i.guild_locale.as_deref(),
-            Self::Modal(i) => i.guild_locale.as_deref(),
+            Self::Command(i) | Self::Autocomplete(i) => i.guild_locale.as_ref(),
+            Self::Component(i) => i.guild_locale.as_ref(),
+            Self::Modal(i) => i.guild_locale.as_ref(),
+        }
+    }
+
+    /// Gets the locale a response should be written in, preferring either the invoking user's
+    /// locale or the guild's, and falling back to the other one if it's not present.
+    ///
+    /// To pick from the locales the application has translations for, pass the result to
+    /// [`Locale::best_match`], or use [`Locale::negotiate`] to try both locales in order.
+    #[must_use]
+    pub fn effective_locale(&self, prefer_user: bool) -> Option<&Locale> {
+        if prefer_user {
+            self.locale().or_else(|| self.guild_locale())
+        } else {
+            self.guild_locale().or_else(|| self.locale())
         }
     }
 
@@ -8,6 +8,8 @@ mod component;
 pub use component::*;
 mod component_interaction;
 pub use component_interaction::*;
+mod followup_handle;
+pub use followup_handle::*;
 mod interaction;
 pub use interaction::*;
 mod modal_interaction;
@@ -16,6 +18,8 @@ mod oauth;
 pub use oauth::*;
 mod ping_interaction;
 pub use ping_interaction::*;
+mod webhook_event;
+pub use webhook_event::*;
 
 use super::id::{ApplicationId, GenericId, GuildId, SkuId, UserId};
 use super::misc::ImageHash;
@@ -54,9 +54,9 @@ pub struct ModalInteraction {
     /// Permissions the app or bot has within the channel the interaction was sent from.
     pub app_permissions: Option<Permissions>,
     /// The selected language of the invoking user.
-    pub locale: FixedString,
+    pub locale: Locale,
     /// The guild's preferred locale.
-    pub guild_locale: Option<FixedString>,
+    pub guild_locale: Option<Locale>,
     /// For monetized applications, any entitlements of the invoking user.
     pub entitlements: Vec<Entitlement>,
 }
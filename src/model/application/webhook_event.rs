@@ -0,0 +1,180 @@
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde::ser::{Serialize, SerializeStruct as _, Serializer};
+use serde_json::from_value;
+
+#[cfg(feature = "unstable")]
+use super::InstallationContext;
+use super::Scope;
+use crate::internal::prelude::*;
+use crate::model::guild::PartialGuild;
+use crate::model::id::ApplicationId;
+use crate::model::monetization::Entitlement;
+use crate::model::user::User;
+use crate::model::Timestamp;
+
+enum_number! {
+    /// The type of a [`WebhookEventPayload`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/events/webhook-events#webhook-types).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[non_exhaustive]
+    pub enum WebhookEventType {
+        /// Sent by Discord to test the Webhook Events URL. Respond with `204 No Content`.
+        Ping = 0,
+        /// Contains an [`WebhookEventBody`].
+        Event = 1,
+        _ => Unknown(u8),
+    }
+}
+
+/// The body of a request sent by Discord to an application's Webhook Events URL.
+///
+/// Requests have to be verified before they are parsed, see the [`interactions_endpoint`] module.
+///
+/// [Discord docs](https://discord.com/developers/docs/events/webhook-events#payload-structure).
+///
+/// [`interactions_endpoint`]: crate::interactions_endpoint
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct WebhookEventPayload {
+    /// The version scheme of the payload, currently always 1.
+    pub version: u8,
+    /// The ID of the application the event is for.
+    pub application_id: ApplicationId,
+    /// The type of the payload.
+    #[serde(rename = "type")]
+    pub kind: WebhookEventType,
+    /// The event, unless this is a [`WebhookEventType::Ping`].
+    pub event: Option<WebhookEventBody>,
+}
+
+/// An event sent to an application's Webhook Events URL.
+///
+/// [Discord docs](https://discord.com/developers/docs/events/webhook-events#event-body-object).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct WebhookEventBody {
+    /// When the event occurred.
+    pub timestamp: Timestamp,
+    /// The event data.
+    pub event: WebhookEvent,
+}
+
+/// The data of a [`WebhookEventBody`].
+///
+/// [Discord docs](https://discord.com/developers/docs/events/webhook-events#event-types).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum WebhookEvent {
+    /// The application was added to a guild or a user's account.
+    ApplicationAuthorized(ApplicationAuthorizedEvent),
+    /// The application was removed from a user's account.
+    ApplicationDeauthorized(ApplicationDeauthorizedEvent),
+    /// An entitlement was created.
+    EntitlementCreate(Entitlement),
+    /// An event which serenity doesn't support yet.
+    Unknown {
+        /// The name of the event, such as `APPLICATION_AUTHORIZED`.
+        kind: FixedString,
+        /// The raw event data.
+        data: Value,
+    },
+}
+
+impl WebhookEvent {
+    /// Returns the name of the event, such as `APPLICATION_AUTHORIZED`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::ApplicationAuthorized(_) => "APPLICATION_AUTHORIZED",
+            Self::ApplicationDeauthorized(_) => "APPLICATION_DEAUTHORIZED",
+            Self::EntitlementCreate(_) => "ENTITLEMENT_CREATE",
+            Self::Unknown {
+                kind, ..
+            } => kind,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookEventBody {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawBody {
+            #[serde(rename = "type")]
+            kind: FixedString,
+            timestamp: FixedString,
+            #[serde(default)]
+            data: Value,
+        }
+
+        let raw = RawBody::deserialize(deserializer)?;
+        // Discord sends timestamps without an offset, which are in UTC.
+        let timestamp = Timestamp::parse(&raw.timestamp)
+            .or_else(|_| Timestamp::parse(&format!("{}Z", raw.timestamp)))
+            .map_err(DeError::custom)?;
+        let event = match raw.kind.as_str() {
+            "APPLICATION_AUTHORIZED" => {
+                from_value(raw.data).map(WebhookEvent::ApplicationAuthorized)
+            },
+            "APPLICATION_DEAUTHORIZED" => {
+                from_value(raw.data).map(WebhookEvent::ApplicationDeauthorized)
+            },
+            "ENTITLEMENT_CREATE" => from_value(raw.data).map(WebhookEvent::EntitlementCreate),
+            _ => Ok(WebhookEvent::Unknown {
+                kind: raw.kind,
+                data: raw.data,
+            }),
+        }
+        .map_err(DeError::custom)?;
+
+        Ok(Self {
+            timestamp,
+            event,
+        })
+    }
+}
+
+impl Serialize for WebhookEventBody {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("WebhookEventBody", 3)?;
+        s.serialize_field("type", self.event.name())?;
+        s.serialize_field("timestamp", &self.timestamp)?;
+        match &self.event {
+            WebhookEvent::ApplicationAuthorized(data) => s.serialize_field("data", data)?,
+            WebhookEvent::ApplicationDeauthorized(data) => s.serialize_field("data", data)?,
+            WebhookEvent::EntitlementCreate(data) => s.serialize_field("data", data)?,
+            WebhookEvent::Unknown {
+                data, ..
+            } => s.serialize_field("data", data)?,
+        }
+        s.end()
+    }
+}
+
+/// Sent when the application was added to a guild or a user's account.
+///
+/// [Discord docs](https://discord.com/developers/docs/events/webhook-events#application-authorized-application-authorized-structure).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationAuthorizedEvent {
+    /// Whether the application was added to a guild or a user's account.
+    #[cfg(feature = "unstable")]
+    pub integration_type: Option<InstallationContext>,
+    /// The user who authorized the application.
+    pub user: User,
+    /// The scopes the user authorized.
+    pub scopes: FixedArray<Scope>,
+    /// The guild the application was added to, if added to a guild.
+    pub guild: Option<Box<PartialGuild>>,
+}
+
+/// Sent when the application was removed from a user's account.
+///
+/// [Discord docs](https://discord.com/developers/docs/events/webhook-events#application-deauthorized-application-deauthorized-structure).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationDeauthorizedEvent {
+    /// The user who deauthorized the application.
+    pub user: User,
+}
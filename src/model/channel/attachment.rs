@@ -1,8 +1,14 @@
+#[cfg(feature = "model")]
+use futures::{stream, Stream, StreamExt as _, TryStreamExt as _};
 use nonmax::NonMaxU32;
 #[cfg(feature = "model")]
-use reqwest::Client as ReqwestClient;
+use reqwest::header::RANGE;
+#[cfg(feature = "model")]
+use reqwest::{Client as ReqwestClient, StatusCode};
 use serde_cow::CowStr;
 
+#[cfg(feature = "model")]
+use crate::http::{AttachmentDownloadError, Http, HttpError};
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 use crate::model::utils::is_false;
@@ -157,6 +163,204 @@ impl Attachment {
         let bytes = reqwest.get(&*self.url).send().await?.bytes().await?;
         Ok(bytes.to_vec())
     }
+
+    /// Downloads the attachment as a stream of chunks, without holding the whole file in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the attachment could not be retrieved. Reading from the stream
+    /// may also return an [`Error::Http`] if the download fails midway.
+    pub async fn stream(&self, http: &Http) -> Result<impl Stream<Item = Result<Vec<u8>>>> {
+        let url = http.cdn_url(&self.url);
+        let request = http.cdn_client().get(&*url).build()?;
+        let response = http.execute_cdn(request).await?.error_for_status()?;
+        Ok(response.bytes_stream().map(|chunk| chunk.map(Vec::from).map_err(Error::from)))
+    }
+
+    /// Downloads the attachment in chunks of `chunk_size` bytes using HTTP range requests, with up
+    /// to `concurrency` chunks being downloaded at a time. This is faster than [`Self::download`]
+    /// for very large attachments, such as when archiving channels.
+    ///
+    /// Downloads go through the client and host configured via [`HttpBuilder::cdn_client`] and
+    /// [`HttpBuilder::cdn_host`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Attachment;
+    /// # async fn run(http: &Http, attachment: Attachment) -> serenity::Result<()> {
+    /// // Download in 4 MiB chunks, 4 at a time
+    /// let data = attachment.download_parallel(http, 4 * 1024 * 1024, 4).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if any chunk fails to download, including
+    /// [`AttachmentDownloadError::RangeNotSupported`] if the host ignores range requests.
+    ///
+    /// [`HttpBuilder::cdn_client`]: crate::http::HttpBuilder::cdn_client
+    /// [`HttpBuilder::cdn_host`]: crate::http::HttpBuilder::cdn_host
+    pub async fn download_parallel(
+        &self,
+        http: &Http,
+        chunk_size: u64,
+        concurrency: usize,
+    ) -> Result<Vec<u8>> {
+        let fail = |why| Error::Http(HttpError::AttachmentDownload(why));
+
+        let size = u64::from(self.size);
+        let chunk_size = chunk_size.max(1);
+        let url = http.cdn_url(&self.url);
+        let client = http.cdn_client();
+
+        let ranges = (0..size.div_ceil(chunk_size))
+            .map(|chunk| (chunk * chunk_size, ((chunk + 1) * chunk_size).min(size)));
+        let chunks: Vec<_> = stream::iter(ranges)
+            .map(|(start, end)| {
+                let request = client.get(&*url).header(RANGE, format!("bytes={start}-{}", end - 1));
+                async move {
+                    let response = http.execute_cdn(request.build()?).await?;
+                    match response.status() {
+                        StatusCode::PARTIAL_CONTENT => {},
+                        status if status.is_success() => {
+                            return Err(fail(AttachmentDownloadError::RangeNotSupported));
+                        },
+                        status => {
+                            return Err(fail(AttachmentDownloadError::UnsuccessfulStatus(status)));
+                        },
+                    }
+
+                    let bytes = response.bytes().await?;
+                    if bytes.len() as u64 != end - start {
+                        return Err(fail(AttachmentDownloadError::SizeMismatch {
+                            expected: end - start,
+                            actual: bytes.len() as u64,
+                        }));
+                    }
+                    Ok(bytes)
+                }
+            })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        let mut data = Vec::with_capacity(self.size as usize);
+        for chunk in chunks {
+            data.extend_from_slice(&chunk);
+        }
+        Ok(data)
+    }
+
+    /// Downloads the attachment, failing as soon as it exceeds `max_bytes` instead of reading an
+    /// arbitrarily large file into memory.
+    ///
+    /// The content of the attachment is also checked against its [`Self::content_type`], see
+    /// [`Self::verify_content_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Attachment;
+    /// # async fn run(http: &Http, attachment: Attachment) -> serenity::Result<()> {
+    /// // Refuse to process uploads larger than 8 MiB
+    /// let data = attachment.download_with_limit(http, 8 * 1024 * 1024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::AttachmentTooLarge`] if the attachment is larger than `max_bytes`,
+    /// or a [`ModelError::AttachmentContentTypeMismatch`] if its content does not match its
+    /// content type. Returns an [`Error::Http`] if the download fails.
+    pub async fn download_with_limit(&self, http: &Http, max_bytes: u64) -> Result<Vec<u8>> {
+        let too_large = |size| ModelError::AttachmentTooLarge {
+            size,
+            limit: max_bytes,
+        };
+
+        // Fail early if the attachment is already known to be too large
+        if u64::from(self.size) > max_bytes {
+            return Err(Error::Model(too_large(u64::from(self.size))));
+        }
+
+        let mut data = Vec::with_capacity(self.size as usize);
+        let mut verified = false;
+        let mut stream = std::pin::pin!(self.stream(http).await?);
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+
+            let size = (data.len() + chunk.len()) as u64;
+            if size > max_bytes {
+                return Err(Error::Model(too_large(size)));
+            }
+            data.extend_from_slice(&chunk);
+
+            if !verified && data.len() >= SNIFF_LEN {
+                self.verify_content_type(&data)?;
+                verified = true;
+            }
+        }
+
+        if !verified {
+            self.verify_content_type(&data)?;
+        }
+
+        Ok(data)
+    }
+
+    /// Checks that the start of the attachment's content matches its [`Self::content_type`], to
+    /// detect files which lie about their type.
+    ///
+    /// Only common image formats and PDF documents are recognised. If the format of the
+    /// content is not recognised, or the attachment has no content type, the check passes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::AttachmentContentTypeMismatch`] if the content is recognised as a
+    /// different type.
+    pub fn verify_content_type(&self, data: &[u8]) -> StdResult<(), ModelError> {
+        let (Some(declared), Some(detected)) = (&self.content_type, sniff_content_type(data))
+        else {
+            return Ok(());
+        };
+
+        let essence = declared.split(';').next().unwrap_or_default().trim();
+        if essence.eq_ignore_ascii_case(detected) {
+            Ok(())
+        } else {
+            Err(ModelError::AttachmentContentTypeMismatch {
+                declared: declared.clone(),
+                detected,
+            })
+        }
+    }
+}
+
+/// The number of bytes needed by [`sniff_content_type`] to recognise all supported formats.
+#[cfg(feature = "model")]
+const SNIFF_LEN: usize = 12;
+
+/// Detects the content type of a file from its magic bytes.
+#[cfg(feature = "model")]
+fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+    ];
+
+    if data.len() >= SNIFF_LEN && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    SIGNATURES.iter().find(|(signature, _)| data.starts_with(signature)).map(|(_, kind)| *kind)
 }
 
 impl ExtractKey<AttachmentId> for Attachment {
@@ -164,3 +368,93 @@ impl ExtractKey<AttachmentId> for Attachment {
         &self.id
     }
 }
+
+#[cfg(all(test, feature = "http_transport"))]
+mod tests {
+    use reqwest::header::RANGE;
+    use serde_json::json;
+
+    use super::Attachment;
+    use crate::http::{
+        AttachmentDownloadError,
+        HttpBuilder,
+        HttpError,
+        LightMethod,
+        MockTransport,
+        StatusCode,
+    };
+
+    const DATA: &[u8] = b"0123456789";
+
+    fn attachment(size: u32) -> Attachment {
+        serde_json::from_value(json!({
+            "id": "1",
+            "filename": "file.bin",
+            "proxy_url": "https://media.discordapp.net/attachments/2/1/file.bin",
+            "size": size,
+            "url": "https://cdn.discordapp.com/attachments/2/1/file.bin",
+        }))
+        .unwrap()
+    }
+
+    fn download_error(error: &crate::Error) -> Option<&AttachmentDownloadError> {
+        match error.as_http()? {
+            HttpError::AttachmentDownload(why) => Some(why),
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn download_parallel_stitches_ranges() {
+        let transport = MockTransport::new().serve_file("/attachments/2/1/file.bin", DATA);
+        let http = HttpBuilder::new("token")
+            .cdn_host("https://cdn.example.com/proxy")
+            .transport(transport.clone())
+            .build();
+
+        let data = attachment(10).download_parallel(&http, 4, 2).await.unwrap();
+        assert_eq!(data, DATA);
+
+        let mut ranges: Vec<_> = transport
+            .requests()
+            .iter()
+            .map(|request| {
+                assert_eq!(request.path, "/proxy/attachments/2/1/file.bin");
+                request.headers[RANGE].to_str().unwrap().to_owned()
+            })
+            .collect();
+        ranges.sort();
+        // The final chunk only requests the remaining bytes
+        assert_eq!(ranges, ["bytes=0-3", "bytes=4-7", "bytes=8-9"]);
+    }
+
+    #[tokio::test]
+    async fn download_parallel_short_chunk() {
+        // The file is shorter than the attachment claims, so the final chunk comes up short
+        let transport = MockTransport::new().serve_file("/attachments/*/*/*", DATA);
+        let http = HttpBuilder::new("token").transport(transport).build();
+
+        let error = attachment(12).download_parallel(&http, 4, 2).await.unwrap_err();
+        assert_eq!(
+            download_error(&error),
+            Some(&AttachmentDownloadError::SizeMismatch {
+                expected: 4,
+                actual: 2,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn download_parallel_without_range_support() {
+        let transport = MockTransport::new().respond_bytes(
+            LightMethod::Get,
+            "/attachments/*/*/*",
+            StatusCode::OK,
+            DATA,
+        );
+        let http = HttpBuilder::new("token").transport(transport).build();
+
+        let error = attachment(10).download_parallel(&http, 4, 2).await.unwrap_err();
+        assert_eq!(download_error(&error), Some(&AttachmentDownloadError::RangeNotSupported));
+    }
+}
@@ -27,9 +27,13 @@ use crate::collector::{MessageCollector, ReactionCollector};
 #[cfg(feature = "collector")]
 use crate::gateway::ShardMessenger;
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http, Typing};
+use crate::http::{CacheHttp, Http, HttpError, Typing};
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::user::current_user_id;
+#[cfg(feature = "model")]
+use crate::utils::{split_message, SplitOptions};
 
 #[cfg(feature = "model")]
 impl ChannelId {
@@ -593,6 +597,8 @@ impl ChannelId {
     /// The optional `after` attribute is to retrieve the users after a certain user. This is
     /// useful for pagination.
     ///
+    /// Pass [`ReactionTypes::Burst`] as `kind` to retrieve the users who super reacted instead.
+    ///
     /// **Note**: Requires the [Read Message History] permission.
     ///
     /// **Note**: If the passed reaction_type is a custom guild emoji, it must contain the name.
@@ -610,12 +616,13 @@ impl ChannelId {
         http: &Http,
         message_id: MessageId,
         reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
         limit: Option<u8>,
         after: Option<UserId>,
     ) -> Result<Vec<User>> {
         let limit = limit.map_or(50, |x| if x > 100 { 100 } else { x });
 
-        http.get_reaction_users(self, message_id, &reaction_type.into(), limit, after).await
+        http.get_reaction_users(self, message_id, &reaction_type.into(), kind, limit, after).await
     }
 
     /// Sends a message with just the given message content in the channel.
@@ -631,6 +638,26 @@ impl ChannelId {
         self.send_message(http, builder).await
     }
 
+    /// Sends message content of any length in the channel, split into multiple messages if it's
+    /// over 2000 unicode code points. The messages are sent one after another, so they appear in
+    /// order.
+    ///
+    /// Content is split via [`split_message`], which keeps words, mentions and code blocks intact.
+    /// Use it directly to customise how content is split, or to send the chunks differently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if sending a message fails, in which case the messages sent before
+    /// are not deleted.
+    pub async fn say_long(self, http: &Http, content: &str) -> Result<Vec<Message>> {
+        let mut messages = Vec::new();
+        for chunk in split_message(content, SplitOptions::default()) {
+            messages.push(self.say(http, chunk).await?);
+        }
+
+        Ok(messages)
+    }
+
     /// Sends file(s) along with optional message contents. The filename _must_ be specified.
     ///
     /// Message contents may be passed using the `builder` argument.
@@ -814,6 +841,43 @@ impl ChannelId {
         self.await_reply(shard_messenger)
     }
 
+    /// Returns a builder which can be awaited to obtain the message sent in this channel with the
+    /// given nonce, as set by [`CreateMessage::nonce`].
+    ///
+    /// This allows confirming that a message was sent, without relying on the order of HTTP
+    /// responses. The collector should be created before sending the message, so the event cannot
+    /// be missed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use serenity::builder::CreateMessage;
+    /// # use serenity::gateway::ShardMessenger;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::ChannelId;
+    /// #
+    /// # async fn run(http: &Http, shard_messenger: ShardMessenger) -> serenity::Result<()> {
+    /// # let channel_id = ChannelId::new(1);
+    /// let confirmation =
+    ///     channel_id.await_nonce(shard_messenger, 1234).timeout(Duration::from_secs(10));
+    /// channel_id.send_message(http, CreateMessage::new().content("Hello").nonce(1234)).await?;
+    ///
+    /// if let Some(message) = confirmation.await {
+    ///     println!("Message {} was received", message.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "collector")]
+    pub fn await_nonce(
+        self,
+        shard_messenger: ShardMessenger,
+        nonce: impl Into<Nonce>,
+    ) -> MessageCollector {
+        MessageCollector::new(shard_messenger).channel_id(self).nonce(nonce.into())
+    }
+
     /// Returns a builder which can be awaited to obtain a reaction or stream of reactions sent in
     /// this channel.
     #[cfg(feature = "collector")]
@@ -827,6 +891,31 @@ impl ChannelId {
         self.await_reaction(shard_messenger)
     }
 
+    /// Sets the status of a voice channel. Pass an empty string to clear the status.
+    ///
+    /// Requires the [Set Voice Channel Status] permission, and the [Manage Channels] permission
+    /// if the current user is not connected to the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the channel is not a
+    /// voice channel.
+    ///
+    /// [Set Voice Channel Status]: Permissions::SET_VOICE_CHANNEL_STATUS
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn set_voice_status(self, http: &Http, status: &str) -> Result<()> {
+        #[derive(serde::Serialize)]
+        struct EditVoiceStatus<'a> {
+            status: &'a str,
+        }
+
+        let map = EditVoiceStatus {
+            status,
+        };
+
+        http.edit_voice_status(self, &map).await
+    }
+
     /// Gets a stage instance.
     ///
     /// # Errors
@@ -945,6 +1034,39 @@ impl ChannelId {
         http.join_thread_channel(self).await
     }
 
+    /// Joins the thread unless the current user is already a member, returning whether it was
+    /// joined.
+    ///
+    /// Thread membership can be lost in various ways, such as moderators removing the bot or some
+    /// archive and unarchive flows, after which the bot silently stops receiving messages sent in
+    /// the thread. See [`ThreadWatchList`] to rejoin a set of threads on startup.
+    ///
+    /// **Note**: The current user is retrieved from the cache if available, otherwise an extra
+    /// HTTP request is made.
+    ///
+    /// # Errors
+    ///
+    /// It may return an [`Error::Http`] if the channel is not a thread channel, or the current
+    /// user lacks permission to join it.
+    ///
+    /// [`ThreadWatchList`]: crate::utils::ThreadWatchList
+    pub async fn ensure_joined_thread(self, cache_http: impl CacheHttp) -> Result<bool> {
+        let user_id = current_user_id(&cache_http).await?;
+
+        let http = cache_http.http();
+        match http.get_thread_channel_member(self, user_id, false).await {
+            Ok(_) => Ok(false),
+            Err(why)
+                if why.as_http().and_then(HttpError::status_code)
+                    == Some(reqwest::StatusCode::NOT_FOUND) =>
+            {
+                http.join_thread_channel(self).await?;
+                Ok(true)
+            },
+            Err(why) => Err(why),
+        }
+    }
+
     /// Leaves the thread, if this channel is a thread.
     ///
     /// # Errors
@@ -1,6 +1,7 @@
 use nonmax::NonMaxU32;
 
 use crate::internal::prelude::*;
+use crate::model::fixed::IntoFixedString;
 use crate::model::{Colour, Timestamp};
 
 /// Represents a rich embed which allows using richer markdown, multiple fields and more. This was
@@ -121,10 +122,10 @@ impl EmbedField {
     /// lengths.
     pub fn new<T, U>(name: T, value: U, inline: bool) -> Self
     where
-        T: Into<String>,
-        U: Into<String>,
+        T: IntoFixedString,
+        U: IntoFixedString,
     {
-        Self::_new(name.into().trunc_into(), value.into().trunc_into(), inline)
+        Self::_new(name.into_fixed_trunc(), value.into_fixed_trunc(), inline)
     }
 
     pub(crate) const fn _new(
@@ -677,6 +677,8 @@ impl GuildChannel {
     /// The optional `after` attribute is to retrieve the users after a certain user. This is
     /// useful for pagination.
     ///
+    /// Pass [`ReactionTypes::Burst`] as `kind` to retrieve the users who super reacted instead.
+    ///
     /// **Note**: Requires the [Read Message History] permission.
     ///
     /// **Note**: If the passed reaction_type is a custom guild emoji, it must contain the name.
@@ -693,10 +695,11 @@ impl GuildChannel {
         http: &Http,
         message_id: MessageId,
         reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
         limit: Option<u8>,
         after: Option<UserId>,
     ) -> Result<Vec<User>> {
-        self.id.reaction_users(http, message_id, reaction_type, limit, after).await
+        self.id.reaction_users(http, message_id, reaction_type, kind, limit, after).await
     }
 
     /// Sends a message with just the given message content in the channel.
@@ -829,19 +832,9 @@ impl GuildChannel {
         let guild = cache.guild(self.guild_id).ok_or(ModelError::GuildNotFound)?;
 
         match self.kind {
-            ChannelType::Voice | ChannelType::Stage => Ok(guild
-                .voice_states
-                .iter()
-                .filter_map(|v| {
-                    v.channel_id.and_then(|c| {
-                        if c == self.id {
-                            guild.members.get(&v.user_id).cloned()
-                        } else {
-                            None
-                        }
-                    })
-                })
-                .collect()),
+            ChannelType::Voice | ChannelType::Stage => {
+                Ok(guild.voice_channel_members(self.id).into_iter().cloned().collect())
+            },
             ChannelType::News | ChannelType::Text => Ok(guild
                 .members
                 .iter()
@@ -918,6 +911,21 @@ impl GuildChannel {
 
     /// Creates a stage instance.
     ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::builder::CreateStageInstance;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::prelude::*;
+    /// # async fn run(http: &Http, channel: &GuildChannel) -> serenity::Result<()> {
+    /// let builder = CreateStageInstance::new("Weekly Q&A")
+    ///     .privacy_level(StageInstancePrivacyLevel::GuildOnly)
+    ///     .send_start_notification(true);
+    /// channel.create_stage_instance(http, builder).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns [`ModelError::InvalidChannelType`] if the channel is not a stage channel.
@@ -1,6 +1,7 @@
 //! Models relating to Discord channels.
 
 use std::borrow::Cow;
+use std::fmt;
 
 use nonmax::NonMaxU64;
 
@@ -21,6 +22,7 @@ use crate::gateway::ShardMessenger;
 #[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
+use crate::model::fixed::IntoFixedString;
 use crate::model::prelude::*;
 use crate::model::utils::{discord_colours, StrOrInt};
 
@@ -99,8 +101,14 @@ pub struct Message {
     /// If the message is an Interaction or application-owned webhook, this is the id of the
     /// application.
     pub application_id: Option<ApplicationId>,
-    /// Reference data sent with crossposted messages.
+    /// Reference data sent with crossposted, replied, and forwarded messages.
     pub message_reference: Option<MessageReference>,
+    /// The messages associated with the [`Self::message_reference`], if it is a
+    /// [`MessageReferenceKind::Forward`].
+    ///
+    /// This is a minimal snapshot of the forwarded message at the time it was forwarded.
+    #[serde(default, skip_serializing_if = "FixedArray::is_empty")]
+    pub message_snapshots: FixedArray<MessageSnapshot>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
     /// The message that was replied to using this message.
@@ -116,7 +124,7 @@ pub struct Message {
     pub thread: Option<Box<GuildChannel>>,
     /// The components of this message
     #[serde(default)]
-    pub components: FixedArray<ActionRow>,
+    pub components: FixedArray<Component>,
     /// Array of message sticker item objects.
     #[serde(default)]
     pub sticker_items: FixedArray<StickerItem>,
@@ -340,6 +348,8 @@ impl Message {
     /// The optional `after` attribute is to retrieve the users after a certain user. This is
     /// useful for pagination.
     ///
+    /// Pass [`ReactionTypes::Burst`] as `kind` to retrieve the users who super reacted instead.
+    ///
     /// **Note**: Requires the [Read Message History] permission.
     ///
     /// **Note**: If the passed reaction_type is a custom guild emoji, it must contain the name.
@@ -355,10 +365,11 @@ impl Message {
         &self,
         http: &Http,
         reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
         limit: Option<u8>,
         after: Option<UserId>,
     ) -> Result<Vec<User>> {
-        self.channel_id.reaction_users(http, self.id, reaction_type, limit, after).await
+        self.channel_id.reaction_users(http, self.id, reaction_type, kind, limit, after).await
     }
 
     /// Returns the associated [`Guild`] for the message if one is in the cache.
@@ -427,6 +438,87 @@ impl Message {
         http.create_reaction(self.channel_id, self.id, &reaction_type.into()).await
     }
 
+    /// React to the message like [`Self::react`], but first checks via the cache whether the
+    /// current user has the permissions needed to do so.
+    ///
+    /// The [Add Reactions] permission is required unless someone already reacted with the same
+    /// emoji, and the [Use External Emojis] permission is required for custom emojis of other
+    /// guilds. The check is skipped if the message was not sent in a guild, or if the guild, the
+    /// channel or the current member are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::MissingPermissions`] naming the missing permissions if the check
+    /// fails. Otherwise, returns [`Error::Http`] if the current user lacks permission or if
+    /// invalid data is given.
+    ///
+    /// [Add Reactions]: Permissions::ADD_REACTIONS
+    /// [Use External Emojis]: Permissions::USE_EXTERNAL_EMOJIS
+    pub async fn react_checked(
+        &self,
+        cache_http: impl CacheHttp,
+        reaction_type: impl Into<ReactionType>,
+    ) -> Result<()> {
+        let reaction_type = reaction_type.into();
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            if let Some(missing) = self.missing_reaction_permissions(cache, &reaction_type) {
+                return Err(Error::Model(ModelError::MissingPermissions(missing)));
+            }
+        }
+
+        self.react(cache_http.http(), reaction_type).await
+    }
+
+    #[cfg(feature = "cache")]
+    fn missing_reaction_permissions(
+        &self,
+        cache: &Cache,
+        reaction_type: &ReactionType,
+    ) -> Option<Permissions> {
+        let current_user_id = cache.current_user().id;
+        let guild = self.guild(cache)?;
+
+        // Threads inherit the permissions of their parent channel
+        let channel = guild.channels.get(&self.channel_id).or_else(|| {
+            let thread = guild.threads.iter().find(|thread| thread.id == self.channel_id)?;
+            guild.channels.get(&thread.parent_id?)
+        })?;
+        let member = guild.members.get(&current_user_id)?;
+        let permissions = guild.user_permissions_in(channel, member);
+
+        let mut required = Permissions::empty();
+        let existing =
+            self.reactions.iter().any(|reaction| match (&reaction.reaction_type, reaction_type) {
+                (
+                    ReactionType::Custom {
+                        id: existing, ..
+                    },
+                    ReactionType::Custom {
+                        id, ..
+                    },
+                ) => existing == id,
+                (ReactionType::Unicode(existing), ReactionType::Unicode(unicode)) => {
+                    existing == unicode
+                },
+                _ => false,
+            });
+        if !existing {
+            required |= Permissions::ADD_REACTIONS;
+        }
+        if let ReactionType::Custom {
+            id, ..
+        } = reaction_type
+        {
+            if !guild.emojis.contains_key(id) {
+                required |= Permissions::USE_EXTERNAL_EMOJIS;
+            }
+        }
+
+        Some(required - permissions).filter(|missing| !missing.is_empty())
+    }
+
     /// React to the message with a custom [`Emoji`] or unicode character.
     ///
     /// **Note**: Requires [Add Reactions] and [Use External Emojis] permissions.
@@ -473,8 +565,27 @@ impl Message {
         self._reply(http, content.into(), true).await
     }
 
+    /// Uses Discord's inline reply to send the given message.
+    ///
+    /// Unlike [`Self::reply`] and [`Self::reply_ping`], the builder is sent as is, so whether the
+    /// replied user is pinged is controlled by the builder's
+    /// [`CreateMessage::allowed_mentions`]. If the builder does not reference a message yet, it
+    /// is set to reference this message. To control whether the reply fails if this message was
+    /// deleted, reference it with [`CreateMessage::reference_message_with`].
+    ///
+    /// # Errors
+    ///
+    /// See the documentation of [`CreateMessage::execute`] for possible errors.
+    pub async fn reply_with(&self, http: &Http, mut builder: CreateMessage<'_>) -> Result<Message> {
+        if builder.message_reference.is_none() {
+            builder = builder.reference_message(self);
+        }
+
+        self.channel_id.send_message(http, builder).await
+    }
+
     async fn _reply(&self, http: &Http, content: Cow<'_, str>, ping_user: bool) -> Result<Message> {
-        let default_allowed_mentions = http.default_allowed_mentions.clone();
+        let default_allowed_mentions = http.default_allowed_mentions();
         let allowed_mentions = default_allowed_mentions.unwrap_or_else(|| {
             CreateAllowedMentions::new().everyone(true).all_users(true).all_roles(true)
         });
@@ -809,13 +920,33 @@ pub struct MessageActivity {
     pub party_id: Option<FixedString>,
 }
 
-/// Reference data sent with crossposted messages.
+enum_number! {
+    /// The type of a [`MessageReference`], which determines how the referenced message is shown.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-types).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[non_exhaustive]
+    #[<default> = 0]
+    pub enum MessageReferenceKind {
+        /// A standard reference used by replies and crossposts.
+        Default = 0,
+        /// A reference used to point to a message at a point in time, used by forwards.
+        Forward = 1,
+        _ => Unknown(u8),
+    }
+}
+
+/// Reference data sent with crossposted, replied, and forwarded messages.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-object-message-reference-structure).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageReference {
+    /// The type of reference.
+    #[serde(rename = "type", default)]
+    pub kind: MessageReferenceKind,
     /// ID of the originating message.
     pub message_id: Option<MessageId>,
     /// ID of the originating message's channel.
@@ -830,6 +961,7 @@ pub struct MessageReference {
 impl From<&Message> for MessageReference {
     fn from(m: &Message) -> Self {
         Self {
+            kind: MessageReferenceKind::Default,
             message_id: Some(m.id),
             channel_id: m.channel_id,
             guild_id: m.guild_id,
@@ -841,6 +973,7 @@ impl From<&Message> for MessageReference {
 impl From<(ChannelId, MessageId)> for MessageReference {
     fn from(pair: (ChannelId, MessageId)) -> Self {
         Self {
+            kind: MessageReferenceKind::Default,
             message_id: Some(pair.1),
             channel_id: pair.0,
             guild_id: None,
@@ -849,6 +982,66 @@ impl From<(ChannelId, MessageId)> for MessageReference {
     }
 }
 
+impl MessageReference {
+    /// Creates a reference which forwards the given message.
+    #[must_use]
+    pub fn forward(message: &Message) -> Self {
+        Self {
+            kind: MessageReferenceKind::Forward,
+            ..message.into()
+        }
+    }
+}
+
+/// A snapshot of a message, sent alongside a forwarded message.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-snapshot-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageSnapshot {
+    /// The subset of the forwarded message's data that was captured.
+    pub message: ForwardedMessage,
+}
+
+/// The subset of [`Message`] fields that are sent as part of a [`MessageSnapshot`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-snapshot-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ForwardedMessage {
+    /// The type of the forwarded message.
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// The content of the forwarded message.
+    pub content: FixedString<u16>,
+    /// Array of embeds sent with the forwarded message.
+    #[serde(default)]
+    pub embeds: FixedArray<Embed>,
+    /// The files attached to the forwarded message.
+    #[serde(default)]
+    pub attachments: FixedArray<Attachment>,
+    /// Initial creation timestamp of the forwarded message.
+    pub timestamp: Timestamp,
+    /// The timestamp of the last time the forwarded message was updated, if it was.
+    pub edited_timestamp: Option<Timestamp>,
+    /// Bit flags describing extra features of the forwarded message.
+    pub flags: Option<MessageFlags>,
+    /// Array of users mentioned in the forwarded message.
+    #[serde(default)]
+    pub mentions: FixedArray<User>,
+    /// Array of [`Role`]s' Ids mentioned in the forwarded message.
+    #[serde(default)]
+    pub mention_roles: FixedArray<RoleId>,
+    /// Array of message sticker item objects.
+    #[serde(default)]
+    pub sticker_items: FixedArray<StickerItem>,
+    /// The components of the forwarded message.
+    #[serde(default)]
+    pub components: FixedArray<Component>,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-mention-object).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -905,6 +1098,11 @@ bitflags! {
         /// As of 2023-04-20, bots are currently not able to send voice messages
         /// ([source](https://github.com/discord/discord-api-docs/pull/6082)).
         const IS_VOICE_MESSAGE = 1 << 13;
+        /// This message uses layout components, such as [`Section`]s and [`Container`]s.
+        ///
+        /// Once set, the flag cannot be removed. Messages with this flag cannot have content,
+        /// embeds, polls or stickers, and all content must be sent as components instead.
+        const IS_COMPONENTS_V2 = 1 << 15;
     }
 }
 
@@ -923,13 +1121,65 @@ impl MessageId {
 }
 
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
-#[derive(Clone, Debug, Serialize)]
+/// A nonce used to verify that a message was sent, see [`CreateMessage::nonce`].
+///
+/// [`CreateMessage::nonce`]: crate::builder::CreateMessage::nonce
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Nonce {
     String(FixedString),
     Number(u64),
 }
 
+impl Nonce {
+    /// Returns `true` if both nonces represent the same value, regardless of whether they are
+    /// strings or numbers, as Discord does not always return a nonce with the type it was sent
+    /// with.
+    #[must_use]
+    pub fn matches(&self, other: &Nonce) -> bool {
+        match (self, other) {
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::String(s), Self::Number(n)) | (Self::Number(n), Self::String(s)) => {
+                s.parse::<u64>().is_ok_and(|s| s == *n)
+            },
+        }
+    }
+}
+
+impl fmt::Display for Nonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(nonce) => f.write_str(nonce),
+            Self::Number(nonce) => nonce.fmt(f),
+        }
+    }
+}
+
+impl From<u64> for Nonce {
+    fn from(nonce: u64) -> Self {
+        Self::Number(nonce)
+    }
+}
+
+impl From<FixedString> for Nonce {
+    fn from(nonce: FixedString) -> Self {
+        Self::String(nonce)
+    }
+}
+
+impl From<String> for Nonce {
+    fn from(nonce: String) -> Self {
+        Self::String(nonce.trunc_into())
+    }
+}
+
+impl From<&str> for Nonce {
+    fn from(nonce: &str) -> Self {
+        Self::String(nonce.into_fixed_trunc())
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for Nonce {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
         Ok(StrOrInt::deserialize(deserializer)?.into_enum(Self::String, Self::Number))
@@ -1075,3 +1325,80 @@ pub struct PollAnswerCount {
     pub count: u64,
     pub me_voted: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Message, MessageReference, MessageReferenceKind};
+    use crate::model::id::{ChannelId, MessageId};
+
+    #[test]
+    fn message_reference_kind() {
+        // References sent before forwards existed have no type
+        let reference: MessageReference =
+            serde_json::from_value(json!({"message_id": "2", "channel_id": "1"})).unwrap();
+        assert_eq!(reference.kind, MessageReferenceKind::Default);
+        assert_eq!(reference.message_id, Some(MessageId::new(2)));
+
+        let value = serde_json::to_value(&reference).unwrap();
+        assert_eq!(value["type"], 0);
+        let reference: MessageReference = serde_json::from_value(value).unwrap();
+        assert_eq!(reference.kind, MessageReferenceKind::Default);
+
+        let forward = MessageReference {
+            kind: MessageReferenceKind::Forward,
+            ..(ChannelId::new(1), MessageId::new(2)).into()
+        };
+        let value = serde_json::to_value(&forward).unwrap();
+        assert_eq!(value["type"], 1);
+        let forward: MessageReference = serde_json::from_value(value).unwrap();
+        assert_eq!(forward.kind, MessageReferenceKind::Forward);
+    }
+
+    #[test]
+    fn message_snapshots() {
+        let message: Message = serde_json::from_value(json!({
+            "id": "3",
+            "channel_id": "4",
+            "author": {"id": "5", "username": "user", "discriminator": "0", "avatar": null},
+            "content": "",
+            "timestamp": "2024-01-02T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "message_reference": {"type": 1, "message_id": "2", "channel_id": "1"},
+            "message_snapshots": [{
+                "message": {
+                    "type": 0,
+                    "content": "hello",
+                    "embeds": [],
+                    "attachments": [],
+                    "timestamp": "2024-01-01T00:00:00Z",
+                    "edited_timestamp": null,
+                    "flags": 0,
+                    "mentions": [],
+                    "mention_roles": [],
+                },
+            }],
+        }))
+        .unwrap();
+
+        let reference = message.message_reference.unwrap();
+        assert_eq!(reference.kind, MessageReferenceKind::Forward);
+        assert_eq!(reference.message_id, Some(MessageId::new(2)));
+
+        let [snapshot] = &*message.message_snapshots else {
+            panic!("expected a single snapshot, got {:?}", message.message_snapshots);
+        };
+        assert_eq!(snapshot.message.content, "hello");
+        assert_eq!(snapshot.message.timestamp.unix_timestamp(), 1_704_067_200);
+        assert!(snapshot.message.sticker_items.is_empty());
+    }
+}
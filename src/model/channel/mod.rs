@@ -335,6 +335,103 @@ pub enum PermissionOverwriteType {
     Role(RoleId),
 }
 
+/// Helpers for building commonly needed sets of [`PermissionOverwrite`]s, such as for
+/// [`CreateChannel::permissions`].
+///
+/// Every preset also allows the current user to view and send messages in the channel, so that
+/// the bot does not lock itself out.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::{GuildId, RoleId, UserId};
+/// # async fn run(http: &Http, guild_id: GuildId, bot_id: UserId) -> serenity::Result<()> {
+/// use serenity::builder::CreateChannel;
+/// use serenity::model::channel::PermissionOverwritePreset;
+///
+/// let presets = PermissionOverwritePreset::new(guild_id, bot_id);
+/// let builder = CreateChannel::new("staff").permissions(presets.staff_only(RoleId::new(1)));
+/// guild_id.create_channel(http, builder).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`CreateChannel::permissions`]: crate::builder::CreateChannel::permissions
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct PermissionOverwritePreset {
+    guild_id: GuildId,
+    bot_id: UserId,
+}
+
+impl PermissionOverwritePreset {
+    /// The permissions denied to `@everyone` by [`Self::read_only_for_everyone`].
+    pub const READ_ONLY_DENY: Permissions = Permissions::SEND_MESSAGES
+        .union(Permissions::SEND_MESSAGES_IN_THREADS)
+        .union(Permissions::CREATE_PUBLIC_THREADS)
+        .union(Permissions::CREATE_PRIVATE_THREADS)
+        .union(Permissions::ADD_REACTIONS);
+
+    /// The permissions allowed to the current user by every preset.
+    pub const BOT_ALLOW: Permissions = Permissions::VIEW_CHANNEL.union(Permissions::SEND_MESSAGES);
+
+    /// Creates presets for channels in the given guild, which allow access for the given bot user.
+    pub fn new(guild_id: GuildId, bot_id: UserId) -> Self {
+        Self {
+            guild_id,
+            bot_id,
+        }
+    }
+
+    /// Hides the channel from `@everyone`, and only allows the given roles to view it.
+    #[must_use]
+    pub fn private_to(self, roles: impl IntoIterator<Item = RoleId>) -> Vec<PermissionOverwrite> {
+        let mut overwrites = vec![PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::VIEW_CHANNEL,
+            kind: PermissionOverwriteType::Role(RoleId::new(self.guild_id.get())),
+        }];
+
+        overwrites.extend(roles.into_iter().map(|role_id| PermissionOverwrite {
+            allow: Permissions::VIEW_CHANNEL,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Role(role_id),
+        }));
+
+        overwrites.push(self.bot_overwrite());
+        overwrites
+    }
+
+    /// Allows `@everyone` to view the channel, but not to send messages, create threads or add
+    /// reactions. See [`Self::READ_ONLY_DENY`].
+    #[must_use]
+    pub fn read_only_for_everyone(self) -> Vec<PermissionOverwrite> {
+        vec![
+            PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Self::READ_ONLY_DENY,
+                kind: PermissionOverwriteType::Role(RoleId::new(self.guild_id.get())),
+            },
+            self.bot_overwrite(),
+        ]
+    }
+
+    /// Hides the channel from everyone except the given staff role.
+    #[must_use]
+    pub fn staff_only(self, role_id: RoleId) -> Vec<PermissionOverwrite> {
+        self.private_to([role_id])
+    }
+
+    fn bot_overwrite(self) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow: Self::BOT_ALLOW,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Member(self.bot_id),
+        }
+    }
+}
+
 enum_number! {
     /// The video quality mode for a voice channel.
     ///
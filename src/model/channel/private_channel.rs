@@ -183,6 +183,8 @@ impl PrivateChannel {
     /// The optional `after` attribute is to retrieve the users after a certain user. This is
     /// useful for pagination.
     ///
+    /// Pass [`ReactionTypes::Burst`] as `kind` to retrieve the users who super reacted instead.
+    ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if a message with the given Id does not exist in the channel.
@@ -191,10 +193,11 @@ impl PrivateChannel {
         http: &Http,
         message_id: MessageId,
         reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
         limit: Option<u8>,
         after: Option<UserId>,
     ) -> Result<Vec<User>> {
-        self.id.reaction_users(http, message_id, reaction_type, limit, after).await
+        self.id.reaction_users(http, message_id, reaction_type, kind, limit, after).await
     }
 
     /// Pins a [`Message`] to the channel.
@@ -188,6 +188,9 @@ impl Reaction {
     /// The optional `after` attribute is to retrieve the users after a certain user. This is
     /// useful for pagination.
     ///
+    /// Only users who reacted the same way as this reaction are listed, so a super reaction will
+    /// retrieve the users who super reacted.
+    ///
     /// Requires the [Read Message History] permission.
     ///
     /// **Note**: This will send a request to the REST API.
@@ -222,7 +225,15 @@ impl Reaction {
             warn!("Reaction users limit clamped to 100! (API Restriction)");
         }
 
-        http.get_reaction_users(self.channel_id, self.message_id, reaction_type, limit, after).await
+        http.get_reaction_users(
+            self.channel_id,
+            self.message_id,
+            reaction_type,
+            self.reaction_type,
+            limit,
+            after,
+        )
+        .await
     }
 }
 
@@ -3,6 +3,11 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use small_fixed_array::FixedString;
+
+use super::application::InvalidCommand;
+use super::Permissions;
+
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Maximum {
@@ -11,9 +16,13 @@ pub enum Maximum {
     MessageLength,
     StickerCount,
     WebhookName,
+    WebhookUsername,
     AuditLogReason,
     DeleteMessageDays,
     BulkDeleteAmount,
+    PollQuestionLength,
+    PollAnswerCount,
+    PollAnswerLength,
 }
 
 impl Maximum {
@@ -36,8 +45,12 @@ impl Maximum {
             Self::MessageLength => crate::constants::MESSAGE_CODE_LIMIT,
             Self::StickerCount => crate::constants::STICKER_MAX_COUNT,
             Self::WebhookName | Self::BulkDeleteAmount => 100,
+            Self::WebhookUsername => crate::constants::WEBHOOK_USERNAME_MAX_LENGTH,
             Self::AuditLogReason => 512,
             Self::DeleteMessageDays => 7,
+            Self::PollQuestionLength => 300,
+            Self::PollAnswerCount => 10,
+            Self::PollAnswerLength => 55,
         }
     }
 }
@@ -50,9 +63,13 @@ impl fmt::Display for Maximum {
             Self::MessageLength => f.write_str("Message length"),
             Self::StickerCount => f.write_str("Sticker count"),
             Self::WebhookName => f.write_str("Webhook name"),
+            Self::WebhookUsername => f.write_str("Webhook username"),
             Self::AuditLogReason => f.write_str("Audit log reason"),
             Self::DeleteMessageDays => f.write_str("Delete message days"),
             Self::BulkDeleteAmount => f.write_str("Message bulk delete count"),
+            Self::PollQuestionLength => f.write_str("Poll question length"),
+            Self::PollAnswerCount => f.write_str("Poll answer count"),
+            Self::PollAnswerLength => f.write_str("Poll answer length"),
         }
     }
 }
@@ -61,6 +78,7 @@ impl fmt::Display for Maximum {
 #[non_exhaustive]
 pub enum Minimum {
     WebhookName,
+    WebhookUsername,
     BulkDeleteAmount,
 }
 
@@ -80,7 +98,7 @@ impl Minimum {
     pub(crate) fn value(self) -> usize {
         match self {
             Self::WebhookName => 2,
-            Self::BulkDeleteAmount => 1,
+            Self::WebhookUsername | Self::BulkDeleteAmount => 1,
         }
     }
 }
@@ -89,11 +107,54 @@ impl fmt::Display for Minimum {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::WebhookName => f.write_str("Webhook name"),
+            Self::WebhookUsername => f.write_str("Webhook username"),
             Self::BulkDeleteAmount => f.write_str("Bulk delete amount"),
         }
     }
 }
 
+/// A part of an embed with its own length limit, see [`Error::EmbedTooLarge`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedPart {
+    Title,
+    Description,
+    FieldCount,
+    FieldName,
+    FieldValue,
+    FooterText,
+    AuthorName,
+}
+
+impl EmbedPart {
+    /// The maximum length of this part, or the maximum number of fields for
+    /// [`Self::FieldCount`].
+    #[must_use]
+    pub const fn max(self) -> usize {
+        match self {
+            Self::Title | Self::FieldName | Self::AuthorName => 256,
+            Self::Description => 4096,
+            Self::FieldCount => 25,
+            Self::FieldValue => 1024,
+            Self::FooterText => 2048,
+        }
+    }
+}
+
+impl fmt::Display for EmbedPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Title => f.write_str("Embed title"),
+            Self::Description => f.write_str("Embed description"),
+            Self::FieldCount => f.write_str("Embed field count"),
+            Self::FieldName => f.write_str("Embed field name"),
+            Self::FieldValue => f.write_str("Embed field value"),
+            Self::FooterText => f.write_str("Embed footer text"),
+            Self::AuthorName => f.write_str("Embed author name"),
+        }
+    }
+}
+
 /// An error returned from the [`model`] module.
 ///
 /// This is always wrapped within the library's [`Error::Model`] variant.
@@ -209,6 +270,37 @@ pub enum Error {
     DeleteNitroSticker,
     /// When attempting to edit a voice message.
     CannotEditVoiceMessage,
+    /// When attempting to send a poll in a message which cannot contain one, such as a forwarded
+    /// message or an interaction response which edits an existing message.
+    PollNotAllowed,
+    /// When an attachment is larger than the limit given to [`Attachment::download_with_limit`].
+    ///
+    /// [`Attachment::download_with_limit`]: super::channel::Attachment::download_with_limit
+    AttachmentTooLarge { size: u64, limit: u64 },
+    /// When the content of an attachment does not match its declared content type.
+    AttachmentContentTypeMismatch { declared: FixedString, detected: &'static str },
+    /// Indicates that the current user lacks the given permissions for an action, as determined
+    /// via the [`Cache`] before performing it.
+    ///
+    /// [`Cache`]: crate::cache::Cache
+    MissingPermissions(Permissions),
+    /// When Discord rejects some of the commands passed to a bulk overwrite, such as
+    /// [`GuildId::set_commands`], or when commands break Discord's rules as checked by
+    /// [`CreateCommand::validate`]. None of the commands were registered.
+    ///
+    /// [`GuildId::set_commands`]: super::id::GuildId::set_commands
+    /// [`CreateCommand::validate`]: crate::builder::CreateCommand::validate
+    InvalidCommands(Vec<InvalidCommand>),
+    /// When the username a webhook message is sent with contains a substring which Discord
+    /// rejects, such as `"clyde"`. See [`sanitize_webhook_username`] for removing them.
+    ///
+    /// [`sanitize_webhook_username`]: crate::utils::sanitize_webhook_username
+    ForbiddenWebhookUsername(&'static str),
+    /// Indicates that a part of an embed is longer than Discord allows, as found by
+    /// [`CreateEmbed::validate`].
+    ///
+    /// [`CreateEmbed::validate`]: crate::builder::CreateEmbed::validate
+    EmbedTooLarge { field: EmbedPart, len: usize, max: usize },
 }
 
 impl Error {
@@ -251,6 +343,41 @@ impl fmt::Display for Error {
             Self::NoTokenSet => f.write_str("Token is not set."),
             Self::DeleteNitroSticker => f.write_str("Cannot delete an official sticker."),
             Self::CannotEditVoiceMessage => f.write_str("Cannot edit voice message."),
+            Self::PollNotAllowed => f.write_str("Cannot send a poll in this message."),
+            Self::AttachmentTooLarge {
+                size,
+                limit,
+            } => write!(f, "Attachment size of {size} bytes exceeds the limit of {limit} bytes."),
+            Self::AttachmentContentTypeMismatch {
+                declared,
+                detected,
+            } => write!(f, "Attachment declared as {declared} but contains {detected}."),
+            #[cfg(feature = "model")]
+            Self::MissingPermissions(permissions) => {
+                write!(f, "The current user lacks the {permissions} permissions.")
+            },
+            #[cfg(not(feature = "model"))]
+            Self::MissingPermissions(permissions) => {
+                write!(f, "The current user lacks the {permissions:?} permissions.")
+            },
+            Self::InvalidCommands(commands) => {
+                f.write_str("Invalid commands:")?;
+                for command in commands {
+                    write!(f, " `{}` at index {}", command.name, command.index)?;
+                    for (path, message) in &command.errors {
+                        write!(f, " ({path}: {message})")?;
+                    }
+                }
+                Ok(())
+            },
+            Self::EmbedTooLarge {
+                field,
+                len,
+                max,
+            } => write!(f, "{field} of length {len} exceeds the maximum of {max}."),
+            Self::ForbiddenWebhookUsername(forbidden) => {
+                write!(f, "Webhook usernames cannot contain \"{forbidden}\".")
+            },
         }
     }
 }
@@ -528,7 +528,7 @@ pub struct MessageUpdateEvent {
     pub interaction_metadata: Option<Option<Box<MessageInteractionMetadata>>>,
     #[serde(default, deserialize_with = "deserialize_some")]
     pub thread: Option<Option<Box<GuildChannel>>>,
-    pub components: Option<FixedArray<ActionRow>>,
+    pub components: Option<FixedArray<Component>>,
     pub sticker_items: Option<FixedArray<StickerItem>>,
     pub position: Option<Option<NonMaxU64>>,
     pub role_subscription_data: Option<Option<RoleSubscriptionData>>,
@@ -1078,7 +1078,8 @@ pub struct MessagePollVoteRemoveEvent {
 pub enum GatewayEvent {
     Dispatch {
         seq: u64,
-        // Avoid deserialising straight away to handle errors and get access to `seq`.
+        // Avoid deserialising straight away to handle errors and get access to `seq`. Left empty
+        // if the event is filtered out by the shard.
         data: JsonMap,
         // Used for debugging, if the data cannot be deserialised.
         original_str: FixedString,
@@ -1344,6 +1345,305 @@ impl Event {
     }
 }
 
+bitflags! {
+    /// A set of [`Event`] types, for example to only receive the events a bot handles via
+    /// [`ClientBuilder::filtered_events`].
+    ///
+    /// Each flag is named after the [`Event::name`] of its event.
+    ///
+    /// [`ClientBuilder::filtered_events`]: crate::gateway::client::ClientBuilder::filtered_events
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct EventTypeFlags: u128 {
+        /// [`Event::CommandPermissionsUpdate`]
+        const COMMAND_PERMISSIONS_UPDATE = 1 << 0;
+        /// [`Event::AutoModRuleCreate`]
+        const AUTO_MOD_RULE_CREATE = 1 << 1;
+        /// [`Event::AutoModRuleUpdate`]
+        const AUTO_MOD_RULE_UPDATE = 1 << 2;
+        /// [`Event::AutoModRuleDelete`]
+        const AUTO_MOD_RULE_DELETE = 1 << 3;
+        /// [`Event::AutoModActionExecution`]
+        const AUTO_MOD_ACTION_EXECUTION = 1 << 4;
+        /// [`Event::ChannelCreate`]
+        const CHANNEL_CREATE = 1 << 5;
+        /// [`Event::ChannelDelete`]
+        const CHANNEL_DELETE = 1 << 6;
+        /// [`Event::ChannelPinsUpdate`]
+        const CHANNEL_PINS_UPDATE = 1 << 7;
+        /// [`Event::ChannelUpdate`]
+        const CHANNEL_UPDATE = 1 << 8;
+        /// [`Event::GuildAuditLogEntryCreate`]
+        const GUILD_AUDIT_LOG_ENTRY_CREATE = 1 << 9;
+        /// [`Event::GuildBanAdd`]
+        const GUILD_BAN_ADD = 1 << 10;
+        /// [`Event::GuildBanRemove`]
+        const GUILD_BAN_REMOVE = 1 << 11;
+        /// [`Event::GuildCreate`]
+        const GUILD_CREATE = 1 << 12;
+        /// [`Event::GuildDelete`]
+        const GUILD_DELETE = 1 << 13;
+        /// [`Event::GuildEmojisUpdate`]
+        const GUILD_EMOJIS_UPDATE = 1 << 14;
+        /// [`Event::GuildIntegrationsUpdate`]
+        const GUILD_INTEGRATIONS_UPDATE = 1 << 15;
+        /// [`Event::GuildMemberAdd`]
+        const GUILD_MEMBER_ADD = 1 << 16;
+        /// [`Event::GuildMemberRemove`]
+        const GUILD_MEMBER_REMOVE = 1 << 17;
+        /// [`Event::GuildMemberUpdate`]
+        const GUILD_MEMBER_UPDATE = 1 << 18;
+        /// [`Event::GuildMembersChunk`]
+        const GUILD_MEMBERS_CHUNK = 1 << 19;
+        /// [`Event::GuildRoleCreate`]
+        const GUILD_ROLE_CREATE = 1 << 20;
+        /// [`Event::GuildRoleDelete`]
+        const GUILD_ROLE_DELETE = 1 << 21;
+        /// [`Event::GuildRoleUpdate`]
+        const GUILD_ROLE_UPDATE = 1 << 22;
+        /// [`Event::GuildStickersUpdate`]
+        const GUILD_STICKERS_UPDATE = 1 << 23;
+        /// [`Event::GuildUpdate`]
+        const GUILD_UPDATE = 1 << 24;
+        /// [`Event::InviteCreate`]
+        const INVITE_CREATE = 1 << 25;
+        /// [`Event::InviteDelete`]
+        const INVITE_DELETE = 1 << 26;
+        /// [`Event::MessageCreate`]
+        const MESSAGE_CREATE = 1 << 27;
+        /// [`Event::MessageDelete`]
+        const MESSAGE_DELETE = 1 << 28;
+        /// [`Event::MessageDeleteBulk`]
+        const MESSAGE_DELETE_BULK = 1 << 29;
+        /// [`Event::MessageUpdate`]
+        const MESSAGE_UPDATE = 1 << 30;
+        /// [`Event::PresenceUpdate`]
+        const PRESENCE_UPDATE = 1 << 31;
+        /// [`Event::ReactionAdd`]
+        const REACTION_ADD = 1 << 32;
+        /// [`Event::ReactionRemove`]
+        const REACTION_REMOVE = 1 << 33;
+        /// [`Event::ReactionRemoveAll`]
+        const REACTION_REMOVE_ALL = 1 << 34;
+        /// [`Event::ReactionRemoveEmoji`]
+        const REACTION_REMOVE_EMOJI = 1 << 35;
+        /// [`Event::Ready`]
+        const READY = 1 << 36;
+        /// [`Event::Resumed`]
+        const RESUMED = 1 << 37;
+        /// [`Event::TypingStart`]
+        const TYPING_START = 1 << 38;
+        /// [`Event::UserUpdate`]
+        const USER_UPDATE = 1 << 39;
+        /// [`Event::VoiceStateUpdate`]
+        const VOICE_STATE_UPDATE = 1 << 40;
+        /// [`Event::VoiceServerUpdate`]
+        const VOICE_SERVER_UPDATE = 1 << 41;
+        /// [`Event::VoiceChannelStatusUpdate`]
+        const VOICE_CHANNEL_STATUS_UPDATE = 1 << 42;
+        /// [`Event::WebhookUpdate`]
+        const WEBHOOK_UPDATE = 1 << 43;
+        /// [`Event::InteractionCreate`]
+        const INTERACTION_CREATE = 1 << 44;
+        /// [`Event::IntegrationCreate`]
+        const INTEGRATION_CREATE = 1 << 45;
+        /// [`Event::IntegrationUpdate`]
+        const INTEGRATION_UPDATE = 1 << 46;
+        /// [`Event::IntegrationDelete`]
+        const INTEGRATION_DELETE = 1 << 47;
+        /// [`Event::StageInstanceCreate`]
+        const STAGE_INSTANCE_CREATE = 1 << 48;
+        /// [`Event::StageInstanceUpdate`]
+        const STAGE_INSTANCE_UPDATE = 1 << 49;
+        /// [`Event::StageInstanceDelete`]
+        const STAGE_INSTANCE_DELETE = 1 << 50;
+        /// [`Event::ThreadCreate`]
+        const THREAD_CREATE = 1 << 51;
+        /// [`Event::ThreadUpdate`]
+        const THREAD_UPDATE = 1 << 52;
+        /// [`Event::ThreadDelete`]
+        const THREAD_DELETE = 1 << 53;
+        /// [`Event::ThreadListSync`]
+        const THREAD_LIST_SYNC = 1 << 54;
+        /// [`Event::ThreadMemberUpdate`]
+        const THREAD_MEMBER_UPDATE = 1 << 55;
+        /// [`Event::ThreadMembersUpdate`]
+        const THREAD_MEMBERS_UPDATE = 1 << 56;
+        /// [`Event::GuildScheduledEventCreate`]
+        const GUILD_SCHEDULED_EVENT_CREATE = 1 << 57;
+        /// [`Event::GuildScheduledEventUpdate`]
+        const GUILD_SCHEDULED_EVENT_UPDATE = 1 << 58;
+        /// [`Event::GuildScheduledEventDelete`]
+        const GUILD_SCHEDULED_EVENT_DELETE = 1 << 59;
+        /// [`Event::GuildScheduledEventUserAdd`]
+        const GUILD_SCHEDULED_EVENT_USER_ADD = 1 << 60;
+        /// [`Event::GuildScheduledEventUserRemove`]
+        const GUILD_SCHEDULED_EVENT_USER_REMOVE = 1 << 61;
+        /// [`Event::EntitlementCreate`]
+        const ENTITLEMENT_CREATE = 1 << 62;
+        /// [`Event::EntitlementUpdate`]
+        const ENTITLEMENT_UPDATE = 1 << 63;
+        /// [`Event::EntitlementDelete`]
+        const ENTITLEMENT_DELETE = 1 << 64;
+        /// [`Event::MessagePollVoteAdd`]
+        const MESSAGE_POLL_VOTE_ADD = 1 << 65;
+        /// [`Event::MessagePollVoteRemove`]
+        const MESSAGE_POLL_VOTE_REMOVE = 1 << 66;
+    }
+}
+
+impl EventTypeFlags {
+    /// Gets the flag of an event from the name Discord sends for it in dispatch payloads, such as
+    /// `MESSAGE_REACTION_ADD`. Returns [`None`] for events unknown to serenity.
+    #[must_use]
+    pub fn from_dispatch_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => Self::COMMAND_PERMISSIONS_UPDATE,
+            "AUTO_MODERATION_RULE_CREATE" => Self::AUTO_MOD_RULE_CREATE,
+            "AUTO_MODERATION_RULE_UPDATE" => Self::AUTO_MOD_RULE_UPDATE,
+            "AUTO_MODERATION_RULE_DELETE" => Self::AUTO_MOD_RULE_DELETE,
+            "AUTO_MODERATION_ACTION_EXECUTION" => Self::AUTO_MOD_ACTION_EXECUTION,
+            "CHANNEL_CREATE" => Self::CHANNEL_CREATE,
+            "CHANNEL_DELETE" => Self::CHANNEL_DELETE,
+            "CHANNEL_PINS_UPDATE" => Self::CHANNEL_PINS_UPDATE,
+            "CHANNEL_UPDATE" => Self::CHANNEL_UPDATE,
+            "GUILD_AUDIT_LOG_ENTRY_CREATE" => Self::GUILD_AUDIT_LOG_ENTRY_CREATE,
+            "GUILD_BAN_ADD" => Self::GUILD_BAN_ADD,
+            "GUILD_BAN_REMOVE" => Self::GUILD_BAN_REMOVE,
+            "GUILD_CREATE" => Self::GUILD_CREATE,
+            "GUILD_DELETE" => Self::GUILD_DELETE,
+            "GUILD_EMOJIS_UPDATE" => Self::GUILD_EMOJIS_UPDATE,
+            "GUILD_INTEGRATIONS_UPDATE" => Self::GUILD_INTEGRATIONS_UPDATE,
+            "GUILD_MEMBER_ADD" => Self::GUILD_MEMBER_ADD,
+            "GUILD_MEMBER_REMOVE" => Self::GUILD_MEMBER_REMOVE,
+            "GUILD_MEMBER_UPDATE" => Self::GUILD_MEMBER_UPDATE,
+            "GUILD_MEMBERS_CHUNK" => Self::GUILD_MEMBERS_CHUNK,
+            "GUILD_ROLE_CREATE" => Self::GUILD_ROLE_CREATE,
+            "GUILD_ROLE_DELETE" => Self::GUILD_ROLE_DELETE,
+            "GUILD_ROLE_UPDATE" => Self::GUILD_ROLE_UPDATE,
+            "GUILD_STICKERS_UPDATE" => Self::GUILD_STICKERS_UPDATE,
+            "GUILD_UPDATE" => Self::GUILD_UPDATE,
+            "INVITE_CREATE" => Self::INVITE_CREATE,
+            "INVITE_DELETE" => Self::INVITE_DELETE,
+            "MESSAGE_CREATE" => Self::MESSAGE_CREATE,
+            "MESSAGE_DELETE" => Self::MESSAGE_DELETE,
+            "MESSAGE_DELETE_BULK" => Self::MESSAGE_DELETE_BULK,
+            "MESSAGE_UPDATE" => Self::MESSAGE_UPDATE,
+            "PRESENCE_UPDATE" => Self::PRESENCE_UPDATE,
+            "MESSAGE_REACTION_ADD" => Self::REACTION_ADD,
+            "MESSAGE_REACTION_REMOVE" => Self::REACTION_REMOVE,
+            "MESSAGE_REACTION_REMOVE_ALL" => Self::REACTION_REMOVE_ALL,
+            "MESSAGE_REACTION_REMOVE_EMOJI" => Self::REACTION_REMOVE_EMOJI,
+            "READY" => Self::READY,
+            "RESUMED" => Self::RESUMED,
+            "TYPING_START" => Self::TYPING_START,
+            "USER_UPDATE" => Self::USER_UPDATE,
+            "VOICE_STATE_UPDATE" => Self::VOICE_STATE_UPDATE,
+            "VOICE_SERVER_UPDATE" => Self::VOICE_SERVER_UPDATE,
+            "VOICE_CHANNEL_STATUS_UPDATE" => Self::VOICE_CHANNEL_STATUS_UPDATE,
+            "WEBHOOKS_UPDATE" => Self::WEBHOOK_UPDATE,
+            "INTERACTION_CREATE" => Self::INTERACTION_CREATE,
+            "INTEGRATION_CREATE" => Self::INTEGRATION_CREATE,
+            "INTEGRATION_UPDATE" => Self::INTEGRATION_UPDATE,
+            "INTEGRATION_DELETE" => Self::INTEGRATION_DELETE,
+            "STAGE_INSTANCE_CREATE" => Self::STAGE_INSTANCE_CREATE,
+            "STAGE_INSTANCE_UPDATE" => Self::STAGE_INSTANCE_UPDATE,
+            "STAGE_INSTANCE_DELETE" => Self::STAGE_INSTANCE_DELETE,
+            "THREAD_CREATE" => Self::THREAD_CREATE,
+            "THREAD_UPDATE" => Self::THREAD_UPDATE,
+            "THREAD_DELETE" => Self::THREAD_DELETE,
+            "THREAD_LIST_SYNC" => Self::THREAD_LIST_SYNC,
+            "THREAD_MEMBER_UPDATE" => Self::THREAD_MEMBER_UPDATE,
+            "THREAD_MEMBERS_UPDATE" => Self::THREAD_MEMBERS_UPDATE,
+            "GUILD_SCHEDULED_EVENT_CREATE" => Self::GUILD_SCHEDULED_EVENT_CREATE,
+            "GUILD_SCHEDULED_EVENT_UPDATE" => Self::GUILD_SCHEDULED_EVENT_UPDATE,
+            "GUILD_SCHEDULED_EVENT_DELETE" => Self::GUILD_SCHEDULED_EVENT_DELETE,
+            "GUILD_SCHEDULED_EVENT_USER_ADD" => Self::GUILD_SCHEDULED_EVENT_USER_ADD,
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE" => Self::GUILD_SCHEDULED_EVENT_USER_REMOVE,
+            "ENTITLEMENT_CREATE" => Self::ENTITLEMENT_CREATE,
+            "ENTITLEMENT_UPDATE" => Self::ENTITLEMENT_UPDATE,
+            "ENTITLEMENT_DELETE" => Self::ENTITLEMENT_DELETE,
+            "MESSAGE_POLL_VOTE_ADD" => Self::MESSAGE_POLL_VOTE_ADD,
+            "MESSAGE_POLL_VOTE_REMOVE" => Self::MESSAGE_POLL_VOTE_REMOVE,
+            _ => return None,
+        })
+    }
+
+    /// Gets the flag of an event.
+    #[must_use]
+    pub fn from_event(event: &Event) -> Self {
+        match event {
+            Event::CommandPermissionsUpdate(_) => Self::COMMAND_PERMISSIONS_UPDATE,
+            Event::AutoModRuleCreate(_) => Self::AUTO_MOD_RULE_CREATE,
+            Event::AutoModRuleUpdate(_) => Self::AUTO_MOD_RULE_UPDATE,
+            Event::AutoModRuleDelete(_) => Self::AUTO_MOD_RULE_DELETE,
+            Event::AutoModActionExecution(_) => Self::AUTO_MOD_ACTION_EXECUTION,
+            Event::ChannelCreate(_) => Self::CHANNEL_CREATE,
+            Event::ChannelDelete(_) => Self::CHANNEL_DELETE,
+            Event::ChannelPinsUpdate(_) => Self::CHANNEL_PINS_UPDATE,
+            Event::ChannelUpdate(_) => Self::CHANNEL_UPDATE,
+            Event::GuildAuditLogEntryCreate(_) => Self::GUILD_AUDIT_LOG_ENTRY_CREATE,
+            Event::GuildBanAdd(_) => Self::GUILD_BAN_ADD,
+            Event::GuildBanRemove(_) => Self::GUILD_BAN_REMOVE,
+            Event::GuildCreate(_) => Self::GUILD_CREATE,
+            Event::GuildDelete(_) => Self::GUILD_DELETE,
+            Event::GuildEmojisUpdate(_) => Self::GUILD_EMOJIS_UPDATE,
+            Event::GuildIntegrationsUpdate(_) => Self::GUILD_INTEGRATIONS_UPDATE,
+            Event::GuildMemberAdd(_) => Self::GUILD_MEMBER_ADD,
+            Event::GuildMemberRemove(_) => Self::GUILD_MEMBER_REMOVE,
+            Event::GuildMemberUpdate(_) => Self::GUILD_MEMBER_UPDATE,
+            Event::GuildMembersChunk(_) => Self::GUILD_MEMBERS_CHUNK,
+            Event::GuildRoleCreate(_) => Self::GUILD_ROLE_CREATE,
+            Event::GuildRoleDelete(_) => Self::GUILD_ROLE_DELETE,
+            Event::GuildRoleUpdate(_) => Self::GUILD_ROLE_UPDATE,
+            Event::GuildStickersUpdate(_) => Self::GUILD_STICKERS_UPDATE,
+            Event::GuildUpdate(_) => Self::GUILD_UPDATE,
+            Event::InviteCreate(_) => Self::INVITE_CREATE,
+            Event::InviteDelete(_) => Self::INVITE_DELETE,
+            Event::MessageCreate(_) => Self::MESSAGE_CREATE,
+            Event::MessageDelete(_) => Self::MESSAGE_DELETE,
+            Event::MessageDeleteBulk(_) => Self::MESSAGE_DELETE_BULK,
+            Event::MessageUpdate(_) => Self::MESSAGE_UPDATE,
+            Event::PresenceUpdate(_) => Self::PRESENCE_UPDATE,
+            Event::ReactionAdd(_) => Self::REACTION_ADD,
+            Event::ReactionRemove(_) => Self::REACTION_REMOVE,
+            Event::ReactionRemoveAll(_) => Self::REACTION_REMOVE_ALL,
+            Event::ReactionRemoveEmoji(_) => Self::REACTION_REMOVE_EMOJI,
+            Event::Ready(_) => Self::READY,
+            Event::Resumed(_) => Self::RESUMED,
+            Event::TypingStart(_) => Self::TYPING_START,
+            Event::UserUpdate(_) => Self::USER_UPDATE,
+            Event::VoiceStateUpdate(_) => Self::VOICE_STATE_UPDATE,
+            Event::VoiceServerUpdate(_) => Self::VOICE_SERVER_UPDATE,
+            Event::VoiceChannelStatusUpdate(_) => Self::VOICE_CHANNEL_STATUS_UPDATE,
+            Event::WebhookUpdate(_) => Self::WEBHOOK_UPDATE,
+            Event::InteractionCreate(_) => Self::INTERACTION_CREATE,
+            Event::IntegrationCreate(_) => Self::INTEGRATION_CREATE,
+            Event::IntegrationUpdate(_) => Self::INTEGRATION_UPDATE,
+            Event::IntegrationDelete(_) => Self::INTEGRATION_DELETE,
+            Event::StageInstanceCreate(_) => Self::STAGE_INSTANCE_CREATE,
+            Event::StageInstanceUpdate(_) => Self::STAGE_INSTANCE_UPDATE,
+            Event::StageInstanceDelete(_) => Self::STAGE_INSTANCE_DELETE,
+            Event::ThreadCreate(_) => Self::THREAD_CREATE,
+            Event::ThreadUpdate(_) => Self::THREAD_UPDATE,
+            Event::ThreadDelete(_) => Self::THREAD_DELETE,
+            Event::ThreadListSync(_) => Self::THREAD_LIST_SYNC,
+            Event::ThreadMemberUpdate(_) => Self::THREAD_MEMBER_UPDATE,
+            Event::ThreadMembersUpdate(_) => Self::THREAD_MEMBERS_UPDATE,
+            Event::GuildScheduledEventCreate(_) => Self::GUILD_SCHEDULED_EVENT_CREATE,
+            Event::GuildScheduledEventUpdate(_) => Self::GUILD_SCHEDULED_EVENT_UPDATE,
+            Event::GuildScheduledEventDelete(_) => Self::GUILD_SCHEDULED_EVENT_DELETE,
+            Event::GuildScheduledEventUserAdd(_) => Self::GUILD_SCHEDULED_EVENT_USER_ADD,
+            Event::GuildScheduledEventUserRemove(_) => Self::GUILD_SCHEDULED_EVENT_USER_REMOVE,
+            Event::EntitlementCreate(_) => Self::ENTITLEMENT_CREATE,
+            Event::EntitlementUpdate(_) => Self::ENTITLEMENT_UPDATE,
+            Event::EntitlementDelete(_) => Self::ENTITLEMENT_DELETE,
+            Event::MessagePollVoteAdd(_) => Self::MESSAGE_POLL_VOTE_ADD,
+            Event::MessagePollVoteRemove(_) => Self::MESSAGE_POLL_VOTE_REMOVE,
+        }
+    }
+}
+
 fn filter_unknown_variant(json_err_dbg: &str) -> bool {
     if let Some(msg) = json_err_dbg.strip_prefix("Error(\"unknown variant `") {
         if let Some((variant_name, _)) = msg.split_once('`') {
@@ -1365,3 +1665,28 @@ fn log_deserialisation_err(json_str: &str, err: serde_json::Error) -> Error {
     debug!("Failing text: {json_str}");
     Error::Json(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn event_type_flags_from_dispatch_name() {
+        let payloads = [
+            ("TYPING_START", json!({"channel_id": "1", "user_id": "2", "timestamp": 0})),
+            ("CHANNEL_PINS_UPDATE", json!({"channel_id": "1"})),
+            ("MESSAGE_DELETE", json!({"id": "1", "channel_id": "2"})),
+            ("MESSAGE_REACTION_REMOVE_ALL", json!({"channel_id": "1", "message_id": "2"})),
+            ("WEBHOOKS_UPDATE", json!({"channel_id": "1", "guild_id": "2"})),
+        ];
+
+        for (name, data) in payloads {
+            let event: Event = serde_json::from_value(json!({"t": name, "d": data})).unwrap();
+            assert_eq!(EventTypeFlags::from_dispatch_name(name), Some(EventTypeFlags::from_event(&event)), "{name}");
+        }
+
+        assert_eq!(EventTypeFlags::from_dispatch_name("SOME_NEW_EVENT"), None);
+    }
+}
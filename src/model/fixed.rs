@@ -0,0 +1,172 @@
+//! Conversions into the fixed size strings and arrays used throughout the model.
+
+use std::borrow::Cow;
+
+use small_fixed_array::{
+    FixedArray,
+    FixedString,
+    InvalidLength,
+    InvalidStrLength,
+    TruncatingInto,
+    ValidLength,
+};
+
+/// Conversions from the common string types into a [`FixedString`].
+///
+/// This is implemented for [`&str`], [`String`], [`&String`], [`Box<str>`] and [`Cow<str>`], so
+/// that values can be handed to model constructors without spelling out
+/// [`FixedString::from_str_trunc`] or [`FixedString::from_string_trunc`] at every call site.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::small_fixed_array::FixedString;
+/// use serenity::model::fixed::IntoFixedString;
+///
+/// let name: FixedString<u8> = "ferris".into_fixed_trunc();
+/// assert_eq!(name.as_str(), "ferris");
+///
+/// let too_long = "a".repeat(300);
+/// assert!(too_long.try_into_fixed::<u8>().is_err());
+/// ```
+pub trait IntoFixedString: Sized {
+    /// Converts into a [`FixedString`], **truncating** to the nearest character boundary below
+    /// `LenT`'s maximum if the value does not fit.
+    fn into_fixed_trunc<LenT: ValidLength>(self) -> FixedString<LenT>;
+
+    /// Converts into a [`FixedString`] without any loss of data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidStrLength`] if the value is longer than `LenT`'s maximum. The original
+    /// string can be recovered with [`InvalidStrLength::get_inner`].
+    fn try_into_fixed<LenT: ValidLength>(self) -> Result<FixedString<LenT>, InvalidStrLength>;
+}
+
+impl IntoFixedString for &str {
+    fn into_fixed_trunc<LenT: ValidLength>(self) -> FixedString<LenT> {
+        FixedString::from_str_trunc(self)
+    }
+
+    fn try_into_fixed<LenT: ValidLength>(self) -> Result<FixedString<LenT>, InvalidStrLength> {
+        FixedString::try_from(Box::<str>::from(self))
+    }
+}
+
+impl IntoFixedString for &String {
+    fn into_fixed_trunc<LenT: ValidLength>(self) -> FixedString<LenT> {
+        self.as_str().into_fixed_trunc()
+    }
+
+    fn try_into_fixed<LenT: ValidLength>(self) -> Result<FixedString<LenT>, InvalidStrLength> {
+        self.as_str().try_into_fixed()
+    }
+}
+
+impl IntoFixedString for String {
+    fn into_fixed_trunc<LenT: ValidLength>(self) -> FixedString<LenT> {
+        self.trunc_into()
+    }
+
+    fn try_into_fixed<LenT: ValidLength>(self) -> Result<FixedString<LenT>, InvalidStrLength> {
+        FixedString::try_from(self)
+    }
+}
+
+impl IntoFixedString for Box<str> {
+    fn into_fixed_trunc<LenT: ValidLength>(self) -> FixedString<LenT> {
+        String::from(self).trunc_into()
+    }
+
+    fn try_into_fixed<LenT: ValidLength>(self) -> Result<FixedString<LenT>, InvalidStrLength> {
+        FixedString::try_from(self)
+    }
+}
+
+impl IntoFixedString for Cow<'_, str> {
+    fn into_fixed_trunc<LenT: ValidLength>(self) -> FixedString<LenT> {
+        match self {
+            Cow::Borrowed(val) => val.into_fixed_trunc(),
+            Cow::Owned(val) => val.into_fixed_trunc(),
+        }
+    }
+
+    fn try_into_fixed<LenT: ValidLength>(self) -> Result<FixedString<LenT>, InvalidStrLength> {
+        match self {
+            Cow::Borrowed(val) => val.try_into_fixed(),
+            Cow::Owned(val) => val.try_into_fixed(),
+        }
+    }
+}
+
+/// Collects the items into a [`FixedArray`], **dropping** any items past `LenT`'s maximum.
+///
+/// This accepts anything implementing [`IntoIterator`], so arrays, [`Vec`]s and iterator chains can
+/// all be turned into the fixed arrays used throughout the model.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::fixed::collect_fixed_trunc;
+/// use serenity::small_fixed_array::FixedArray;
+///
+/// let evens: FixedArray<u32, u8> = collect_fixed_trunc((0..10).filter(|n| n % 2 == 0));
+/// assert_eq!(evens.as_slice(), &[0, 2, 4, 6, 8]);
+/// ```
+pub fn collect_fixed_trunc<T, LenT: ValidLength>(
+    items: impl IntoIterator<Item = T>,
+) -> FixedArray<T, LenT> {
+    let max = LenT::MAX.to_usize();
+    items.into_iter().take(max).collect::<Vec<_>>().trunc_into()
+}
+
+/// Collects the items into a [`FixedArray`] without any loss of data.
+///
+/// # Errors
+///
+/// Returns [`InvalidLength`] if there are more items than `LenT`'s maximum. The collected items
+/// can be recovered with [`InvalidLength::get_inner`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::fixed::try_collect_fixed;
+///
+/// assert!(try_collect_fixed::<_, u8>(0..300).is_err());
+/// assert!(try_collect_fixed::<_, u16>(0..300).is_ok());
+/// ```
+pub fn try_collect_fixed<T, LenT: ValidLength>(
+    items: impl IntoIterator<Item = T>,
+) -> Result<FixedArray<T, LenT>, InvalidLength<T>> {
+    FixedArray::try_from(items.into_iter().collect::<Box<[_]>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_fixed_string() {
+        let short: FixedString<u8> = "short".into_fixed_trunc();
+        assert_eq!(short.as_str(), "short");
+
+        // Truncation must not split the multi-byte character straddling the limit.
+        let long = format!("{}é", "a".repeat(254));
+        let truncated: FixedString<u8> = long.as_str().into_fixed_trunc();
+        assert_eq!(truncated.as_str(), "a".repeat(254));
+
+        let err = long.clone().try_into_fixed::<u8>().unwrap_err();
+        assert_eq!(&*err.get_inner(), long);
+        assert!(Cow::Borrowed(long.as_str()).try_into_fixed::<u16>().is_ok());
+    }
+
+    #[test]
+    fn test_collect_fixed() {
+        let truncated: FixedArray<u16, u8> = collect_fixed_trunc(0..300);
+        assert_eq!(truncated.len(), u8::MAX);
+
+        let err = try_collect_fixed::<_, u8>(vec![0; 256]).unwrap_err();
+        assert_eq!(err.get_inner().len(), 256);
+        assert!(try_collect_fixed::<_, u8>([1, 2, 3]).is_ok());
+    }
+}
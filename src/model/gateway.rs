@@ -270,6 +270,7 @@ impl PresenceUser {
             public_flags: self.public_flags,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
             locale: None,
             email: self.email,
@@ -12,6 +12,7 @@ use crate::model::guild::{
     VerificationLevel,
 };
 use crate::model::id::{ApplicationId, ChannelId, GenericId, GuildId, RoleId, UserId};
+use crate::model::locale::Locale;
 use crate::model::misc::ImageHash;
 use crate::model::sticker::StickerFormatType;
 use crate::model::utils::StrOrInt;
@@ -221,7 +222,7 @@ generate_change! {
     /// Channel or role position was changed.
     "position" => Position(u32),
     /// Preferred locale of a guild was changed.
-    "preferred_locale" => PreferredLocale(FixedString),
+    "preferred_locale" => PreferredLocale(Locale),
     /// Privacy level of the stage instance was changed.
     "privacy_level" => PrivacyLevel(u64),
     /// Number of days after which inactive and role-unassigned members are kicked was changed.
@@ -55,6 +55,7 @@ pub struct Rule {
 #[non_exhaustive]
 pub enum EventType {
     MessageSend,
+    MemberUpdate,
     Unknown(u8),
 }
 
@@ -62,6 +63,7 @@ impl From<u8> for EventType {
     fn from(value: u8) -> Self {
         match value {
             1 => Self::MessageSend,
+            2 => Self::MemberUpdate,
             _ => Self::Unknown(value),
         }
     }
@@ -71,6 +73,7 @@ impl From<EventType> for u8 {
     fn from(value: EventType) -> Self {
         match value {
             EventType::MessageSend => 1,
+            EventType::MemberUpdate => 2,
             EventType::Unknown(unknown) => unknown,
         }
     }
@@ -108,6 +111,19 @@ pub enum Trigger {
     MentionSpam {
         /// Total number of unique role and user mentions allowed per message (Maximum of 50)
         mention_total_limit: u8,
+        /// Whether to automatically detect mention raids
+        mention_raid_protection_enabled: bool,
+    },
+    /// Checks the names and other profile information of members when they join or update their
+    /// profile. Can only be used with [`EventType::MemberUpdate`].
+    MemberProfile {
+        /// Substrings which will be searched for in the member profile (Maximum of 1000)
+        strings: Vec<String>,
+        /// Regular expression patterns which will be matched against the member profile (Maximum
+        /// of 10)
+        regex_patterns: Vec<String>,
+        /// Substrings which should not trigger the rule (Maximum of 1000)
+        allow_list: Vec<String>,
     },
     Unknown(u8),
 }
@@ -153,6 +169,24 @@ impl<'de> Deserialize<'de> for Trigger {
                     .metadata
                     .mention_total_limit
                     .ok_or_else(|| Error::missing_field("mention_total_limit"))?,
+                mention_raid_protection_enabled: trigger
+                    .metadata
+                    .mention_raid_protection_enabled
+                    .unwrap_or_default(),
+            },
+            TriggerType::MemberProfile => Self::MemberProfile {
+                strings: trigger
+                    .metadata
+                    .keyword_filter
+                    .ok_or_else(|| Error::missing_field("keyword_filter"))?,
+                regex_patterns: trigger
+                    .metadata
+                    .regex_patterns
+                    .ok_or_else(|| Error::missing_field("regex_patterns"))?,
+                allow_list: trigger
+                    .metadata
+                    .allow_list
+                    .ok_or_else(|| Error::missing_field("allow_list"))?,
             },
             TriggerType::Unknown(unknown) => Self::Unknown(unknown),
         };
@@ -170,6 +204,7 @@ impl Serialize for Trigger {
                 presets: None,
                 allow_list: None,
                 mention_total_limit: None,
+                mention_raid_protection_enabled: None,
             },
         };
         match self {
@@ -177,6 +212,11 @@ impl Serialize for Trigger {
                 strings,
                 regex_patterns,
                 allow_list,
+            }
+            | Self::MemberProfile {
+                strings,
+                regex_patterns,
+                allow_list,
             } => {
                 trigger.metadata.keyword_filter = Some(strings.clone());
                 trigger.metadata.regex_patterns = Some(regex_patterns.clone());
@@ -191,7 +231,12 @@ impl Serialize for Trigger {
             },
             Self::MentionSpam {
                 mention_total_limit,
-            } => trigger.metadata.mention_total_limit = Some(*mention_total_limit),
+                mention_raid_protection_enabled,
+            } => {
+                trigger.metadata.mention_total_limit = Some(*mention_total_limit);
+                trigger.metadata.mention_raid_protection_enabled =
+                    Some(*mention_raid_protection_enabled);
+            },
             Self::Spam | Self::Unknown(_) => {},
         }
         trigger.serialize(serializer)
@@ -212,6 +257,9 @@ impl Trigger {
             Self::MentionSpam {
                 ..
             } => TriggerType::MentionSpam,
+            Self::MemberProfile {
+                ..
+            } => TriggerType::MemberProfile,
             Self::Unknown(unknown) => TriggerType::Unknown(*unknown),
         }
     }
@@ -229,6 +277,7 @@ pub enum TriggerType {
     Spam,
     KeywordPreset,
     MentionSpam,
+    MemberProfile,
     Unknown(u8),
 }
 
@@ -239,6 +288,7 @@ impl From<u8> for TriggerType {
             3 => Self::Spam,
             4 => Self::KeywordPreset,
             5 => Self::MentionSpam,
+            6 => Self::MemberProfile,
             _ => Self::Unknown(value),
         }
     }
@@ -251,6 +301,7 @@ impl From<TriggerType> for u8 {
             TriggerType::Spam => 3,
             TriggerType::KeywordPreset => 4,
             TriggerType::MentionSpam => 5,
+            TriggerType::MemberProfile => 6,
             TriggerType::Unknown(unknown) => unknown,
         }
     }
@@ -278,6 +329,8 @@ pub struct TriggerMetadata {
     pub allow_list: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mention_total_limit: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_raid_protection_enabled: Option<bool>,
 }
 
 /// Internally pre-defined wordsets which will be searched for in content.
@@ -345,6 +398,13 @@ pub enum Action {
     /// [`Keyword`]: TriggerType::Keyword
     /// [Moderate Members]: crate::model::Permissions::MODERATE_MEMBERS
     Timeout(Duration),
+    /// Prevents the member from interacting with the guild, such as sending messages, until their
+    /// profile no longer triggers the rule.
+    ///
+    /// Can only be set up for [`MemberProfile`] rules.
+    ///
+    /// [`MemberProfile`]: TriggerType::MemberProfile
+    BlockMemberInteraction,
     Unknown(u8),
 }
 
@@ -438,6 +498,7 @@ impl<'de> Deserialize<'de> for Action {
                     .duration_seconds
                     .ok_or_else(|| Error::missing_field("duration_seconds"))?,
             )),
+            ActionType::BlockMemberInteraction => Action::BlockMemberInteraction,
             ActionType(unknown) => Action::Unknown(unknown),
         })
     }
@@ -469,6 +530,10 @@ impl Serialize for Action {
                     ..Default::default()
                 }),
             },
+            Action::BlockMemberInteraction => RawAction {
+                kind: ActionType::BlockMemberInteraction,
+                metadata: None,
+            },
             Action::Unknown(n) => RawAction {
                 kind: ActionType::Unknown(n),
                 metadata: None,
@@ -487,6 +552,7 @@ impl Action {
             } => ActionType::BlockMessage,
             Self::Alert(_) => ActionType::Alert,
             Self::Timeout(_) => ActionType::Timeout,
+            Self::BlockMemberInteraction => ActionType::BlockMemberInteraction,
             Self::Unknown(unknown) => ActionType::Unknown(*unknown),
         }
     }
@@ -502,6 +568,7 @@ enum_number! {
         BlockMessage = 1,
         Alert = 2,
         Timeout = 3,
+        BlockMemberInteraction = 4,
         _ => Unknown(u8),
     }
 }
@@ -557,9 +624,21 @@ mod tests {
             &Rule {
                 trigger: Trigger::MentionSpam {
                     mention_total_limit: 7,
+                    mention_raid_protection_enabled: true,
                 },
             },
-            json!({"trigger_type": 5, "trigger_metadata": {"mention_total_limit": 7}}),
+            json!({"trigger_type": 5, "trigger_metadata": {"mention_total_limit": 7, "mention_raid_protection_enabled": true}}),
+        );
+
+        assert_json(
+            &Rule {
+                trigger: Trigger::MemberProfile {
+                    strings: vec![String::from("discord.gg")],
+                    regex_patterns: vec![],
+                    allow_list: vec![],
+                },
+            },
+            json!({"trigger_type": 6, "trigger_metadata": {"keyword_filter": ["discord.gg"], "regex_patterns": [], "allow_list": []}}),
         );
 
         assert_json(
@@ -589,6 +668,8 @@ mod tests {
             json!({"type": 3, "metadata": {"duration_seconds": 1024}}),
         );
 
+        assert_json(&Action::BlockMemberInteraction, json!({"type": 4}));
+
         assert_json(&Action::Unknown(123), json!({"type": 123}));
     }
 }
@@ -1,6 +1,8 @@
 use std::fmt;
 
 use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::guild::{Guild, Member};
 use crate::model::id::{EmojiId, RoleId};
 use crate::model::user::User;
 use crate::model::utils::default_true;
@@ -65,6 +67,29 @@ impl Emoji {
         let extension = if self.animated() { "gif" } else { "png" };
         cdn!("/emojis/{}.{}", self.id, extension)
     }
+
+    /// Checks whether the member can use the emoji in the given guild. If this returns `false`, a
+    /// message containing the emoji will show its raw text instead.
+    ///
+    /// This is the case if the emoji belongs to the guild, is [available], and is either not
+    /// restricted to any roles or the member has one of its [roles]. The guild's copy of the emoji
+    /// is checked, as it is kept up to date by the cache.
+    ///
+    /// **Note**: Emojis from other guilds are always reported as unusable, as that depends on the
+    /// member's Nitro status and their permissions in the channel.
+    ///
+    /// [available]: Self::available
+    /// [roles]: Self::roles
+    #[must_use]
+    pub fn is_usable_by(&self, member: &Member, guild: &Guild) -> bool {
+        let Some(emoji) = guild.emojis.get(&self.id) else {
+            return false;
+        };
+
+        emoji.available()
+            && (emoji.roles.is_empty()
+                || emoji.roles.iter().any(|role| member.roles.contains(role)))
+    }
 }
 
 impl fmt::Display for Emoji {
@@ -1,9 +1,13 @@
 use std::fmt;
+#[cfg(feature = "model")]
+use std::time::Duration;
 
 #[cfg(feature = "model")]
 use futures::stream::Stream;
 use nonmax::{NonMaxU16, NonMaxU8};
 
+#[cfg(feature = "model")]
+use crate::builder::{overwrite_commands, overwrite_commands_partial};
 #[cfg(feature = "model")]
 use crate::builder::{
     AddMember,
@@ -22,11 +26,11 @@ use crate::builder::{
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::{Cache, GuildRef};
+use crate::cache::{Cache, GuildChannelRef, GuildRef, MemberRef};
 #[cfg(feature = "collector")]
 use crate::collector::{MessageCollector, ReactionCollector};
 #[cfg(feature = "collector")]
-use crate::gateway::ShardMessenger;
+use crate::gateway::{ChunkGuildFilter, ShardMessenger};
 #[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http, UserPagination};
 #[cfg(feature = "model")]
@@ -34,6 +38,20 @@ use crate::internal::prelude::*;
 use crate::model::error::Maximum;
 use crate::model::prelude::*;
 
+// Without the cache, nothing is ever cached, so plain references stand in for cache references.
+#[cfg(all(feature = "cache", feature = "model"))]
+type CachedGuild<'a> = GuildRef<'a>;
+#[cfg(all(not(feature = "cache"), feature = "model"))]
+type CachedGuild<'a> = &'a Guild;
+#[cfg(all(feature = "cache", feature = "model"))]
+type CachedMember<'a> = MemberRef<'a>;
+#[cfg(all(not(feature = "cache"), feature = "model"))]
+type CachedMember<'a> = &'a Member;
+#[cfg(all(feature = "cache", feature = "model"))]
+type CachedGuildChannel<'a> = GuildChannelRef<'a>;
+#[cfg(all(not(feature = "cache"), feature = "model"))]
+type CachedGuildChannel<'a> = &'a GuildChannel;
+
 #[cfg(feature = "model")]
 impl GuildId {
     /// Gets all auto moderation [`Rule`]s of this guild via HTTP.
@@ -236,6 +254,53 @@ impl GuildId {
         http.bulk_ban_users(self, &map, reason).await
     }
 
+    /// Bans an arbitrary number of users from the guild, by splitting them up into chunks of
+    /// [`BULK_BAN_LIMIT`] users and calling [`Self::bulk_ban`] for each chunk, waiting `delay`
+    /// between each request.
+    ///
+    /// The result of each chunk is yielded from the returned stream as soon as it is available,
+    /// allowing for progress to be reported. Errors do not stop the stream, so the remaining
+    /// chunks will still be attempted.
+    ///
+    /// **Note**: Requires the [Ban Members] and [Manage Guild] permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{GuildId, UserId};
+    /// # async fn run() {
+    /// # let guild_id = GuildId::new(1);
+    /// # let http: Http = unimplemented!();
+    /// # let raiders: Vec<UserId> = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut bans =
+    ///     guild_id.bulk_ban_iter(&http, &raiders, 0, Duration::from_secs(1), Some("Raid")).boxed();
+    /// while let Some(result) = bans.next().await {
+    ///     match result {
+    ///         Ok(response) => println!("Chunk banned: {response:?}"),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`BULK_BAN_LIMIT`]: crate::constants::BULK_BAN_LIMIT
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub fn bulk_ban_iter<'a>(
+        self,
+        http: &'a Http,
+        user_ids: &'a [UserId],
+        delete_message_seconds: u32,
+        delay: Duration,
+        reason: Option<&'a str>,
+    ) -> impl Stream<Item = Result<BulkBanResponse>> + 'a {
+        BulkBanIter::stream(http, self, user_ids, delete_message_seconds, delay, reason)
+    }
+
     /// Gets a list of the guild's bans, with additional options and filtering. See
     /// [`Http::get_bans`] for details.
     ///
@@ -276,6 +341,49 @@ impl GuildId {
         http.get_audit_logs(self, action_type, user_id, before, limit).await
     }
 
+    /// Streams over all of the guild's audit log entries, from most to least recent.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`Self::audit_logs`], following
+    /// the `before` cursor of each page. A buffer of at most `page_size` entries is used to reduce
+    /// the number of calls necessary. If `page_size` is [`None`], Discord's default of 50 entries
+    /// is used, otherwise it is clamped to Discord's range of 1 to 100 entries.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::new(1);
+    /// # let http: Http = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    /// use serenity::model::guild::audit_log::{Action, MemberAction};
+    ///
+    /// let mut entries = guild_id
+    ///     .audit_logs_iter(&http, Some(Action::Member(MemberAction::BanAdd)), None, None)
+    ///     .boxed();
+    /// while let Some(entry_result) = entries.next().await {
+    ///     match entry_result {
+    ///         Ok(entry) => println!("{:?} was banned by {:?}", entry.target_id, entry.user_id),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    pub fn audit_logs_iter(
+        self,
+        http: &Http,
+        action_type: Option<audit_log::Action>,
+        user_id: Option<UserId>,
+        page_size: Option<NonMaxU8>,
+    ) -> impl Stream<Item = Result<AuditLogEntry>> + '_ {
+        AuditLogsIter::stream(http, self, action_type, user_id, page_size)
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// # Errors
@@ -285,6 +393,53 @@ impl GuildId {
         http.get_channels(self).await
     }
 
+    /// Gets one of the guild's channels from the cache if the cache is enabled and the guild is
+    /// cached, otherwise requests it over the REST API.
+    ///
+    /// Threads are only fetched over the REST API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, GuildId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(1));
+    /// let channel = guild_id.to_channel(&http, channel_id).await?;
+    /// println!("Channel name: {}", channel.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel is not a guild channel, and
+    /// [`ModelError::ChannelNotFound`] if it is not in this guild.
+    ///
+    /// Returns [`Error::Http`] if the channel retrieval request failed.
+    pub async fn to_channel(
+        self,
+        cache_http: &impl CacheHttp,
+        channel_id: ChannelId,
+    ) -> Result<CachedOrFetched<CachedGuildChannel<'_>, GuildChannel>> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            if let Some(channel) = cache.guild_channel(self, channel_id) {
+                return Ok(CachedOrFetched::Cached(channel));
+            }
+        }
+
+        let channel = cache_http.http().get_channel(channel_id).await?;
+        let channel = channel.guild().ok_or(ModelError::InvalidChannelType)?;
+        if channel.guild_id != self {
+            return Err(Error::Model(ModelError::ChannelNotFound));
+        }
+
+        Ok(CachedOrFetched::Fetched(channel))
+    }
+
     /// Creates a [`GuildChannel`] in the the guild.
     ///
     /// Refer to [`Http::create_channel`] for more information.
@@ -876,7 +1031,7 @@ impl GuildId {
         self,
         http: &Http,
         builder: EditGuildWidget<'_>,
-    ) -> Result<GuildWidget> {
+    ) -> Result<GuildWidgetSettings> {
         builder.execute(http, self).await
     }
 
@@ -890,6 +1045,18 @@ impl GuildId {
         http.get_guild_roles(self).await
     }
 
+    /// Gets a single role of the guild over the REST API.
+    ///
+    /// To use the cache if possible, see [`RoleId::to_role`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the role does not exist, or the current user is not in the
+    /// guild.
+    pub async fn role(self, http: &Http, role_id: RoleId) -> Result<Role> {
+        http.get_guild_role(self, role_id).await
+    }
+
     /// Gets the default permission role (@everyone) from the guild.
     #[must_use]
     pub fn everyone_role(&self) -> RoleId {
@@ -902,6 +1069,43 @@ impl GuildId {
         cache.guild(self)
     }
 
+    /// Gets the [`Guild`] from the cache if the cache is enabled and the guild is cached,
+    /// otherwise requests a [`PartialGuild`] over the REST API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::prelude::*;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let guild_id = GuildId::new(1);
+    /// let name = match guild_id.to_guild(&http).await? {
+    ///     CachedOrFetched::Cached(guild) => guild.name.clone(),
+    ///     CachedOrFetched::Fetched(guild) => guild.name,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is not cached and the current user is not in it.
+    pub async fn to_guild(
+        self,
+        cache_http: &impl CacheHttp,
+    ) -> Result<CachedOrFetched<CachedGuild<'_>, PartialGuild>> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            if let Some(guild) = cache.guild(self) {
+                return Ok(CachedOrFetched::Cached(guild));
+            }
+        }
+
+        cache_http.http().get_guild(self).await.map(CachedOrFetched::Fetched)
+    }
+
     /// Requests [`PartialGuild`] over REST API.
     ///
     /// **Note**: This will not be a [`Guild`], as the REST API does not send
@@ -1041,7 +1245,7 @@ impl GuildId {
     /// Gets a user's [`Member`] for the guild by Id.
     ///
     /// If the cache feature is enabled the cache will be checked first. If not found it will
-    /// resort to an http request.
+    /// resort to an http request, storing the member in the `temp_cache` if enabled.
     ///
     /// # Errors
     ///
@@ -1051,15 +1255,45 @@ impl GuildId {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if let Some(guild) = cache.guild(self) {
-                    if let Some(member) = guild.members.get(&user_id) {
-                        return Ok(member.clone());
-                    }
+                if let Some(member) = cache.member(self, user_id) {
+                    return Ok(member.clone());
                 }
             }
         }
 
-        cache_http.http().get_member(self, user_id).await
+        let member = cache_http.http().get_member(self, user_id).await?;
+        #[cfg(feature = "temp_cache")]
+        cache_temp_member(&cache_http, &member);
+
+        Ok(member)
+    }
+
+    /// Gets a user's [`Member`] from the cache if the cache is enabled and the member is cached,
+    /// otherwise requests it over the REST API.
+    ///
+    /// Unlike [`Self::member`], this does not clone cached members.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the user is not in the guild, or if the guild is otherwise
+    /// unavailable.
+    pub async fn to_member(
+        self,
+        cache_http: &impl CacheHttp,
+        user_id: UserId,
+    ) -> Result<CachedOrFetched<CachedMember<'_>, Member>> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            if let Some(member) = cache.member(self, user_id) {
+                return Ok(CachedOrFetched::Cached(member));
+            }
+        }
+
+        let member = cache_http.http().get_member(self, user_id).await?;
+        #[cfg(feature = "temp_cache")]
+        cache_temp_member(cache_http, &member);
+
+        Ok(CachedOrFetched::Fetched(member))
     }
 
     /// Gets a list of the guild's members.
@@ -1300,6 +1534,45 @@ impl GuildId {
         http.get_scheduled_event_users(self, event_id, limit, target, with_member).await
     }
 
+    /// Streams over all interested users of the specified event, in ascending order of their Ids.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`Self::scheduled_event_users_optioned`], using the `after` cursor of each page. A buffer of
+    /// at most 100 users is used to reduce the number of calls necessary.
+    ///
+    /// **Note**: Requires the [View Channel] permission for the channel associated with the event.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use serenity::model::id::{GuildId, ScheduledEventId};
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::new(1);
+    /// # let event_id = ScheduledEventId::new(1);
+    /// # let http: Http = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut users = guild_id.scheduled_event_users_iter(&http, event_id, None).boxed();
+    /// while let Some(user_result) = users.next().await {
+    ///     match user_result {
+    ///         Ok(user) => println!("{} is interested", user.user.name),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub fn scheduled_event_users_iter(
+        self,
+        http: &Http,
+        event_id: ScheduledEventId,
+        with_member: Option<bool>,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> + '_ {
+        ScheduledEventUsersIter::stream(http, self, event_id, with_member)
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// This is just a shortcut for [`utils::shard_id`], the shard count should
@@ -1417,6 +1690,80 @@ impl GuildId {
         self.await_reaction(shard_messenger)
     }
 
+    /// Requests the guild's members over the gateway, and returns a stream over the batches of
+    /// members sent back in each [`GuildMembersChunkEvent`].
+    ///
+    /// This is an alternative to [`Self::members_iter`] which avoids the HTTP ratelimits. The
+    /// request is sent with a unique nonce, which is used to only collect the chunks belonging to
+    /// it. The stream ends once all chunks have been received.
+    ///
+    /// **Note**: Requesting all members with [`ChunkGuildFilter::None`] requires the
+    /// [`GatewayIntents::GUILD_MEMBERS`] intent. Other filters return at most 100 members.
+    ///
+    /// **Note**: If the shard disconnects before all chunks are received, the stream will not
+    /// end. Consider using [`StreamExt::take_until`] with a timeout.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::gateway::ShardMessenger;
+    /// #
+    /// # async fn run(shard_messenger: ShardMessenger) {
+    /// # let guild_id = GuildId::new(1);
+    /// use serenity::futures::StreamExt;
+    /// use serenity::gateway::ChunkGuildFilter;
+    ///
+    /// let mut chunks = guild_id.chunk_members(&shard_messenger, ChunkGuildFilter::None).boxed();
+    /// while let Some(members) = chunks.next().await {
+    ///     println!("Received {} members", members.len());
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`StreamExt::take_until`]: futures::StreamExt::take_until
+    #[cfg(feature = "collector")]
+    pub fn chunk_members(
+        self,
+        shard_messenger: &ShardMessenger,
+        filter: ChunkGuildFilter,
+    ) -> impl Stream<Item = Vec<Member>> {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        use futures::StreamExt as _;
+
+        static NONCE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let nonce = NONCE_COUNTER.fetch_add(1, Ordering::Relaxed).to_string();
+        let limit = match filter {
+            ChunkGuildFilter::None => None,
+            ChunkGuildFilter::Query(_) | ChunkGuildFilter::UserIds(_) => Some(100),
+        };
+
+        // The collector must be registered before sending the request, so no chunks are missed.
+        let expected_nonce = nonce.clone();
+        let chunks = crate::collector::collect(shard_messenger, move |event| match event {
+            Event::GuildMembersChunk(event)
+                if event.guild_id == self
+                    && event.nonce.as_deref() == Some(expected_nonce.as_str()) =>
+            {
+                Some((event.chunk_count, event.members.iter().cloned().collect::<Vec<_>>()))
+            },
+            _ => None,
+        });
+
+        shard_messenger.chunk_guild(self, limit, false, filter, Some(nonce));
+
+        futures::stream::unfold((chunks.boxed(), None), |(mut chunks, remaining)| async move {
+            if remaining == Some(0) {
+                return None;
+            }
+
+            let (chunk_count, members) = chunks.next().await?;
+            let remaining = remaining.unwrap_or(chunk_count).saturating_sub(1);
+            Some((members, (chunks, Some(remaining))))
+        })
+    }
+
     /// Create a guild specific application [`Command`].
     ///
     /// **Note**: Unlike global commands, guild commands will update instantly.
@@ -1432,13 +1779,32 @@ impl GuildId {
     ///
     /// # Errors
     ///
-    /// Returns the same errors as [`Self::create_command`].
+    /// Returns the same errors as [`Self::create_command`]. If Discord rejects some of the
+    /// commands, returns [`ModelError::InvalidCommands`] naming them.
     pub async fn set_commands(
         self,
         http: &Http,
         commands: &[CreateCommand<'_>],
     ) -> Result<Vec<Command>> {
-        http.create_guild_commands(self, &commands).await
+        overwrite_commands(http, Some(self), commands).await
+    }
+
+    /// Override all guild application commands, leaving out commands which Discord rejects
+    /// instead of failing the whole request.
+    ///
+    /// **Note**: If every command is rejected, all guild commands are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::set_commands`], except for
+    /// [`ModelError::InvalidCommands`], which is reported via [`CommandRegistration::invalid`]
+    /// instead.
+    pub async fn set_commands_partial(
+        self,
+        http: &Http,
+        commands: &[CreateCommand<'_>],
+    ) -> Result<CommandRegistration> {
+        overwrite_commands_partial(http, Some(self), commands).await
     }
 
     /// Overwrites permissions for a specific command.
@@ -1531,13 +1897,38 @@ impl GuildId {
 
     /// Get the guild welcome screen.
     ///
+    /// **Note**: Requires the [Manage Guild] permission if the welcome screen is not enabled.
+    ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the guild does not have a welcome screen.
+    /// Returns [`Error::Http`] if the guild does not have a welcome screen, or if the current user
+    /// lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
     pub async fn get_welcome_screen(self, http: &Http) -> Result<GuildWelcomeScreen> {
         http.get_guild_welcome_screen(self).await
     }
 
+    /// Gets the voice state of a user in the guild over the REST API, such as to check whether
+    /// they are in a voice channel without caching voice states.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the user is not connected to a voice channel in the guild.
+    pub async fn get_user_voice_state(self, http: &Http, user_id: UserId) -> Result<VoiceState> {
+        http.get_user_voice_state(self, user_id).await
+    }
+
+    /// Gets the current user's voice state in the guild over the REST API.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not connected to a voice channel in the
+    /// guild.
+    pub async fn get_current_user_voice_state(self, http: &Http) -> Result<VoiceState> {
+        http.get_current_user_voice_state(self).await
+    }
+
     /// Get the guild preview.
     ///
     /// **Note**: The bot need either to be part of the guild or the guild needs to have the
@@ -1550,15 +1941,39 @@ impl GuildId {
         http.get_guild_preview(self).await
     }
 
-    /// Get the guild widget.
+    /// Get the public data of the guild widget, such as its online members.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the bot does not have `MANAGE_MESSAGES` permission.
+    /// Returns [`Error::Http`] if the widget is disabled.
     pub async fn get_widget(self, http: &Http) -> Result<GuildWidget> {
         http.get_guild_widget(self).await
     }
 
+    /// Get the settings of the guild widget.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_widget_settings(self, http: &Http) -> Result<GuildWidgetSettings> {
+        http.get_guild_widget_settings(self).await
+    }
+
+    /// Get the PNG image of the guild widget in the given style.
+    ///
+    /// To embed the image instead, use [`Self::widget_image_url`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the widget is disabled.
+    pub async fn get_widget_image(self, http: &Http, style: GuildWidgetStyle) -> Result<Vec<u8>> {
+        http.get_guild_widget_image(self, style).await
+    }
+
     /// Get the widget image URL.
     #[must_use]
     pub fn widget_image_url(self, style: GuildWidgetStyle) -> String {
@@ -1576,6 +1991,18 @@ impl GuildId {
     }
 }
 
+/// Stores a member fetched over HTTP in the temporary cache, so repeated lookups are served from
+/// it until the TTL expires.
+#[cfg(feature = "temp_cache")]
+fn cache_temp_member(cache_http: &impl CacheHttp, member: &Member) {
+    use crate::cache::MaybeOwnedArc;
+
+    if let Some(cache) = cache_http.cache() {
+        let cached_member = MaybeOwnedArc::new(member.clone());
+        cache.temp_members.insert((member.guild_id, member.user.id), cached_member);
+    }
+}
+
 impl From<PartialGuild> for GuildId {
     /// Gets the Id of a partial guild.
     fn from(guild: PartialGuild) -> GuildId {
@@ -1733,6 +2160,246 @@ impl<'a> MembersIter<'a> {
     }
 }
 
+/// A helper class returned by [`GuildId::audit_logs_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct AuditLogsIter<'a> {
+    guild_id: GuildId,
+    http: &'a Http,
+    action_type: Option<audit_log::Action>,
+    user_id: Option<UserId>,
+    page_size: Option<NonMaxU8>,
+    buffer: Vec<AuditLogEntry>,
+    before: Option<AuditLogEntryId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<'a> AuditLogsIter<'a> {
+    fn new(
+        guild_id: GuildId,
+        http: &'a Http,
+        action_type: Option<audit_log::Action>,
+        user_id: Option<UserId>,
+        page_size: Option<NonMaxU8>,
+    ) -> Self {
+        Self {
+            guild_id,
+            http,
+            action_type,
+            user_id,
+            // Discord only accepts page sizes from 1 to 100
+            page_size: page_size.and_then(|size| NonMaxU8::new(size.get().clamp(1, 100))),
+            buffer: Vec::new(),
+            before: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of audit log entries.
+    ///
+    /// This drops any entries that were currently in the buffer, so it should only be called when
+    /// `self.buffer` is empty. Additionally, this updates `self.before` so that the next call does
+    /// not return duplicate items. If there are no more entries to be fetched, then this marks
+    /// `self.before` as None, indicating that no more calls ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        // Discord's default page size, if none was specified.
+        let grab_size = self.page_size.map_or(50, |size| size.get());
+
+        let audit_logs = self
+            .guild_id
+            .audit_logs(self.http, self.action_type, self.user_id, self.before, self.page_size)
+            .await?;
+        self.buffer = audit_logs.entries.into_vec();
+
+        // Get the last entry. If shorter than the page size, there are no more results anyway.
+        self.before = self.buffer.get(usize::from(grab_size) - 1).map(|entry| entry.id);
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all audit log entries of a guild, from most to least recent.
+    ///
+    /// See [`GuildId::audit_logs_iter`] for more details.
+    pub fn stream(
+        http: &'a Http,
+        guild_id: GuildId,
+        action_type: Option<audit_log::Action>,
+        user_id: Option<UserId>,
+        page_size: Option<NonMaxU8>,
+    ) -> impl Stream<Item = Result<AuditLogEntry>> + 'a {
+        let init_state = AuditLogsIter::new(guild_id, http, action_type, user_id, page_size);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.before.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
+/// A helper class returned by [`GuildId::scheduled_event_users_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct ScheduledEventUsersIter<'a> {
+    guild_id: GuildId,
+    http: &'a Http,
+    event_id: ScheduledEventId,
+    with_member: Option<bool>,
+    buffer: Vec<ScheduledEventUser>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<'a> ScheduledEventUsersIter<'a> {
+    fn new(
+        guild_id: GuildId,
+        http: &'a Http,
+        event_id: ScheduledEventId,
+        with_member: Option<bool>,
+    ) -> Self {
+        Self {
+            guild_id,
+            http,
+            event_id,
+            with_member,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of interested users.
+    ///
+    /// This drops any users that were currently in the buffer, so it should only be called when
+    /// `self.buffer` is empty. Additionally, this updates `self.after` so that the next call does
+    /// not return duplicate items. If there are no more users to be fetched, then this marks
+    /// `self.after` as None, indicating that no more calls ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        let grab_size = crate::constants::SCHEDULED_EVENT_USER_FETCH_LIMIT;
+
+        self.buffer = self
+            .guild_id
+            .scheduled_event_users_optioned(
+                self.http,
+                self.event_id,
+                Some(grab_size),
+                self.after.map(UserPagination::After),
+                self.with_member,
+            )
+            .await?;
+
+        // Get the last user. If shorter than 100, there are no more results anyway.
+        self.after = self.buffer.get(usize::from(grab_size.get()) - 1).map(|user| user.user.id);
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all interested users of a scheduled event.
+    ///
+    /// See [`GuildId::scheduled_event_users_iter`] for more details.
+    pub fn stream(
+        http: &'a Http,
+        guild_id: GuildId,
+        event_id: ScheduledEventId,
+        with_member: Option<bool>,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> + 'a {
+        let init_state = ScheduledEventUsersIter::new(guild_id, http, event_id, with_member);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
+/// A helper class returned by [`GuildId::bulk_ban_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct BulkBanIter<'a> {
+    guild_id: GuildId,
+    http: &'a Http,
+    chunks: std::slice::Chunks<'a, UserId>,
+    delete_message_seconds: u32,
+    delay: Duration,
+    reason: Option<&'a str>,
+    tried_ban: bool,
+}
+
+#[cfg(feature = "model")]
+impl<'a> BulkBanIter<'a> {
+    fn new(
+        guild_id: GuildId,
+        http: &'a Http,
+        user_ids: &'a [UserId],
+        delete_message_seconds: u32,
+        delay: Duration,
+        reason: Option<&'a str>,
+    ) -> Self {
+        Self {
+            guild_id,
+            http,
+            chunks: user_ids.chunks(crate::constants::BULK_BAN_LIMIT),
+            delete_message_seconds,
+            delay,
+            reason,
+            tried_ban: false,
+        }
+    }
+
+    /// Bans the next chunk of users, waiting for the configured delay first if this is not the
+    /// first chunk. Returns [`None`] once all chunks have been banned.
+    async fn ban_next(&mut self) -> Option<Result<BulkBanResponse>> {
+        let chunk = self.chunks.next()?;
+        if self.tried_ban {
+            tokio::time::sleep(self.delay).await;
+        }
+
+        self.tried_ban = true;
+        let guild_id = self.guild_id;
+        Some(guild_id.bulk_ban(self.http, chunk, self.delete_message_seconds, self.reason).await)
+    }
+
+    /// Streams over the results of banning the given users in chunks.
+    ///
+    /// See [`GuildId::bulk_ban_iter`] for more details.
+    pub fn stream(
+        http: &'a Http,
+        guild_id: GuildId,
+        user_ids: &'a [UserId],
+        delete_message_seconds: u32,
+        delay: Duration,
+        reason: Option<&'a str>,
+    ) -> impl Stream<Item = Result<BulkBanResponse>> + 'a {
+        let init_state =
+            BulkBanIter::new(guild_id, http, user_ids, delete_message_seconds, delay, reason);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            state.ban_next().await.map(|result| (result, state))
+        })
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub enum GuildWidgetStyle {
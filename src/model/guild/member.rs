@@ -1,16 +1,22 @@
+#[cfg(feature = "model")]
+use std::cmp::Ordering;
 #[cfg(feature = "cache")]
 use std::cmp::Reverse;
 use std::fmt;
+#[cfg(feature = "model")]
+use std::time::Duration;
 
 #[cfg(feature = "model")]
-use crate::builder::EditMember;
+use crate::builder::{EditMember, EditVoiceState};
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
-use crate::http::Http;
+use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 #[cfg(feature = "model")]
+use crate::model::user::current_user_id;
+#[cfg(feature = "model")]
 use crate::model::utils::avatar_url;
 
 /// Information about a member of a guild.
@@ -221,6 +227,51 @@ impl Member {
         }
     }
 
+    /// Returns `true` if the member joined the guild before the given time.
+    ///
+    /// Returns `false` if the join date is unknown.
+    #[must_use]
+    pub fn joined_before(&self, time: Timestamp) -> bool {
+        self.joined_at.is_some_and(|joined_at| joined_at < time)
+    }
+
+    /// Returns for how long the member has been boosting the guild, or [`None`] if they are not
+    /// boosting.
+    #[must_use]
+    pub fn boosting_duration(&self) -> Option<Duration> {
+        let since = self.premium_since?;
+        let secs = Timestamp::now().unix_timestamp() - since.unix_timestamp();
+        Some(Duration::from_secs(secs.try_into().unwrap_or(0)))
+    }
+
+    /// Compares members by the time they joined the guild, earliest first. Members whose join date
+    /// is unknown are ordered last, and ties are broken by user Id so the order is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::model::guild::Member;
+    /// # fn run(mut members: Vec<Member>) {
+    /// members.sort_by(Member::cmp_by_join);
+    /// let newest = members.iter().rev().take(10);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn cmp_by_join(&self, other: &Self) -> Ordering {
+        let key = |member: &Self| (member.joined_at.is_none(), member.joined_at, member.user.id);
+        key(self).cmp(&key(other))
+    }
+
+    /// Compares members by the time they started boosting the guild, longest boosting first.
+    /// Members who are not boosting are ordered last, and ties are broken by user Id so the order
+    /// is stable.
+    #[must_use]
+    pub fn cmp_by_boosting(&self, other: &Self) -> Ordering {
+        let key =
+            |member: &Self| (member.premium_since.is_none(), member.premium_since, member.user.id);
+        key(self).cmp(&key(other))
+    }
+
     /// Edits the member in place with the given data.
     ///
     /// See [`EditMember`] for the permission(s) required for separate builder methods, as well as
@@ -313,6 +364,78 @@ impl Member {
         self.guild_id.disconnect_member(http, self.user.id).await
     }
 
+    /// Moves the member to a stage channel, and if `speak` is set, invites them to speak by
+    /// unsuppressing them.
+    ///
+    /// Requires the [Move Members] permission, and the [Mute Members] permission if `speak` is
+    /// set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the member is not currently in a voice channel, if the channel
+    /// is not a stage channel, or if the current user lacks permission.
+    ///
+    /// [Move Members]: Permissions::MOVE_MEMBERS
+    /// [Mute Members]: Permissions::MUTE_MEMBERS
+    pub async fn move_to_stage(
+        &self,
+        http: &Http,
+        channel_id: ChannelId,
+        speak: bool,
+    ) -> Result<Member> {
+        let member = self.move_to_voice_channel(http, channel_id).await?;
+        if speak {
+            self.suppress(http, channel_id, false).await?;
+        }
+
+        Ok(member)
+    }
+
+    /// Suppresses the member in a stage channel, moving them to the audience. Passing `false`
+    /// instead invites them to speak.
+    ///
+    /// Requires the [Mute Members] permission, unless suppressing the current user.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the member is not in the given stage channel, or if the current
+    /// user lacks permission.
+    ///
+    /// [Mute Members]: Permissions::MUTE_MEMBERS
+    pub async fn suppress(&self, http: &Http, channel_id: ChannelId, suppress: bool) -> Result<()> {
+        let builder = EditVoiceState::new().suppress(suppress);
+        builder.execute(http, self.guild_id, channel_id, Some(self.user.id)).await
+    }
+
+    /// Requests to speak in a stage channel, setting the member's request to speak timestamp to
+    /// the current time. Passing `false` instead clears the request.
+    ///
+    /// Only the current user can request to speak, so the member must be the current user.
+    ///
+    /// Requires the [Request to Speak] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidUser`] if the member is not the current user.
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the given stage channel, or lacks
+    /// permission.
+    ///
+    /// [Request to Speak]: Permissions::REQUEST_TO_SPEAK
+    pub async fn request_to_speak(
+        &self,
+        cache_http: impl CacheHttp,
+        channel_id: ChannelId,
+        request: bool,
+    ) -> Result<()> {
+        if current_user_id(&cache_http).await? != self.user.id {
+            return Err(Error::Model(ModelError::InvalidUser));
+        }
+
+        let builder = EditVoiceState::new().request_to_speak(request);
+        builder.execute(cache_http.http(), self.guild_id, channel_id, None).await
+    }
+
     /// Returns the guild-level permissions for the member.
     ///
     /// # Examples
@@ -0,0 +1,267 @@
+use std::fmt;
+
+use super::Member;
+
+/// Scores how well a member's name matches a search query, used by
+/// [`Guild::search_members_cached`].
+///
+/// The default scorer is [`FuzzyScorer`]. [`PrefixScorer`] and [`SubstringScorer`] only match
+/// names which start with or contain the query.
+///
+/// [`Guild::search_members_cached`]: super::Guild::search_members_cached
+pub trait MemberSearchScorer: Send + Sync {
+    /// Scores the name against the query, where a higher score is a better match. Returns [`None`]
+    /// if the name doesn't match at all.
+    fn score(&self, query: &str, name: &str, case_sensitive: bool) -> Option<i64>;
+}
+
+/// Matches names starting with the query, preferring shorter names.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PrefixScorer;
+
+impl MemberSearchScorer for PrefixScorer {
+    fn score(&self, query: &str, name: &str, case_sensitive: bool) -> Option<i64> {
+        find(name, query, case_sensitive).filter(|position| *position == 0)?;
+        Some(-(name.len() as i64))
+    }
+}
+
+/// Matches names containing the query, preferring matches closer to the start of shorter names.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SubstringScorer;
+
+impl MemberSearchScorer for SubstringScorer {
+    fn score(&self, query: &str, name: &str, case_sensitive: bool) -> Option<i64> {
+        let position = find(name, query, case_sensitive)?;
+        Some(-((position + name.len()) as i64))
+    }
+}
+
+fn find(haystack: &str, needle: &str, case_sensitive: bool) -> Option<usize> {
+    if case_sensitive {
+        haystack.find(needle)
+    } else {
+        haystack.to_lowercase().find(&needle.to_lowercase())
+    }
+}
+
+/// Matches names containing the characters of the query in order, though not necessarily next to
+/// each other, similar to the matching of fuzzy finders such as skim and fzf.
+///
+/// Consecutive characters and characters at the start of words score higher, while gaps between
+/// matched characters are penalised. For example, the query "zla" matches "zeyla" and "Zey Lane",
+/// with the latter scoring higher.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FuzzyScorer;
+
+impl FuzzyScorer {
+    const SCORE_MATCH: i64 = 16;
+    const PENALTY_GAP_START: i64 = -3;
+    const PENALTY_GAP_EXTENSION: i64 = -1;
+    const BONUS_BOUNDARY: i64 = 8;
+    const BONUS_CAMEL_CASE: i64 = 7;
+    const BONUS_CONSECUTIVE: i64 = 4;
+    const BONUS_FIRST_CHAR_MULTIPLIER: i64 = 2;
+
+    fn bonus(previous: Option<char>, current: char) -> i64 {
+        match previous {
+            None => Self::BONUS_BOUNDARY,
+            Some(previous) if !previous.is_alphanumeric() && current.is_alphanumeric() => {
+                Self::BONUS_BOUNDARY
+            },
+            Some(previous) if previous.is_lowercase() && current.is_uppercase() => {
+                Self::BONUS_CAMEL_CASE
+            },
+            Some(previous) if !previous.is_numeric() && current.is_numeric() => {
+                Self::BONUS_CAMEL_CASE
+            },
+            Some(_) => 0,
+        }
+    }
+}
+
+impl MemberSearchScorer for FuzzyScorer {
+    fn score(&self, query: &str, name: &str, case_sensitive: bool) -> Option<i64> {
+        let eq =
+            |a: char, b: char| a == b || (!case_sensitive && a.to_lowercase().eq(b.to_lowercase()));
+
+        let query: Vec<char> = query.chars().collect();
+        let name: Vec<char> = name.chars().collect();
+        if query.is_empty() {
+            return Some(0);
+        }
+        if query.len() > name.len() {
+            return None;
+        }
+
+        let bonuses: Vec<i64> = name
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Self::bonus(i.checked_sub(1).map(|i| name[i]), *c))
+            .collect();
+
+        // `scores[j]` is the best score of the query so far, with its last character matched at
+        // `name[j]`.
+        let mut scores: Vec<Option<i64>> = name
+            .iter()
+            .zip(&bonuses)
+            .map(|(c, bonus)| {
+                eq(query[0], *c)
+                    .then_some(Self::SCORE_MATCH + bonus * Self::BONUS_FIRST_CHAR_MULTIPLIER)
+            })
+            .collect();
+
+        for q in &query[1..] {
+            let mut next = vec![None; name.len()];
+            // The best score of a previous match followed by a gap, ending right before `name[j]`.
+            let mut gap: Option<i64> = None;
+            for j in 1..name.len() {
+                if j >= 2 {
+                    let started = scores[j - 2].map(|score| score + Self::PENALTY_GAP_START);
+                    let extended = gap.map(|score| score + Self::PENALTY_GAP_EXTENSION);
+                    gap = started.max(extended);
+                }
+
+                if !eq(*q, name[j]) {
+                    continue;
+                }
+
+                let consecutive = scores[j - 1].map(|score| {
+                    score + Self::SCORE_MATCH + bonuses[j].max(Self::BONUS_CONSECUTIVE)
+                });
+                let after_gap = gap.map(|score| score + Self::SCORE_MATCH + bonuses[j]);
+                next[j] = consecutive.max(after_gap);
+            }
+
+            scores = next;
+        }
+
+        scores.into_iter().flatten().max()
+    }
+}
+
+/// Which names of a member are searched by [`Guild::search_members_cached`].
+///
+/// [`Guild::search_members_cached`]: super::Guild::search_members_cached
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MemberSearchField {
+    /// Both the username and the nickname, using whichever matches better.
+    #[default]
+    Any,
+    /// Only the username.
+    Username,
+    /// The nickname, or the username for members without one.
+    Nick,
+}
+
+/// Options for [`Guild::search_members_cached`].
+///
+/// By default, members are matched with a case insensitive [`FuzzyScorer`] against both their
+/// username and nickname, and all matches are returned.
+///
+/// [`Guild::search_members_cached`]: super::Guild::search_members_cached
+#[must_use]
+pub struct MemberSearchOptions {
+    pub(crate) scorer: Box<dyn MemberSearchScorer>,
+    pub(crate) field: MemberSearchField,
+    pub(crate) case_sensitive: bool,
+    pub(crate) limit: Option<usize>,
+    pub(crate) offset: usize,
+}
+
+impl MemberSearchOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the scorer used to match and rank names.
+    pub fn scorer(mut self, scorer: impl MemberSearchScorer + 'static) -> Self {
+        self.scorer = Box::new(scorer);
+        self
+    }
+
+    /// Sets which names of a member are searched.
+    pub fn field(mut self, field: MemberSearchField) -> Self {
+        self.field = field;
+        self
+    }
+
+    /// Sets whether names have to match the case of the query.
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Sets the maximum number of results to return.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets how many of the best results to skip, for paginating through results.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Scores a member against the query, using the best matching of the searched names.
+    pub(crate) fn score<'a>(&self, query: &str, member: &'a Member) -> Option<(&'a str, i64)> {
+        let username = member.user.name.as_str();
+        let nick = member.nick.as_deref();
+
+        let names = match self.field {
+            MemberSearchField::Any => [Some(username), nick],
+            MemberSearchField::Username => [Some(username), None],
+            MemberSearchField::Nick => [Some(nick.unwrap_or(username)), None],
+        };
+
+        names
+            .into_iter()
+            .flatten()
+            .filter_map(|name| {
+                let score = self.scorer.score(query, name, self.case_sensitive)?;
+                Some((name, score))
+            })
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+    }
+}
+
+impl Default for MemberSearchOptions {
+    fn default() -> Self {
+        Self {
+            scorer: Box::new(FuzzyScorer),
+            field: MemberSearchField::default(),
+            case_sensitive: false,
+            limit: None,
+            offset: 0,
+        }
+    }
+}
+
+impl fmt::Debug for MemberSearchOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemberSearchOptions")
+            .field("scorer", &"MemberSearchScorer")
+            .field("field", &self.field)
+            .field("case_sensitive", &self.case_sensitive)
+            .field("limit", &self.limit)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+/// A member found by [`Guild::search_members_cached`].
+///
+/// [`Guild::search_members_cached`]: super::Guild::search_members_cached
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct MemberSearchResult<'a> {
+    /// The matching member.
+    pub member: &'a Member,
+    /// The name of the member which matched the query.
+    pub name: &'a str,
+    /// The score of the match, as returned by the [`MemberSearchScorer`].
+    pub score: i64,
+}
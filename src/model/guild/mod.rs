@@ -7,7 +7,10 @@ mod guild_id;
 mod guild_preview;
 mod integration;
 mod member;
+mod member_search;
 mod partial_guild;
+#[cfg(feature = "model")]
+mod permission_report;
 mod premium_tier;
 mod role;
 mod scheduled_event;
@@ -16,6 +19,9 @@ mod welcome_screen;
 
 #[cfg(feature = "model")]
 use std::borrow::Cow;
+#[cfg(feature = "cache")]
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 
 use nonmax::{NonMaxU16, NonMaxU64, NonMaxU8};
 #[cfg(feature = "model")]
@@ -26,7 +32,10 @@ pub use self::guild_id::*;
 pub use self::guild_preview::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::member_search::*;
 pub use self::partial_guild::*;
+#[cfg(feature = "model")]
+pub use self::permission_report::*;
 pub use self::premium_tier::*;
 pub use self::role::*;
 pub use self::scheduled_event::*;
@@ -54,7 +63,7 @@ use crate::collector::{MessageCollector, ReactionCollector};
 #[cfg(doc)]
 use crate::constants::LARGE_THRESHOLD;
 #[cfg(feature = "collector")]
-use crate::gateway::ShardMessenger;
+use crate::gateway::{ChunkGuildFilter, ShardMessenger};
 #[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http, UserPagination};
 use crate::internal::prelude::*;
@@ -85,6 +94,20 @@ pub struct BulkBanResponse {
     failed_users: Vec<UserId>,
 }
 
+impl BulkBanResponse {
+    /// The users that were successfully banned.
+    #[must_use]
+    pub fn banned_users(&self) -> &[UserId] {
+        &self.banned_users
+    }
+
+    /// The users that were not successfully banned.
+    #[must_use]
+    pub fn failed_users(&self) -> &[UserId] {
+        &self.failed_users
+    }
+}
+
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct AfkMetadata {
@@ -211,7 +234,7 @@ pub struct Guild {
     pub premium_subscription_count: Option<NonMaxU64>,
     /// The preferred locale of this guild only set if guild has the "DISCOVERABLE" feature,
     /// defaults to en-US.
-    pub preferred_locale: FixedString,
+    pub preferred_locale: Locale,
     /// The id of the channel where admins and moderators of Community guilds receive notices from
     /// Discord.
     ///
@@ -252,6 +275,10 @@ pub struct Guild {
     pub member_count: u64,
     /// A mapping of [`User`]s to their current voice state.
     pub voice_states: ExtractMap<UserId, VoiceState>,
+    /// The users connected to each voice channel, maintained by the cache alongside
+    /// [`Self::voice_states`]. Empty for guilds which don't come from the cache.
+    #[serde(skip)]
+    pub(crate) voice_channel_users: HashMap<ChannelId, Vec<UserId>>,
     /// Users who are members of the guild.
     ///
     /// Members might not all be available when the [`ReadyEvent`] is received if the
@@ -275,6 +302,41 @@ pub struct Guild {
     pub scheduled_events: FixedArray<ScheduledEvent>,
 }
 
+#[cfg(feature = "cache")]
+impl Guild {
+    /// Rebuilds the index of users connected to each voice channel from [`Self::voice_states`].
+    pub(crate) fn index_voice_states(&mut self) {
+        self.voice_channel_users.clear();
+        for state in &self.voice_states {
+            if let Some(channel_id) = state.channel_id {
+                self.voice_channel_users.entry(channel_id).or_default().push(state.user_id);
+            }
+        }
+    }
+
+    /// Moves a user from their previous voice channel to another one in the index, or removes
+    /// them if they disconnected.
+    pub(crate) fn index_voice_state(
+        &mut self,
+        user_id: UserId,
+        old_channel_id: Option<ChannelId>,
+        channel_id: Option<ChannelId>,
+    ) {
+        if let Some(old_channel_id) = old_channel_id {
+            if let Entry::Occupied(mut users) = self.voice_channel_users.entry(old_channel_id) {
+                users.get_mut().retain(|id| *id != user_id);
+                if users.get().is_empty() {
+                    users.remove();
+                }
+            }
+        }
+
+        if let Some(channel_id) = channel_id {
+            self.voice_channel_users.entry(channel_id).or_default().push(user_id);
+        }
+    }
+}
+
 #[cfg(feature = "model")]
 impl Guild {
     /// Gets all auto moderation [`Rule`]s of this guild via HTTP.
@@ -654,7 +716,8 @@ impl Guild {
     ///
     /// # Errors
     ///
-    /// Returns the same errors as [`Self::create_command`].
+    /// Returns the same errors as [`Self::create_command`]. If Discord rejects some of the
+    /// commands, returns [`ModelError::InvalidCommands`] naming them.
     pub async fn set_commands(
         &self,
         http: &Http,
@@ -663,6 +726,22 @@ impl Guild {
         self.id.set_commands(http, commands).await
     }
 
+    /// Override all guild application commands, leaving out commands which Discord rejects
+    /// instead of failing the whole request.
+    ///
+    /// Refer to [`GuildId::set_commands_partial`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`GuildId::set_commands_partial`].
+    pub async fn set_commands_partial(
+        &self,
+        http: &Http,
+        commands: &[CreateCommand<'_>],
+    ) -> Result<CommandRegistration> {
+        self.id.set_commands_partial(http, commands).await
+    }
+
     /// Overwrites permissions for a specific command.
     ///
     /// **Note**: It will update instantly.
@@ -1202,6 +1281,20 @@ impl Guild {
         self.id.edit_welcome_screen(http, builder).await
     }
 
+    /// Gets the guild's welcome screen.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission if the welcome screen is not enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild does not have a welcome screen, or if the current user
+    /// lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_welcome_screen(&self, http: &Http) -> Result<GuildWelcomeScreen> {
+        self.id.get_welcome_screen(http).await
+    }
+
     /// Edits the guild's widget.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1215,7 +1308,7 @@ impl Guild {
         &self,
         http: &Http,
         builder: EditGuildWidget<'_>,
-    ) -> Result<GuildWidget> {
+    ) -> Result<GuildWidgetSettings> {
         self.id.edit_widget(http, builder).await
     }
 
@@ -1476,6 +1569,69 @@ impl Guild {
         self.id.members(http, limit, after).await
     }
 
+    /// Returns all members of the guild, sorted by the time they joined the guild, earliest first.
+    ///
+    /// See [`Member::cmp_by_join`] for how ties and unknown join dates are ordered.
+    #[must_use]
+    pub fn members_sorted_by_join(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.iter().collect();
+        members.sort_by(|a, b| a.cmp_by_join(b));
+        members
+    }
+
+    /// Returns all cached members boosting the guild, in no particular order.
+    pub fn boosters(&self) -> impl Iterator<Item = &Member> {
+        self.members.iter().filter(|member| member.premium_since.is_some())
+    }
+
+    /// Returns all members boosting the guild, sorted by how long they have been boosting,
+    /// longest first.
+    #[must_use]
+    pub fn boosters_sorted_by_duration(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.boosters().collect();
+        members.sort_by(|a, b| a.cmp_by_boosting(b));
+        members
+    }
+
+    /// Returns how many more boosts the guild needs to reach the next premium tier, or [`None`] if
+    /// it is at the highest tier.
+    #[must_use]
+    pub fn boosts_to_next_tier(&self) -> Option<u64> {
+        let required = self.premium_tier.next()?.boosts_required()?;
+        let boosts = self.premium_subscription_count.map_or(0, |count| count.get());
+        Some(required.saturating_sub(boosts))
+    }
+
+    /// Summarizes the boosting statistics of the guild, such as for a "server stats" command.
+    ///
+    /// Boosters are counted from the cached members, so may be incomplete for large guilds.
+    #[must_use]
+    pub fn boost_summary(&self) -> BoostSummary {
+        let mut boosters = 0;
+        let mut longest_booster: Option<(UserId, Timestamp)> = None;
+        let mut newest_booster: Option<(UserId, Timestamp)> = None;
+        for member in self.boosters() {
+            let Some(since) = member.premium_since else { continue };
+            boosters += 1;
+            if longest_booster.map_or(true, |(_, longest)| since < longest) {
+                longest_booster = Some((member.user.id, since));
+            }
+            if newest_booster.map_or(true, |(_, newest)| since > newest) {
+                newest_booster = Some((member.user.id, since));
+            }
+        }
+
+        BoostSummary {
+            tier: self.premium_tier,
+            boosts: self.premium_subscription_count.map_or(0, |count| count.get()),
+            boosters,
+            next_tier: self.premium_tier.next(),
+            boosts_to_next_tier: self.boosts_to_next_tier(),
+            longest_booster,
+            newest_booster,
+        }
+    }
+
     /// Gets a list of all the members (satisfying the status provided to the function) in this
     /// guild.
     pub fn members_with_status(&self, status: OnlineStatus) -> impl Iterator<Item = &Member> {
@@ -1484,6 +1640,37 @@ impl Guild {
         })
     }
 
+    /// Gets the voice channel a user is currently connected to.
+    #[must_use]
+    pub fn voice_channel_of(&self, user_id: UserId) -> Option<ChannelId> {
+        self.voice_states.get(&user_id)?.channel_id
+    }
+
+    /// Gets the members currently connected to a voice channel.
+    ///
+    /// For cached guilds this uses an index kept up to date from [`VoiceStateUpdateEvent`]s,
+    /// otherwise all voice states are scanned.
+    ///
+    /// **Note**: Users who are not in [`Self::members`] are left out.
+    #[must_use]
+    pub fn voice_channel_members(&self, channel_id: ChannelId) -> Vec<&Member> {
+        if self.voice_channel_users.is_empty() {
+            return self
+                .voice_states
+                .iter()
+                .filter(|state| state.channel_id == Some(channel_id))
+                .filter_map(|state| self.members.get(&state.user_id))
+                .collect();
+        }
+
+        self.voice_channel_users
+            .get(&channel_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|user_id| self.members.get(user_id))
+            .collect()
+    }
+
     /// Retrieves the first [`Member`] found that matches the name - with an optional discriminator
     /// - provided.
     ///
@@ -1518,202 +1705,79 @@ impl Guild {
         self.members.iter().find(|member| member.nick.as_deref().is_some_and(|nick| nick == name))
     }
 
-    /// Retrieves all [`Member`] that start with a given [`String`].
+    /// Searches the cached members by username or nickname, returning the best matches first.
     ///
-    /// `sorted` decides whether the best early match of the `prefix` should be the criteria to
-    /// sort the result.
+    /// By default, names are matched fuzzily, so the query "zla" finds "zeyla" and "Zey Lane". Use
+    /// [`MemberSearchOptions::scorer`] with a [`PrefixScorer`] or [`SubstringScorer`] to only find
+    /// names starting with or containing the query. Members with the same score are sorted by
+    /// the length of the matched name.
     ///
-    /// For the `prefix` "zey" and the unsorted result:
-    /// - "zeya", "zeyaa", "zeyla", "zeyzey", "zeyzeyzey"
-    ///
-    /// It would be sorted:
-    /// - "zeya", "zeyaa", "zeyla", "zeyzey", "zeyzeyzey"
-    ///
-    /// **Note**: This will only search members that are cached. If you want to search all members
-    /// in the guild via the Http API, use [`Self::search_members`].
-    #[must_use]
-    pub fn members_starting_with(
-        &self,
-        prefix: &str,
-        case_sensitive: bool,
-        sorted: bool,
-    ) -> Vec<(&Member, &str)> {
-        fn starts_with(name: &str, prefix: &str, case_sensitive: bool) -> bool {
-            if case_sensitive {
-                name.starts_with(prefix)
-            } else {
-                name.to_lowercase().starts_with(&prefix.to_lowercase())
-            }
-        }
-
-        let mut members = self
-            .members
-            .iter()
-            .filter_map(|member| {
-                let username = &member.user.name;
-
-                if starts_with(username, prefix, case_sensitive) {
-                    Some((member, username.as_str()))
-                } else {
-                    match &member.nick {
-                        Some(nick) => starts_with(nick, prefix, case_sensitive)
-                            .then(|| (member, nick.as_str())),
-                        None => None,
-                    }
-                }
-            })
-            .collect::<Vec<(&Member, &str)>>();
-
-        if sorted {
-            members.sort_by(|a, b| closest_to_origin(prefix, a.1, b.1));
-        }
-
-        members
-    }
-
-    /// Retrieves all [`Member`] containing a given [`String`] as either username or nick, with a
-    /// priority on username.
-    ///
-    /// If the substring is "yla", following results are possible:
-    /// - "zeyla", "meiyla", "yladenisyla"
-    ///
-    /// If 'case_sensitive' is false, the following are not found:
-    /// - "zeYLa", "meiyLa", "LYAdenislyA"
-    ///
-    /// `sorted` decides whether the best early match of the search-term should be the criteria to
-    /// sort the result. It will look at the account name first, if that does not fit the
-    /// search-criteria `substring`, the display-name will be considered.
-    ///
-    /// For the `substring` "zey" and the unsorted result:
-    /// - "azey", "zey", "zeyla", "zeylaa", "zeyzeyzey"
-    ///
-    /// It would be sorted:
-    /// - "zey", "azey", "zeyla", "zeylaa", "zeyzeyzey"
-    ///
-    /// **Note**: Due to two fields of a [`Member`] being candidates for the searched field,
-    /// setting `sorted` to `true` will result in an overhead, as both fields have to be considered
-    /// again for sorting.
-    ///
-    /// **Note**: This will only search members that are cached. If you want to search all members
-    /// in the guild via the Http API, use [`Self::search_members`].
-    #[must_use]
-    pub fn members_containing(
-        &self,
-        substring: &str,
-        case_sensitive: bool,
-        sorted: bool,
-    ) -> Vec<(&Member, String)> {
-        let mut members = self
-            .members
-            .iter()
-            .filter_map(|member| {
-                let username = &member.user.name;
-
-                if contains(username, substring, case_sensitive) {
-                    Some((member, username.clone().into()))
-                } else {
-                    match &member.nick {
-                        Some(nick) => contains(nick, substring, case_sensitive)
-                            .then(|| (member, nick.clone().into())),
-                        None => None,
-                    }
-                }
-            })
-            .collect::<Vec<(&Member, String)>>();
-
-        if sorted {
-            members.sort_by(|a, b| closest_to_origin(substring, &a.1[..], &b.1[..]));
-        }
-
-        members
-    }
-
-    /// Retrieves a tuple of [`Member`]s containing a given [`String`] in their username as the
-    /// first field and the name used for sorting as the second field.
-    ///
-    /// If the substring is "yla", following results are possible:
-    /// - "zeyla", "meiyla", "yladenisyla"
-    ///
-    /// If 'case_sensitive' is false, the following are not found:
-    /// - "zeYLa", "meiyLa", "LYAdenislyA"
-    ///
-    /// `sort` decides whether the best early match of the search-term should be the criteria to
-    /// sort the result.
-    ///
-    /// For the `substring` "zey" and the unsorted result:
-    /// - "azey", "zey", "zeyla", "zeylaa", "zeyzeyzey"
+    /// # Examples
     ///
-    /// It would be sorted:
-    /// - "zey", "azey", "zeyla", "zeylaa", "zeyzeyzey"
+    /// ```rust
+    /// # use serenity::model::prelude::*;
+    /// # fn run(guild: &Guild) {
+    /// let options = MemberSearchOptions::new().scorer(PrefixScorer).limit(10);
+    /// for result in guild.search_members_cached("zey", &options) {
+    ///     println!("{} matched as {}", result.member.user.id, result.name);
+    /// }
+    /// # }
+    /// ```
     ///
     /// **Note**: This will only search members that are cached. If you want to search all members
     /// in the guild via the Http API, use [`Self::search_members`].
     #[must_use]
-    pub fn members_username_containing(
+    pub fn search_members_cached(
         &self,
-        substring: &str,
-        case_sensitive: bool,
-        sorted: bool,
-    ) -> Vec<(&Member, String)> {
-        let mut members = self
+        query: &str,
+        options: &MemberSearchOptions,
+    ) -> Vec<MemberSearchResult<'_>> {
+        let mut results: Vec<_> = self
             .members
             .iter()
             .filter_map(|member| {
-                let name = &member.user.name;
-                contains(name, substring, case_sensitive).then(|| (member, name.clone().into()))
+                let (name, score) = options.score(query, member)?;
+                Some(MemberSearchResult {
+                    member,
+                    name,
+                    score,
+                })
             })
-            .collect::<Vec<(&Member, String)>>();
+            .collect();
 
-        if sorted {
-            members.sort_by(|a, b| closest_to_origin(substring, &a.1[..], &b.1[..]));
-        }
-
-        members
+        results.sort_by(|a, b| b.score.cmp(&a.score).then(a.name.len().cmp(&b.name.len())));
+        results.into_iter().skip(options.offset).take(options.limit.unwrap_or(usize::MAX)).collect()
     }
 
-    /// Retrieves all [`Member`] containing a given [`String`] in their nick.
-    ///
-    /// If the substring is "yla", following results are possible:
-    /// - "zeyla", "meiyla", "yladenisyla"
+    /// Generates a report of the effective permissions in each channel of the guild, per role and
+    /// for the members given in the options.
     ///
-    /// If 'case_sensitive' is false, the following are not found:
-    /// - "zeYLa", "meiyLa", "LYAdenislyA"
+    /// The report highlights overwrites which have no effect, such as allowing a permission a role
+    /// already has, and channels where @everyone has permissions it isn't expected to have, see
+    /// [`PermissionReportOptions::everyone_flagged`].
     ///
-    /// `sort` decides whether the best early match of the search-term should be the criteria to
-    /// sort the result.
+    /// Only cached channels and members are included, and threads are skipped, as they inherit the
+    /// permissions of their parent channel.
     ///
-    /// For the `substring` "zey" and the unsorted result:
-    /// - "azey", "zey", "zeyla", "zeylaa", "zeyzeyzey"
-    ///
-    /// It would be sorted:
-    /// - "zey", "azey", "zeyla", "zeylaa", "zeyzeyzey"
-    ///
-    /// **Note**: Instead of panicking, when sorting does not find a nick, the username will be
-    /// used (this should never happen).
+    /// # Examples
     ///
-    /// **Note**: This will only search members that are cached. If you want to search all members
-    /// in the guild via the Http API, use [`Self::search_members`].
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// # fn run(guild: &Guild) {
+    /// let report = guild.permission_report(&PermissionReportOptions::new());
+    /// for channel in report.issues() {
+    ///     if !channel.everyone_flagged.is_empty() {
+    ///         println!("@everyone can {:?} in <#{}>", channel.everyone_flagged, channel.channel_id);
+    ///     }
+    ///     for overwrite in &channel.redundant_overwrites {
+    ///         println!("Redundant overwrite for {:?} in <#{}>", overwrite.kind, channel.channel_id);
+    ///     }
+    /// }
+    /// # }
+    /// ```
     #[must_use]
-    pub fn members_nick_containing(
-        &self,
-        substring: &str,
-        case_sensitive: bool,
-        sorted: bool,
-    ) -> Vec<(&Member, String)> {
-        let mut members = self
-            .members
-            .iter()
-            .filter_map(|member| {
-                let nick = member.nick.as_ref().unwrap_or(&member.user.name);
-                contains(nick, substring, case_sensitive).then(|| (member, nick.clone().into()))
-            })
-            .collect::<Vec<(&Member, String)>>();
-
-        if sorted {
-            members.sort_by(|a, b| closest_to_origin(substring, &a.1[..], &b.1[..]));
-        }
-
-        members
+    pub fn permission_report(&self, options: &PermissionReportOptions) -> PermissionReport {
+        permission_report::generate(self, options)
     }
 
     /// Calculate a [`Member`]'s permissions in the guild.
@@ -2103,6 +2167,15 @@ impl Guild {
         self.id.webhooks(http).await
     }
 
+    /// Checks whether an emoji of this guild can currently be used, meaning it exists and has not
+    /// been made unavailable, for example because the guild lost boosts.
+    ///
+    /// Use [`Emoji::is_usable_by`] to also take role restrictions into account.
+    #[must_use]
+    pub fn emoji_available(&self, emoji_id: EmojiId) -> bool {
+        self.emojis.get(&emoji_id).is_some_and(Emoji::available)
+    }
+
     /// Obtain a reference to a role by its name.
     ///
     /// **Note**: If two or more roles have the same name, obtained reference will be one of them.
@@ -2161,6 +2234,19 @@ impl Guild {
         self.await_reaction(shard_messenger)
     }
 
+    /// Requests the guild's members over the gateway, and returns a stream over the batches of
+    /// members received.
+    ///
+    /// Refer to [`GuildId::chunk_members`] for more information.
+    #[cfg(feature = "collector")]
+    pub fn chunk_members(
+        &self,
+        shard_messenger: &ShardMessenger,
+        filter: ChunkGuildFilter,
+    ) -> impl futures::Stream<Item = Vec<Member>> {
+        self.id.chunk_members(shard_messenger, filter)
+    }
+
     /// Gets the guild active threads.
     ///
     /// # Errors
@@ -2258,44 +2344,70 @@ fn calculate_permissions(data: CalculatePermissions) -> Permissions {
     permissions
 }
 
-/// Checks if a `&str` contains another `&str`.
-#[cfg(feature = "model")]
-fn contains(haystack: &str, needle: &str, case_sensitive: bool) -> bool {
-    if case_sensitive {
-        haystack.contains(needle)
-    } else {
-        haystack.to_lowercase().contains(&needle.to_lowercase())
-    }
+/// The settings of a [`Guild`]'s widget.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-settings-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetSettings {
+    /// Whether the widget is enabled.
+    pub enabled: bool,
+    /// The widget channel id.
+    pub channel_id: Option<ChannelId>,
 }
 
-/// Takes a `&str` as `origin` and tests if either `word_a` or `word_b` is closer.
+/// The public data of a [`Guild`]'s widget, available when the widget is enabled.
 ///
-/// **Note**: Normally `word_a` and `word_b` are expected to contain `origin` as substring. If not,
-/// using `closest_to_origin` would sort these the end.
-#[cfg(feature = "model")]
-fn closest_to_origin(origin: &str, word_a: &str, word_b: &str) -> std::cmp::Ordering {
-    let value_a = match word_a.find(origin) {
-        Some(value) => value + word_a.len(),
-        None => return std::cmp::Ordering::Greater,
-    };
-
-    let value_b = match word_b.find(origin) {
-        Some(value) => value + word_b.len(),
-        None => return std::cmp::Ordering::Less,
-    };
-
-    value_a.cmp(&value_b)
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidget {
+    /// The Id of the guild.
+    pub id: GuildId,
+    /// The name of the guild.
+    pub name: FixedString,
+    /// The invite URL to the widget channel, if one is set.
+    pub instant_invite: Option<FixedString>,
+    /// The voice channels which are visible to @everyone.
+    pub channels: FixedArray<GuildWidgetChannel>,
+    /// Up to 100 online members, with anonymized Ids.
+    pub members: FixedArray<GuildWidgetMember>,
+    /// The number of online members in the guild.
+    pub presence_count: u64,
 }
 
-/// A [`Guild`] widget.
+/// A voice channel in a [`GuildWidget`].
 ///
-/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-settings-object).
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object-example-guild-widget).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
-pub struct GuildWidget {
-    /// Whether the widget is enabled.
-    pub enabled: bool,
-    /// The widget channel id.
+pub struct GuildWidgetChannel {
+    /// The Id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel.
+    pub name: FixedString,
+    /// The position of the channel.
+    pub position: u16,
+}
+
+/// An online member in a [`GuildWidget`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object-example-guild-widget).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetMember {
+    /// The anonymized Id of the member, which is only unique within the widget.
+    pub id: FixedString,
+    /// The username of the member, or nickname if they have one.
+    pub username: FixedString,
+    /// The online status of the member.
+    pub status: OnlineStatus,
+    /// The URL of the member's avatar.
+    pub avatar_url: FixedString,
+    /// The voice channel the member is connected to, if it's in [`GuildWidget::channels`].
     pub channel_id: Option<ChannelId>,
 }
 
@@ -2482,6 +2594,31 @@ enum_number! {
 
 #[cfg(test)]
 mod test {
+    #[test]
+    fn guild_widget() {
+        let widget: super::GuildWidget = serde_json::from_value(serde_json::json!({
+            "id": "290926798626357999",
+            "name": "Test Server",
+            "instant_invite": "https://discord.com/invite/abcdefg",
+            "channels": [{"id": "705216630279993882", "name": "elephant", "position": 2}],
+            "members": [{
+                "id": "0",
+                "username": "1234",
+                "discriminator": "0000",
+                "avatar": null,
+                "status": "online",
+                "avatar_url": "https://cdn.discordapp.com/widget-avatars/FfvURgcr3Za92K3JtoCppqnYMppMDc5B-Rll74YrGCU/C-1DyBZPQ6t5q2RuATFuMFgq0_uEMZVzd_6LbHosNbHxNr_f2mKUNh9pBHF0ocXAHlm2PcR1kuK8wpRvsiLkWTa1otdUPVXd-tJQPt5g4c-ecEF6UhoXYzYuGqmilmN7hKmHdCx9CDsYyw",
+                "channel_id": null,
+            }],
+            "presence_count": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(widget.channels[0].name.as_str(), "elephant");
+        assert_eq!(widget.members[0].status, super::OnlineStatus::Online);
+        assert_eq!(widget.presence_count, 1);
+    }
+
     #[cfg(feature = "model")]
     mod model {
         use std::num::NonZeroU16;
@@ -2524,5 +2661,205 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        #[test]
+        fn search_members_cached() {
+            let member = |id, name: &'static str, nick: Option<&'static str>| Member {
+                user: User {
+                    id: UserId::new(id),
+                    name: FixedString::from_static_trunc(name),
+                    ..User::default()
+                },
+                nick: nick.map(FixedString::from_static_trunc),
+                ..Default::default()
+            };
+            let guild = Guild {
+                members: ExtractMap::from_iter([
+                    member(1, "zeyla", None),
+                    member(2, "someone", Some("Zey Lane")),
+                    member(3, "azeyzey", None),
+                    member(4, "other", None),
+                ]),
+                ..Default::default()
+            };
+            let search = |query, options| {
+                guild
+                    .search_members_cached(query, &options)
+                    .into_iter()
+                    .map(|result| result.member.user.id.get())
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(search("zla", MemberSearchOptions::new()), vec![2, 1]);
+            assert_eq!(search("zla", MemberSearchOptions::new().case_sensitive(true)), vec![1]);
+            assert_eq!(
+                search("zla", MemberSearchOptions::new().field(MemberSearchField::Username)),
+                vec![1]
+            );
+            assert_eq!(search("zey", MemberSearchOptions::new().scorer(PrefixScorer)), vec![1, 2]);
+            assert_eq!(search("zey", MemberSearchOptions::new().scorer(SubstringScorer)), vec![
+                1, 3, 2
+            ]);
+            assert_eq!(
+                search(
+                    "zey",
+                    MemberSearchOptions::new().scorer(SubstringScorer).offset(1).limit(1)
+                ),
+                vec![3]
+            );
+        }
+
+        #[test]
+        fn permission_report() {
+            let role = |id, permissions| Role {
+                id: RoleId::new(id),
+                permissions,
+                position: id as i16,
+                ..Default::default()
+            };
+            let overwrite = |kind, allow, deny| PermissionOverwrite {
+                allow,
+                deny,
+                kind,
+            };
+            let everyone = PermissionOverwriteType::Role(RoleId::new(1));
+            let moderator = PermissionOverwriteType::Role(RoleId::new(2));
+            let mut member = gen_member();
+            member.user.id = UserId::new(10);
+            member.roles = FixedArray::from_vec_trunc(vec![RoleId::new(2)]);
+
+            let guild = Guild {
+                id: GuildId::new(1),
+                roles: ExtractMap::from_iter([
+                    role(1, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES),
+                    role(2, Permissions::MANAGE_MESSAGES),
+                ]),
+                channels: ExtractMap::from_iter([GuildChannel {
+                    id: ChannelId::new(3),
+                    permission_overwrites: FixedArray::from_vec_trunc(vec![
+                        overwrite(everyone, Permissions::MENTION_EVERYONE, Permissions::empty()),
+                        overwrite(
+                            moderator,
+                            Permissions::MANAGE_MESSAGES,
+                            Permissions::BAN_MEMBERS,
+                        ),
+                        overwrite(
+                            PermissionOverwriteType::Member(member.user.id),
+                            Permissions::SEND_MESSAGES,
+                            Permissions::empty(),
+                        ),
+                    ]),
+                    ..Default::default()
+                }]),
+                members: ExtractMap::from_iter([member]),
+                ..Default::default()
+            };
+
+            let report =
+                guild.permission_report(&PermissionReportOptions::new().members([UserId::new(10)]));
+            let channel = &report.channels[0];
+            let everyone_permissions = Permissions::VIEW_CHANNEL
+                | Permissions::SEND_MESSAGES
+                | Permissions::MENTION_EVERYONE;
+            assert_eq!(channel.roles, vec![
+                (RoleId::new(2), everyone_permissions | Permissions::MANAGE_MESSAGES),
+                (RoleId::new(1), everyone_permissions),
+            ]);
+            assert_eq!(channel.members, vec![(
+                UserId::new(10),
+                everyone_permissions | Permissions::MANAGE_MESSAGES
+            )]);
+            assert_eq!(channel.everyone_flagged, Permissions::MENTION_EVERYONE);
+
+            let redundant: Vec<_> = channel
+                .redundant_overwrites
+                .iter()
+                .map(|overwrite| (overwrite.kind, overwrite.allow, overwrite.deny, overwrite.no_op))
+                .collect();
+            assert_eq!(redundant, vec![
+                (moderator, Permissions::MANAGE_MESSAGES, Permissions::BAN_MEMBERS, true),
+                (
+                    PermissionOverwriteType::Member(UserId::new(10)),
+                    Permissions::SEND_MESSAGES,
+                    Permissions::empty(),
+                    true
+                ),
+            ]);
+            assert_eq!(report.issues().count(), 1);
+        }
+
+        #[test]
+        fn emoji_usable_by() {
+            let emoji = |id: u64, available: bool, roles: &[u64]| -> Emoji {
+                serde_json::from_value(serde_json::json!({
+                    "id": id.to_string(),
+                    "name": "emoji",
+                    "available": available,
+                    "roles": roles.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                }))
+                .unwrap()
+            };
+
+            let mut member = gen_member();
+            member.roles = FixedArray::from_vec_trunc(vec![RoleId::new(10)]);
+            let guild = Guild {
+                emojis: ExtractMap::from_iter([
+                    emoji(1, true, &[]),
+                    emoji(2, false, &[]),
+                    emoji(3, true, &[10]),
+                    emoji(4, true, &[20]),
+                ]),
+                ..Default::default()
+            };
+
+            assert!(guild.emoji_available(EmojiId::new(1)));
+            assert!(!guild.emoji_available(EmojiId::new(2)));
+            assert!(!guild.emoji_available(EmojiId::new(5)));
+
+            assert!(emoji(1, true, &[]).is_usable_by(&member, &guild));
+            assert!(!emoji(2, true, &[]).is_usable_by(&member, &guild));
+            assert!(emoji(3, true, &[]).is_usable_by(&member, &guild));
+            assert!(!emoji(4, true, &[]).is_usable_by(&member, &guild));
+            assert!(!emoji(5, true, &[]).is_usable_by(&member, &guild));
+        }
+
+        #[test]
+        fn boost_summary() {
+            let booster = |id, since: Option<i64>| Member {
+                user: User {
+                    id: UserId::new(id),
+                    ..User::default()
+                },
+                premium_since: since.map(|since| Timestamp::from_unix_timestamp(since).unwrap()),
+                ..Default::default()
+            };
+            let guild = Guild {
+                members: ExtractMap::from_iter([
+                    booster(1, Some(200)),
+                    booster(2, None),
+                    booster(3, Some(100)),
+                    booster(4, Some(300)),
+                ]),
+                premium_tier: PremiumTier::Tier1,
+                premium_subscription_count: nonmax::NonMaxU64::new(4),
+                ..Default::default()
+            };
+
+            assert_eq!(guild.boosters().count(), 3);
+            assert_eq!(guild.boosts_to_next_tier(), Some(3));
+
+            let summary = guild.boost_summary();
+            assert_eq!(summary.boosts, 4);
+            assert_eq!(summary.boosters, 3);
+            assert_eq!(summary.next_tier, Some(PremiumTier::Tier2));
+            assert_eq!(summary.longest_booster.map(|(id, _)| id), Some(UserId::new(3)));
+            assert_eq!(summary.newest_booster.map(|(id, _)| id), Some(UserId::new(4)));
+
+            let guild = Guild {
+                premium_tier: PremiumTier::Tier3,
+                ..guild
+            };
+            assert_eq!(guild.boosts_to_next_tier(), None);
+        }
     }
 }
@@ -146,7 +146,7 @@ pub struct PartialGuild {
     pub premium_subscription_count: Option<NonMaxU64>,
     /// The preferred locale of this guild only set if guild has the "DISCOVERABLE" feature,
     /// defaults to en-US.
-    pub preferred_locale: FixedString,
+    pub preferred_locale: Locale,
     /// The id of the channel where admins and moderators of Community guilds receive notices from
     /// Discord.
     ///
@@ -449,7 +449,8 @@ impl PartialGuild {
     ///
     /// # Errors
     ///
-    /// Returns the same errors as [`Self::create_command`].
+    /// Returns the same errors as [`Self::create_command`]. If Discord rejects some of the
+    /// commands, returns [`ModelError::InvalidCommands`] naming them.
     pub async fn set_commands(
         &self,
         http: &Http,
@@ -458,6 +459,22 @@ impl PartialGuild {
         self.id.set_commands(http, commands).await
     }
 
+    /// Override all guild application commands, leaving out commands which Discord rejects
+    /// instead of failing the whole request.
+    ///
+    /// Refer to [`GuildId::set_commands_partial`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`GuildId::set_commands_partial`].
+    pub async fn set_commands_partial(
+        &self,
+        http: &Http,
+        commands: &[CreateCommand<'_>],
+    ) -> Result<CommandRegistration> {
+        self.id.set_commands_partial(http, commands).await
+    }
+
     /// Overwrites permissions for a specific command.
     ///
     /// **Note**: It will update instantly.
@@ -907,6 +924,20 @@ impl PartialGuild {
         self.id.edit_welcome_screen(http, builder).await
     }
 
+    /// Gets the guild's welcome screen.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission if the welcome screen is not enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild does not have a welcome screen, or if the current user
+    /// lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_welcome_screen(&self, http: &Http) -> Result<GuildWelcomeScreen> {
+        self.id.get_welcome_screen(http).await
+    }
+
     /// Edits the guild's widget.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -920,7 +951,7 @@ impl PartialGuild {
         &self,
         http: &Http,
         builder: EditGuildWidget<'_>,
-    ) -> Result<GuildWidget> {
+    ) -> Result<GuildWidgetSettings> {
         self.id.edit_widget(http, builder).await
     }
 
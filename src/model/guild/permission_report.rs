@@ -0,0 +1,315 @@
+use std::cmp::Reverse;
+
+use super::{calculate_permissions, CalculatePermissions, Guild, Member};
+use crate::model::prelude::*;
+
+/// Options for [`Guild::permission_report`].
+///
+/// By default, the report contains the permissions of every role, and flags channels where
+/// @everyone has any of [`PermissionReportOptions::DEFAULT_EVERYONE_FLAGGED`].
+///
+/// [`Guild::permission_report`]: super::Guild::permission_report
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct PermissionReportOptions {
+    members: Vec<UserId>,
+    everyone_flagged: Permissions,
+}
+
+impl PermissionReportOptions {
+    /// The permissions @everyone is flagged for by default, as they allow moderating the guild or
+    /// pinging all of its members.
+    pub const DEFAULT_EVERYONE_FLAGGED: Permissions = Permissions::ADMINISTRATOR
+        .union(Permissions::KICK_MEMBERS)
+        .union(Permissions::BAN_MEMBERS)
+        .union(Permissions::MANAGE_CHANNELS)
+        .union(Permissions::MANAGE_GUILD)
+        .union(Permissions::MANAGE_MESSAGES)
+        .union(Permissions::MANAGE_ROLES)
+        .union(Permissions::MANAGE_WEBHOOKS)
+        .union(Permissions::MANAGE_THREADS)
+        .union(Permissions::MENTION_EVERYONE)
+        .union(Permissions::MODERATE_MEMBERS);
+
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the members whose permissions are included in the report. Members which are not
+    /// cached in the guild are skipped.
+    pub fn members(mut self, members: impl IntoIterator<Item = UserId>) -> Self {
+        self.members = members.into_iter().collect();
+        self
+    }
+
+    /// Sets the permissions @everyone is not expected to have. Channels where @everyone has any of
+    /// them are flagged in [`ChannelPermissionReport::everyone_flagged`].
+    pub fn everyone_flagged(mut self, permissions: Permissions) -> Self {
+        self.everyone_flagged = permissions;
+        self
+    }
+}
+
+impl Default for PermissionReportOptions {
+    fn default() -> Self {
+        Self {
+            members: Vec::new(),
+            everyone_flagged: Self::DEFAULT_EVERYONE_FLAGGED,
+        }
+    }
+}
+
+/// The effective permissions in every channel of a guild, generated by
+/// [`Guild::permission_report`].
+///
+/// [`Guild::permission_report`]: super::Guild::permission_report
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct PermissionReport {
+    /// The reports of the guild's channels, ordered by position.
+    pub channels: Vec<ChannelPermissionReport>,
+}
+
+impl PermissionReport {
+    /// Returns the reports of channels with redundant overwrites, or where @everyone has flagged
+    /// permissions.
+    pub fn issues(&self) -> impl Iterator<Item = &ChannelPermissionReport> {
+        self.channels.iter().filter(|channel| channel.has_issues())
+    }
+}
+
+/// The effective permissions in a single channel, part of a [`PermissionReport`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ChannelPermissionReport {
+    /// The Id of the channel.
+    pub channel_id: ChannelId,
+    /// The permissions of a member having only the given role, ordered from the highest role to
+    /// @everyone.
+    pub roles: Vec<(RoleId, Permissions)>,
+    /// The permissions of the members requested with [`PermissionReportOptions::members`].
+    pub members: Vec<(UserId, Permissions)>,
+    /// The overwrites of the channel with permissions which have no effect.
+    pub redundant_overwrites: Vec<RedundantOverwrite>,
+    /// The flagged permissions @everyone has in the channel, see
+    /// [`PermissionReportOptions::everyone_flagged`].
+    pub everyone_flagged: Permissions,
+}
+
+impl ChannelPermissionReport {
+    /// Whether the channel has redundant overwrites, or @everyone has flagged permissions in it.
+    #[must_use]
+    pub fn has_issues(&self) -> bool {
+        !self.redundant_overwrites.is_empty() || !self.everyone_flagged.is_empty()
+    }
+}
+
+/// The permissions of a [`PermissionOverwrite`] which have no effect, and can be removed from it
+/// without changing anyone's permissions.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RedundantOverwrite {
+    /// The target of the overwrite.
+    pub kind: PermissionOverwriteType,
+    /// The allowed permissions which are granted regardless of the overwrite.
+    pub allow: Permissions,
+    /// The denied permissions which are not granted to begin with, or allowed by the overwrite
+    /// itself.
+    pub deny: Permissions,
+    /// Whether the overwrite has no effect at all, and can be deleted.
+    pub no_op: bool,
+}
+
+pub(super) fn generate(guild: &Guild, options: &PermissionReportOptions) -> PermissionReport {
+    let mut channels: Vec<_> = guild.channels.iter().collect();
+    channels.sort_by_key(|channel| (channel.position, channel.id));
+
+    let mut roles: Vec<_> = guild.roles.iter().collect();
+    roles.sort_by_key(|role| Reverse(*role));
+
+    let members: Vec<_> =
+        options.members.iter().filter_map(|user_id| guild.members.get(user_id)).collect();
+
+    PermissionReport {
+        channels: channels
+            .into_iter()
+            .map(|channel| ChannelReporter::new(guild, channel).report(&roles, &members, options))
+            .collect(),
+    }
+}
+
+struct ChannelReporter<'a> {
+    guild: &'a Guild,
+    channel: &'a GuildChannel,
+    everyone_id: RoleId,
+    everyone_permissions: Permissions,
+    everyone_overwrite: (Permissions, Permissions),
+}
+
+impl<'a> ChannelReporter<'a> {
+    fn new(guild: &'a Guild, channel: &'a GuildChannel) -> Self {
+        let everyone_id = RoleId::new(guild.id.get());
+        let everyone_overwrite = channel
+            .permission_overwrites
+            .iter()
+            .find(|overwrite| overwrite.kind == PermissionOverwriteType::Role(everyone_id))
+            .map_or((Permissions::empty(), Permissions::empty()), |overwrite| {
+                (overwrite.allow, overwrite.deny)
+            });
+
+        Self {
+            guild,
+            channel,
+            everyone_id,
+            everyone_permissions: guild
+                .roles
+                .get(&everyone_id)
+                .map_or(Permissions::empty(), |role| role.permissions),
+            everyone_overwrite,
+        }
+    }
+
+    fn report(
+        &self,
+        roles: &[&Role],
+        members: &[&Member],
+        options: &PermissionReportOptions,
+    ) -> ChannelPermissionReport {
+        let everyone = self.permissions(false, &[]);
+
+        ChannelPermissionReport {
+            channel_id: self.channel.id,
+            roles: roles
+                .iter()
+                .map(|role| {
+                    let permissions = if role.id == self.everyone_id {
+                        everyone
+                    } else {
+                        self.permissions(false, &[role.id])
+                    };
+                    (role.id, permissions)
+                })
+                .collect(),
+            members: members
+                .iter()
+                .map(|member| {
+                    let permissions = self.guild.user_permissions_in(self.channel, member);
+                    (member.user.id, permissions)
+                })
+                .collect(),
+            redundant_overwrites: self
+                .channel
+                .permission_overwrites
+                .iter()
+                .filter_map(|overwrite| self.redundant(overwrite))
+                .collect(),
+            everyone_flagged: everyone & options.everyone_flagged,
+        }
+    }
+
+    /// Calculates the permissions of a member with the given roles, without member overwrites.
+    fn permissions(&self, is_owner: bool, member_roles: &[RoleId]) -> Permissions {
+        let (roles_allow_overwrites, roles_deny_overwrites) = self
+            .channel
+            .permission_overwrites
+            .iter()
+            .filter(|overwrite| match overwrite.kind {
+                PermissionOverwriteType::Role(role_id) => {
+                    role_id != self.everyone_id && member_roles.contains(&role_id)
+                },
+                _ => false,
+            })
+            .map(|overwrite| (overwrite.allow, overwrite.deny))
+            .unzip();
+
+        calculate_permissions(CalculatePermissions {
+            is_guild_owner: is_owner,
+            everyone_permissions: self.everyone_permissions,
+            user_roles_permissions: member_roles
+                .iter()
+                .filter_map(|role_id| self.guild.roles.get(role_id))
+                .map(|role| role.permissions)
+                .collect(),
+            everyone_allow_overwrites: self.everyone_overwrite.0,
+            everyone_deny_overwrites: self.everyone_overwrite.1,
+            roles_allow_overwrites,
+            roles_deny_overwrites,
+            ..Default::default()
+        })
+    }
+
+    fn redundant(&self, overwrite: &PermissionOverwrite) -> Option<RedundantOverwrite> {
+        let (allow, deny) = match overwrite.kind {
+            PermissionOverwriteType::Role(role_id) => self.redundant_role(role_id, overwrite),
+            PermissionOverwriteType::Member(user_id) => {
+                self.redundant_member(user_id, overwrite)?
+            },
+        };
+        // Allows are applied after denies, so denying an allowed permission has no effect.
+        let deny = deny | (overwrite.deny & overwrite.allow);
+        if allow.is_empty() && deny.is_empty() && !(overwrite.allow | overwrite.deny).is_empty() {
+            return None;
+        }
+
+        Some(RedundantOverwrite {
+            kind: overwrite.kind,
+            allow,
+            deny,
+            no_op: allow == overwrite.allow && deny == overwrite.deny,
+        })
+    }
+
+    fn redundant_role(
+        &self,
+        role_id: RoleId,
+        overwrite: &PermissionOverwrite,
+    ) -> (Permissions, Permissions) {
+        // Overwrites of deleted roles and administrators don't apply to anyone.
+        let Some(role) = self.guild.roles.get(&role_id) else {
+            return (overwrite.allow, overwrite.deny);
+        };
+        if self.everyone_permissions.contains(Permissions::ADMINISTRATOR)
+            || role.permissions.contains(Permissions::ADMINISTRATOR)
+        {
+            return (overwrite.allow, overwrite.deny);
+        }
+
+        let granted_by_any_role =
+            self.guild.roles.iter().fold(Permissions::empty(), |acc, role| acc | role.permissions);
+        if role_id == self.everyone_id {
+            return (
+                overwrite.allow & self.everyone_permissions,
+                overwrite.deny & !granted_by_any_role,
+            );
+        }
+
+        // The allows of a role override the denies of @everyone and other roles.
+        let denied_by_others = self
+            .channel
+            .permission_overwrites
+            .iter()
+            .filter(|other| other.kind != overwrite.kind)
+            .filter(|other| matches!(other.kind, PermissionOverwriteType::Role(_)))
+            .fold(Permissions::empty(), |acc, other| acc | other.deny);
+        (
+            overwrite.allow & role.permissions & !denied_by_others,
+            overwrite.deny & !(granted_by_any_role | self.everyone_overwrite.0),
+        )
+    }
+
+    fn redundant_member(
+        &self,
+        user_id: UserId,
+        overwrite: &PermissionOverwrite,
+    ) -> Option<(Permissions, Permissions)> {
+        let member = self.guild.members.get(&user_id)?;
+        let is_owner = user_id == self.guild.owner_id;
+        let without_overwrite = self.permissions(is_owner, &member.roles);
+        if is_owner || without_overwrite.contains(Permissions::ADMINISTRATOR) {
+            return Some((overwrite.allow, overwrite.deny));
+        }
+
+        Some((overwrite.allow & without_overwrite, overwrite.deny & !without_overwrite))
+    }
+}
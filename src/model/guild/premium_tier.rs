@@ -1,3 +1,6 @@
+use crate::model::id::UserId;
+use crate::model::Timestamp;
+
 enum_number! {
     /// The guild's premium tier, depends on the amount of users boosting the guild currently
     ///
@@ -17,3 +20,52 @@ enum_number! {
         _ => Unknown(u8),
     }
 }
+
+impl PremiumTier {
+    /// Returns the number of boosts a guild needs to unlock the tier, or [`None`] if the tier is
+    /// unknown.
+    #[must_use]
+    pub fn boosts_required(self) -> Option<u64> {
+        match self {
+            Self::Tier0 => Some(0),
+            Self::Tier1 => Some(2),
+            Self::Tier2 => Some(7),
+            Self::Tier3 => Some(14),
+            _ => None,
+        }
+    }
+
+    /// Returns the tier following this one, or [`None`] if this is the highest or an unknown tier.
+    #[must_use]
+    pub fn next(self) -> Option<Self> {
+        match self {
+            Self::Tier0 => Some(Self::Tier1),
+            Self::Tier1 => Some(Self::Tier2),
+            Self::Tier2 => Some(Self::Tier3),
+            _ => None,
+        }
+    }
+}
+
+/// Boosting statistics of a guild, as returned by [`Guild::boost_summary`].
+///
+/// [`Guild::boost_summary`]: super::Guild::boost_summary
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct BoostSummary {
+    /// The guild's premium tier.
+    pub tier: PremiumTier,
+    /// The number of boosts the guild has, which may be more than the number of boosters, as
+    /// users can boost a guild multiple times.
+    pub boosts: u64,
+    /// The number of cached members boosting the guild.
+    pub boosters: usize,
+    /// The next premium tier, or [`None`] if the guild is at the highest tier.
+    pub next_tier: Option<PremiumTier>,
+    /// The number of additional boosts needed to reach [`Self::next_tier`].
+    pub boosts_to_next_tier: Option<u64>,
+    /// The cached member boosting the guild the longest, and since when.
+    pub longest_booster: Option<(UserId, Timestamp)>,
+    /// The cached member who most recently started boosting the guild, and since when.
+    pub newest_booster: Option<(UserId, Timestamp)>,
+}
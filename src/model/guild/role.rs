@@ -4,7 +4,7 @@ use std::fmt;
 #[cfg(feature = "model")]
 use crate::builder::EditRole;
 #[cfg(feature = "model")]
-use crate::http::Http;
+use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 use crate::model::utils::is_false;
@@ -163,6 +163,29 @@ impl Ord for Role {
     }
 }
 
+#[cfg(feature = "model")]
+impl RoleId {
+    /// Attempts to find the [`Role`] in the guild's cache, otherwise requests it over the REST
+    /// API.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the role is not cached and does not exist, or the current user
+    /// is not in the guild.
+    pub async fn to_role(self, cache_http: impl CacheHttp, guild_id: GuildId) -> Result<Role> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            if let Some(guild) = cache.guild(guild_id) {
+                if let Some(role) = guild.roles.get(&self) {
+                    return Ok(role.clone());
+                }
+            }
+        }
+
+        guild_id.role(cache_http.http(), self).await
+    }
+}
+
 impl From<Role> for RoleId {
     /// Gets the Id of a role.
     fn from(role: Role) -> RoleId {
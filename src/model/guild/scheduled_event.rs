@@ -1,5 +1,15 @@
 use nonmax::NonMaxU64;
 
+#[cfg(feature = "model")]
+use crate::builder::CreateStageInstance;
+#[cfg(feature = "cache")]
+use crate::cache::Cache;
+#[cfg(feature = "collector")]
+use crate::collector::{ScheduledEventUserAddCollector, ScheduledEventUserRemoveCollector};
+#[cfg(feature = "collector")]
+use crate::gateway::ShardMessenger;
+#[cfg(feature = "model")]
+use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
@@ -56,6 +66,78 @@ pub struct ScheduledEvent {
     pub image: Option<ImageHash>,
 }
 
+#[cfg(feature = "model")]
+impl ScheduledEvent {
+    /// Starts the stage instance of a [`ScheduledEventType::StageInstance`] event, in the event's
+    /// channel. Discord marks the event as [`ScheduledEventStatus::Active`] once the stage
+    /// instance is created.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::InvalidChannelType`] if the event is not a stage event.
+    ///
+    /// Returns [`Error::Http`] if there is already a stage instance in the channel.
+    pub async fn start_stage_instance(
+        &self,
+        http: &Http,
+        builder: CreateStageInstance<'_>,
+    ) -> Result<StageInstance> {
+        let Some(channel_id) =
+            self.channel_id.filter(|_| self.kind == ScheduledEventType::StageInstance)
+        else {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        };
+
+        builder.guild_scheduled_event_id(self.id).execute(http, channel_id).await
+    }
+
+    /// Returns a builder which can be awaited to obtain a user subscribing to this event, or a
+    /// stream of users subscribing to it.
+    ///
+    /// Combine with [`Self::await_user_remove`] and [`GuildId::scheduled_event_users_iter`] to
+    /// keep track of all interested users.
+    ///
+    /// **Note**: Requires the [`GatewayIntents::GUILD_SCHEDULED_EVENTS`] intent.
+    #[cfg(feature = "collector")]
+    pub fn await_user_add(
+        &self,
+        shard_messenger: ShardMessenger,
+    ) -> ScheduledEventUserAddCollector {
+        ScheduledEventUserAddCollector::new(shard_messenger)
+            .guild_id(self.guild_id)
+            .scheduled_event_id(self.id)
+    }
+
+    /// Returns a builder which can be awaited to obtain a user unsubscribing from this event, or a
+    /// stream of users unsubscribing from it.
+    ///
+    /// **Note**: Requires the [`GatewayIntents::GUILD_SCHEDULED_EVENTS`] intent.
+    #[cfg(feature = "collector")]
+    pub fn await_user_remove(
+        &self,
+        shard_messenger: ShardMessenger,
+    ) -> ScheduledEventUserRemoveCollector {
+        ScheduledEventUserRemoveCollector::new(shard_messenger)
+            .guild_id(self.guild_id)
+            .scheduled_event_id(self.id)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl ScheduledEventId {
+    /// Returns the number of users interested in the scheduled event, as tracked by the cache if
+    /// enabled via [`Settings::cache_scheduled_event_interest`].
+    ///
+    /// Returns [`None`] if the count is not known. See [`Cache::seed_scheduled_event_interest`]
+    /// for tracking events which existed before the bot started.
+    ///
+    /// [`Settings::cache_scheduled_event_interest`]: crate::cache::Settings::cache_scheduled_event_interest
+    #[must_use]
+    pub fn interested_count(self, cache: &Cache) -> Option<u64> {
+        cache.scheduled_event_interest(self)
+    }
+}
+
 enum_number! {
     /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status).
     #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
@@ -0,0 +1,222 @@
+//! Locales supported by Discord, used by users, guilds and interactions.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::internal::prelude::*;
+
+macro_rules! locales {
+    ($($(#[$attr:meta])* $variant:ident => $code:literal,)*) => {
+        /// A locale Discord supports, such as for a user's client language or a guild's preferred
+        /// locale.
+        ///
+        /// Locale codes Discord adds after this was written are kept as [`Self::Unknown`].
+        ///
+        /// [Discord docs](https://discord.com/developers/docs/reference#locales).
+        #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        #[non_exhaustive]
+        pub enum Locale {
+            $($(#[$attr])* $variant,)*
+            /// A locale code not known to serenity.
+            Unknown(FixedString),
+        }
+
+        impl Locale {
+            /// Returns the locale code used by Discord, such as `en-US` or `fr`.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for Locale {
+            fn from(code: &str) -> Self {
+                match code {
+                    $($code => Self::$variant,)*
+                    _ => Self::Unknown(FixedString::from_str_trunc(code)),
+                }
+            }
+        }
+    };
+}
+
+locales! {
+    /// `id`
+    Indonesian => "id",
+    /// `da`
+    Danish => "da",
+    /// `de`
+    German => "de",
+    /// `en-GB`
+    EnglishUk => "en-GB",
+    /// `en-US`
+    EnglishUs => "en-US",
+    /// `es-ES`
+    Spanish => "es-ES",
+    /// `es-419`
+    SpanishLatam => "es-419",
+    /// `fr`
+    French => "fr",
+    /// `hr`
+    Croatian => "hr",
+    /// `it`
+    Italian => "it",
+    /// `lt`
+    Lithuanian => "lt",
+    /// `hu`
+    Hungarian => "hu",
+    /// `nl`
+    Dutch => "nl",
+    /// `no`
+    Norwegian => "no",
+    /// `pl`
+    Polish => "pl",
+    /// `pt-BR`
+    PortugueseBrazil => "pt-BR",
+    /// `ro`
+    Romanian => "ro",
+    /// `fi`
+    Finnish => "fi",
+    /// `sv-SE`
+    Swedish => "sv-SE",
+    /// `vi`
+    Vietnamese => "vi",
+    /// `tr`
+    Turkish => "tr",
+    /// `cs`
+    Czech => "cs",
+    /// `el`
+    Greek => "el",
+    /// `bg`
+    Bulgarian => "bg",
+    /// `ru`
+    Russian => "ru",
+    /// `uk`
+    Ukrainian => "uk",
+    /// `hi`
+    Hindi => "hi",
+    /// `th`
+    Thai => "th",
+    /// `zh-CN`
+    ChineseChina => "zh-CN",
+    /// `ja`
+    Japanese => "ja",
+    /// `zh-TW`
+    ChineseTaiwan => "zh-TW",
+    /// `ko`
+    Korean => "ko",
+}
+
+impl Locale {
+    /// Returns the language part of the locale code, such as `en` for `en-US`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        let code = self.as_str();
+        code.split_once('-').map_or(code, |(language, _)| language)
+    }
+
+    /// Picks the locale from `supported` that best matches this one, such as to choose which
+    /// translation to respond with.
+    ///
+    /// An exact match is preferred, falling back to the first supported locale of the same
+    /// language, so that `en-GB` picks `en-US` if that is the only English locale supported.
+    /// Returns [`None`] if no supported locale shares the language.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::locale::Locale;
+    ///
+    /// let supported = [Locale::EnglishUs, Locale::German, Locale::SpanishLatam];
+    ///
+    /// assert_eq!(Locale::German.best_match(&supported), Some(&Locale::German));
+    /// assert_eq!(Locale::EnglishUk.best_match(&supported), Some(&Locale::EnglishUs));
+    /// assert_eq!(Locale::Spanish.best_match(&supported), Some(&Locale::SpanishLatam));
+    /// assert_eq!(Locale::Japanese.best_match(&supported), None);
+    /// ```
+    #[must_use]
+    pub fn best_match<'a>(&self, supported: &'a [Locale]) -> Option<&'a Locale> {
+        supported.iter().find(|locale| *locale == self).or_else(|| {
+            supported.iter().find(|locale| locale.language().eq_ignore_ascii_case(self.language()))
+        })
+    }
+
+    /// Picks the best supported locale for the first of `preferred` that has a match, as done by
+    /// [`Self::best_match`].
+    ///
+    /// This is useful to try the user's locale before falling back to the guild's, returning
+    /// [`None`] if neither is supported so a default can be used.
+    #[must_use]
+    pub fn negotiate<'a, 'b>(
+        preferred: impl IntoIterator<Item = &'b Locale>,
+        supported: &'a [Locale],
+    ) -> Option<&'a Locale> {
+        preferred.into_iter().find_map(|locale| locale.best_match(supported))
+    }
+}
+
+impl Default for Locale {
+    /// Returns [`Self::EnglishUs`], Discord's default locale.
+    fn default() -> Self {
+        Self::EnglishUs
+    }
+}
+
+impl FromStr for Locale {
+    type Err = std::convert::Infallible;
+
+    fn from_str(code: &str) -> StdResult<Self, Self::Err> {
+        Ok(Self::from(code))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(Self::from(code.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+
+    #[test]
+    fn locale_serde() {
+        let locale: Locale = serde_json::from_str("\"pt-BR\"").unwrap();
+        assert_eq!(locale, Locale::PortugueseBrazil);
+        assert_eq!(serde_json::to_string(&locale).unwrap(), "\"pt-BR\"");
+
+        let locale: Locale = serde_json::from_str("\"tlh\"").unwrap();
+        assert_eq!(locale.as_str(), "tlh");
+        assert_eq!(serde_json::to_string(&locale).unwrap(), "\"tlh\"");
+    }
+
+    #[test]
+    fn locale_negotiate() {
+        let supported = [Locale::EnglishUs, Locale::French];
+
+        let preferred = [Locale::German, Locale::EnglishUk];
+        assert_eq!(Locale::negotiate(&preferred, &supported), Some(&Locale::EnglishUs));
+        assert_eq!(Locale::negotiate(&[Locale::Korean], &supported), None);
+    }
+}
@@ -287,6 +287,65 @@ pub struct Maintenance {
     pub updated_at: FixedString,
 }
 
+/// A value which was either found in the cache, or fetched over HTTP because it was not cached,
+/// as returned by methods such as [`GuildId::to_guild`].
+///
+/// Cached values are references into the cache, which must not be held across `.await` points.
+/// Use [`Self::into_owned`] to get an owned value either way.
+#[derive(Clone, Debug)]
+pub enum CachedOrFetched<C, F> {
+    /// The value was found in the cache.
+    Cached(C),
+    /// The value was fetched over HTTP.
+    Fetched(F),
+}
+
+impl<C, F> CachedOrFetched<C, F> {
+    /// Whether the value was found in the cache.
+    #[must_use]
+    pub fn is_cached(&self) -> bool {
+        matches!(self, Self::Cached(_))
+    }
+
+    /// Returns the fetched value, or [`None`] if it was found in the cache.
+    #[must_use]
+    pub fn fetched(self) -> Option<F> {
+        match self {
+            Self::Cached(_) => None,
+            Self::Fetched(fetched) => Some(fetched),
+        }
+    }
+}
+
+impl<C, F> CachedOrFetched<C, F>
+where
+    C: std::ops::Deref,
+    C::Target: Clone + Into<F>,
+{
+    /// Returns an owned value, cloning it out of the cache if needed.
+    #[must_use]
+    pub fn into_owned(self) -> F {
+        match self {
+            Self::Cached(cached) => (*cached).clone().into(),
+            Self::Fetched(fetched) => fetched,
+        }
+    }
+}
+
+impl<C, F> std::ops::Deref for CachedOrFetched<C, F>
+where
+    C: std::ops::Deref<Target = F>,
+{
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Cached(cached) => cached,
+            Self::Fetched(fetched) => fetched,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::model::prelude::*;
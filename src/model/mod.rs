@@ -28,10 +28,12 @@ pub mod colour;
 pub mod connection;
 pub mod error;
 pub mod event;
+pub mod fixed;
 pub mod gateway;
 pub mod guild;
 pub mod id;
 pub mod invite;
+pub mod locale;
 pub mod mention;
 pub mod misc;
 pub mod monetization;
@@ -90,6 +92,7 @@ pub mod prelude {
         guild::*,
         id::*,
         invite::*,
+        locale::*,
         mention::*,
         misc::*,
         monetization::*,
@@ -262,8 +262,10 @@ pub struct User {
     /// [`crate::http::Http::get_user`].
     #[serde(rename = "accent_color")]
     pub accent_colour: Option<Colour>,
+    /// The user's avatar decoration, if one is equipped.
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
     /// The user's chosen language option
-    pub locale: Option<FixedString>,
+    pub locale: Option<Locale>,
     /// Whether the email on this account has been verified
     ///
     /// Requires [`Scope::Email`]
@@ -287,6 +289,19 @@ pub struct User {
     pub member: Option<Box<PartialMember>>,
 }
 
+/// The avatar decoration equipped by a [`User`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#avatar-decoration-data-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AvatarDecorationData {
+    /// The avatar decoration hash.
+    pub asset: ImageHash,
+    /// The ID of the avatar decoration's SKU.
+    pub sku_id: SkuId,
+}
+
 impl ExtractKey<UserId> for User {
     fn extract_key(&self) -> &UserId {
         &self.id
@@ -376,6 +391,14 @@ impl User {
         banner_url(self.id, self.banner.as_ref())
     }
 
+    /// Returns the formatted URL of the user's avatar decoration, if one is equipped.
+    #[must_use]
+    pub fn avatar_decoration_url(&self) -> Option<String> {
+        self.avatar_decoration_data
+            .as_ref()
+            .map(|decoration| cdn!("/avatar-decoration-presets/{}.png", decoration.asset))
+    }
+
     /// Creates a direct message channel between the [current user] and the user. This can also
     /// retrieve the channel if one already exists.
     ///
@@ -791,6 +814,17 @@ fn tag(name: &str, discriminator: Option<NonZeroU16>) -> String {
     tag
 }
 
+/// Returns the Id of the current user from the cache if available, otherwise fetches it.
+#[cfg(feature = "model")]
+pub(crate) async fn current_user_id(cache_http: impl CacheHttp) -> Result<UserId> {
+    #[cfg(feature = "cache")]
+    if let Some(cache) = cache_http.cache() {
+        return Ok(cache.current_user().id);
+    }
+
+    Ok(cache_http.http().get_current_user().await?.id)
+}
+
 #[cfg(test)]
 mod test {
     use std::num::NonZeroU16;
@@ -857,6 +891,23 @@ mod test {
             assert_eq!(user.tag(), "test#1432");
         }
 
+        #[test]
+        fn avatar_decoration() {
+            let user: User = serde_json::from_value(serde_json::json!({
+                "id": "210",
+                "username": "test",
+                "avatar": null,
+                "avatar_decoration_data": {
+                    "asset": "a_fb211703bcc04ee612c88d494df0272f",
+                    "sku_id": "1144058522808614923"
+                }
+            }))
+            .unwrap();
+
+            let expected = "/avatar-decoration-presets/a_fb211703bcc04ee612c88d494df0272f.png";
+            assert!(user.avatar_decoration_url().unwrap().ends_with(expected));
+        }
+
         #[test]
         fn default_avatars() {
             let mut user = User {
@@ -390,6 +390,9 @@ impl Webhook {
 
     /// Gets a previously sent message from the webhook.
     ///
+    /// If the message was sent within a thread, its Id must be given as `thread_id`, equivalent to
+    /// [`ExecuteWebhook::in_thread`].
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Model`] if the [`Self::token`] is [`None`].
@@ -410,6 +413,9 @@ impl Webhook {
 
     /// Edits a webhook message with the fields set via the given builder.
     ///
+    /// If the message was sent within a thread, its Id must be set with
+    /// [`EditWebhookMessage::in_thread`].
+    ///
     /// **Note**: Message contents must be under 2000 unicode code points.
     ///
     /// # Errors
@@ -433,6 +439,32 @@ impl Webhook {
 
     /// Deletes a webhook message.
     ///
+    /// If the message was sent within a thread, its Id must be given as `thread_id`, equivalent to
+    /// [`ExecuteWebhook::in_thread`].
+    ///
+    /// # Examples
+    ///
+    /// Sending a message in a thread, and deleting it again:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::webhook::Webhook;
+    /// # use serenity::model::id::ChannelId;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let webhook: Webhook = unimplemented!();
+    /// use serenity::builder::ExecuteWebhook;
+    ///
+    /// let thread_id = ChannelId::new(12345678);
+    /// let builder = ExecuteWebhook::new().in_thread(thread_id).content("test");
+    /// if let Some(message) = webhook.execute(&http, true, builder).await? {
+    ///     webhook.delete_message(&http, Some(thread_id), message.id).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Model`] if the [`Self::token`] is [`None`].
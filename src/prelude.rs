@@ -17,7 +17,13 @@ pub use tokio::sync::{Mutex, RwLock};
 
 pub use crate::error::Error as SerenityError;
 #[cfg(feature = "gateway")]
-pub use crate::gateway::client::{Client, Context, EventHandler, RawEventHandler};
+pub use crate::gateway::client::{
+    Client,
+    Context,
+    EventHandler,
+    RawEventHandler,
+    RawPayloadHandler,
+};
 #[cfg(feature = "gateway")]
 pub use crate::gateway::GatewayError;
 #[cfg(feature = "http")]
@@ -0,0 +1,405 @@
+//! Utilities for integration testing bots without a gateway connection.
+//!
+//! The [`EventRecorder`] records the events received by a live shard to disk, and the
+//! [`EventReplayer`] replays a recording into the event handlers, framework and cache configured
+//! on a [`ClientBuilder`], without connecting to Discord. Combined with a mock HTTP transport (see
+//! the `http_transport` feature), this allows testing the behaviour of a bot end-to-end.
+//!
+//! Recordings contain one event per line, in the same `{"t": ..., "d": ...}` format as gateway
+//! dispatch payloads. They can also be written by hand.
+//!
+//! # Examples
+//!
+//! Recording the events of a live bot:
+//!
+//! ```rust,no_run
+//! use serenity::model::gateway::GatewayIntents;
+//! use serenity::testing::EventRecorder;
+//! use serenity::Client;
+//!
+//! # async fn run() -> serenity::Result<()> {
+//! let recorder = EventRecorder::create("events.jsonl")?;
+//! let mut client =
+//!     Client::builder("token", GatewayIntents::all()).raw_event_handler(recorder).await?;
+//! client.start().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Replaying them into an event handler:
+//!
+//! ```rust,no_run
+//! # use serenity::model::gateway::GatewayIntents;
+//! # use serenity::prelude::*;
+//! # struct Handler;
+//! # impl EventHandler for Handler {}
+//! # async fn run() -> serenity::Result<()> {
+//! let replayer =
+//!     Client::builder("token", GatewayIntents::all()).event_handler(Handler).into_replayer();
+//!
+//! let dispatched = replayer.replay_file("events.jsonl").await?;
+//! println!("Replayed {dispatched} events");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ClientBuilder`]: crate::gateway::client::ClientBuilder
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
+use parking_lot::Mutex;
+use tracing::warn;
+
+#[cfg(feature = "cache")]
+use crate::cache::Cache;
+#[cfg(feature = "framework")]
+use crate::framework::Framework;
+use crate::gateway::client::dispatch::dispatch_model;
+use crate::gateway::client::{
+    CommandStats,
+    Context,
+    EventHandler,
+    Extensions,
+    RawEventHandler,
+    ShardState,
+};
+#[cfg(feature = "collector")]
+use crate::gateway::EventBus;
+use crate::gateway::{ShardMessenger, ShardRunnerMessage};
+use crate::http::Http;
+use crate::internal::prelude::*;
+use crate::model::event::Event;
+use crate::model::id::ShardId;
+
+/// A [`RawEventHandler`] which writes every received event to a recording, to be replayed later
+/// by an [`EventReplayer`].
+///
+/// If the client already has a raw event handler, call [`Self::record`] from it instead.
+pub struct EventRecorder {
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl EventRecorder {
+    /// Creates a recorder writing to the given writer.
+    #[must_use]
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Mutex::new(Box::new(writer)),
+        }
+    }
+
+    /// Creates a recorder writing to a file, truncating it if it already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file could not be created.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Appends an event to the recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the event could not be serialized, or [`Error::Io`] if writing
+    /// it failed.
+    pub fn record(&self, event: &Event) -> Result<()> {
+        let mut writer = self.writer.lock();
+        serde_json::to_writer(&mut *writer, event)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for EventRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventRecorder").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl RawEventHandler for EventRecorder {
+    async fn raw_event(&self, _ctx: Context, event: &Event) {
+        if let Err(why) = self.record(event) {
+            warn!("Failed to record event: {why:?}");
+        }
+    }
+}
+
+/// Replays recorded events into the event handlers, framework and cache of a client, as if they
+/// were received by a shard. Created via [`ClientBuilder::into_replayer`].
+///
+/// Unlike a live shard, events are dispatched one at a time, and each dispatch finishes before
+/// the next event is replayed, so tests are deterministic. Collectors receive replayed events as
+/// well.
+///
+/// Messages sent to the shard through [`Context::shard`], such as presence updates, are not sent
+/// anywhere, but can be inspected via [`Self::take_shard_messages`].
+///
+/// **Note**: [`Framework::init`] is not called, as there is no [`Client`] to pass to it.
+///
+/// [`ClientBuilder::into_replayer`]: crate::gateway::client::ClientBuilder::into_replayer
+/// [`Framework::init`]: crate::framework::Framework::init
+/// [`Client`]: crate::Client
+pub struct EventReplayer {
+    data: Arc<dyn std::any::Any + Send + Sync>,
+    extensions: Arc<Extensions>,
+    shard_state: ShardState,
+    http: Arc<Http>,
+    #[cfg(feature = "cache")]
+    cache: Arc<Cache>,
+    #[cfg(feature = "framework")]
+    framework: Option<Arc<dyn Framework>>,
+    event_handler: Option<Arc<dyn EventHandler>>,
+    raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    command_stats: Option<Arc<CommandStats>>,
+    shard_id: ShardId,
+    #[cfg(feature = "collector")]
+    event_bus: EventBus,
+    runner_tx: Sender<ShardRunnerMessage>,
+    runner_rx: Mutex<Receiver<ShardRunnerMessage>>,
+}
+
+impl EventReplayer {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        data: Arc<dyn std::any::Any + Send + Sync>,
+        extensions: Arc<Extensions>,
+        http: Arc<Http>,
+        #[cfg(feature = "cache")] cache: Arc<Cache>,
+        #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
+        event_handler: Option<Arc<dyn EventHandler>>,
+        raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+        command_stats: Option<Arc<CommandStats>>,
+    ) -> Self {
+        let (runner_tx, runner_rx) = mpsc::unbounded();
+        Self {
+            data,
+            extensions,
+            shard_state: ShardState::default(),
+            http,
+            #[cfg(feature = "cache")]
+            cache,
+            #[cfg(feature = "framework")]
+            framework,
+            event_handler,
+            raw_event_handler,
+            command_stats,
+            shard_id: ShardId(0),
+            #[cfg(feature = "collector")]
+            event_bus: EventBus::new(),
+            runner_tx,
+            runner_rx: Mutex::new(runner_rx),
+        }
+    }
+
+    /// Sets the ID of the shard which replayed events appear to be received by. Defaults to shard
+    /// 0.
+    #[must_use]
+    pub fn shard_id(mut self, shard_id: ShardId) -> Self {
+        self.shard_id = shard_id;
+        self
+    }
+
+    /// The cache which replayed events are applied to.
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn cache(&self) -> &Arc<Cache> {
+        &self.cache
+    }
+
+    /// Creates a context, as passed to the event handlers for replayed events.
+    #[must_use]
+    pub fn context(&self) -> Context {
+        let shard = ShardMessenger {
+            tx: self.runner_tx.clone(),
+            #[cfg(feature = "collector")]
+            shard_id: self.shard_id,
+            #[cfg(feature = "collector")]
+            event_bus: self.event_bus.clone(),
+        };
+
+        Context::new(
+            Arc::clone(&self.data),
+            Arc::clone(&self.extensions),
+            self.shard_state.clone(),
+            shard,
+            self.shard_id,
+            Arc::clone(&self.http),
+            #[cfg(feature = "cache")]
+            Arc::clone(&self.cache),
+        )
+    }
+
+    /// Dispatches a single event, waiting for all handlers to finish.
+    ///
+    /// Returns `false` if the event was filtered out by [`EventHandler::filter_event`] or
+    /// [`RawEventHandler::filter_event`].
+    pub async fn dispatch(&self, event: Event) -> bool {
+        let context = self.context();
+        let can_dispatch = self
+            .event_handler
+            .as_ref()
+            .map_or(true, |handler| handler.filter_event(&context, &event))
+            && self
+                .raw_event_handler
+                .as_ref()
+                .map_or(true, |handler| handler.filter_event(&context, &event));
+
+        if !can_dispatch {
+            return false;
+        }
+
+        #[cfg(feature = "collector")]
+        self.event_bus.publish(self.shard_id, &event);
+
+        Box::pin(dispatch_model(
+            event,
+            context,
+            #[cfg(feature = "framework")]
+            self.framework.clone(),
+            self.event_handler.clone(),
+            self.raw_event_handler.clone(),
+            self.command_stats.clone(),
+        ))
+        .await;
+
+        true
+    }
+
+    /// Dispatches the events in order, returning how many of them were dispatched.
+    pub async fn replay(&self, events: impl IntoIterator<Item = Event>) -> usize {
+        let mut dispatched = 0;
+        for event in events {
+            if self.dispatch(event).await {
+                dispatched += 1;
+            }
+        }
+
+        dispatched
+    }
+
+    /// Parses a recording and dispatches its events in order, returning how many of them were
+    /// dispatched. Empty lines are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if reading failed, or [`Error::Json`] if a line is not a valid event.
+    /// Events before the invalid line are still dispatched.
+    pub async fn replay_reader(&self, reader: impl BufRead) -> Result<usize> {
+        let mut dispatched = 0;
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let event = serde_json::from_str(&line)?;
+            if self.dispatch(event).await {
+                dispatched += 1;
+            }
+        }
+
+        Ok(dispatched)
+    }
+
+    /// Reads a recording from a file and dispatches its events in order, see
+    /// [`Self::replay_reader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file could not be read, or [`Error::Json`] if it contains an
+    /// invalid event.
+    pub async fn replay_file(&self, path: impl AsRef<Path>) -> Result<usize> {
+        self.replay_reader(BufReader::new(File::open(path)?)).await
+    }
+
+    /// Takes the messages which handlers sent to the shard, in the order they were sent.
+    pub fn take_shard_messages(&self) -> Vec<ShardRunnerMessage> {
+        let mut runner_rx = self.runner_rx.lock();
+        let mut messages = Vec::new();
+        while let Ok(message) = runner_rx.try_recv() {
+            messages.push(message);
+        }
+
+        messages
+    }
+}
+
+impl std::fmt::Debug for EventReplayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventReplayer").field("shard_id", &self.shard_id).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use crate::gateway::client::{Context, EventHandler};
+    use crate::model::prelude::*;
+    use crate::Client;
+
+    #[derive(Default)]
+    struct Counter(AtomicUsize);
+
+    #[async_trait]
+    impl EventHandler for Counter {
+        async fn message(&self, _ctx: Context, new_message: Message) {
+            assert_eq!(new_message.content, "hello");
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn replay_recording() {
+        let counter = Arc::new(Counter::default());
+        let replayer = Client::builder("token", GatewayIntents::all())
+            .event_handler::<Counter>(Arc::clone(&counter))
+            .into_replayer();
+
+        let message = r#"{"t":"MESSAGE_CREATE","d":{"id":"2","channel_id":"1","author":{"id":"3","username":"user","discriminator":"0","avatar":null},"content":"hello","timestamp":"2024-01-01T00:00:00Z","edited_timestamp":null,"tts":false,"mention_everyone":false,"mentions":[],"mention_roles":[],"attachments":[],"embeds":[],"pinned":false,"type":0}}"#;
+        let recording = format!("{message}\n\n{message}\n");
+
+        let dispatched = replayer.replay_reader(recording.as_bytes()).await.unwrap();
+        assert_eq!(dispatched, 2);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "collector")]
+    #[tokio::test]
+    async fn collectors_receive_every_event() {
+        use futures::StreamExt as _;
+
+        let replayer = Client::builder("token", GatewayIntents::all()).into_replayer();
+        let stream = crate::collector::collect(&replayer.context().shard, |event| match event {
+            Event::MessageCreate(event) => Some(event.message.id),
+            _ => None,
+        });
+
+        // More events than each receiver of `Client::events` buffers, before the collector is
+        // polled at all.
+        let count = 2 * crate::constants::EVENT_BUS_CAPACITY;
+        let events = (1..=count as u64).map(|id| {
+            Event::MessageCreate(MessageCreateEvent {
+                message: Message {
+                    id: MessageId::new(id),
+                    ..Default::default()
+                },
+            })
+        });
+        assert_eq!(replayer.replay(events).await, count);
+
+        let ids: Vec<_> = stream.take(count).map(MessageId::get).collect().await;
+        assert!(ids.iter().copied().eq(1..=count as u64));
+    }
+}
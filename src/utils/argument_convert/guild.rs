@@ -41,12 +41,12 @@ impl ArgumentConvert for Guild {
     ) -> Result<Self, Self::Err> {
         let guilds = &ctx.cache().ok_or(GuildParseError::NoCache)?.guilds;
 
-        let lookup_by_id = || guilds.get(&s.parse().ok()?).map(|g| g.clone());
+        let lookup_by_id = || guilds.get(&s.parse().ok()?).map(|g| Guild::clone(&g));
 
         let lookup_by_name = || {
             guilds.iter().find_map(|m| {
                 let guild = m.value();
-                guild.name.eq_ignore_ascii_case(s).then(|| guild.clone())
+                guild.name.eq_ignore_ascii_case(s).then(|| Guild::clone(guild))
             })
         };
 
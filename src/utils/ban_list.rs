@@ -0,0 +1,359 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use nonmax::NonMaxU16;
+
+use crate::constants::BULK_BAN_LIMIT;
+use crate::http::{Http, UserPagination};
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A single entry of a [`BanList`].
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct BanListEntry {
+    /// The Id of the banned user.
+    pub user_id: UserId,
+    /// The reason given for the ban, if any.
+    pub reason: Option<String>,
+}
+
+impl BanListEntry {
+    /// Creates a new entry for the given user.
+    #[must_use]
+    pub fn new(user_id: UserId, reason: Option<String>) -> Self {
+        Self {
+            user_id,
+            reason,
+        }
+    }
+}
+
+impl From<Ban> for BanListEntry {
+    fn from(ban: Ban) -> Self {
+        Self::new(ban.user.id, ban.reason.map(String::from))
+    }
+}
+
+/// A list of banned users and their ban reasons, which can be exported to and imported from JSON
+/// or CSV, and applied to a guild.
+///
+/// This is useful for syncing bans between multiple guilds.
+///
+/// # Examples
+///
+/// Copying all bans from one guild to another:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::GuildId;
+/// use serenity::utils::BanList;
+///
+/// # async fn run() -> serenity::Result<()> {
+/// # let http: Http = unimplemented!();
+/// # let (source, target) = (GuildId::new(1), GuildId::new(2));
+/// let bans = BanList::fetch(&http, source).await?;
+/// std::fs::write("bans.csv", bans.to_csv())?;
+///
+/// let report = bans.apply(&http, target, 0).await;
+/// println!("Banned {} users, {} failed", report.banned_users.len(), report.failed_users.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct BanList {
+    /// The entries of this list.
+    pub entries: Vec<BanListEntry>,
+}
+
+impl BanList {
+    /// Creates an empty ban list.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fetches all bans of a guild, following the pagination of [`GuildId::bans`].
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn fetch(http: &Http, guild_id: GuildId) -> Result<Self> {
+        const PAGE_SIZE: u16 = 1000;
+
+        let mut entries = Vec::new();
+        let mut after = None;
+        loop {
+            let target = after.map(UserPagination::After);
+            let bans = guild_id.bans(http, target, NonMaxU16::new(PAGE_SIZE)).await?;
+
+            let page_len = bans.len();
+            after = bans.last().map(|ban| ban.user.id);
+            entries.extend(bans.into_iter().map(BanListEntry::from));
+
+            if page_len < usize::from(PAGE_SIZE) {
+                break;
+            }
+        }
+
+        Ok(Self {
+            entries,
+        })
+    }
+
+    /// Serializes the list to a JSON array of entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(Error::from)
+    }
+
+    /// Deserializes a list from a JSON array of entries, as produced by [`Self::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the input is not a valid ban list.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(Error::from)
+    }
+
+    /// Serializes the list to CSV, with a `user_id,reason` header. Reasons are quoted if
+    /// necessary.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("user_id,reason\n");
+        for entry in &self.entries {
+            csv.push_str(&entry.user_id.to_string());
+            csv.push(',');
+            if let Some(reason) = &entry.reason {
+                if reason.contains([',', '"', '\n', '\r']) {
+                    csv.push('"');
+                    csv.push_str(&reason.replace('"', "\"\""));
+                    csv.push('"');
+                } else {
+                    csv.push_str(reason);
+                }
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// Deserializes a list from CSV, as produced by [`Self::to_csv`].
+    ///
+    /// The header row is optional. Empty reasons are read as [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidBanList`] if a row does not contain a valid user Id, or if a quoted reason
+    /// is not terminated.
+    pub fn from_csv(csv: &str) -> StdResult<Self, InvalidBanList> {
+        let mut entries = Vec::new();
+        for (index, row) in parse_csv(csv)?.into_iter().enumerate() {
+            let mut fields = row.fields.into_iter();
+            let user_id = fields.next().unwrap_or_default();
+            if index == 0 && user_id == "user_id" {
+                continue;
+            }
+
+            let Ok(user_id) = user_id.trim().parse() else {
+                return Err(InvalidBanList {
+                    line: row.line,
+                });
+            };
+            let reason = fields.next().filter(|reason| !reason.is_empty());
+
+            entries.push(BanListEntry::new(user_id, reason));
+        }
+
+        Ok(Self {
+            entries,
+        })
+    }
+
+    /// Bans all users in the list from the given guild, using [`GuildId::bulk_ban`] with chunks
+    /// of at most [`BULK_BAN_LIMIT`] users. Users with the same reason are banned together, with
+    /// the reason used for the audit log.
+    ///
+    /// Failing chunks do not stop the remaining chunks from being applied. Instead, their users
+    /// are reported as failed, alongside the error.
+    ///
+    /// **Note**: Requires the [Ban Members] and [Manage Guild] permissions.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn apply(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        delete_message_seconds: u32,
+    ) -> BanListReport {
+        let mut by_reason: BTreeMap<Option<&str>, Vec<UserId>> = BTreeMap::new();
+        for entry in &self.entries {
+            by_reason.entry(entry.reason.as_deref()).or_default().push(entry.user_id);
+        }
+
+        let mut report = BanListReport::default();
+        for (reason, user_ids) in by_reason {
+            for chunk in user_ids.chunks(BULK_BAN_LIMIT) {
+                match guild_id.bulk_ban(http, chunk, delete_message_seconds, reason).await {
+                    Ok(response) => {
+                        report.banned_users.extend_from_slice(response.banned_users());
+                        report.failed_users.extend_from_slice(response.failed_users());
+                    },
+                    Err(error) => {
+                        report.failed_users.extend_from_slice(chunk);
+                        report.errors.push(error);
+                    },
+                }
+            }
+        }
+
+        report
+    }
+}
+
+impl FromIterator<Ban> for BanList {
+    fn from_iter<T: IntoIterator<Item = Ban>>(iter: T) -> Self {
+        Self {
+            entries: iter.into_iter().map(BanListEntry::from).collect(),
+        }
+    }
+}
+
+/// The aggregated results of [`BanList::apply`].
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct BanListReport {
+    /// The users that were successfully banned.
+    pub banned_users: Vec<UserId>,
+    /// The users that were not successfully banned, either because Discord rejected them or
+    /// because their chunk failed entirely.
+    pub failed_users: Vec<UserId>,
+    /// The errors returned by chunks that failed entirely.
+    pub errors: Vec<Error>,
+}
+
+/// Error that can be returned by [`BanList::from_csv`].
+#[derive(Debug)]
+pub struct InvalidBanList {
+    line: usize,
+}
+
+impl InvalidBanList {
+    /// The line, starting at 1, on which the invalid row starts.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl std::error::Error for InvalidBanList {}
+
+impl fmt::Display for InvalidBanList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid ban list row on line {}", self.line)
+    }
+}
+
+struct CsvRow {
+    line: usize,
+    fields: Vec<String>,
+}
+
+/// Splits CSV into rows of fields, supporting quoted fields containing separators, escaped quotes
+/// and newlines. Blank lines are skipped.
+fn parse_csv(csv: &str) -> StdResult<Vec<CsvRow>, InvalidBanList> {
+    let mut rows = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = 1;
+
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            },
+            '"' if field.is_empty() => in_quotes = true,
+            '\n' if in_quotes => {
+                line += 1;
+                field.push(c);
+            },
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {},
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                if fields.iter().any(|field| !field.is_empty()) {
+                    rows.push(CsvRow {
+                        line: row_line,
+                        fields: std::mem::take(&mut fields),
+                    });
+                } else {
+                    fields.clear();
+                }
+
+                line += 1;
+                row_line = line;
+            },
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(InvalidBanList {
+            line: row_line,
+        });
+    }
+
+    fields.push(field);
+    if fields.iter().any(|field| !field.is_empty()) {
+        rows.push(CsvRow {
+            line: row_line,
+            fields,
+        });
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_round_trip() {
+        let list = BanList {
+            entries: vec![
+                BanListEntry::new(UserId::new(1), None),
+                BanListEntry::new(UserId::new(2), Some("spam".into())),
+                BanListEntry::new(UserId::new(3), Some("said \"hi\", then\nleft".into())),
+            ],
+        };
+
+        let csv = list.to_csv();
+        assert_eq!(csv, "user_id,reason\n1,\n2,spam\n3,\"said \"\"hi\"\", then\nleft\"\n");
+        assert_eq!(BanList::from_csv(&csv).unwrap(), list);
+    }
+
+    #[test]
+    fn csv_invalid_rows() {
+        assert_eq!(BanList::from_csv("user_id,reason\n\nabc,spam\n").unwrap_err().line(), 3);
+        assert_eq!(BanList::from_csv("1,\"unterminated\n").unwrap_err().line(), 1);
+    }
+}
@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use futures::StreamExt as _;
+use tokio::time::{timeout_at, Instant};
+
+use crate::builder::{
+    CreateButton,
+    CreateInteractionResponse,
+    CreateInteractionResponseMessage,
+    CreateMessage,
+    EditMessage,
+};
+use crate::collector::ComponentInteractionCollector;
+use crate::gateway::client::Context;
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// Asks a user to confirm an action, by sending the prompt with "Confirm" and "Cancel" buttons and
+/// waiting for the user to click one of them.
+///
+/// Clicks by other users are acknowledged without effect, and don't extend the timeout. Once the
+/// user answered or the timeout passed, the buttons are removed from the message, leaving the
+/// prompt.
+///
+/// Returns `true` if the user clicked "Confirm", and `false` if they clicked "Cancel" or didn't
+/// answer in time.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use serenity::model::prelude::*;
+/// # use serenity::prelude::*;
+/// # async fn run(ctx: &Context, message: &Message) -> serenity::Result<()> {
+/// use serenity::utils::confirm;
+///
+/// let prompt = "Do you really want to delete all your data?";
+/// if confirm(ctx, message.channel_id, message.author.id, prompt, Duration::from_secs(30)).await? {
+///     // Delete the data.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Http`] if sending or editing the message, or responding to a click fails.
+pub async fn confirm(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user_id: UserId,
+    prompt: impl Into<Cow<'_, str>>,
+    timeout: Duration,
+) -> Result<bool> {
+    let builder = CreateMessage::new()
+        .content(prompt)
+        .button(CreateButton::new("confirm").label("Confirm").style(ButtonStyle::Success))
+        .button(CreateButton::new("cancel").label("Cancel").style(ButtonStyle::Danger));
+    let message = channel_id.send_message(&ctx.http, builder).await?;
+
+    let interactions =
+        ComponentInteractionCollector::new(ctx.shard.clone()).message_id(message.id).stream();
+    tokio::pin!(interactions);
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        let Ok(Some(interaction)) = timeout_at(deadline, interactions.next()).await else {
+            let builder = EditMessage::new().components(Vec::new());
+            channel_id.edit_message(&ctx.http, message.id, builder).await?;
+            return Ok(false);
+        };
+
+        if interaction.user.id != user_id {
+            let response = CreateInteractionResponse::Acknowledge;
+            interaction.create_response(&ctx.http, response).await?;
+            continue;
+        }
+
+        let response = CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new().components(Vec::new()),
+        );
+        interaction.create_response(&ctx.http, response).await?;
+
+        return Ok(interaction.data.custom_id == "confirm");
+    }
+}
+
+#[cfg(all(test, feature = "http_transport"))]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use serde_json::{json, Value};
+
+    use super::confirm;
+    use crate::gateway::client::Context;
+    use crate::http::{HttpBuilder, LightMethod, MockTransport, StatusCode};
+    use crate::model::event::{Event, InteractionCreateEvent};
+    use crate::model::id::{ChannelId, ShardId, UserId};
+
+    fn message() -> Value {
+        json!({
+            "id": "10",
+            "channel_id": "1",
+            "author": {"id": "2", "username": "bot", "discriminator": "0", "avatar": null},
+            "content": "Are you sure?",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        })
+    }
+
+    fn click(user_id: u64, custom_id: &str) -> Event {
+        let interaction = serde_json::from_value(json!({
+            "id": "20",
+            "application_id": "2",
+            "type": 3,
+            "data": {"custom_id": custom_id, "component_type": 2},
+            "channel_id": "1",
+            "user": {
+                "id": user_id.to_string(),
+                "username": "user",
+                "discriminator": "0",
+                "avatar": null,
+            },
+            "token": "token",
+            "version": 1,
+            "message": message(),
+            "locale": "en-US",
+            "entitlements": [],
+        }))
+        .unwrap();
+        Event::InteractionCreate(InteractionCreateEvent {
+            interaction,
+        })
+    }
+
+    fn context() -> (Context, MockTransport) {
+        let callback = "/interactions/*/*/callback";
+        let transport = MockTransport::new()
+            .respond(LightMethod::Post, "/channels/*/messages", StatusCode::OK, message())
+            .respond(LightMethod::Patch, "/channels/*/messages/*", StatusCode::OK, message())
+            .respond(LightMethod::Post, callback, StatusCode::NO_CONTENT, Value::Null);
+        let mut ctx = Context::new_for_test(ShardId(0));
+        ctx.http = Arc::new(HttpBuilder::new("token").transport(transport.clone()).build());
+        (ctx, transport)
+    }
+
+    fn callbacks(transport: &MockTransport) -> Vec<Value> {
+        let requests = transport.requests();
+        let callbacks = requests.iter().filter(|request| request.path.ends_with("/callback"));
+        callbacks.map(|request| request.json().unwrap()).collect()
+    }
+
+    #[tokio::test]
+    async fn confirm_times_out() {
+        let (ctx, transport) = context();
+
+        let timeout = Duration::from_millis(50);
+        let confirmed = confirm(&ctx, ChannelId::new(1), UserId::new(5), "Are you sure?", timeout);
+        assert!(!confirmed.await.unwrap());
+
+        // The buttons are removed once the timeout passed
+        let requests = transport.requests();
+        let edit = requests.last().unwrap();
+        assert_eq!(edit.path, "/api/v10/channels/1/messages/10");
+        assert_eq!(edit.json().unwrap()["components"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn confirm_ignores_other_users() {
+        let (ctx, transport) = context();
+
+        let task_ctx = ctx.clone();
+        let task = tokio::spawn(async move {
+            let timeout = Duration::from_secs(10);
+            confirm(&task_ctx, ChannelId::new(1), UserId::new(5), "Are you sure?", timeout).await
+        });
+        while ctx.shard.event_bus.subscriber_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        ctx.shard.event_bus.publish(ShardId(0), &click(6, "confirm"));
+        ctx.shard.event_bus.publish(ShardId(0), &click(5, "cancel"));
+        assert!(!task.await.unwrap().unwrap());
+
+        let callbacks = callbacks(&transport);
+        assert_eq!(callbacks[0]["type"], 6);
+        assert_eq!(callbacks[1]["type"], 7);
+        assert_eq!(callbacks[1]["data"]["components"], json!([]));
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use super::{
+    parse_channel_mention,
+    parse_emoji,
+    parse_role_mention,
+    parse_user_mention,
+    FormattedTimestamp,
+};
+use crate::model::mention::Mention;
+use crate::model::prelude::*;
+
+/// Characters which are escaped from formatting when preceded by a backslash.
+const ESCAPABLE: &[u8] = b"\\`|*_~<>:#@[]()-";
+
+/// A segment of message content, as returned by [`parse_content`].
+///
+/// The [`Display`] implementation formats the segment back into message content, so segments can
+/// be rewritten and joined into a new message.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ContentSegment<'a> {
+    /// Text without any of the other segments, including markdown such as bold or italics.
+    Text(&'a str),
+    /// A user mention, such as `<@114941315417899012>`.
+    UserMention(UserId),
+    /// A role mention, such as `<@&136107769680887808>`.
+    RoleMention(RoleId),
+    /// A channel mention, such as `<#81384788765712384>`.
+    ChannelMention(ChannelId),
+    /// A custom emoji, such as `<:smugAnimeFace:302516740095606785>`.
+    CustomEmoji(EmojiIdentifier),
+    /// A formatted timestamp, such as `<t:1618953630:R>`.
+    Timestamp(FormattedTimestamp),
+    /// A code block surrounded by three backticks, with the language of its syntax highlighting.
+    CodeBlock { lang: Option<&'a str>, code: &'a str },
+    /// Inline code surrounded by one or two backticks.
+    InlineCode(&'a str),
+    /// A URL, which doesn't embed if surrounded by angle brackets.
+    Link { url: &'a str, suppress_embed: bool },
+    /// Content hidden behind a spoiler, surrounded by `||`.
+    Spoiler(Vec<ContentSegment<'a>>),
+}
+
+impl fmt::Display for ContentSegment<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::UserMention(user_id) => Mention::from(*user_id).fmt(f),
+            Self::RoleMention(role_id) => Mention::from(*role_id).fmt(f),
+            Self::ChannelMention(channel_id) => Mention::from(*channel_id).fmt(f),
+            Self::CustomEmoji(emoji) => {
+                let animated = if emoji.animated { "a" } else { "" };
+                write!(f, "<{animated}:{}:{}>", emoji.name, emoji.id)
+            },
+            Self::Timestamp(timestamp) => timestamp.fmt(f),
+            Self::CodeBlock {
+                lang,
+                code,
+            } => match lang {
+                Some(lang) => write!(f, "```{lang}\n{code}```"),
+                None => write!(f, "```{code}```"),
+            },
+            Self::InlineCode(code) => {
+                if code.contains('`') {
+                    write!(f, "``{code}``")
+                } else {
+                    write!(f, "`{code}`")
+                }
+            },
+            Self::Link {
+                url,
+                suppress_embed,
+            } => {
+                if *suppress_embed {
+                    write!(f, "<{url}>")
+                } else {
+                    f.write_str(url)
+                }
+            },
+            Self::Spoiler(segments) => {
+                f.write_str("||")?;
+                for segment in segments {
+                    segment.fmt(f)?;
+                }
+                f.write_str("||")
+            },
+        }
+    }
+}
+
+/// Splits message content into [`ContentSegment`]s, such as mentions, emojis and code.
+///
+/// Mentions and links within code are left as is, while spoilers are split into segments of their
+/// own. Formatting escaped with a backslash is kept as [`ContentSegment::Text`].
+///
+/// # Examples
+///
+/// Replacing role mentions with the role's name:
+///
+/// ```rust
+/// use serenity::utils::{parse_content, ContentSegment};
+///
+/// let content = "Hello <@&136107769680887808>, see `<@&1>` ||<@&136107769680887808>||";
+/// let rewritten: String = parse_content(content)
+///     .into_iter()
+///     .map(|segment| match segment {
+///         ContentSegment::RoleMention(_) => "@Moderators".to_string(),
+///         segment => segment.to_string(),
+///     })
+///     .collect();
+///
+/// assert_eq!(rewritten, "Hello @Moderators, see `<@&1>` ||<@&136107769680887808>||");
+/// ```
+#[must_use]
+pub fn parse_content(content: &str) -> Vec<ContentSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < content.len() {
+        let rest = &content[i..];
+        if rest.starts_with('\\') && rest.as_bytes().get(1).is_some_and(|c| ESCAPABLE.contains(c)) {
+            i += 2;
+            continue;
+        }
+
+        if let Some((segment, len)) = parse_segment(rest) {
+            if text_start < i {
+                segments.push(ContentSegment::Text(&content[text_start..i]));
+            }
+            segments.push(segment);
+            i += len;
+            text_start = i;
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    if text_start < content.len() {
+        segments.push(ContentSegment::Text(&content[text_start..]));
+    }
+
+    segments
+}
+
+/// Parses the segment at the start of the given content, returning it and its length.
+fn parse_segment(rest: &str) -> Option<(ContentSegment<'_>, usize)> {
+    match rest.as_bytes()[0] {
+        b'`' => parse_code(rest),
+        b'|' => {
+            let inner = rest.strip_prefix("||")?;
+            let end = inner.find("||").filter(|end| *end > 0)?;
+            Some((ContentSegment::Spoiler(parse_content(&inner[..end])), end + 4))
+        },
+        b'<' => parse_angle_brackets(rest),
+        b'h' if rest.starts_with("https://") || rest.starts_with("http://") => {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let url = rest[..end].trim_end_matches(['.', ',', ':', ';', '!', '?', '"', '\'', ')']);
+            Some((
+                ContentSegment::Link {
+                    url,
+                    suppress_embed: false,
+                },
+                url.len(),
+            ))
+        },
+        _ => None,
+    }
+}
+
+fn parse_code(rest: &str) -> Option<(ContentSegment<'_>, usize)> {
+    if let Some(inner) = rest.strip_prefix("```") {
+        if let Some(end) = inner.find("```") {
+            let block = &inner[..end];
+            let (lang, code) = match block.split_once('\n') {
+                Some((lang, code)) if !lang.is_empty() && !lang.contains(char::is_whitespace) => {
+                    (Some(lang), code)
+                },
+                _ => (None, block),
+            };
+            return Some((
+                ContentSegment::CodeBlock {
+                    lang,
+                    code,
+                },
+                end + 6,
+            ));
+        }
+    }
+
+    let delimiter = if rest.starts_with("``") { "``" } else { "`" };
+    let inner = &rest[delimiter.len()..];
+    let end = inner.find(delimiter).filter(|end| *end > 0)?;
+    Some((ContentSegment::InlineCode(&inner[..end]), end + delimiter.len() * 2))
+}
+
+fn parse_angle_brackets(rest: &str) -> Option<(ContentSegment<'_>, usize)> {
+    let end = rest[1..].find(['>', '<', ' ', '\n'])? + 1;
+    if rest.as_bytes()[end] != b'>' {
+        return None;
+    }
+
+    let tag = &rest[..=end];
+    let segment = if let Some(role_id) = parse_role_mention(tag) {
+        ContentSegment::RoleMention(role_id)
+    } else if let Some(user_id) = parse_user_mention(tag) {
+        ContentSegment::UserMention(user_id)
+    } else if let Some(channel_id) = parse_channel_mention(tag) {
+        ContentSegment::ChannelMention(channel_id)
+    } else if let Some(emoji) = parse_emoji(tag) {
+        ContentSegment::CustomEmoji(emoji)
+    } else if let Ok(timestamp) = tag.parse() {
+        ContentSegment::Timestamp(timestamp)
+    } else {
+        let url = &tag[1..end];
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return None;
+        }
+        ContentSegment::Link {
+            url,
+            suppress_embed: true,
+        }
+    };
+
+    Some((segment, end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_content_segments() {
+        let content = "Hi <@1> and <@!2> in <#3> <@&4> <a:wave:5> at <t:6:R>: \
+                       ```rust\nlet x = 1;``` `<@7>` ``a`b`` ||see <https://a.b> \\<@8>|| \
+                       https://example.com/x. <nope>";
+        let segments = parse_content(content);
+
+        assert_eq!(segments, vec![
+            ContentSegment::Text("Hi "),
+            ContentSegment::UserMention(UserId::new(1)),
+            ContentSegment::Text(" and "),
+            ContentSegment::UserMention(UserId::new(2)),
+            ContentSegment::Text(" in "),
+            ContentSegment::ChannelMention(ChannelId::new(3)),
+            ContentSegment::Text(" "),
+            ContentSegment::RoleMention(RoleId::new(4)),
+            ContentSegment::Text(" "),
+            ContentSegment::CustomEmoji(EmojiIdentifier {
+                animated: true,
+                id: EmojiId::new(5),
+                name: FixedString::from_static_trunc("wave"),
+            }),
+            ContentSegment::Text(" at "),
+            ContentSegment::Timestamp("<t:6:R>".parse().unwrap()),
+            ContentSegment::Text(": "),
+            ContentSegment::CodeBlock {
+                lang: Some("rust"),
+                code: "let x = 1;",
+            },
+            ContentSegment::Text(" "),
+            ContentSegment::InlineCode("<@7>"),
+            ContentSegment::Text(" "),
+            ContentSegment::InlineCode("a`b"),
+            ContentSegment::Text(" "),
+            ContentSegment::Spoiler(vec![
+                ContentSegment::Text("see "),
+                ContentSegment::Link {
+                    url: "https://a.b",
+                    suppress_embed: true,
+                },
+                ContentSegment::Text(" \\<@8>"),
+            ]),
+            ContentSegment::Text(" "),
+            ContentSegment::Link {
+                url: "https://example.com/x",
+                suppress_embed: false,
+            },
+            ContentSegment::Text(". <nope>"),
+        ]);
+
+        let joined: String = segments.iter().map(ToString::to_string).collect();
+        assert_eq!(joined, content.replace("<@!2>", "<@2>"));
+    }
+
+    #[test]
+    fn parse_unterminated_content() {
+        assert_eq!(parse_content("```a ||c <@1"), vec![ContentSegment::Text("```a ||c <@1")]);
+        assert_eq!(parse_content("```\nx```"), vec![ContentSegment::CodeBlock {
+            lang: None,
+            code: "\nx",
+        }]);
+        let unicode = "\u{fc}n\u{ef}code";
+        assert_eq!(parse_content(unicode), vec![ContentSegment::Text(unicode)]);
+    }
+}
@@ -0,0 +1,446 @@
+use std::fmt;
+use std::str::Split;
+
+use crate::model::id::*;
+
+/// The maximum length of a component's `custom_id`, in characters.
+const CUSTOM_ID_LIMIT: usize = 100;
+
+/// The digits used to encode numbers compactly.
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// An error encoding or decoding a [`CustomId`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CustomIdError {
+    /// The encoded `custom_id` is longer than the 100 characters Discord allows.
+    TooLong(usize),
+    /// The `custom_id` doesn't start with the expected prefix, so it belongs to another component.
+    Prefix,
+    /// The `custom_id` was encoded with another version, such as by an older release of the bot.
+    Version {
+        /// The version expected by the type.
+        expected: u8,
+        /// The version the `custom_id` was encoded with, if it could be parsed.
+        found: Option<u8>,
+    },
+    /// The field at the given index couldn't be decoded.
+    Field(usize),
+    /// The `custom_id` contains fewer or more fields than the type has.
+    FieldCount,
+}
+
+impl fmt::Display for CustomIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong(len) => {
+                write!(f, "Custom ID is {len} characters long, at most {CUSTOM_ID_LIMIT} allowed")
+            },
+            Self::Prefix => f.write_str("Custom ID has an unexpected prefix"),
+            Self::Version {
+                expected,
+                found: Some(found),
+            } => write!(f, "Custom ID has version {found}, expected {expected}"),
+            Self::Version {
+                expected,
+                found: None,
+            } => write!(f, "Custom ID has an invalid version, expected {expected}"),
+            Self::Field(index) => write!(f, "Custom ID field {index} is invalid"),
+            Self::FieldCount => f.write_str("Custom ID has an unexpected number of fields"),
+        }
+    }
+}
+
+impl std::error::Error for CustomIdError {}
+
+/// State that is stored in the `custom_id` of a component, so handling interactions with it
+/// doesn't require keeping the state anywhere else.
+///
+/// The `custom_id` consists of a prefix identifying the type, its version and the fields, separated
+/// by colons, such as `delete:1:1MuK794uAbS:5w0YM0RIFk`. Numbers and IDs are encoded in base 62
+/// to keep it short. Decoding fails if the prefix or version don't match, so bumping the version
+/// when changing the fields makes components sent by older releases of the bot fail cleanly.
+///
+/// Rather than implementing this by hand, use the [`custom_id!`] macro.
+///
+/// [`custom_id!`]: crate::custom_id
+pub trait CustomId: Sized {
+    /// The prefix identifying the type. Must not contain colons.
+    const PREFIX: &'static str;
+    /// The version of the fields.
+    const VERSION: u8;
+
+    /// Encodes the fields in order.
+    fn encode_fields(&self, encoder: &mut CustomIdEncoder);
+
+    /// Decodes the fields in the order they were encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CustomIdError`] if a field couldn't be decoded.
+    fn decode_fields(decoder: &mut CustomIdDecoder<'_>) -> Result<Self, CustomIdError>;
+
+    /// Encodes the value into a `custom_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomIdError::TooLong`] if the `custom_id` is longer than Discord allows.
+    fn encode(&self) -> Result<String, CustomIdError> {
+        let mut encoder = CustomIdEncoder(String::from(Self::PREFIX));
+        encoder.field(&Self::VERSION);
+        self.encode_fields(&mut encoder);
+
+        let len = encoder.0.chars().count();
+        if len > CUSTOM_ID_LIMIT {
+            return Err(CustomIdError::TooLong(len));
+        }
+
+        Ok(encoder.0)
+    }
+
+    /// Decodes a value from a `custom_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CustomIdError`] if the prefix or version don't match, or the fields couldn't be
+    /// decoded.
+    fn decode(custom_id: &str) -> Result<Self, CustomIdError> {
+        let mut parts = custom_id.split(':');
+        if parts.next() != Some(Self::PREFIX) {
+            return Err(CustomIdError::Prefix);
+        }
+
+        let found = parts.next().and_then(u8::decode);
+        if found != Some(Self::VERSION) {
+            return Err(CustomIdError::Version {
+                expected: Self::VERSION,
+                found,
+            });
+        }
+
+        let mut decoder = CustomIdDecoder {
+            parts,
+            index: 0,
+        };
+        let value = Self::decode_fields(&mut decoder)?;
+        if decoder.parts.next().is_some() {
+            return Err(CustomIdError::FieldCount);
+        }
+
+        Ok(value)
+    }
+}
+
+/// Writes the fields of a [`CustomId`].
+#[derive(Debug)]
+pub struct CustomIdEncoder(String);
+
+impl CustomIdEncoder {
+    /// Appends a field.
+    pub fn field<T: CustomIdField>(&mut self, value: &T) {
+        self.0.push(':');
+        value.encode(&mut self.0);
+    }
+}
+
+/// Reads the fields of a [`CustomId`].
+#[derive(Debug)]
+pub struct CustomIdDecoder<'a> {
+    parts: Split<'a, char>,
+    index: usize,
+}
+
+impl CustomIdDecoder<'_> {
+    /// Reads the next field.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CustomIdError::FieldCount`] if there are no fields left, or
+    /// [`CustomIdError::Field`] if the field couldn't be decoded.
+    pub fn field<T: CustomIdField>(&mut self) -> Result<T, CustomIdError> {
+        let part = self.parts.next().ok_or(CustomIdError::FieldCount)?;
+        let value = T::decode(part).ok_or(CustomIdError::Field(self.index))?;
+        self.index += 1;
+        Ok(value)
+    }
+}
+
+/// A value that can be stored as a field of a [`CustomId`].
+///
+/// This is implemented for integers, [`bool`], [`String`] and IDs.
+pub trait CustomIdField: Sized {
+    /// Appends the encoded value, which must not contain colons.
+    fn encode(&self, out: &mut String);
+
+    /// Decodes a value, returning [`None`] if it is invalid.
+    fn decode(value: &str) -> Option<Self>;
+}
+
+fn encode_base62(mut value: u64, out: &mut String) {
+    // u64::MAX has 11 digits in base 62.
+    let mut digits = [0; 11];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = BASE62[(value % 62) as usize];
+        value /= 62;
+        if value == 0 {
+            break;
+        }
+    }
+
+    out.extend(digits[start..].iter().map(|&digit| char::from(digit)));
+}
+
+fn decode_base62(value: &str) -> Option<u64> {
+    if value.is_empty() {
+        return None;
+    }
+
+    value.bytes().try_fold(0_u64, |acc, byte| {
+        let digit = BASE62.iter().position(|&d| d == byte)?;
+        acc.checked_mul(62)?.checked_add(digit as u64)
+    })
+}
+
+macro_rules! unsigned_field {
+    ($($ty:ty),*) => {$(
+        impl CustomIdField for $ty {
+            fn encode(&self, out: &mut String) {
+                encode_base62(u64::from(*self), out);
+            }
+
+            fn decode(value: &str) -> Option<Self> {
+                decode_base62(value)?.try_into().ok()
+            }
+        }
+    )*};
+}
+
+macro_rules! signed_field {
+    ($($ty:ty),*) => {$(
+        impl CustomIdField for $ty {
+            fn encode(&self, out: &mut String) {
+                if *self < 0 {
+                    out.push('-');
+                }
+                encode_base62(u64::from(self.unsigned_abs()), out);
+            }
+
+            fn decode(value: &str) -> Option<Self> {
+                match value.strip_prefix('-') {
+                    Some(abs) => (-i128::from(decode_base62(abs)?)).try_into().ok(),
+                    None => decode_base62(value)?.try_into().ok(),
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! id_field {
+    ($($ty:ty),*) => {$(
+        impl CustomIdField for $ty {
+            fn encode(&self, out: &mut String) {
+                encode_base62(self.get(), out);
+            }
+
+            fn decode(value: &str) -> Option<Self> {
+                decode_base62(value).filter(|&id| id != u64::MAX).map(<$ty>::new)
+            }
+        }
+    )*};
+}
+
+unsigned_field!(u8, u16, u32, u64);
+signed_field!(i8, i16, i32, i64);
+id_field!(
+    ApplicationId,
+    AttachmentId,
+    ChannelId,
+    CommandId,
+    EmojiId,
+    GenericId,
+    GuildId,
+    MessageId,
+    RoleId,
+    ScheduledEventId,
+    UserId
+);
+
+impl CustomIdField for bool {
+    fn encode(&self, out: &mut String) {
+        out.push(if *self { '1' } else { '0' });
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        match value {
+            "1" => Some(true),
+            "0" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl CustomIdField for String {
+    fn encode(&self, out: &mut String) {
+        for c in self.chars() {
+            match c {
+                '%' => out.push_str("%25"),
+                ':' => out.push_str("%3A"),
+                c => out.push(c),
+            }
+        }
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        let mut decoded = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(i) = rest.find('%') {
+            decoded.push_str(&rest[..i]);
+            match rest.get(i..i + 3)? {
+                "%25" => decoded.push('%'),
+                "%3A" => decoded.push(':'),
+                _ => return None,
+            }
+            rest = &rest[i + 3..];
+        }
+
+        decoded.push_str(rest);
+        Some(decoded)
+    }
+}
+
+/// Declares a struct stored in the `custom_id` of components, implementing [`CustomId`] for it.
+///
+/// The struct is preceded by a `#[custom_id(prefix = "...", version = ...)]` line, setting
+/// [`CustomId::PREFIX`] and [`CustomId::VERSION`]. Fields are encoded in the order they are
+/// declared, and have to implement [`CustomIdField`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::custom_id;
+/// use serenity::model::id::{MessageId, UserId};
+/// use serenity::utils::CustomId;
+///
+/// custom_id! {
+///     #[custom_id(prefix = "delete", version = 1)]
+///     #[derive(Debug, PartialEq)]
+///     pub struct DeleteButton {
+///         pub message_id: MessageId,
+///         pub requested_by: UserId,
+///     }
+/// }
+///
+/// let button = DeleteButton {
+///     message_id: MessageId::new(1149413154010353754),
+///     requested_by: UserId::new(80351110224678912),
+/// };
+///
+/// let custom_id = button.encode()?;
+/// assert_eq!(custom_id, "delete:1:1MuK794uAbS:5w0YM0RIFk");
+/// assert_eq!(DeleteButton::decode(&custom_id)?, button);
+/// # Ok::<_, serenity::utils::CustomIdError>(())
+/// ```
+///
+/// [`CustomId`]: crate::utils::CustomId
+/// [`CustomId::PREFIX`]: crate::utils::CustomId::PREFIX
+/// [`CustomId::VERSION`]: crate::utils::CustomId::VERSION
+/// [`CustomIdField`]: crate::utils::CustomIdField
+#[macro_export]
+macro_rules! custom_id {
+    (
+        #[custom_id(prefix = $prefix:literal, version = $version:literal)]
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident: $ty:ty),*
+            $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($(#[$field_attr])* $field_vis $field: $ty,)*
+        }
+
+        impl $crate::utils::CustomId for $name {
+            const PREFIX: &'static str = $prefix;
+            const VERSION: u8 = $version;
+
+            fn encode_fields(&self, encoder: &mut $crate::utils::CustomIdEncoder) {
+                $(encoder.field(&self.$field);)*
+            }
+
+            fn decode_fields(
+                decoder: &mut $crate::utils::CustomIdDecoder<'_>,
+            ) -> ::std::result::Result<Self, $crate::utils::CustomIdError> {
+                ::std::result::Result::Ok(Self {
+                    $($field: decoder.field()?,)*
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomId, CustomIdError, CustomIdField};
+    use crate::model::id::ChannelId;
+
+    custom_id! {
+        #[custom_id(prefix = "page", version = 2)]
+        #[derive(Debug, PartialEq)]
+        struct SearchPage {
+            channel_id: ChannelId,
+            page: i32,
+            query: String,
+            ascending: bool,
+        }
+    }
+
+    #[test]
+    fn custom_id_round_trip() {
+        let page = SearchPage {
+            channel_id: ChannelId::new(381880193700069377),
+            page: -3,
+            query: "50%: done".to_string(),
+            ascending: true,
+        };
+
+        let custom_id = page.encode().unwrap();
+        assert_eq!(custom_id, "page:2:SD0wxcLKin:-3:50%25%3A done:1");
+        assert_eq!(SearchPage::decode(&custom_id).unwrap(), page);
+
+        assert_eq!(SearchPage::decode("other:2:1:1:a:1"), Err(CustomIdError::Prefix));
+        assert_eq!(
+            SearchPage::decode("page:1:1:1:a:1"),
+            Err(CustomIdError::Version {
+                expected: 2,
+                found: Some(1)
+            })
+        );
+        assert_eq!(SearchPage::decode("page:2:1:1:a"), Err(CustomIdError::FieldCount));
+        assert_eq!(SearchPage::decode("page:2:1:1:a:1:x"), Err(CustomIdError::FieldCount));
+        assert_eq!(SearchPage::decode("page:2:1:1:a:yes"), Err(CustomIdError::Field(3)));
+
+        let long = SearchPage {
+            query: "a".repeat(100),
+            ..page
+        };
+        assert_eq!(long.encode(), Err(CustomIdError::TooLong(123)));
+    }
+
+    #[test]
+    fn custom_id_fields() {
+        for value in [0, 61, 62, u64::MAX] {
+            let mut out = String::new();
+            value.encode(&mut out);
+            assert_eq!(u64::decode(&out), Some(value));
+        }
+
+        let mut out = String::new();
+        i8::MIN.encode(&mut out);
+        assert_eq!(i8::decode(&out), Some(i8::MIN));
+        assert_eq!(u8::decode("Zz"), None);
+        assert_eq!(String::decode("%2"), None);
+    }
+}
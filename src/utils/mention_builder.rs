@@ -0,0 +1,294 @@
+use super::message_builder::escape_everyone;
+use super::{Content, FormattedTimestampStyle, MessageBuilder};
+use crate::builder::CreateAllowedMentions;
+use crate::model::id::{ChannelId, RoleId, UserId};
+use crate::model::mention::{Mention, Mentionable};
+use crate::model::Timestamp;
+
+/// A builder for message content containing mentions, which computes the
+/// [`CreateAllowedMentions`] needed for exactly those mentions to ping.
+///
+/// Mentions added via [`Self::user`], [`Self::role`], [`Self::everyone`] and [`Self::here`] will
+/// ping, while those added via [`Self::user_silent`] and [`Self::role_silent`] only display the
+/// mention. Any mention contained in text pushed via [`Self::push`] will not ping either, as the
+/// computed allowed mentions only contain the users and roles mentioned via this builder.
+///
+/// Text pushed to the builder has `@everyone` and `@here` escaped with a zero-width space, so they
+/// neither ping nor display as a mention unless added via [`Self::everyone`] or [`Self::here`].
+///
+/// Discord decides whether to ping per user and role, so if the same user is mentioned both with
+/// and without a ping, every mention of that user will ping.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::{ChannelId, RoleId, UserId};
+/// #
+/// # async fn run(http: &Http, channel_id: ChannelId) -> Result<(), serenity::Error> {
+/// use serenity::builder::CreateMessage;
+/// use serenity::utils::MentionBuilder;
+///
+/// let (content, allowed_mentions) = MentionBuilder::new()
+///     .role(RoleId::new(182894738100322304))
+///     .push(": ")
+///     .user_silent(UserId::new(110372470472613888))
+///     .push(" has released a new version!")
+///     .build();
+///
+/// let builder = CreateMessage::new().content(content).allowed_mentions(allowed_mentions);
+/// channel_id.send_message(http, builder).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MentionBuilder {
+    content: MessageBuilder,
+    users: Vec<UserId>,
+    roles: Vec<RoleId>,
+    everyone: bool,
+    replied_user: Option<bool>,
+}
+
+impl MentionBuilder {
+    /// Creates a new, empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes text to the content, without pinging any mentions it may contain.
+    ///
+    /// See [`MessageBuilder::push`].
+    pub fn push<'a>(mut self, content: impl Into<Content<'a>>) -> Self {
+        self.push_escaped(content.into(), |builder, content| builder.push(content));
+        self
+    }
+
+    /// Pushes text to the content followed by a newline, without pinging any mentions it may
+    /// contain.
+    ///
+    /// See [`MessageBuilder::push_line`].
+    pub fn push_line<'a>(mut self, content: impl Into<Content<'a>>) -> Self {
+        self.push_escaped(content.into(), |builder, content| builder.push_line(content));
+        self
+    }
+
+    /// Pushes normalized text to the content, escaping any markdown and mentions.
+    ///
+    /// See [`MessageBuilder::push_safe`].
+    pub fn push_safe<'a>(mut self, content: impl Into<Content<'a>>) -> Self {
+        self.content = self.content.push_safe(content);
+        self
+    }
+
+    /// Pushes a code block with optional syntax highlighting to the content, normalizing the code
+    /// so it can't end the block early.
+    ///
+    /// See [`MessageBuilder::push_codeblock_safe`].
+    pub fn push_codeblock_safe<'a>(
+        mut self,
+        content: impl Into<Content<'a>>,
+        language: Option<&str>,
+    ) -> Self {
+        self.content = self.content.push_codeblock_safe(content, language);
+        self
+    }
+
+    /// Pushes a header to the content, on its own line.
+    ///
+    /// See [`MessageBuilder::push_header`].
+    pub fn push_header<'a>(mut self, content: impl Into<Content<'a>>, level: u8) -> Self {
+        self.push_escaped(content.into(), |builder, content| builder.push_header(content, level));
+        self
+    }
+
+    /// Pushes subtext to the content, on its own line.
+    ///
+    /// See [`MessageBuilder::push_subtext`].
+    pub fn push_subtext<'a>(mut self, content: impl Into<Content<'a>>) -> Self {
+        self.push_escaped(content.into(), |builder, content| builder.push_subtext(content));
+        self
+    }
+
+    /// Pushes a timestamp, displayed in each user's timezone and locale, to the content.
+    ///
+    /// See [`MessageBuilder::push_timestamp`].
+    pub fn push_timestamp(
+        mut self,
+        timestamp: Timestamp,
+        style: Option<FormattedTimestampStyle>,
+    ) -> Self {
+        self.content = self.content.push_timestamp(timestamp, style);
+        self
+    }
+
+    /// Pushes a masked link to the content, escaping the name and URL so the link can't be broken
+    /// out of.
+    ///
+    /// See [`MessageBuilder::push_masked_link`].
+    pub fn push_masked_link(mut self, name: &str, url: &str) -> Self {
+        self.content = self.content.push_masked_link(&escape_everyone(name), url);
+        self
+    }
+
+    /// Mentions the channel in the content. Channel mentions never ping.
+    pub fn channel(mut self, channel: ChannelId) -> Self {
+        self.content = self.content.channel(channel);
+        self
+    }
+
+    /// Mentions the user in the content, and allows the mention to ping them.
+    pub fn user(mut self, user: UserId) -> Self {
+        if !self.users.contains(&user) {
+            self.users.push(user);
+        }
+
+        self.user_silent(user)
+    }
+
+    /// Mentions the user in the content, without pinging them.
+    pub fn user_silent(mut self, user: UserId) -> Self {
+        self.content = self.content.user(user);
+        self
+    }
+
+    /// Mentions the role in the content, and allows the mention to ping its members.
+    ///
+    /// **Note**: Members are only pinged if the role is mentionable, or if the author has the
+    /// [Mention Everyone] permission.
+    ///
+    /// [Mention Everyone]: crate::model::permissions::Permissions::MENTION_EVERYONE
+    pub fn role(mut self, role: RoleId) -> Self {
+        if !self.roles.contains(&role) {
+            self.roles.push(role);
+        }
+
+        self.role_silent(role)
+    }
+
+    /// Mentions the role in the content, without pinging its members.
+    pub fn role_silent(mut self, role: RoleId) -> Self {
+        self.content = self.content.role(role);
+        self
+    }
+
+    /// Mentions something that implements the [`Mentionable`] trait, pinging it if it is a user or
+    /// role.
+    pub fn mention<M: Mentionable>(self, item: &M) -> Self {
+        match item.mention() {
+            Mention::Channel(channel) => self.channel(channel),
+            Mention::Role(role) => self.role(role),
+            Mention::User(user) => self.user(user),
+        }
+    }
+
+    /// Pushes an `@everyone` mention to the content, and allows it to ping.
+    pub fn everyone(mut self) -> Self {
+        self.everyone = true;
+        self.content = self.content.push("@everyone");
+        self
+    }
+
+    /// Pushes an `@here` mention to the content, and allows it to ping.
+    pub fn here(mut self) -> Self {
+        self.everyone = true;
+        self.content = self.content.push("@here");
+        self
+    }
+
+    /// Sets whether the author of the message being replied to is pinged. This is independent of
+    /// the mentions in the content.
+    pub fn replied_user(mut self, mention_user: bool) -> Self {
+        self.replied_user = Some(mention_user);
+        self
+    }
+
+    /// Pushes the content via `push`, with `@everyone` and `@here` escaped.
+    fn push_escaped(
+        &mut self,
+        content: Content<'_>,
+        push: impl FnOnce(MessageBuilder, Content<'_>) -> MessageBuilder,
+    ) {
+        let escaped = escape_everyone(content.inner);
+        let content = Content {
+            inner: &escaped,
+            ..content
+        };
+
+        self.content = push(std::mem::take(&mut self.content), content);
+    }
+
+    /// Returns the allowed mentions pinging exactly the mentions added so far.
+    pub fn allowed_mentions(&self) -> CreateAllowedMentions<'static> {
+        let allowed_mentions = CreateAllowedMentions::new()
+            .everyone(self.everyone)
+            .users(self.users.clone())
+            .roles(self.roles.clone());
+
+        match self.replied_user {
+            Some(mention_user) => allowed_mentions.replied_user(mention_user),
+            None => allowed_mentions,
+        }
+    }
+
+    /// Returns the built content, along with the allowed mentions pinging exactly the mentions
+    /// contained in it.
+    pub fn build(self) -> (String, CreateAllowedMentions<'static>) {
+        let allowed_mentions = self.allowed_mentions();
+        (self.content.build(), allowed_mentions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_mentions_match_content() {
+        let (content, allowed_mentions) = MentionBuilder::new()
+            .user(UserId::new(1))
+            .push(" <@2> ")
+            .user_silent(UserId::new(3))
+            .role(RoleId::new(4))
+            .user(UserId::new(1))
+            .build();
+
+        assert_eq!(content, "<@1> <@2> <@3><@&4><@1>");
+        assert_eq!(
+            serde_json::to_value(allowed_mentions).unwrap(),
+            serde_json::json!({"parse": [], "users": ["1"], "roles": ["4"]})
+        );
+    }
+
+    #[test]
+    fn pushed_text_does_not_ping_everyone() {
+        let (content, allowed_mentions) = MentionBuilder::new()
+            .push_header("@here", 1)
+            .push("hello @everyone ")
+            .everyone()
+            .build();
+
+        assert_eq!(content, "# @\u{200B}here\nhello @\u{200B}everyone @everyone");
+        assert_eq!(
+            serde_json::to_value(allowed_mentions).unwrap(),
+            serde_json::json!({"parse": ["everyone"], "users": [], "roles": []})
+        );
+    }
+
+    #[test]
+    fn everyone_and_replied_user() {
+        let (content, allowed_mentions) = MentionBuilder::new()
+            .here()
+            .push(" ")
+            .role_silent(RoleId::new(1))
+            .replied_user(false)
+            .build();
+
+        assert_eq!(content, "@here <@&1>");
+        assert_eq!(
+            serde_json::to_value(allowed_mentions).unwrap(),
+            serde_json::json!({"parse": ["everyone"], "users": [], "roles": [], "replied_user": false})
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use std::fmt::{self as fmt, Write};
 use std::ops::Add;
 
+use super::{FormattedTimestamp, FormattedTimestampStyle};
 use crate::model::guild::Emoji;
 use crate::model::id::{ChannelId, RoleId, UserId};
 use crate::model::mention::Mentionable;
+use crate::model::Timestamp;
 
 /// The Message Builder is an ergonomic utility to easily build a message, by adding text and
 /// mentioning mentionable structs.
@@ -769,6 +771,125 @@ impl MessageBuilder {
         this
     }
 
+    /// Pushes a header to the content, on its own line. The `level` is clamped between 1, the
+    /// largest header, and 3, the smallest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Release notes")
+    ///     .push_header("Fixes", 2)
+    ///     .push_line("- Stuff works now")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Release notes\n## Fixes\n- Stuff works now\n");
+    /// ```
+    pub fn push_header<'a>(mut self, content: impl Into<Content<'a>>, level: u8) -> Self {
+        self.start_line();
+        for _ in 0..level.clamp(1, 3) {
+            self.0.push('#');
+        }
+
+        self.0.push(' ');
+        self.push_line(content)
+    }
+
+    /// Pushes subtext, which is displayed in a smaller and dimmer font, to the content, on its own
+    /// line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push("Done!").push_subtext("Took 2 seconds").build();
+    ///
+    /// assert_eq!(content, "Done!\n-# Took 2 seconds\n");
+    /// ```
+    pub fn push_subtext<'a>(mut self, content: impl Into<Content<'a>>) -> Self {
+        self.start_line();
+        self.0.push_str("-# ");
+        self.push_line(content)
+    }
+
+    /// Pushes a timestamp, which is displayed in each user's timezone and locale, to the content.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::Timestamp;
+    /// use serenity::utils::{FormattedTimestampStyle, MessageBuilder};
+    ///
+    /// let timestamp = Timestamp::from_unix_timestamp(1618953630).unwrap();
+    /// let content = MessageBuilder::new()
+    ///     .push("Ends ")
+    ///     .push_timestamp(timestamp, Some(FormattedTimestampStyle::RelativeTime))
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Ends <t:1618953630:R>");
+    /// ```
+    pub fn push_timestamp(
+        mut self,
+        timestamp: Timestamp,
+        style: Option<FormattedTimestampStyle>,
+    ) -> Self {
+        self._push(&FormattedTimestamp::new(timestamp, style));
+        self
+    }
+
+    /// Pushes a masked link, displaying `name` as a link to `url`, to the content.
+    ///
+    /// Brackets in the name are escaped, and characters in the URL which would end the link early
+    /// are percent-encoded, so the link can't be broken out of. Unlike
+    /// [`EmbedMessageBuilding::push_named_link_safe`], this keeps the URL working.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_masked_link("Docs [v2]", "https://example.com/a (b)")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "[Docs \\[v2\\]](https://example.com/a%20%28b%29)");
+    /// ```
+    pub fn push_masked_link(mut self, name: &str, url: &str) -> Self {
+        self.0.push('[');
+        for c in name.chars() {
+            if matches!(c, '[' | ']' | '\\') {
+                self.0.push('\\');
+            }
+
+            self.0.push(c);
+        }
+
+        self.0.push_str("](");
+        for c in url.chars() {
+            match c {
+                ' ' => self.0.push_str("%20"),
+                '(' => self.0.push_str("%28"),
+                ')' => self.0.push_str("%29"),
+                '<' => self.0.push_str("%3C"),
+                '>' => self.0.push_str("%3E"),
+                c => self.0.push(c),
+            }
+        }
+
+        self.0.push(')');
+        self
+    }
+
+    /// Pushes a newline, unless the content is empty or already ends with one.
+    fn start_line(&mut self) {
+        if !self.0.is_empty() && !self.0.ends_with('\n') {
+            self.0.push('\n');
+        }
+    }
+
     /// Starts a multi-line quote, every push after this one will be quoted
     pub fn quote_rest(mut self) -> Self {
         self.0.push_str("\n>>> ");
@@ -1075,7 +1196,7 @@ impl<'a> From<&'a str> for Content<'a> {
 fn normalize(text: &str) -> String {
     // Remove invite links and popular scam websites, mostly to prevent the current user from
     // triggering various ad detectors and prevent embeds.
-    text.replace("discord.gg", "discord\u{2024}gg")
+    let text = text.replace("discord.gg", "discord\u{2024}gg")
         .replace("discord.me", "discord\u{2024}me")
         .replace("discordlist.net", "discordlist\u{2024}net")
         .replace("discordservers.com", "discordservers\u{2024}com")
@@ -1089,11 +1210,17 @@ fn normalize(text: &str) -> String {
             '\u{200B}', // Zero-width space
             '\u{200D}', // Zero-width joiner
             '\u{200C}', // Zero-width non-joiner
-        ], " ")
-        // Remove everyone and here mentions. Has to be put after ZWS replacement because it
-        // utilises it itself.
-        .replace("@everyone", "@\u{200B}everyone")
-        .replace("@here", "@\u{200B}here")
+        ], " ");
+
+    // Remove everyone and here mentions. Has to be put after ZWS replacement because it utilises
+    // it itself.
+    escape_everyone(&text)
+}
+
+/// Inserts a zero-width space into `@everyone` and `@here`, so they don't ping or display as a
+/// mention.
+pub(super) fn escape_everyone(text: &str) -> String {
+    text.replace("@everyone", "@\u{200B}everyone").replace("@here", "@\u{200B}here")
 }
 
 #[cfg(test)]
@@ -3,13 +3,31 @@
 
 #[cfg(feature = "gateway")]
 mod argument_convert;
+#[cfg(feature = "model")]
+mod ban_list;
 #[cfg(feature = "cache")]
 mod content_safe;
+#[cfg(feature = "collector")]
+mod confirm;
+mod content_segments;
+mod custom_id;
 mod custom_message;
 mod formatted_timestamp;
+#[cfg(feature = "builder")]
+mod mention_builder;
 mod message_builder;
 #[cfg(feature = "collector")]
+mod paginate;
+#[cfg(feature = "collector")]
 mod quick_modal;
+mod sanitize;
+#[cfg(feature = "model")]
+mod send_queue;
+mod split_message;
+#[cfg(feature = "model")]
+mod thread_watch;
+#[cfg(feature = "collector")]
+mod wizard;
 
 pub mod token;
 
@@ -17,15 +35,33 @@ use std::num::NonZeroU16;
 
 #[cfg(feature = "gateway")]
 pub use argument_convert::*;
+#[cfg(feature = "model")]
+pub use ban_list::*;
 #[cfg(feature = "cache")]
 pub use content_safe::*;
+#[cfg(feature = "collector")]
+pub use confirm::*;
+pub use content_segments::*;
+pub use custom_id::*;
 pub use formatted_timestamp::*;
 #[cfg(feature = "collector")]
+pub use paginate::*;
+#[cfg(feature = "collector")]
 pub use quick_modal::*;
+pub use sanitize::*;
+#[cfg(feature = "model")]
+pub use send_queue::*;
+pub use split_message::*;
+#[cfg(feature = "model")]
+pub use thread_watch::*;
+#[cfg(feature = "collector")]
+pub use wizard::*;
 use tracing::warn;
 use url::Url;
 
 pub use self::custom_message::CustomMessage;
+#[cfg(feature = "builder")]
+pub use self::mention_builder::MentionBuilder;
 pub use self::message_builder::{Content, ContentModifier, EmbedMessageBuilding, MessageBuilder};
 #[doc(inline)]
 pub use self::token::validate as validate_token;
@@ -0,0 +1,322 @@
+use std::time::Duration;
+
+use futures::StreamExt as _;
+use tokio::time::{timeout_at, Instant};
+
+use crate::builder::{
+    CreateActionRow,
+    CreateButton,
+    CreateComponent,
+    CreateEmbed,
+    CreateInteractionResponse,
+    CreateInteractionResponseMessage,
+    CreateMessage,
+    EditMessage,
+};
+use crate::collector::ComponentInteractionCollector;
+use crate::gateway::client::Context;
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// Options for [`paginate`].
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct PaginateOptions {
+    user_id: Option<UserId>,
+    timeout: Duration,
+    delete_on_timeout: bool,
+}
+
+impl PaginateOptions {
+    /// Creates options letting anyone flip pages, until nobody did for 2 minutes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lets the given user flip pages. Clicks by other users are acknowledged without effect,
+    /// and don't extend the timeout.
+    pub fn user(mut self, user_id: UserId) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Sets how long to wait for a page to be flipped before the buttons are removed.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets whether the message is deleted once the timeout passed, instead of only removing the
+    /// buttons.
+    ///
+    /// Defaults to `false`.
+    pub fn delete_on_timeout(mut self, delete_on_timeout: bool) -> Self {
+        self.delete_on_timeout = delete_on_timeout;
+        self
+    }
+}
+
+impl Default for PaginateOptions {
+    fn default() -> Self {
+        Self {
+            user_id: None,
+            timeout: Duration::from_secs(2 * 60),
+            delete_on_timeout: false,
+        }
+    }
+}
+
+/// Sends the first of the given pages, with buttons to flip to the previous and next page, and
+/// handles clicks on them until the timeout passed.
+///
+/// The buttons are disabled on the first and last page, and the one in between shows the current
+/// page number. If there is only a single page, it is sent without buttons and this returns
+/// immediately. Once nobody flipped a page for the timeout, the buttons are removed, or the
+/// message is deleted if [`PaginateOptions::delete_on_timeout`] is set.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::model::prelude::*;
+/// # use serenity::prelude::*;
+/// # async fn run(ctx: &Context, message: &Message, lines: &[String]) -> serenity::Result<()> {
+/// use serenity::builder::CreateEmbed;
+/// use serenity::utils::{paginate, PaginateOptions};
+///
+/// let pages = lines
+///     .chunks(10)
+///     .map(|chunk| CreateEmbed::new().title("Leaderboard").description(chunk.join("\n")))
+///     .collect();
+///
+/// let options = PaginateOptions::new().user(message.author.id);
+/// paginate(ctx, message.channel_id, pages, options).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Http`] if sending, editing or deleting the message, or responding to a click
+/// fails.
+pub async fn paginate(
+    ctx: &Context,
+    channel_id: ChannelId,
+    pages: Vec<CreateEmbed<'_>>,
+    options: PaginateOptions,
+) -> Result<()> {
+    let Some(first) = pages.first() else {
+        return Ok(());
+    };
+
+    let mut builder = CreateMessage::new().embed(first.clone());
+    if pages.len() > 1 {
+        builder = builder.components(page_buttons(0, pages.len()));
+    }
+    let message = channel_id.send_message(&ctx.http, builder).await?;
+    if pages.len() == 1 {
+        return Ok(());
+    }
+
+    let interactions =
+        ComponentInteractionCollector::new(ctx.shard.clone()).message_id(message.id).stream();
+    tokio::pin!(interactions);
+
+    let mut page: usize = 0;
+    let mut deadline = Instant::now() + options.timeout;
+    loop {
+        let Ok(Some(interaction)) = timeout_at(deadline, interactions.next()).await else {
+            if options.delete_on_timeout {
+                channel_id.delete_message(&ctx.http, message.id, None).await?;
+            } else {
+                let builder = EditMessage::new().components(Vec::new());
+                channel_id.edit_message(&ctx.http, message.id, builder).await?;
+            }
+            return Ok(());
+        };
+
+        if options.user_id.is_some_and(|user_id| user_id != interaction.user.id) {
+            let response = CreateInteractionResponse::Acknowledge;
+            interaction.create_response(&ctx.http, response).await?;
+            continue;
+        }
+
+        deadline = Instant::now() + options.timeout;
+        page = match interaction.data.custom_id.as_str() {
+            "paginate_previous" => page.saturating_sub(1),
+            "paginate_next" => (page + 1).min(pages.len() - 1),
+            _ => page,
+        };
+
+        let response = CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(pages[page].clone())
+                .components(page_buttons(page, pages.len())),
+        );
+        interaction.create_response(&ctx.http, response).await?;
+    }
+}
+
+fn page_buttons<'a>(page: usize, count: usize) -> Vec<CreateComponent<'a>> {
+    let buttons = vec![
+        CreateButton::new("paginate_previous").label("\u{25c0}").disabled(page == 0),
+        CreateButton::new("paginate_page")
+            .label(format!("{} / {count}", page + 1))
+            .style(ButtonStyle::Secondary)
+            .disabled(true),
+        CreateButton::new("paginate_next").label("\u{25b6}").disabled(page + 1 == count),
+    ];
+
+    vec![CreateActionRow::Buttons(buttons).into()]
+}
+
+#[cfg(all(test, feature = "http_transport"))]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use reqwest::Method;
+    use serde_json::{json, Value};
+
+    use super::{paginate, PaginateOptions};
+    use crate::builder::CreateEmbed;
+    use crate::gateway::client::Context;
+    use crate::http::{HttpBuilder, LightMethod, MockRequest, MockTransport, StatusCode};
+    use crate::model::event::{Event, InteractionCreateEvent};
+    use crate::model::id::{ChannelId, ShardId, UserId};
+
+    fn message() -> Value {
+        json!({
+            "id": "10",
+            "channel_id": "1",
+            "author": {"id": "2", "username": "bot", "discriminator": "0", "avatar": null},
+            "content": "",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        })
+    }
+
+    fn click(user_id: u64, custom_id: &str) -> Event {
+        let interaction = serde_json::from_value(json!({
+            "id": "20",
+            "application_id": "2",
+            "type": 3,
+            "data": {"custom_id": custom_id, "component_type": 2},
+            "channel_id": "1",
+            "user": {
+                "id": user_id.to_string(),
+                "username": "user",
+                "discriminator": "0",
+                "avatar": null,
+            },
+            "token": "token",
+            "version": 1,
+            "message": message(),
+            "locale": "en-US",
+            "entitlements": [],
+        }))
+        .unwrap();
+        Event::InteractionCreate(InteractionCreateEvent {
+            interaction,
+        })
+    }
+
+    fn pages(count: usize) -> Vec<CreateEmbed<'static>> {
+        (1..=count).map(|page| CreateEmbed::new().title(format!("Page {page}"))).collect()
+    }
+
+    /// Runs [`paginate`] in a task, passing it the given clicks once it's listening for them.
+    async fn run(
+        pages: Vec<CreateEmbed<'static>>,
+        options: PaginateOptions,
+        clicks: &[(u64, &str)],
+    ) -> Vec<MockRequest> {
+        let messages = "/channels/*/messages/*";
+        let callback = "/interactions/*/*/callback";
+        let transport = MockTransport::new()
+            .respond(LightMethod::Post, "/channels/*/messages", StatusCode::OK, message())
+            .respond(LightMethod::Patch, messages, StatusCode::OK, message())
+            .respond(LightMethod::Delete, messages, StatusCode::NO_CONTENT, Value::Null)
+            .respond(LightMethod::Post, callback, StatusCode::NO_CONTENT, Value::Null);
+        let mut ctx = Context::new_for_test(ShardId(0));
+        ctx.http = Arc::new(HttpBuilder::new("token").transport(transport.clone()).build());
+
+        let task_ctx = ctx.clone();
+        let task = tokio::spawn(async move {
+            paginate(&task_ctx, ChannelId::new(1), pages, options).await
+        });
+        if !clicks.is_empty() {
+            while ctx.shard.event_bus.subscriber_count() == 0 {
+                tokio::task::yield_now().await;
+            }
+        }
+        for (user_id, custom_id) in clicks {
+            ctx.shard.event_bus.publish(ShardId(0), &click(*user_id, custom_id));
+        }
+
+        task.await.unwrap().unwrap();
+        transport.requests()
+    }
+
+    #[tokio::test]
+    async fn pages_stay_in_bounds() {
+        let timeout = Duration::from_millis(200);
+        let options = PaginateOptions::new().user(UserId::new(5)).timeout(timeout);
+        let clicks = [
+            (5, "paginate_previous"),
+            (5, "paginate_next"),
+            (5, "paginate_next"),
+            (5, "paginate_next"),
+            (6, "paginate_previous"),
+        ];
+        let requests = run(pages(3), options, &clicks).await;
+
+        let buttons = &requests[0].json().unwrap()["components"][0]["components"];
+        assert_eq!(buttons[0]["disabled"], true);
+        assert_eq!(buttons[1]["label"], "1 / 3");
+
+        let callbacks: Vec<_> =
+            requests[1..6].iter().map(|request| request.json().unwrap()).collect();
+        let titles: Vec<_> = callbacks[..4]
+            .iter()
+            .map(|callback| &callback["data"]["embeds"][0]["title"])
+            .collect();
+        assert_eq!(titles, ["Page 1", "Page 2", "Page 3", "Page 3"]);
+        let buttons = &callbacks[3]["data"]["components"][0]["components"];
+        assert_eq!(buttons[1]["label"], "3 / 3");
+        assert_eq!(buttons[2]["disabled"], true);
+        // Clicks by other users are only acknowledged
+        assert_eq!(callbacks[4]["type"], 6);
+
+        // The buttons are removed once the timeout passed
+        assert_eq!(requests.len(), 7);
+        assert_eq!(requests[6].method, Method::PATCH);
+        assert_eq!(requests[6].json().unwrap()["components"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn message_is_deleted_on_timeout() {
+        let timeout = Duration::from_millis(50);
+        let options = PaginateOptions::new().timeout(timeout).delete_on_timeout(true);
+        let requests = run(pages(2), options, &[]).await;
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, Method::DELETE);
+        assert_eq!(requests[1].path, "/api/v10/channels/1/messages/10");
+    }
+
+    #[tokio::test]
+    async fn single_page_has_no_buttons() {
+        let requests = run(pages(1), PaginateOptions::new(), &[]).await;
+
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].json().unwrap()["components"], Value::Null);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::constants::{WEBHOOK_USERNAME_FORBIDDEN, WEBHOOK_USERNAME_MAX_LENGTH};
+
+/// Options for [`sanitize_content`].
+#[bool_to_bitflags::bool_to_bitflags(
+    getter_prefix = "get_",
+    setter_prefix = "",
+    private_getters,
+    document_setters,
+    owning_setters
+)]
+#[derive(Copy, Clone, Debug)]
+pub struct SanitizeOptions {
+    /// If set, [`sanitize_content`] will remove zero-width characters, such as zero-width spaces,
+    /// except for joiners within emoji sequences.
+    pub strip_zero_width: bool,
+    /// If set, [`sanitize_content`] will remove bidirectional control characters, such as the
+    /// right-to-left override, which can be used to disguise text.
+    pub strip_bidi_controls: bool,
+    /// If set, [`sanitize_content`] will replace `@everyone` and `@here` with non-pinging
+    /// alternatives, by inserting a zero-width space after the `@`.
+    pub neutralize_mass_mentions: bool,
+    max_newlines: Option<usize>,
+}
+
+impl SanitizeOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        SanitizeOptions::default()
+    }
+
+    /// Sets the maximum number of consecutive newlines, with longer runs being shortened. Defaults
+    /// to 2, which allows a single empty line.
+    #[must_use]
+    pub fn max_newlines(mut self, max_newlines: Option<usize>) -> Self {
+        self.max_newlines = max_newlines;
+        self
+    }
+}
+
+impl Default for SanitizeOptions {
+    /// Instantiates with all options set to `true`, and at most 2 consecutive newlines.
+    fn default() -> Self {
+        SanitizeOptions {
+            max_newlines: Some(2),
+            __generated_flags: SanitizeOptionsGeneratedFlags::all(),
+        }
+    }
+}
+
+/// What [`sanitize_content`] removed or replaced.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct SanitizeReport {
+    /// The number of zero-width characters removed.
+    pub zero_width: usize,
+    /// The number of bidirectional control characters removed.
+    pub bidi_controls: usize,
+    /// The number of newlines removed from overly long runs.
+    pub newlines: usize,
+    /// The number of `@everyone` and `@here` mentions neutralized.
+    pub mass_mentions: usize,
+}
+
+impl SanitizeReport {
+    /// Whether the content was left unchanged.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{180E}' | '\u{200B}' | '\u{200C}' | ZERO_WIDTH_JOINER | '\u{2060}' | '\u{FEFF}')
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Whether a zero-width joiner between the given characters joins an emoji sequence, such as 👨‍👩‍👧.
+fn joins_emoji(previous: Option<char>, next: Option<char>) -> bool {
+    let is_emoji = |c: Option<char>| c.is_some_and(|c| c >= '\u{2000}' && !is_zero_width(c));
+    is_emoji(previous) && is_emoji(next)
+}
+
+/// Cleans up content before echoing or quoting it, by removing invisible characters which can
+/// disguise text, shortening long runs of newlines, and neutralizing `@everyone` and `@here`
+/// mentions. Returns the sanitized content, and a report of what was changed.
+///
+/// Other mentions are left as is, see [`content_safe`] for replacing them with names.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::{sanitize_content, SanitizeOptions};
+///
+/// let (content, report) =
+///     sanitize_content("@every\u{200B}one look\n\n\n\nhere", SanitizeOptions::default());
+///
+/// assert_eq!(content, "@\u{200B}everyone look\n\nhere");
+/// assert_eq!(report.zero_width, 1);
+/// assert_eq!(report.newlines, 2);
+/// assert_eq!(report.mass_mentions, 1);
+/// ```
+///
+/// [`content_safe`]: super::content_safe
+#[must_use]
+pub fn sanitize_content(content: &str, options: SanitizeOptions) -> (String, SanitizeReport) {
+    let mut report = SanitizeReport::default();
+    let mut sanitized = String::with_capacity(content.len());
+    let mut newlines = 0;
+
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if options.get_strip_zero_width()
+            && is_zero_width(c)
+            && !(c == ZERO_WIDTH_JOINER
+                && joins_emoji(sanitized.chars().last(), chars.peek().copied()))
+        {
+            report.zero_width += 1;
+            continue;
+        }
+        if options.get_strip_bidi_controls() && is_bidi_control(c) {
+            report.bidi_controls += 1;
+            continue;
+        }
+
+        if c == '\n' {
+            newlines += 1;
+            if options.max_newlines.is_some_and(|max| newlines > max) {
+                if sanitized.ends_with('\r') {
+                    sanitized.pop();
+                }
+                report.newlines += 1;
+                continue;
+            }
+        } else if c != '\r' {
+            newlines = 0;
+        }
+
+        sanitized.push(c);
+    }
+
+    if options.get_neutralize_mass_mentions() {
+        for mention in ["@everyone", "@here"] {
+            let count = sanitized.matches(mention).count();
+            if count > 0 {
+                report.mass_mentions += count;
+                sanitized = sanitized.replace(mention, &mention.replacen('@', "@\u{200B}", 1));
+            }
+        }
+    }
+
+    (sanitized, report)
+}
+
+/// What [`sanitize_webhook_username`] changed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct WebhookUsernameReport {
+    /// The number of forbidden substrings, such as `"clyde"`, removed.
+    pub forbidden: usize,
+    /// Whether the username was shortened to fit the maximum length.
+    pub truncated: bool,
+    /// Whether nothing was left of the username, so the fallback was used instead.
+    pub used_fallback: bool,
+}
+
+impl WebhookUsernameReport {
+    /// Whether the username was left unchanged.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Cleans up a name to be used as the username a webhook message is sent with, such as a user's
+/// nickname being echoed. Returns the sanitized username, and a report of what was changed.
+///
+/// Discord rejects usernames containing [`WEBHOOK_USERNAME_FORBIDDEN`] substrings or longer than
+/// [`WEBHOOK_USERNAME_MAX_LENGTH`], so these substrings are removed and the name is shortened. If
+/// nothing is left afterwards, `fallback` is used as is.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::sanitize_webhook_username;
+///
+/// let (username, report) = sanitize_webhook_username("Discord Mod", "Anonymous");
+/// assert_eq!(username, "Mod");
+/// assert_eq!(report.forbidden, 1);
+///
+/// let (username, report) = sanitize_webhook_username("clyde", "Anonymous");
+/// assert_eq!(username, "Anonymous");
+/// assert!(report.used_fallback);
+/// ```
+///
+/// [`WEBHOOK_USERNAME_FORBIDDEN`]: crate::constants::WEBHOOK_USERNAME_FORBIDDEN
+/// [`WEBHOOK_USERNAME_MAX_LENGTH`]: crate::constants::WEBHOOK_USERNAME_MAX_LENGTH
+#[must_use]
+pub fn sanitize_webhook_username(name: &str, fallback: &str) -> (String, WebhookUsernameReport) {
+    let mut report = WebhookUsernameReport::default();
+    let mut username = name.to_owned();
+
+    // Removing a substring may join the surrounding text into another one, so repeat until none
+    // are left.
+    while let Some((start, len)) = find_forbidden(&username) {
+        username.replace_range(start..start + len, "");
+        report.forbidden += 1;
+    }
+
+    let mut username = username.trim().to_owned();
+    if let Some((index, _)) = username.char_indices().nth(WEBHOOK_USERNAME_MAX_LENGTH) {
+        username.truncate(index);
+        username.truncate(username.trim_end().len());
+        report.truncated = true;
+    }
+
+    if username.is_empty() {
+        report.used_fallback = true;
+        return (fallback.to_owned(), report);
+    }
+
+    (username, report)
+}
+
+/// Finds the first forbidden substring of a webhook username, ignoring ASCII case.
+fn find_forbidden(username: &str) -> Option<(usize, usize)> {
+    (0..username.len()).filter(|&start| username.is_char_boundary(start)).find_map(|start| {
+        WEBHOOK_USERNAME_FORBIDDEN.iter().find_map(|forbidden| {
+            let candidate = username.get(start..start + forbidden.len())?;
+            candidate.eq_ignore_ascii_case(forbidden).then_some((start, forbidden.len()))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_content() {
+        // Zero width joiners inside an emoji sequence are kept.
+        let family = "\u{1f468}\u{200D}\u{1f469}\u{200D}\u{1f467}";
+        let content =
+            format!("\u{202E}@here\u{200B}\n\n\n{family} a\u{200D}b\r\n\r\n\r\n@everyone");
+        let (sanitized, report) = sanitize_content(&content, SanitizeOptions::default());
+
+        assert_eq!(sanitized, format!("@\u{200B}here\n\n{family} ab\r\n\r\n@\u{200B}everyone"));
+        assert_eq!(report, SanitizeReport {
+            zero_width: 2,
+            bidi_controls: 1,
+            newlines: 2,
+            mass_mentions: 2,
+        });
+
+        let options = SanitizeOptions::new()
+            .strip_zero_width(false)
+            .strip_bidi_controls(false)
+            .neutralize_mass_mentions(false)
+            .max_newlines(None);
+        let (sanitized, report) = sanitize_content(&content, options);
+        assert_eq!(sanitized, content);
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn test_sanitize_webhook_username() {
+        let (username, report) = sanitize_webhook_username("Ferris", "Anonymous");
+        assert_eq!(username, "Ferris");
+        assert!(report.is_clean());
+
+        let (username, report) = sanitize_webhook_username("ClDISCORDyde bot", "Anonymous");
+        assert_eq!(username, "bot");
+        assert_eq!(report.forbidden, 2);
+        assert!(!report.used_fallback);
+
+        let (username, report) = sanitize_webhook_username(&"\u{e9}".repeat(100), "Anonymous");
+        assert_eq!(username, "\u{e9}".repeat(80));
+        assert!(report.truncated);
+
+        let (username, report) = sanitize_webhook_username("  ", "Anonymous");
+        assert_eq!(username, "Anonymous");
+        assert!(report.used_fallback);
+    }
+}
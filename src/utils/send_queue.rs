@@ -0,0 +1,237 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::warn;
+
+use crate::constants::MESSAGE_CODE_LIMIT;
+use crate::http::Http;
+use crate::internal::tokio::spawn_named;
+use crate::model::prelude::*;
+
+/// How many messages can be sent to a channel per [`CHANNEL_BUCKET_WINDOW`].
+const CHANNEL_BUCKET_LIMIT: usize = 5;
+const CHANNEL_BUCKET_WINDOW: Duration = Duration::from_secs(5);
+
+/// Options for a [`ChannelSendQueue`].
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SendQueueOptions {
+    slowmode: Duration,
+    coalesce: bool,
+}
+
+impl SendQueueOptions {
+    /// Creates options without slowmode or coalescing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates options respecting the slowmode of the given channel, unless the given permissions
+    /// of the bot in the channel bypass it.
+    pub fn for_channel(channel: &GuildChannel, permissions: Permissions) -> Self {
+        let bypass =
+            permissions.intersects(Permissions::MANAGE_MESSAGES | Permissions::MANAGE_CHANNELS);
+        let slowmode = match channel.rate_limit_per_user {
+            Some(seconds) if !bypass => Duration::from_secs(seconds.get().into()),
+            _ => Duration::ZERO,
+        };
+
+        Self::new().slowmode(slowmode)
+    }
+
+    /// Sets the minimum time between two messages, as set by the channel's
+    /// [`GuildChannel::rate_limit_per_user`]. Defaults to no delay.
+    pub fn slowmode(mut self, slowmode: Duration) -> Self {
+        self.slowmode = slowmode;
+        self
+    }
+
+    /// Sets whether messages queued while waiting for a rate limit are joined into a single
+    /// message, separated by newlines, as long as they fit within [`MESSAGE_CODE_LIMIT`]. Defaults
+    /// to `false`.
+    pub fn coalesce(mut self, coalesce: bool) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+}
+
+/// Tracks the messages sent to a channel, to calculate when the next one can be sent.
+#[derive(Debug)]
+struct SendPacer {
+    slowmode: Duration,
+    sent: VecDeque<Instant>,
+}
+
+impl SendPacer {
+    fn new(slowmode: Duration) -> Self {
+        Self {
+            slowmode,
+            sent: VecDeque::with_capacity(CHANNEL_BUCKET_LIMIT),
+        }
+    }
+
+    fn next_send(&self, now: Instant) -> Instant {
+        let mut next = now;
+        if let Some(last) = self.sent.back() {
+            next = next.max(*last + self.slowmode);
+        }
+        if self.sent.len() >= CHANNEL_BUCKET_LIMIT {
+            next =
+                next.max(self.sent[self.sent.len() - CHANNEL_BUCKET_LIMIT] + CHANNEL_BUCKET_WINDOW);
+        }
+
+        next
+    }
+
+    fn record(&mut self, sent_at: Instant) {
+        if self.sent.len() >= CHANNEL_BUCKET_LIMIT {
+            self.sent.pop_front();
+        }
+        self.sent.push_back(sent_at);
+    }
+}
+
+/// A queue sending messages to a channel one at a time, waiting for the channel's slowmode and
+/// its limit of 5 messages per 5 seconds instead of running into rate limits.
+///
+/// Messages are sent by a background task, and failures are logged. To handle the sent messages
+/// or errors yourself, use [`ChannelId::send_message`] instead.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::sync::Arc;
+/// # use serenity::http::Http;
+/// # use serenity::model::prelude::*;
+/// use serenity::utils::{ChannelSendQueue, SendQueueOptions};
+///
+/// # async fn run(http: Arc<Http>, channel: GuildChannel, permissions: Permissions) {
+/// let options = SendQueueOptions::for_channel(&channel, permissions).coalesce(true);
+/// let queue = ChannelSendQueue::new(http, channel.id, options);
+/// for i in 0..100 {
+///     queue.send(format!("Progress: {i}%"));
+/// }
+///
+/// // Wait until all messages are sent.
+/// queue.close().await;
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ChannelSendQueue {
+    channel_id: ChannelId,
+    tx: mpsc::UnboundedSender<String>,
+    task: JoinHandle<()>,
+}
+
+impl ChannelSendQueue {
+    /// Creates a queue for the given channel, spawning the task sending its messages.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    #[must_use]
+    pub fn new(http: Arc<Http>, channel_id: ChannelId, options: SendQueueOptions) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let task = spawn_named("utils::send_queue", run(http, channel_id, options, rx));
+
+        Self {
+            channel_id,
+            tx,
+            task,
+        }
+    }
+
+    /// The channel messages are sent to.
+    #[must_use]
+    pub fn channel_id(&self) -> ChannelId {
+        self.channel_id
+    }
+
+    /// Queues a message to be sent to the channel.
+    pub fn send(&self, content: impl Into<String>) {
+        // The task only stops once the sender is dropped.
+        drop(self.tx.send(content.into()));
+    }
+
+    /// Stops accepting messages, and waits until the queued messages are sent.
+    pub async fn close(self) {
+        drop(self.tx);
+        if let Err(why) = self.task.await {
+            warn!("Send queue task failed: {why:?}");
+        }
+    }
+}
+
+async fn run(
+    http: Arc<Http>,
+    channel_id: ChannelId,
+    options: SendQueueOptions,
+    mut rx: mpsc::UnboundedReceiver<String>,
+) {
+    let mut pacer = SendPacer::new(options.slowmode);
+    let mut next = None;
+
+    loop {
+        let mut content = match next.take() {
+            Some(content) => content,
+            None => match rx.recv().await {
+                Some(content) => content,
+                None => break,
+            },
+        };
+
+        tokio::time::sleep_until(pacer.next_send(Instant::now())).await;
+
+        if options.coalesce {
+            let mut length = content.chars().count();
+            while let Ok(queued) = rx.try_recv() {
+                let queued_length = queued.chars().count();
+                if length + 1 + queued_length > MESSAGE_CODE_LIMIT {
+                    next = Some(queued);
+                    break;
+                }
+
+                content.push('\n');
+                content.push_str(&queued);
+                length += 1 + queued_length;
+            }
+        }
+
+        pacer.record(Instant::now());
+        if let Err(why) = channel_id.say(&http, content).await {
+            warn!("Failed to send queued message to {channel_id}: {why:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::SendPacer;
+
+    #[test]
+    fn pacer_respects_bucket_and_slowmode() {
+        let start = Instant::now();
+        let secs = |secs| start + Duration::from_secs(secs);
+
+        let mut pacer = SendPacer::new(Duration::ZERO);
+        for _ in 0..5 {
+            assert_eq!(pacer.next_send(start), start);
+            pacer.record(start);
+        }
+        assert_eq!(pacer.next_send(start), secs(5));
+
+        let mut pacer = SendPacer::new(Duration::from_secs(10));
+        assert_eq!(pacer.next_send(start), start);
+        pacer.record(start);
+        assert_eq!(pacer.next_send(secs(1)), secs(10));
+        assert_eq!(pacer.next_send(secs(12)), secs(12));
+    }
+}
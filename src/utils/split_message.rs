@@ -0,0 +1,216 @@
+use crate::constants::MESSAGE_CODE_LIMIT;
+
+/// The fence closing a code block that was split across chunks.
+const CLOSING_FENCE: &str = "\n```";
+
+/// Options for [`split_message`].
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct SplitOptions {
+    max_length: usize,
+    reopen_code_blocks: bool,
+}
+
+impl SplitOptions {
+    /// Creates options splitting into chunks Discord accepts as message content, reopening split
+    /// code blocks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum length of each chunk, in unicode code points.
+    ///
+    /// Defaults to [`MESSAGE_CODE_LIMIT`]. Values below 16 are raised to 16, to leave room for
+    /// reopening code blocks.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length.max(16);
+        self
+    }
+
+    /// Sets whether a code block split across chunks is closed at the end of one chunk and
+    /// reopened, with the same language, at the start of the next.
+    ///
+    /// Defaults to `true`.
+    pub fn reopen_code_blocks(mut self, reopen_code_blocks: bool) -> Self {
+        self.reopen_code_blocks = reopen_code_blocks;
+        self
+    }
+}
+
+impl Default for SplitOptions {
+    fn default() -> Self {
+        Self {
+            max_length: MESSAGE_CODE_LIMIT,
+            reopen_code_blocks: true,
+        }
+    }
+}
+
+/// Splits message content into chunks short enough to be sent as separate messages.
+///
+/// Chunks are split at the last line break that fits, falling back to the last whitespace, so
+/// that words are only broken if a single word doesn't fit into a chunk. Mentions, custom emojis
+/// and timestamps are never broken. If a code block is split, it is closed at the end of the
+/// chunk and reopened at the start of the next one, so both halves keep their formatting. The
+/// line break or whitespace a chunk was split at is dropped.
+///
+/// Returns no chunks for empty content.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::{split_message, SplitOptions};
+///
+/// let content = "Some code:\n```rust\nlet a = 1;\nlet b = 2;\n```";
+/// let chunks = split_message(content, SplitOptions::new().max_length(33));
+///
+/// assert_eq!(chunks, [
+///     "Some code:\n```rust\nlet a = 1;\n```",
+///     "```rust\nlet b = 2;\n```",
+/// ]);
+/// ```
+#[must_use]
+pub fn split_message(content: &str, options: SplitOptions) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = content;
+    // The opening line of the code block the remaining content starts in, such as "```rust".
+    let mut open_fence: Option<String> = None;
+
+    while !rest.is_empty() {
+        let prefix = open_fence.as_ref().map(|fence| format!("{fence}\n")).unwrap_or_default();
+        let available = options.max_length.saturating_sub(prefix.chars().count()).max(1);
+
+        if rest.chars().count() <= available {
+            chunks.push(prefix + rest);
+            break;
+        }
+
+        let (mut chunk_end, mut next_start) = find_cut(rest, available);
+        let mut fence = fence_state(&rest[..chunk_end], open_fence.clone());
+        if options.reopen_code_blocks && fence.is_some() {
+            // Leave room to close the code block the cut fell into.
+            let budget = available.saturating_sub(CLOSING_FENCE.len()).max(1);
+            (chunk_end, next_start) = find_cut(rest, budget);
+            fence = fence_state(&rest[..chunk_end], open_fence);
+        }
+
+        let mut text = prefix;
+        text.push_str(&rest[..chunk_end]);
+        if options.reopen_code_blocks && fence.is_some() {
+            text.push_str(CLOSING_FENCE);
+            open_fence = fence;
+        } else {
+            open_fence = None;
+        }
+
+        chunks.push(text);
+        rest = &rest[next_start..];
+    }
+
+    chunks
+}
+
+/// Returns where the chunk taken from the start of `content` ends, and where the next one starts,
+/// keeping the chunk within `budget` code points.
+fn find_cut(content: &str, budget: usize) -> (usize, usize) {
+    let mut indices = content.char_indices().map(|(i, _)| i).skip(budget);
+    let limit = indices.next().unwrap_or(content.len());
+    // The line break or whitespace split at is dropped, so it may be just past the limit.
+    let candidate = &content[..indices.next().unwrap_or(content.len())];
+
+    if let Some(i) = candidate.rfind('\n').filter(|&i| i > 0) {
+        return (i, i + 1);
+    }
+
+    if let Some((i, c)) = candidate.char_indices().rev().find(|(_, c)| c.is_whitespace()) {
+        if i > 0 {
+            return (i, i + c.len_utf8());
+        }
+    }
+
+    let candidate = &content[..limit];
+    // A single word doesn't fit, so it has to be broken, but not in the middle of a mention.
+    if let Some(open) = candidate.rfind('<') {
+        if open > 0 && !candidate[open..].contains('>') && content[limit..].starts_with(is_mention)
+        {
+            return (open, open);
+        }
+    }
+
+    (limit, limit)
+}
+
+/// Whether the character may continue a mention, custom emoji or timestamp.
+fn is_mention(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '@' | '&' | '#' | ':' | '!' | '_' | '>')
+}
+
+/// Returns the opening line of the code block still open at the end of `chunk`, given the one open
+/// at its start.
+fn fence_state(chunk: &str, mut open_fence: Option<String>) -> Option<String> {
+    let mut rest = chunk;
+    while let Some(i) = rest.find("```") {
+        let after = &rest[i + 3..];
+        if open_fence.is_some() {
+            open_fence = None;
+        } else {
+            let line = after.split('\n').next().unwrap_or_default();
+            let lang = if after.contains('\n') && !line.contains(char::is_whitespace) {
+                line
+            } else {
+                ""
+            };
+            open_fence = Some(format!("```{lang}"));
+        }
+        rest = after;
+    }
+
+    open_fence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_message, SplitOptions};
+
+    #[test]
+    fn split_message_words() {
+        let options = SplitOptions::new().max_length(20);
+
+        assert!(split_message("", options).is_empty());
+        assert_eq!(split_message("short", options), ["short"]);
+        assert_eq!(split_message("one two three four five six", options), [
+            "one two three four",
+            "five six"
+        ]);
+        assert_eq!(split_message("first line\nsecond line here", options), [
+            "first line",
+            "second line here"
+        ]);
+        assert_eq!(split_message(&"a".repeat(30), options), ["a".repeat(20), "a".repeat(10)]);
+    }
+
+    #[test]
+    fn split_message_mentions() {
+        let options = SplitOptions::new().max_length(20);
+        let content = "aaaaaaaaaa<@1149413154>";
+
+        assert_eq!(split_message(content, options), ["aaaaaaaaaa", "<@1149413154>"]);
+    }
+
+    #[test]
+    fn split_message_code_blocks() {
+        let options = SplitOptions::new().max_length(28);
+        let content = "```py\nprint(1)\nprint(2)\nprint(3)\n```\ndone";
+
+        assert_eq!(split_message(content, options), [
+            "```py\nprint(1)\nprint(2)\n```",
+            "```py\nprint(3)\n```\ndone",
+        ]);
+
+        let options = options.reopen_code_blocks(false);
+        assert_eq!(split_message(content, options), [
+            "```py\nprint(1)\nprint(2)",
+            "print(3)\n```\ndone",
+        ]);
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::BTreeSet;
+
+use tracing::warn;
+
+use crate::http::CacheHttp;
+use crate::internal::prelude::*;
+use crate::model::id::ChannelId;
+
+/// A set of threads the bot has to stay a member of, to keep receiving the messages sent in
+/// them.
+///
+/// Thread membership can be lost in various ways, such as moderators removing the bot or some
+/// archive and unarchive flows, after which the bot silently stops receiving messages sent in the
+/// thread. Call [`Self::rejoin`] after the bot is ready, and whenever membership may have been
+/// lost, to join every thread the bot is no longer a member of.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::model::prelude::*;
+/// use serenity::prelude::*;
+/// use serenity::utils::ThreadWatchList;
+///
+/// struct Handler {
+///     threads: ThreadWatchList,
+/// }
+///
+/// #[serenity::async_trait]
+/// impl EventHandler for Handler {
+///     async fn ready(&self, ctx: Context, _ready: Ready) {
+///         let joined = self.threads.rejoin(&ctx).await;
+///         println!("Rejoined {} threads", joined.len());
+///     }
+/// }
+///
+/// let handler = Handler {
+///     threads: ThreadWatchList::new([ChannelId::new(1), ChannelId::new(2)]),
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ThreadWatchList {
+    threads: BTreeSet<ChannelId>,
+}
+
+impl ThreadWatchList {
+    /// Creates a list watching the given threads.
+    #[must_use]
+    pub fn new(threads: impl IntoIterator<Item = ChannelId>) -> Self {
+        Self {
+            threads: threads.into_iter().collect(),
+        }
+    }
+
+    /// Adds a thread to the list, returning `false` if it was already watched.
+    pub fn watch(&mut self, thread_id: ChannelId) -> bool {
+        self.threads.insert(thread_id)
+    }
+
+    /// Removes a thread from the list, returning `false` if it wasn't watched.
+    pub fn unwatch(&mut self, thread_id: ChannelId) -> bool {
+        self.threads.remove(&thread_id)
+    }
+
+    /// Returns whether the given thread is watched.
+    #[must_use]
+    pub fn is_watched(&self, thread_id: ChannelId) -> bool {
+        self.threads.contains(&thread_id)
+    }
+
+    /// Iterates over the watched threads.
+    pub fn iter(&self) -> impl Iterator<Item = ChannelId> + '_ {
+        self.threads.iter().copied()
+    }
+
+    /// Joins every watched thread the current user is not a member of, returning the threads
+    /// which were joined. Failures are logged, see [`Self::try_rejoin`] to handle them yourself.
+    pub async fn rejoin(&self, cache_http: impl CacheHttp) -> Vec<ChannelId> {
+        let mut joined = Vec::new();
+        for (thread_id, result) in self.try_rejoin(cache_http).await {
+            match result {
+                Ok(true) => joined.push(thread_id),
+                Ok(false) => {},
+                Err(why) => warn!("Failed to rejoin thread {thread_id}: {why:?}"),
+            }
+        }
+
+        joined
+    }
+
+    /// Joins every watched thread the current user is not a member of, returning the result of
+    /// [`ChannelId::ensure_joined_thread`] for each thread.
+    pub async fn try_rejoin(&self, cache_http: impl CacheHttp) -> Vec<(ChannelId, Result<bool>)> {
+        let mut results = Vec::with_capacity(self.threads.len());
+        for thread_id in self.iter() {
+            results.push((thread_id, thread_id.ensure_joined_thread(&cache_http).await));
+        }
+
+        results
+    }
+}
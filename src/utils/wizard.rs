@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt as _;
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+use tokio::time::{timeout_at, Instant};
+
+use super::{CreateQuickModal, QuickModalResponse};
+use crate::builder::{
+    CreateInteractionResponse,
+    CreateInteractionResponseMessage,
+    EditInteractionResponse,
+};
+use crate::collector::ComponentInteractionCollector;
+use crate::gateway::client::Context;
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+type Render<S> = Box<dyn Fn(&S) -> CreateInteractionResponseMessage<'static> + Send + Sync>;
+type OnComponent<S> = Box<dyn Fn(&mut S, &ComponentInteraction) -> Transition + Send + Sync>;
+type BuildModal<S> = Box<dyn Fn(&S) -> CreateQuickModal<'static> + Send + Sync>;
+type OnSubmit<S> = Box<dyn Fn(&mut S, &QuickModalResponse) -> Transition + Send + Sync>;
+
+enum StepKind<S> {
+    Component(OnComponent<S>),
+    Modal { modal: BuildModal<S>, on_submit: OnSubmit<S> },
+}
+
+struct WizardStep<S> {
+    render: Render<S>,
+    kind: StepKind<S>,
+}
+
+/// Where a [`Wizard`] goes after the user interacted with a step.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Transition {
+    /// Continue with the following step, finishing the wizard after the last one.
+    Next,
+    /// Return to the previously shown step, or stay if this is the first one.
+    Back,
+    /// Continue with the step at the given index, finishing the wizard if it's out of range.
+    Goto(usize),
+    /// Show the current step again, such as after an invalid choice.
+    Stay,
+    /// Finish the wizard.
+    Finish,
+    /// Cancel the wizard.
+    Cancel,
+}
+
+/// The step a [`Wizard`] session is on, along with the steps it went through to get there.
+#[derive(Debug, Default)]
+struct Progress {
+    step: usize,
+    history: Vec<usize>,
+}
+
+impl Progress {
+    /// Applies a transition, returning the step to show next, or [`None`] if the wizard ends.
+    fn apply(&mut self, transition: Transition, step_count: usize) -> Option<usize> {
+        let next = match transition {
+            Transition::Next => self.step + 1,
+            Transition::Goto(index) => index,
+            Transition::Back => self.history.last().copied().unwrap_or(self.step),
+            Transition::Stay => self.step,
+            Transition::Finish | Transition::Cancel => return None,
+        };
+
+        if next >= step_count {
+            return None;
+        }
+
+        match transition {
+            Transition::Back => {
+                self.history.pop();
+            },
+            Transition::Next | Transition::Goto(_) if next != self.step => {
+                self.history.push(self.step);
+            },
+            _ => {},
+        }
+        self.step = next;
+        Some(next)
+    }
+}
+
+/// Registers a user's session of a [`Wizard`], and removes it again when dropped.
+struct Session<'a> {
+    sessions: &'a Mutex<HashMap<UserId, Arc<Notify>>>,
+    user_id: UserId,
+    superseded: Arc<Notify>,
+}
+
+impl<'a> Session<'a> {
+    /// Starts a session for the user, superseding the user's previous session, if any.
+    fn start(sessions: &'a Mutex<HashMap<UserId, Arc<Notify>>>, user_id: UserId) -> Self {
+        let superseded = Arc::new(Notify::new());
+        if let Some(previous) = sessions.lock().insert(user_id, Arc::clone(&superseded)) {
+            previous.notify_one();
+        }
+
+        Self {
+            sessions,
+            user_id,
+            superseded,
+        }
+    }
+}
+
+impl Drop for Session<'_> {
+    fn drop(&mut self) {
+        let mut sessions = self.sessions.lock();
+        if sessions.get(&self.user_id).is_some_and(|current| Arc::ptr_eq(current, &self.superseded))
+        {
+            sessions.remove(&self.user_id);
+        }
+    }
+}
+
+/// How a [`Wizard`] ended, along with the state it built up.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WizardOutcome<S> {
+    /// The last step was completed, or a step returned [`Transition::Finish`].
+    Finished(S),
+    /// A step returned [`Transition::Cancel`], or the user started another session of the
+    /// wizard.
+    Cancelled(S),
+    /// The user did not interact with a step in time.
+    TimedOut(S),
+}
+
+/// A multi-step interactive flow, such as configuring a bot for a guild, shown by repeatedly
+/// updating a single message.
+///
+/// Each step renders the message from the state built up so far, and handles the user's
+/// interaction with its components by updating the state and returning a [`Transition`]. Steps
+/// added with [`Self::modal_step`] open a modal instead when any of their components are used.
+///
+/// Only the user who started the wizard can interact with it, interactions by other users are
+/// acknowledged without effect and don't extend the [timeout] of the current step. Once the
+/// wizard ends, the components are removed from the message, so stale buttons can't be clicked
+/// anymore.
+///
+/// A wizard can be shared, such as via [`Context::data`], and run for several users at once, each
+/// with their own state. Running it again for a user who is still in a session cancels that
+/// session.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::builder::*;
+/// # use serenity::model::prelude::*;
+/// # use serenity::prelude::*;
+/// # use serenity::utils::{CreateQuickModal, Transition, Wizard, WizardOutcome};
+/// # async fn run(ctx: &Context, interaction: &CommandInteraction) -> serenity::Result<()> {
+/// #[derive(Default)]
+/// struct Setup {
+///     channel: Option<ChannelId>,
+///     greeting: Option<String>,
+/// }
+///
+/// let wizard = Wizard::new()
+///     .step(
+///         |_| {
+///             let select = CreateSelectMenu::new(
+///                 "channel",
+///                 CreateSelectMenuKind::Channel {
+///                     channel_types: None,
+///                     default_channels: None,
+///                 },
+///             );
+///             CreateInteractionResponseMessage::new()
+///                 .content("Where should new members be greeted?")
+///                 .select_menu(select)
+///         },
+///         |setup: &mut Setup, interaction| {
+///             if let ComponentInteractionDataKind::ChannelSelect {
+///                 values,
+///             } = &interaction.data.kind
+///             {
+///                 setup.channel = values.first().copied();
+///             }
+///             Transition::Next
+///         },
+///     )
+///     .modal_step(
+///         |_| {
+///             CreateInteractionResponseMessage::new()
+///                 .content("How should they be greeted?")
+///                 .button(CreateButton::new("greeting").label("Set greeting"))
+///         },
+///         |_| CreateQuickModal::new("Greeting").paragraph_field("Greeting"),
+///         |setup, response| {
+///             setup.greeting = response.inputs.first().map(|input| input.to_string());
+///             Transition::Next
+///         },
+///     );
+///
+/// let outcome =
+///     wizard.run(ctx, interaction.id, &interaction.token, interaction.user.id, Setup::default());
+/// if let WizardOutcome::Finished(setup) = outcome.await? {
+///     // Save the setup.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [timeout]: Self::timeout
+#[must_use]
+pub struct Wizard<S> {
+    steps: Vec<WizardStep<S>>,
+    timeout: Duration,
+    sessions: Mutex<HashMap<UserId, Arc<Notify>>>,
+}
+
+impl<S: Send> Wizard<S> {
+    /// Creates a wizard without any steps, which waits up to 5 minutes for each step.
+    pub fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            timeout: Duration::from_secs(5 * 60),
+            sessions: Mutex::default(),
+        }
+    }
+
+    /// Sets how long to wait for the user to interact with each step before the wizard times out.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Adds a step, rendered by `render` and handling interactions with its components via
+    /// `on_component`.
+    pub fn step(
+        mut self,
+        render: impl Fn(&S) -> CreateInteractionResponseMessage<'static> + Send + Sync + 'static,
+        on_component: impl Fn(&mut S, &ComponentInteraction) -> Transition + Send + Sync + 'static,
+    ) -> Self {
+        self.steps.push(WizardStep {
+            render: Box::new(render),
+            kind: StepKind::Component(Box::new(on_component)),
+        });
+        self
+    }
+
+    /// Adds a step, rendered by `render`, which opens the modal built by `modal` when any of its
+    /// components are used. The submitted modal is handled via `on_submit`.
+    ///
+    /// The modal's timeout is replaced by [`Self::timeout`].
+    pub fn modal_step(
+        mut self,
+        render: impl Fn(&S) -> CreateInteractionResponseMessage<'static> + Send + Sync + 'static,
+        modal: impl Fn(&S) -> CreateQuickModal<'static> + Send + Sync + 'static,
+        on_submit: impl Fn(&mut S, &QuickModalResponse) -> Transition + Send + Sync + 'static,
+    ) -> Self {
+        self.steps.push(WizardStep {
+            render: Box::new(render),
+            kind: StepKind::Modal {
+                modal: Box::new(modal),
+                on_submit: Box::new(on_submit),
+            },
+        });
+        self
+    }
+
+    /// Runs the wizard for the given user, responding to the interaction with the first step.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if responding to an interaction or editing the message fails.
+    pub async fn run(
+        &self,
+        ctx: &Context,
+        interaction_id: InteractionId,
+        token: &str,
+        user_id: UserId,
+        mut state: S,
+    ) -> Result<WizardOutcome<S>> {
+        let Some(first) = self.steps.first() else {
+            return Ok(WizardOutcome::Finished(state));
+        };
+
+        let session = Session::start(&self.sessions, user_id);
+        let response = CreateInteractionResponse::Message((first.render)(&state));
+        response.execute(&ctx.http, interaction_id, token).await?;
+        let message = ctx.http.get_original_interaction_response(token).await?;
+
+        let interactions =
+            ComponentInteractionCollector::new(ctx.shard.clone()).message_id(message.id).stream();
+        tokio::pin!(interactions);
+
+        let mut progress = Progress::default();
+        // Only interactions by the user extend the timeout.
+        let mut deadline = Instant::now() + self.timeout;
+        // The message is edited through the most recent interaction, as tokens expire.
+        let mut latest_token = FixedString::from_str_trunc(token);
+        loop {
+            let next = timeout_at(deadline, interactions.next());
+            let interaction = tokio::select! {
+                interaction = next => interaction.ok().flatten(),
+                () = session.superseded.notified() => {
+                    remove_components(ctx, &latest_token).await?;
+                    return Ok(WizardOutcome::Cancelled(state));
+                },
+            };
+
+            let Some(interaction) = interaction else {
+                remove_components(ctx, &latest_token).await?;
+                return Ok(WizardOutcome::TimedOut(state));
+            };
+
+            if interaction.user.id != user_id {
+                let response = CreateInteractionResponse::Acknowledge;
+                response.execute(&ctx.http, interaction.id, &interaction.token).await?;
+                continue;
+            }
+
+            let (transition, interaction_id, token) = match &self.steps[progress.step].kind {
+                StepKind::Component(on_component) => {
+                    let transition = on_component(&mut state, &interaction);
+                    (transition, interaction.id, interaction.token)
+                },
+                StepKind::Modal {
+                    modal,
+                    on_submit,
+                } => {
+                    let modal = modal(&state).timeout(self.timeout);
+                    let Some(response) =
+                        modal.execute(ctx, interaction.id, &interaction.token).await?
+                    else {
+                        remove_components(ctx, &interaction.token).await?;
+                        return Ok(WizardOutcome::TimedOut(state));
+                    };
+
+                    let transition = on_submit(&mut state, &response);
+                    (transition, response.interaction.id, response.interaction.token)
+                },
+            };
+            latest_token = token;
+
+            let Some(step) = progress.apply(transition, self.steps.len()) else {
+                let response = CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new().components(Vec::new()),
+                );
+                response.execute(&ctx.http, interaction_id, &latest_token).await?;
+
+                return Ok(match transition {
+                    Transition::Cancel => WizardOutcome::Cancelled(state),
+                    _ => WizardOutcome::Finished(state),
+                });
+            };
+            deadline = Instant::now() + self.timeout;
+
+            let render = &self.steps[step].render;
+            let response = CreateInteractionResponse::UpdateMessage(render(&state));
+            response.execute(&ctx.http, interaction_id, &latest_token).await?;
+        }
+    }
+}
+
+impl<S: Send> Default for Wizard<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn remove_components(ctx: &Context, token: &str) -> Result<()> {
+    let builder = EditInteractionResponse::new().components(Vec::new());
+    builder.execute(&ctx.http, token).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt as _;
+
+    use super::*;
+
+    #[test]
+    fn progress_transitions() {
+        let mut progress = Progress::default();
+        assert_eq!(progress.apply(Transition::Back, 4), Some(0));
+        assert_eq!(progress.apply(Transition::Next, 4), Some(1));
+        assert_eq!(progress.apply(Transition::Stay, 4), Some(1));
+        assert_eq!(progress.apply(Transition::Goto(3), 4), Some(3));
+        // Going to the current step doesn't need to be undone
+        assert_eq!(progress.apply(Transition::Goto(3), 4), Some(3));
+        assert_eq!(progress.history, [0, 1]);
+
+        assert_eq!(progress.apply(Transition::Back, 4), Some(1));
+        assert_eq!(progress.apply(Transition::Back, 4), Some(0));
+        assert_eq!(progress.apply(Transition::Back, 4), Some(0));
+        assert!(progress.history.is_empty());
+    }
+
+    #[test]
+    fn progress_finishes() {
+        let mut progress = Progress::default();
+        assert_eq!(progress.apply(Transition::Goto(1), 2), Some(1));
+        assert_eq!(progress.apply(Transition::Next, 2), None);
+        assert_eq!(progress.apply(Transition::Goto(2), 2), None);
+        assert_eq!(progress.apply(Transition::Finish, 2), None);
+        assert_eq!(progress.apply(Transition::Cancel, 2), None);
+        // Ending the wizard doesn't change the current step
+        assert_eq!(progress.step, 1);
+        assert_eq!(progress.history, [0]);
+    }
+
+    #[test]
+    fn sessions_supersede_each_other() {
+        let sessions = Mutex::default();
+        let user_id = UserId::new(1);
+
+        let first = Session::start(&sessions, user_id);
+        let second = Session::start(&sessions, user_id);
+        assert!(first.superseded.notified().now_or_never().is_some());
+        assert!(second.superseded.notified().now_or_never().is_none());
+
+        // Only the current session unregisters itself
+        drop(first);
+        assert!(sessions.lock().contains_key(&user_id));
+        drop(second);
+        assert!(sessions.lock().is_empty());
+    }
+}